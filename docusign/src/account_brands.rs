@@ -553,6 +553,7 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
         resource_content_type: &str,
+        body: crate::types::BrandResourcesPutForm,
     ) -> ClientResult<crate::types::BrandResources> {
         let url = self.client.url(
             &format!(
@@ -564,13 +565,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::PUT, &url, body.into_form()?)
             .await
     }
 }
@@ -31,7 +31,7 @@ impl Comments {
         account_id: &str,
         envelope_id: &str,
         encoding: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -47,12 +47,14 @@ impl Comments {
             None,
         );
         self.client
-            .get(
+            .request_download(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
+                "application/pdf",
             )
             .await
     }
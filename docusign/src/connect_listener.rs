@@ -0,0 +1,290 @@
+//! Receive Connect notifications: check DocuSign sent them and parse them.
+//!
+//! With HMAC turned on in a Connect configuration, DocuSign signs each
+//! notification with every secret made by `connect_secret()`, in
+//! `X-DocuSign-Signature-1`, `X-DocuSign-Signature-2` and so on: the base64
+//! HMAC-SHA256 of the body. Notifications are JSON, or XML for configurations
+//! still on the legacy format.
+//! See <https://developers.docusign.com/platform/webhooks/connect/hmac/>.
+//!
+//! ```
+//! # fn run(headers: &http::HeaderMap, body: &[u8]) -> Result<(), docusign::ClientError> {
+//! use docusign::connect_listener::{ConnectEvent, WebhookHandler};
+//!
+//! let handler = WebhookHandler::new("my-connect-secret");
+//! match handler.handle(headers, body)? {
+//!     ConnectEvent::Envelope(event) if event.event == "envelope-completed" => {
+//!         println!("{} was completed", event.envelope.envelope_id)
+//!     }
+//!     ConnectEvent::Recipient(event) => {
+//!         for signer in event.recipients.iter().flat_map(|r| &r.signers) {
+//!             println!("{} is {}", signer.email, signer.status);
+//!         }
+//!     }
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use serde_json::json;
+
+use crate::{
+    types,
+    utils::{hmac_sha256, verify_hmac_sha256},
+    ClientError, ClientResult,
+};
+
+/// What the headers DocuSign signs notifications in start with, one for each
+/// secret, numbered from 1.
+pub const SIGNATURE_HEADER_PREFIX: &str = "X-DocuSign-Signature-";
+
+/// Check the `X-DocuSign-Signature-N` values of a notification against its
+/// body, in constant time. Any signature made with any of the secrets will do.
+pub fn verify_signature<S>(secrets: &[S], signatures: &[&str], body: &[u8]) -> ClientResult<()>
+where
+    S: AsRef<[u8]>,
+{
+    let tags: Vec<Vec<u8>> = signatures
+        .iter()
+        .filter_map(|s| base64::decode(s.trim()).ok())
+        .collect();
+
+    if !verify_hmac_sha256(secrets, &tags, body) {
+        return Err(ClientError::InvalidWebhookSignature);
+    }
+
+    Ok(())
+}
+
+/// Sign a body the way DocuSign does, for `X-DocuSign-Signature-N`.
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    base64::encode(hmac_sha256(secret, body))
+}
+
+/// `envelope-*`: an envelope was sent, delivered, completed, declined,
+/// voided, etc.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EnvelopeEvent {
+    /// The event, e.g. `envelope-completed`.
+    pub event: String,
+    /// Empty for the legacy XML format.
+    pub account_id: String,
+    pub generated_date_time: String,
+    /// The envelope as of the event, without its recipients.
+    pub envelope: types::Envelope,
+    /// The envelope's recipients, if the configuration includes them.
+    pub recipients: Option<types::EnvelopeRecipients>,
+}
+
+/// `recipient-*`: a recipient of an envelope was sent it, signed it,
+/// declined it, etc.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecipientEvent {
+    /// The event, e.g. `recipient-completed`.
+    pub event: String,
+    pub account_id: String,
+    pub generated_date_time: String,
+    /// The ID of the recipient the event is about, within the envelope.
+    pub recipient_id: String,
+    /// The envelope as of the event, without its recipients.
+    pub envelope: types::Envelope,
+    /// The envelope's recipients, if the configuration includes them.
+    pub recipients: Option<types::EnvelopeRecipients>,
+}
+
+/// A Connect notification.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ConnectEvent {
+    Envelope(Box<EnvelopeEvent>),
+    Recipient(Box<RecipientEvent>),
+    /// An event we don't have a type for, e.g. `template-created`.
+    Other {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl ConnectEvent {
+    /// Parse the body of a notification, JSON or legacy XML.
+    pub fn parse(body: &[u8]) -> ClientResult<Self> {
+        if body.trim_ascii_start().starts_with(b"<") {
+            return Self::parse_xml(body);
+        }
+
+        let payload: serde_json::Value = serde_json::from_slice(body)?;
+        let field = |value: &serde_json::Value, name: &str| {
+            value
+                .get(name)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let event = field(&payload, "event");
+        let data = payload.get("data").cloned().unwrap_or_default();
+        let summary = match data.get("envelopeSummary") {
+            Some(summary) => summary.clone(),
+            None => json!({ "envelopeId": field(&data, "envelopeId") }),
+        };
+        let mut envelope: types::Envelope = serde_json::from_value(summary)?;
+        let recipients = envelope.recipients.take();
+
+        Ok(if event.starts_with("envelope-") {
+            ConnectEvent::Envelope(Box::new(EnvelopeEvent {
+                account_id: field(&data, "accountId"),
+                generated_date_time: field(&payload, "generatedDateTime"),
+                event,
+                envelope,
+                recipients,
+            }))
+        } else if event.starts_with("recipient-") {
+            ConnectEvent::Recipient(Box::new(RecipientEvent {
+                account_id: field(&data, "accountId"),
+                generated_date_time: field(&payload, "generatedDateTime"),
+                recipient_id: field(&data, "recipientId"),
+                event,
+                envelope,
+                recipients,
+            }))
+        } else {
+            ConnectEvent::Other { event, payload }
+        })
+    }
+
+    /// Parse a legacy `DocuSignEnvelopeInformation` notification, which is
+    /// always about an envelope, into the same types as the JSON format.
+    fn parse_xml(body: &[u8]) -> ClientResult<Self> {
+        let malformed = |reason: &str| ClientError::MalformedConnectXml {
+            reason: reason.to_string(),
+        };
+
+        let body = std::str::from_utf8(body).map_err(|_| malformed("not UTF-8"))?;
+        let document = roxmltree::Document::parse(body).map_err(|e| malformed(&e.to_string()))?;
+        let status = child(document.root_element(), "EnvelopeStatus")
+            .ok_or_else(|| malformed("no EnvelopeStatus"))?;
+
+        let text = |node: roxmltree::Node, name: &str| {
+            child(node, name)
+                .and_then(|n| n.text())
+                .unwrap_or_default()
+                .trim()
+                .to_string()
+        };
+
+        let mut recipients = serde_json::Map::new();
+        for recipient in child(status, "RecipientStatuses")
+            .into_iter()
+            .flat_map(|r| r.children())
+            .filter(|r| r.tag_name().name() == "RecipientStatus")
+        {
+            let kind = match text(recipient, "Type").as_str() {
+                "Signer" => "signers",
+                "CarbonCopy" => "carbonCopies",
+                "CertifiedDelivery" => "certifiedDeliveries",
+                "InPersonSigner" => "inPersonSigners",
+                "Agent" => "agents",
+                "Editor" => "editors",
+                "Intermediary" => "intermediaries",
+                _ => continue,
+            };
+
+            let entry = recipients.entry(kind).or_insert_with(|| json!([]));
+            if let Some(list) = entry.as_array_mut() {
+                list.push(json!({
+                    "recipientIdGuid": text(recipient, "RecipientId"),
+                    "email": text(recipient, "Email"),
+                    "name": text(recipient, "UserName"),
+                    "routingOrder": text(recipient, "RoutingOrder"),
+                    "clientUserId": text(recipient, "ClientUserId"),
+                    "status": text(recipient, "Status").to_lowercase(),
+                    "sentDateTime": text(recipient, "Sent"),
+                    "deliveredDateTime": text(recipient, "Delivered"),
+                    "signedDateTime": text(recipient, "Signed"),
+                    "declinedDateTime": text(recipient, "Declined"),
+                    "declinedReason": text(recipient, "DeclineReason"),
+                }));
+            }
+        }
+
+        let envelope_status = text(status, "Status").to_lowercase();
+        let envelope: types::Envelope = serde_json::from_value(json!({
+            "envelopeId": text(status, "EnvelopeID"),
+            "status": envelope_status,
+            "emailSubject": text(status, "Subject"),
+            "sender": {
+                "userName": text(status, "UserName"),
+                "email": text(status, "Email"),
+            },
+            "createdDateTime": text(status, "Created"),
+            "sentDateTime": text(status, "Sent"),
+            "deliveredDateTime": text(status, "Delivered"),
+            "completedDateTime": text(status, "Completed"),
+            "declinedDateTime": text(status, "Declined"),
+            "voidedReason": text(status, "VoidReason"),
+        }))?;
+
+        Ok(ConnectEvent::Envelope(Box::new(EnvelopeEvent {
+            event: format!("envelope-{}", envelope_status),
+            account_id: String::new(),
+            generated_date_time: text(status, "TimeGenerated"),
+            envelope,
+            recipients: Some(serde_json::from_value(recipients.into())?),
+        })))
+    }
+}
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children().find(|n| n.tag_name().name() == name)
+}
+
+/// Holds the Connect secrets a notification may be signed with, one for each
+/// `X-DocuSign-Signature-N` header, and turns signed notifications into
+/// `ConnectEvent`s.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    secrets: Vec<Vec<u8>>,
+}
+
+impl WebhookHandler {
+    /// Create a handler for notifications signed with a Connect secret.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: AsRef<[u8]>,
+    {
+        WebhookHandler {
+            secrets: vec![secret.as_ref().to_vec()],
+        }
+    }
+
+    /// Also accept notifications signed with another secret, e.g. a new one
+    /// before the old one is deleted.
+    pub fn with_secret<S>(&mut self, secret: S) -> &mut Self
+    where
+        S: AsRef<[u8]>,
+    {
+        self.secrets.push(secret.as_ref().to_vec());
+        self
+    }
+
+    /// Verify a notification from its headers and raw body, then parse it.
+    pub fn handle(&self, headers: &http::HeaderMap, body: &[u8]) -> ClientResult<ConnectEvent> {
+        let prefix = SIGNATURE_HEADER_PREFIX.to_lowercase();
+        let signatures: Vec<&str> = headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with(&prefix))
+            .filter_map(|(_, value)| value.to_str().ok())
+            .collect();
+        if signatures.is_empty() {
+            return Err(ClientError::MissingWebhookHeader {
+                header: "X-DocuSign-Signature-1",
+            });
+        }
+
+        verify_signature(&self.secrets, &signatures, body)?;
+
+        ConnectEvent::parse(body)
+    }
+}
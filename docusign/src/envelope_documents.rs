@@ -229,7 +229,7 @@ impl EnvelopeDocuments {
         shared_user_id: &str,
         show_changes: &str,
         watermark: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !certificate.is_empty() {
             query_args.push(("certificate".to_string(), certificate.to_string()));
@@ -273,12 +273,14 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .get(
+            .request_download(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
+                "application/pdf",
             )
             .await
     }
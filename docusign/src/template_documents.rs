@@ -164,7 +164,7 @@ impl TemplateDocuments {
         template_id: &str,
         encrypt: &str,
         show_changes: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encrypt.is_empty() {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
@@ -184,12 +184,14 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .get(
+            .request_download(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
+                "application/pdf",
             )
             .await
     }
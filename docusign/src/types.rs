@@ -104025,3 +104025,21 @@ pub struct Zip {
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
 }
+
+/// The `multipart/form-data` body of a request.
+#[derive(Debug, Clone, Default)]
+pub struct BrandResourcesPutForm {
+    /// Brand resource XML file.
+    pub file_xml: crate::utils::FilePart,
+}
+
+impl BrandResourcesPutForm {
+    /// Turn this into a form to send.
+    pub fn into_form(self) -> crate::ClientResult<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        let file_xml = self.file_xml;
+        form = form.part("file.xml", file_xml.into_part()?);
+
+        Ok(form)
+    }
+}
@@ -1 +1,302 @@
+use docusign::Client;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_get_document_returns_the_pdf() {
+    let server = MockServer::start().await;
+
+    let pdf = b"%PDF-1.7\n\xe2\xe3\xcf\xd3\n".to_vec();
+
+    Mock::given(method("GET"))
+        .and(path("/v2.1/accounts/acct/envelopes/env/documents/combined"))
+        .and(query_param("certificate", "true"))
+        .and(header("accept", "application/pdf"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(pdf.clone(), "application/pdf")
+                .insert_header("content-disposition", "file; filename=\"Signed.pdf\""),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client-id", "client-secret", "", "test-token", "");
+    client.with_host_override(server.uri());
+
+    let download = client
+        .envelope_documents()
+        .documents_get_document(
+            "acct", "combined", "env", "true", "", "", "", "", "", "", "", "",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(download.body.as_ref(), pdf.as_slice());
+    assert_eq!(download.content_type.as_deref(), Some("application/pdf"));
+    assert_eq!(download.filename.as_deref(), Some("Signed.pdf"));
+}
+
+#[tokio::test]
+async fn test_get_document_error_is_an_http_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2.1/accounts/acct/envelopes/env/documents/1"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "errorCode": "ENVELOPE_DOES_NOT_EXIST",
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client-id", "client-secret", "", "test-token", "");
+    client.with_host_override(server.uri());
+
+    let err = client
+        .envelope_documents()
+        .documents_get_document("acct", "1", "env", "", "", "", "", "", "", "", "", "")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        docusign::ClientError::HttpError { status, .. } if status == http::StatusCode::NOT_FOUND
+    ));
+}
+
+#[cfg(feature = "webhooks")]
+mod connect_listener {
+    use docusign::{
+        connect_listener::{sign, verify_signature, ConnectEvent, WebhookHandler},
+        ClientError,
+    };
+
+    const CONNECT_KEY: &str = "y8MZhB2W+xyE4pLRCJnAYfJ8fa7pkEwdBf2+GAtwnKk=";
+
+    fn headers(signatures: &[String]) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        for (i, signature) in signatures.iter().enumerate() {
+            headers.insert(
+                http::header::HeaderName::from_bytes(
+                    format!("X-DocuSign-Signature-{}", i + 1).as_bytes(),
+                )
+                .unwrap(),
+                signature.parse().unwrap(),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let body = br#"{"event":"envelope-sent"}"#;
+        let signature = sign(CONNECT_KEY.as_bytes(), body);
+
+        verify_signature(&[CONNECT_KEY], &[&signature], body).unwrap();
+        // With a signature for each secret, and either of them configured.
+        let other = sign(b"another secret", body);
+        verify_signature(&[CONNECT_KEY], &[&other, &signature], body).unwrap();
+        verify_signature(&["another secret"], &[&other, &signature], body).unwrap();
+
+        // Only signed with a key we don't have.
+        assert!(matches!(
+            verify_signature(&[CONNECT_KEY], &[&other], body),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+        // Headers that aren't base64, or are empty, don't count as signatures.
+        assert!(verify_signature(&[CONNECT_KEY], &["not base64!", ""], body).is_err());
+        assert!(verify_signature(&[CONNECT_KEY], &[], body).is_err());
+        assert!(matches!(
+            verify_signature(
+                &[CONNECT_KEY],
+                &[&signature],
+                br#"{"event":"envelope-voided"}"#
+            ),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+    }
+
+    #[test]
+    fn test_handle_json_events() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "event": "recipient-completed",
+            "apiVersion": "v2.1",
+            "uri": "/restapi/v2.1/accounts/8a4b6e4e-0000-0000-0000-000000000000/envelopes/93be49ab-0000-0000-0000-000000000000",
+            "retryCount": 0,
+            "configurationId": 10418,
+            "generatedDateTime": "2023-03-08T21:12:06.1170000Z",
+            "data": {
+                "accountId": "8a4b6e4e-0000-0000-0000-000000000000",
+                "userId": "f6a7ab0e-0000-0000-0000-000000000000",
+                "envelopeId": "93be49ab-0000-0000-0000-000000000000",
+                "recipientId": "1",
+                "envelopeSummary": {
+                    "status": "sent",
+                    "emailSubject": "Please sign this document",
+                    "envelopeId": "93be49ab-0000-0000-0000-000000000000",
+                    "sentDateTime": "2023-03-08T21:10:31.5230000Z",
+                    "recipients": {
+                        "signers": [{
+                            "email": "signer@example.com",
+                            "name": "Ann Signer",
+                            "recipientId": "1",
+                            "routingOrder": "1",
+                            "status": "completed",
+                            "signedDateTime": "2023-03-08T21:12:04.9700000Z",
+                        }],
+                        "carbonCopies": [{
+                            "email": "cc@example.com",
+                            "name": "Bo Copy",
+                            "recipientId": "2",
+                            "routingOrder": "2",
+                            "status": "created",
+                        }],
+                        "recipientCount": "2",
+                    },
+                },
+            },
+        }))
+        .unwrap();
+
+        let mut handler = WebhookHandler::new("an old secret");
+        handler.with_secret(CONNECT_KEY);
+        let event = handler
+            .handle(&headers(&[sign(CONNECT_KEY.as_bytes(), &body)]), &body)
+            .unwrap();
+
+        let event = match event {
+            ConnectEvent::Recipient(event) => event,
+            e => panic!("expected a recipient event, got {:?}", e),
+        };
+        assert_eq!(event.event, "recipient-completed");
+        assert_eq!(event.recipient_id, "1");
+        assert_eq!(event.account_id, "8a4b6e4e-0000-0000-0000-000000000000");
+        assert_eq!(event.envelope.status, "sent");
+        assert_eq!(event.envelope.recipients, None);
+        let recipients = event.recipients.unwrap();
+        assert_eq!(recipients.signers[0].email, "signer@example.com");
+        assert_eq!(recipients.signers[0].status, "completed");
+        assert_eq!(recipients.carbon_copies[0].name, "Bo Copy");
+
+        // Events without the envelope summary still say which envelope.
+        let event = ConnectEvent::parse(
+            br#"{"event":"envelope-voided","data":{"accountId":"a","envelopeId":"e"}}"#,
+        )
+        .unwrap();
+        match event {
+            ConnectEvent::Envelope(event) => {
+                assert_eq!(event.envelope.envelope_id, "e");
+                assert_eq!(event.recipients, None);
+            }
+            e => panic!("expected an envelope event, got {:?}", e),
+        }
+
+        let event = ConnectEvent::parse(br#"{"event":"template-created","data":{}}"#).unwrap();
+        assert!(matches!(event, ConnectEvent::Other { event, .. } if event == "template-created"));
+    }
+
+    #[test]
+    fn test_handle_xml_events() {
+        let body = br#"<?xml version="1.0" encoding="utf-8"?>
+<DocuSignEnvelopeInformation xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns="http://www.docusign.net/API/3.0">
+  <EnvelopeStatus>
+    <RecipientStatuses>
+      <RecipientStatus>
+        <Type>Signer</Type>
+        <Email>signer@example.com</Email>
+        <UserName>Ann Signer</UserName>
+        <RoutingOrder>1</RoutingOrder>
+        <Sent>2023-03-08T13:10:31.523</Sent>
+        <Delivered>2023-03-08T13:11:15.077</Delivered>
+        <Signed>2023-03-08T13:12:04.97</Signed>
+        <DeclineReason xsi:nil="true" />
+        <Status>Completed</Status>
+        <RecipientId>b2f4b6a4-0000-0000-0000-000000000000</RecipientId>
+      </RecipientStatus>
+      <RecipientStatus>
+        <Type>CarbonCopy</Type>
+        <Email>cc@example.com</Email>
+        <UserName>Bo Copy</UserName>
+        <RoutingOrder>2</RoutingOrder>
+        <Status>Completed</Status>
+        <RecipientId>c3a5c7b5-0000-0000-0000-000000000000</RecipientId>
+      </RecipientStatus>
+    </RecipientStatuses>
+    <TimeGenerated>2023-03-08T13:12:30.6433305</TimeGenerated>
+    <EnvelopeID>93be49ab-0000-0000-0000-000000000000</EnvelopeID>
+    <Subject>Please sign this document</Subject>
+    <UserName>Sender</UserName>
+    <Email>sender@example.com</Email>
+    <Status>Completed</Status>
+    <Created>2023-03-08T13:10:30.443</Created>
+    <Sent>2023-03-08T13:10:31.57</Sent>
+    <Delivered>2023-03-08T13:11:15.203</Delivered>
+    <Signed>2023-03-08T13:12:05.563</Signed>
+    <Completed>2023-03-08T13:12:05.563</Completed>
+  </EnvelopeStatus>
+</DocuSignEnvelopeInformation>"#;
+
+        let event = WebhookHandler::new(CONNECT_KEY)
+            .handle(&headers(&[sign(CONNECT_KEY.as_bytes(), body)]), body)
+            .unwrap();
+
+        let event = match event {
+            ConnectEvent::Envelope(event) => event,
+            e => panic!("expected an envelope event, got {:?}", e),
+        };
+        assert_eq!(event.event, "envelope-completed");
+        assert_eq!(event.generated_date_time, "2023-03-08T13:12:30.6433305");
+        assert_eq!(
+            event.envelope.envelope_id,
+            "93be49ab-0000-0000-0000-000000000000"
+        );
+        assert_eq!(event.envelope.status, "completed");
+        assert_eq!(event.envelope.email_subject, "Please sign this document");
+        assert_eq!(
+            event.envelope.completed_date_time,
+            "2023-03-08T13:12:05.563"
+        );
+        assert_eq!(
+            event.envelope.sender.as_ref().unwrap().email,
+            "sender@example.com"
+        );
+
+        let recipients = event.recipients.unwrap();
+        assert_eq!(recipients.signers.len(), 1);
+        assert_eq!(recipients.signers[0].name, "Ann Signer");
+        assert_eq!(recipients.signers[0].status, "completed");
+        assert_eq!(
+            recipients.signers[0].signed_date_time,
+            "2023-03-08T13:12:04.97"
+        );
+        assert_eq!(
+            recipients.signers[0].recipient_id_guid,
+            "b2f4b6a4-0000-0000-0000-000000000000"
+        );
+        assert_eq!(recipients.carbon_copies[0].email, "cc@example.com");
+    }
+
+    #[test]
+    fn test_handle_rejects_bad_notifications() {
+        let handler = WebhookHandler::new(CONNECT_KEY);
+        let body = b"<DocuSignEnvelopeInformation>";
+
+        assert!(matches!(
+            handler.handle(&http::HeaderMap::new(), body),
+            Err(ClientError::MissingWebhookHeader {
+                header: "X-DocuSign-Signature-1"
+            })
+        ));
+        assert!(matches!(
+            handler.handle(&headers(&[sign(b"another secret", body)]), body),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+        assert!(matches!(
+            handler.handle(&headers(&[sign(CONNECT_KEY.as_bytes(), body)]), body),
+            Err(ClientError::MalformedConnectXml { .. })
+        ));
+    }
+}
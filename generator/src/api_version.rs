@@ -0,0 +1,231 @@
+/*
+ * Some APIs, Shopify's being one, put a release in their paths, e.g.
+ * `/admin/api/2021-01/orders.json`, and their specs describe every operation
+ * once per release. We'd rather generate one function per operation, and have
+ * the client fill in the release it was configured with.
+ */
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Result};
+
+/// The path parameter the client fills in with its version, see
+/// `template::CLIENT_PARAMETERS`.
+const PARAMETER: &str = "{api_version}";
+
+/// The tag Shopify puts on the operations of its latest stable release.
+const LATEST_TAG: &str = "latest_api_version";
+
+/// Split a path into the part before the version, the version and the rest, if
+/// it has one.
+fn split(path: &str) -> Option<(&str, &str, &str)> {
+    let (prefix, rest) = path.split_once("/api/")?;
+    let (version, rest) = rest.split_once('/')?;
+
+    let is_release = version.len() == 7
+        && version.as_bytes()[4] == b'-'
+        && version
+            .chars()
+            .enumerate()
+            .all(|(i, c)| i == 4 || c.is_ascii_digit());
+    if !is_release && version != "unstable" {
+        return None;
+    }
+
+    Some((prefix, version, rest))
+}
+
+/// How much we'd like to generate an operation from a version: the latest
+/// release first, then stable releases newest first, then `unstable`.
+fn rank(version: &str, op: &openapiv3::Operation) -> (bool, bool, String) {
+    (
+        op.tags.iter().any(|t| t == LATEST_TAG),
+        version != "unstable",
+        version.to_string(),
+    )
+}
+
+/// Shopify names the operations of all but its latest release after the
+/// release, e.g. `deprecated_202001_get_checkouts_count`.
+fn operation_id(id: &str) -> String {
+    if let Some(rest) = id.strip_prefix("deprecated_") {
+        if let Some((version, rest)) = rest.split_once('_') {
+            if version == "unstable" || version.chars().all(|c| c.is_ascii_digit()) {
+                return rest.to_string();
+            }
+        }
+    }
+
+    id.to_string()
+}
+
+fn operations(item: &mut openapiv3::PathItem) -> Vec<(&'static str, &mut openapiv3::Operation)> {
+    [
+        ("get", item.get.as_mut()),
+        ("put", item.put.as_mut()),
+        ("post", item.post.as_mut()),
+        ("delete", item.delete.as_mut()),
+        ("patch", item.patch.as_mut()),
+    ]
+    .into_iter()
+    .filter_map(|(method, op)| op.map(|op| (method, op)))
+    .collect()
+}
+
+/// Replace the copies of each operation, one per version, with a single one
+/// whose path takes the version from the client.
+pub fn collapse(api: &mut openapiv3::OpenAPI) -> Result<()> {
+    let mut versions: BTreeMap<String, Vec<(String, openapiv3::PathItem)>> = BTreeMap::new();
+    let mut all: BTreeSet<String> = BTreeSet::new();
+
+    let paths = std::mem::take(&mut api.paths.paths);
+    for (path, item) in paths {
+        if let Some((prefix, version, rest)) = split(&path) {
+            let item = match item {
+                openapiv3::ReferenceOr::Item(item) => item,
+                openapiv3::ReferenceOr::Reference { reference } => {
+                    bail!("cannot collapse a path reference: {}", reference)
+                }
+            };
+
+            // Hold the place of the first copy, to keep the order of the paths.
+            let collapsed = format!("{}/api/{}/{}", prefix, PARAMETER, rest);
+            api.paths
+                .paths
+                .entry(collapsed.to_string())
+                .or_insert_with(|| openapiv3::ReferenceOr::Item(Default::default()));
+
+            all.insert(version.to_string());
+            versions
+                .entry(collapsed)
+                .or_default()
+                .push((version.to_string(), item));
+        } else {
+            api.paths.paths.insert(path, item);
+        }
+    }
+
+    for (path, mut items) in versions {
+        // Take each method from the best version that has it.
+        let mut chosen: BTreeMap<&'static str, (String, openapiv3::Operation, Vec<String>)> =
+            BTreeMap::new();
+        for (version, item) in items.iter_mut() {
+            for (method, op) in operations(item) {
+                let entry = chosen
+                    .entry(method)
+                    .or_insert_with(|| (version.to_string(), op.clone(), Vec::new()));
+                entry.2.push(version.to_string());
+                if rank(version, op) > rank(&entry.0, &entry.1) {
+                    entry.0 = version.to_string();
+                    entry.1 = op.clone();
+                }
+            }
+        }
+
+        let mut collapsed = openapiv3::PathItem::default();
+        for (method, (_, mut op, mut in_versions)) in chosen {
+            op.tags.retain(|t| t != LATEST_TAG);
+            op.operation_id = op.operation_id.as_deref().map(operation_id);
+
+            in_versions.sort();
+            if in_versions.len() < all.len() {
+                let note = format!("Only available in API versions {}.", in_versions.join(", "));
+                op.description = Some(match op.description {
+                    Some(d) if !d.trim().is_empty() => format!("{}\n\n{}", d.trim_end(), note),
+                    _ => note,
+                });
+            }
+
+            match method {
+                "get" => collapsed.get = Some(op),
+                "put" => collapsed.put = Some(op),
+                "post" => collapsed.post = Some(op),
+                "delete" => collapsed.delete = Some(op),
+                "patch" => collapsed.patch = Some(op),
+                _ => unreachable!(),
+            }
+        }
+
+        api.paths
+            .paths
+            .insert(path, openapiv3::ReferenceOr::Item(collapsed));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{collapse, operation_id};
+
+    #[test]
+    fn test_operation_id() {
+        assert_eq!(
+            operation_id("deprecated_202001_get_checkouts_count"),
+            "get_checkouts_count"
+        );
+        assert_eq!(
+            operation_id("deprecated_unstable_get_checkouts_count"),
+            "get_checkouts_count"
+        );
+        assert_eq!(operation_id("get_checkouts_count"), "get_checkouts_count");
+        assert_eq!(operation_id("deprecated_thing"), "deprecated_thing");
+    }
+
+    #[test]
+    fn test_collapse() {
+        let op = |id: &str, tags: &[&str]| {
+            serde_json::json!({
+                "operationId": id,
+                "tags": tags,
+                "responses": { "200": { "description": "" } },
+            })
+        };
+        let mut api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "test", "version": "1" },
+            "paths": {
+                "/admin/api/2020-01/orders.json": {
+                    "get": op("deprecated_202001_get_orders", &["orders"]),
+                    "post": op("deprecated_202001_create_orders", &["orders"]),
+                },
+                "/admin/api/2020-10/orders.json": {
+                    "get": op("get_orders", &["orders", "latest_api_version"]),
+                },
+                "/admin/api/unstable/orders.json": {
+                    "get": op("deprecated_unstable_get_orders", &["orders"]),
+                },
+                "/admin/oauth/access_scopes.json": {
+                    "get": op("get_access_scopes", &["access"]),
+                },
+            }
+        }))
+        .unwrap();
+
+        collapse(&mut api).unwrap();
+
+        let paths: Vec<&String> = api.paths.paths.keys().collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/admin/api/{api_version}/orders.json",
+                "/admin/oauth/access_scopes.json"
+            ]
+        );
+
+        let item = api.paths.paths["/admin/api/{api_version}/orders.json"]
+            .as_item()
+            .unwrap();
+
+        let get = item.get.as_ref().unwrap();
+        assert_eq!(get.operation_id.as_deref(), Some("get_orders"));
+        assert_eq!(get.tags, vec!["orders"]);
+        assert_eq!(get.description, None);
+
+        let post = item.post.as_ref().unwrap();
+        assert_eq!(post.operation_id.as_deref(), Some("create_orders"));
+        assert_eq!(
+            post.description.as_deref(),
+            Some("Only available in API versions 2020-01.")
+        );
+    }
+}
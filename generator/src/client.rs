@@ -1,6 +1,6 @@
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::struct_name;
+use crate::{security::ApiKeyAuth, struct_name};
 
 /*
  * Declare the client object:
@@ -364,11 +364,19 @@ impl Client {
         ).await
     }
 
-    async fn get_all_pages<D>(&self, uri: &str,  _message: Message) -> ClientResult<Vec<D>>
+    /// Lazily walk the pages of a request that links to the next page in its headers.
+    fn get_all_pages_stream<D>(&self, uri: &str) -> impl futures::Stream<Item = ClientResult<D>> + '_
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.unfold(uri).await
+        crate::utils::paginate(uri.to_string(), move |url| async move {
+            let (link, items) = self.get_pages(&url).await?;
+
+            // An empty page is the last page, whatever the headers say.
+            let next = if items.is_empty() { None } else { link.map(|l| l.0) };
+
+            Ok((items, next))
+        })
     }
 
     async fn get_pages<D>(&self, uri: &str) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
@@ -384,19 +392,6 @@ impl Client {
         ).await
     }
 
-    async fn get_pages_url<D>(&self, url: &reqwest::Url) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request(
-            http::Method::GET,
-            url.as_str(),
-            Message::default(),
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        ).await
-    }
-
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -479,31 +474,6 @@ impl Client {
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::Unconstrained,
         ).await
-    }
-
-    /// "unfold" paginated results of a vector of items
-    async fn unfold<D>(
-        &self,
-        uri: &str,
-    ) -> ClientResult<Vec<D>>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
-
-        Ok(global_items)
     }"#;
 
 pub fn generate_client_generic_token(
@@ -512,6 +482,7 @@ pub fn generate_client_generic_token(
     user_consent_endpoint: &str,
     add_post_header: &str,
     servers: &GeneratedServers,
+    api_version: Option<&str>,
 ) -> String {
     let mut new_from_env = basic_new_from_env(proper_name, add_post_header, servers);
     if proper_name.starts_with("Google") {
@@ -558,7 +529,75 @@ pub fn generate_client_generic_token(
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    // When the server has variables, so do the OAuth endpoints on the same host.
+    let (endpoints_struct, endpoints_fn, token_endpoint_ref, user_consent_endpoint_ref) =
+        if servers.variables.is_empty() {
+            ("", "", "TOKEN_ENDPOINT", "USER_CONSENT_ENDPOINT")
+        } else {
+            (
+                "token_endpoint: String,\n    user_consent_endpoint: String,",
+                "token_endpoint: server.substitute(TOKEN_ENDPOINT),\n                    \
+                 user_consent_endpoint: server.substitute(USER_CONSENT_ENDPOINT),",
+                "&self.token_endpoint",
+                "self.user_consent_endpoint",
+            )
+        };
+
+    // MailChimp accounts live on a datacenter of their own, which we only learn
+    // from the API key or from the metadata of an access token.
+    let (mailchimp_struct, mailchimp_fn, mailchimp_functions, after_access_token) =
+        if proper_name == "MailChimp" {
+            (
+                "api_key: String,",
+                "api_key: String::new(),",
+                MAILCHIMP_TEMPLATE,
+                r#"
+
+    // Point the client at the datacenter the account lives in.
+    let metadata = self.get_metadata().await?;
+    self.set_datacenter(&metadata.dc);"#,
+            )
+        } else {
+            ("", "", "", "")
+        };
+
+    // APIs with the version in their paths get it from the client.
+    let (api_version_block, api_version_struct, api_version_fn, api_version_functions) =
+        if let Some(version) = api_version {
+            (
+                api_version_template(version),
+                "api_version: ApiVersion,",
+                "api_version: ApiVersion::default(),",
+                API_VERSION_FUNCTIONS_TEMPLATE,
+            )
+        } else {
+            (String::new(), "", "", "")
+        };
+
+    // Shopify rate limits each store with a leaky bucket, and tells us how full it is.
+    // The throttle waits out a `429` itself, so the retries mustn't back off from it too.
+    let (retry_middleware, throttle) = if proper_name == "Shopify" {
+        (
+            "crate::throttle::RetryTransient",
+            r#"
+                    // Keep under the store's rate limit.
+                    .with(crate::throttle::Throttle::default())"#,
+        )
+    } else {
+        ("reqwest_retry::RetryTransientMiddleware", "")
+    };
+
+    let mut access_token_struct = ACCESS_TOKEN_STRUCT_TEMPLATE.to_string();
+    if proper_name == "MailChimp" {
+        access_token_struct.push_str(MAILCHIMP_METADATA_TEMPLATE);
+    }
+
+    let token_auth_template = get_token_auth_template(
+        consent_pattern,
+        token_endpoint_ref,
+        user_consent_endpoint_ref,
+        after_access_token,
+    );
 
     let server_block = servers.server_block();
     let server_arg = servers.server_arg();
@@ -576,7 +615,7 @@ const TOKEN_ENDPOINT: &str = "https://{}";
 const USER_CONSENT_ENDPOINT: &str = "https://{}";
 
 {server_block}
-
+{api_version_block}
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {{
@@ -586,7 +625,7 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-    {}
+    {}{endpoints_struct}{mailchimp_struct}{api_version_struct}
     auto_refresh: bool,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
@@ -615,8 +654,7 @@ impl Client {{
         redirect_uri: R,
         token: T,
         refresh_token: Q,
-        {}
-        {server_arg}
+        {}{server_arg}
     ) -> Self
     where
         I: ToString,
@@ -637,10 +675,10 @@ impl Client {{
                     // Retry failed requests.
                     .with(
                         reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                            {retry_middleware}::new_with_policy(retry_policy),
                             |req: &reqwest::Request| req.try_clone().is_some()
                         )
-                    )
+                    ){throttle}
                     .build();
 
                 {server_to_host}
@@ -656,7 +694,7 @@ impl Client {{
                         refresh_token: refresh_token.to_string(),
                         expires_at: None
                     }})),
-                    {}
+                    {}{endpoints_fn}{mailchimp_fn}{api_version_fn}
                     auto_refresh: false,
                     client,
                 }}
@@ -747,7 +785,7 @@ impl Client {{
 
     {}
 
-
+    {mailchimp_functions}{api_version_functions}
 
     {}
 
@@ -755,14 +793,14 @@ impl Client {{
         token_endpoint.trim_start_matches("https://"),
         user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
-        ACCESS_TOKEN_STRUCT_TEMPLATE,
+        access_token_struct,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
         add_post_header_fn,
         new_from_env,
         token_auth_template,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, add_post_header, &ApiKeyAuth::default())
     )
 }
 
@@ -896,6 +934,7 @@ pub fn generate_client_generic_api_key(
     proper_name: &str,
     add_post_header: &str,
     servers: &GeneratedServers,
+    auth: &ApiKeyAuth,
 ) -> String {
     let server_block = if servers.count > 0 {
         servers.output.as_deref().unwrap()
@@ -1004,11 +1043,11 @@ impl Client {{
     {}"#,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, add_post_header, auth)
     )
 }
 
-fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
+fn get_shared_functions(proper_name: &str, add_post_header: &str, auth: &ApiKeyAuth) -> String {
     let post_header_args = if !add_post_header.is_empty() {
         format!(
             r#"if method == reqwest::Method::POST {{
@@ -1024,10 +1063,21 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         String::new()
     };
 
-    let bearer = if proper_name == "Okta" {
-        "SSWS".to_string()
+    let auth_header = auth.header();
+
+    // Slack answers most failures with a `200 OK`, so check the envelope
+    // before we try to read the response.
+    let envelope_check = if proper_name == "Slack" {
+        "\n        crate::utils::slack_ok(&response_body)?;"
     } else {
-        "Bearer".to_string()
+        ""
+    };
+
+    // Drive files can be far bigger than we want to hold in memory.
+    let download_to_writer = if proper_name == "Google Drive" {
+        DOWNLOAD_TO_WRITER_TEMPLATE
+    } else {
+        ""
     };
 
     // Add auto refresh functionality to clients that support it
@@ -1040,9 +1090,13 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         || proper_name == "Slack"
         || proper_name == "Zoom"
     {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
+        get_shared_raw_functions_with_refresh(
+            "Bearer",
+            &post_header_args,
+            proper_name == "MailChimp",
+        )
     } else {
-        get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
+        get_shared_raw_functions_without_refresh(auth, &post_header_args)
     };
 
     format!(
@@ -1065,7 +1119,7 @@ async fn request<Out>(
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{envelope_check}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else {{
@@ -1106,7 +1160,7 @@ where
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{envelope_check}
 
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
@@ -1133,12 +1187,25 @@ async fn post_form<Out>(
 ) -> ClientResult<Out>
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
+{{
+    self.request_form(http::Method::POST, uri, form).await
+}}
+
+#[allow(dead_code)]
+async fn request_form<Out>(
+    &self,
+    method: http::Method,
+    uri: &str,
+    form: reqwest::multipart::Form,
+) -> ClientResult<Out>
+    where
+    Out: serde::de::DeserializeOwned + 'static + Send,
 {{
     let (url, auth) = self.url_and_auth(uri).await?;
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(http::Method::POST, url);
+    let mut req = instance.client.request(method, url);
 
     // Set the default headers.
     req = req.header(
@@ -1147,7 +1214,7 @@ async fn post_form<Out>(
     );
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
     req = req.multipart(form);
@@ -1159,7 +1226,7 @@ async fn post_form<Out>(
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{envelope_check}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
@@ -1205,7 +1272,7 @@ async fn request_with_accept_mime<Out>(
     );
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
     let response = req.send().await?;
@@ -1215,7 +1282,7 @@ async fn request_with_accept_mime<Out>(
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{envelope_check}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
@@ -1275,7 +1342,7 @@ async fn request_with_mime<Out>(
     );
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
     if content.len() > 1 {{
@@ -1291,7 +1358,7 @@ async fn request_with_mime<Out>(
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{envelope_check}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else {{
@@ -1309,6 +1376,80 @@ async fn request_with_mime<Out>(
     }}
 }}
 
+/* TODO: make this more DRY */
+/// Make a request for a file, handing back the raw body rather than parsing it.
+#[allow(dead_code)]
+async fn request_download(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    message: Message,
+    accept_mime_type: &str,
+) -> ClientResult<crate::utils::Download>
+{{
+    let (url, auth) = self.url_and_auth(uri).await?;
+
+    let instance = <&Client>::clone(&self);
+
+    let mut req = instance.client.request(method, url);
+
+    // Set the default headers.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+    );
+
+    if let Some(content_type) = &message.content_type {{
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str(content_type)?,
+        );
+    }}
+
+    if let Some(auth_str) = auth {{
+        req = req.header({auth_header}, &*auth_str);
+    }}
+
+    if let Some(body) = message.body {{
+        req = req.body(body);
+    }}
+
+    let response = req.send().await?;
+
+    let status = response.status();
+
+    let header = |name: reqwest::header::HeaderName| {{
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string())
+    }};
+    let content_type = header(reqwest::header::CONTENT_TYPE);
+    let filename = header(reqwest::header::CONTENT_DISPOSITION)
+        .and_then(|v| crate::utils::content_disposition_filename(&v));
+
+    let response_body = response.bytes().await?;
+
+    if status.is_success() {{
+        log::debug!("Received successful response. Read payload.");
+        Ok(crate::utils::Download {{
+            body: response_body,
+            content_type,
+            filename,
+        }})
+    }} else {{
+        let error = if response_body.is_empty() {{
+            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        }} else {{
+            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+        }};
+
+        Err(error)
+    }}
+}}
+
+{download_to_writer}
 async fn request_entity<D>(
     &self,
     method: http::Method,
@@ -1336,39 +1477,20 @@ where
     ).await
 }}
 
+/// Lazily walk the pages of a request that links to the next page in its headers.
 #[allow(dead_code)]
-async fn get_all_pages<D>(&self, uri: &str,  _message: Message) -> ClientResult<Vec<D>>
+fn get_all_pages_stream<D>(&self, uri: &str) -> impl futures::Stream<Item = ClientResult<D>> + '_
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
-    // TODO: implement this.
-    self.unfold(uri).await
-}}
+    crate::utils::paginate(uri.to_string(), move |url| async move {{
+        let (link, items) = self.get_pages(&url).await?;
 
-/// "unfold" paginated results of a vector of items
-#[allow(dead_code)]
-async fn unfold<D>(
-    &self,
-    uri: &str,
-) -> ClientResult<Vec<D>>
-where
-    D: serde::de::DeserializeOwned + 'static + Send,
-{{
-    let mut global_items = Vec::new();
-    let (new_link, mut items) = self.get_pages(uri).await?;
-    let mut link = new_link;
-    while !items.is_empty() {{
-        global_items.append(&mut items);
-        // We need to get the next link.
-        if let Some(url) = link.as_ref() {{
-            let url = reqwest::Url::parse(&url.0)?;
-            let (new_link, new_items) = self.get_pages_url(&url).await?;
-            link = new_link;
-            items = new_items;
-        }}
-    }}
+        // An empty page is the last page, whatever the headers say.
+        let next = if items.is_empty() {{ None }} else {{ link.map(|l| l.0) }};
 
-    Ok(global_items)
+        Ok((items, next))
+    }})
 }}
 
 #[allow(dead_code)]
@@ -1383,18 +1505,6 @@ where
     ).await
 }}
 
-#[allow(dead_code)]
-async fn get_pages_url<D>(&self, url: &reqwest::Url) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-where
-    D: serde::de::DeserializeOwned + 'static + Send,
-{{
-    self.request_with_links(
-        http::Method::GET,
-        url.as_str(),
-        Message::default(),
-    ).await
-}}
-
 #[allow(dead_code)]
 async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
 where
@@ -1442,20 +1552,95 @@ where
         message,
     ).await
 }}"#,
-        raw_request
+        raw_request,
+        envelope_check = envelope_check,
+        download_to_writer = download_to_writer
     )
 }
 
-fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
+const DOWNLOAD_TO_WRITER_TEMPLATE: &str = r#"
+/* TODO: make this more DRY */
+/// Make a request for a file, writing the body to `writer` as it arrives
+/// rather than holding it in memory.
+#[allow(dead_code)]
+async fn request_download_to_writer<W>(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    message: Message,
+    accept_mime_type: &str,
+    writer: &mut W,
+) -> ClientResult<u64>
+where
+    W: tokio::io::AsyncWrite + Unpin + Send + ?Sized,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let (url, auth) = self.url_and_auth(uri).await?;
+
+    let instance = <&Client>::clone(&self);
+
+    let mut req = instance.client.request(method, url);
+
+    // Set the default headers.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+    );
+
+    if let Some(content_type) = &message.content_type {
+        req = req.header(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str(content_type)?,
+        );
+    }
+
+    if let Some(auth_str) = auth {
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }
+
+    if let Some(body) = message.body {
+        req = req.body(body);
+    }
+
+    let mut response = req.send().await?;
+
+    let status = response.status();
+
+    if !status.is_success() {
+        let response_body = response.bytes().await?;
+        let error = if response_body.is_empty() {
+            ClientError::HttpError{status: status, error: "empty response".into()}
+        } else {
+            ClientError::HttpError{status: status, error: String::from_utf8_lossy(&response_body).into()}
+        };
+
+        return Err(error);
+    }
+
+    log::debug!("Received successful response. Write payload.");
+    let mut written = 0;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await?;
+
+    Ok(written)
+}
+"#;
+
+fn get_shared_raw_functions_without_refresh(auth: &ApiKeyAuth, post_header_args: &str) -> String {
+    let url_and_auth = auth.url_and_auth();
+    let auth_header = auth.header();
+
     format!(
         r#"
 async fn url_and_auth(
     &self,
     uri: &str,
 ) -> ClientResult<(reqwest::Url, Option<String>)> {{
-    let parsed_url = uri.parse::<reqwest::Url>()?;
-    let auth = format!("{} {{}}", self.token);
-    Ok((parsed_url, Some(auth)))
+    {url_and_auth}
 }}
 
 async fn request_raw(
@@ -1488,7 +1673,7 @@ async fn request_raw(
 
     {}
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
     if let Some(body) = message.body {{
         req = req.body(body);
@@ -1496,11 +1681,33 @@ async fn request_raw(
     Ok(req.send().await?)
 }}
 "#,
-        bearer, post_header_args
+        post_header_args
     )
 }
 
-fn get_shared_raw_functions_with_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_shared_raw_functions_with_refresh(
+    bearer: &str,
+    post_header_args: &str,
+    api_key_auth: bool,
+) -> String {
+    let auth = if api_key_auth {
+        // Clients that were given an API key send it with basic auth, the
+        // username can be anything.
+        format!(
+            r#"let auth = if self.api_key.is_empty() {{
+        format!("{} {{}}", self.token.read().await.access_token)
+    }} else {{
+        format!("Basic {{}}", base64::encode(format!("anystring:{{}}", self.api_key)))
+    }};"#,
+            bearer
+        )
+    } else {
+        format!(
+            r#"let auth = format!("{} {{}}", self.token.read().await.access_token);"#,
+            bearer
+        )
+    };
+
     format!(
         r#"
 async fn url_and_auth(
@@ -1509,7 +1716,7 @@ async fn url_and_auth(
 ) -> ClientResult<(reqwest::Url, Option<String>)> {{
     let parsed_url = uri.parse::<reqwest::Url>()?;
 
-    let auth = format!("{} {{}}", self.token.read().await.access_token);
+    {auth}
     Ok((parsed_url, Some(auth)))
 }}
 
@@ -1594,11 +1801,16 @@ async fn request_raw(
 
     Ok(resp)
 }}"#,
-        bearer, post_header_args
+        post_header_args
     )
 }
 
-fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
+fn get_token_auth_template<S: AsRef<str>>(
+    consent_pattern: S,
+    token_endpoint: &str,
+    user_consent_endpoint: &str,
+    after_access_token: &str,
+) -> String {
     format!(
         r#"
 /// Return a user consent url with an optional set of scopes.
@@ -1608,7 +1820,7 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 
     let url = format!(
         "{}",
-        USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
+        {user_consent_endpoint}, self.client_id, self.redirect_uri, state
     );
 
     if scopes.is_empty() {{
@@ -1644,7 +1856,7 @@ pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
         ];
         let client = reqwest::Client::new();
         client
-            .post(TOKEN_ENDPOINT)
+            .post({token_endpoint})
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -1685,7 +1897,7 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
     ];
     let client = reqwest::Client::new();
     let resp = client
-        .post(TOKEN_ENDPOINT)
+        .post({token_endpoint})
         .headers(headers)
         .form(&params)
         .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -1699,7 +1911,7 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
         access_token: t.access_token.clone(),
         refresh_token: t.refresh_token.clone(),
         expires_at: Self::compute_expires_at(t.expires_in),
-    }};
+    }};{after_access_token}
 
     Ok(t)
 }}"#,
@@ -1875,10 +2087,170 @@ impl Client {{
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         CLIENT_AUTH_TEMPLATE,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, add_post_header, &ApiKeyAuth::default())
+    )
+}
+
+fn api_version_template(version: &str) -> String {
+    format!(
+        r#"
+/// A release of the API, which every request is made against, e.g. `{version}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiVersion(String);
+
+impl ApiVersion {{
+    pub fn new<V>(version: V) -> Self
+    where
+        V: ToString,
+    {{
+        ApiVersion(version.to_string())
+    }}
+
+    /// The release still being worked on, which may change without notice.
+    pub fn unstable() -> Self {{
+        ApiVersion::new("unstable")
+    }}
+
+    pub fn as_str(&self) -> &str {{
+        &self.0
+    }}
+}}
+
+impl Default for ApiVersion {{
+    /// The release this crate was generated for.
+    fn default() -> Self {{
+        ApiVersion::new("{version}")
+    }}
+}}
+
+impl std::fmt::Display for ApiVersion {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(&self.0)
+    }}
+}}
+
+impl std::str::FromStr for ApiVersion {{
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        Ok(ApiVersion::new(s))
+    }}
+}}
+"#
     )
 }
 
+const API_VERSION_FUNCTIONS_TEMPLATE: &str = r#"/// Make requests against another release of the API.
+pub fn with_api_version(&mut self, api_version: ApiVersion) -> &mut Self {
+    self.api_version = api_version;
+    self
+}
+
+/// The release of the API requests are made against.
+pub fn api_version(&self) -> &ApiVersion {
+    &self.api_version
+}
+"#;
+
+const MAILCHIMP_TEMPLATE: &str = r#"/// Create a new Client struct from an API key, which is sent with basic auth.
+///
+/// The datacenter the account lives on is taken from the end of the key, for
+/// example `us6` for `0123456789abcdef-us6`.
+pub fn new_with_api_key<K>(api_key: K) -> Self
+where
+    K: ToString,
+{
+    let api_key = api_key.to_string();
+
+    let mut client = Client::new("", "", "", "", "");
+    if let Some((_, dc)) = api_key.rsplit_once('-') {
+        client.set_datacenter(dc);
+    }
+    client.api_key = api_key;
+
+    client
+}
+
+/// Create a new Client struct from the `MAILCHIMP_API_KEY` environment variable.
+pub fn new_with_api_key_from_env() -> Self {
+    let api_key = env::var("MAILCHIMP_API_KEY").expect("must set MAILCHIMP_API_KEY");
+
+    Client::new_with_api_key(api_key)
+}
+
+/// Point the client at the datacenter an account lives on, for example `us6`.
+pub fn set_datacenter(&mut self, dc: &str) -> &mut Self {
+    self.host = format!("https://{}.api.mailchimp.com/3.0", dc);
+    self
+}
+
+/// Get the metadata for the current access token, including the datacenter
+/// the account lives on.
+pub async fn get_metadata(&self) -> ClientResult<Metadata> {
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(METADATA_ENDPOINT)
+        .header(
+            reqwest::header::AUTHORIZATION,
+            format!("OAuth {}", self.token.read().await.access_token),
+        )
+        .header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        return Err(ClientError::HttpError {
+            status,
+            error: resp.text().await?,
+        });
+    }
+
+    Ok(resp.json().await?)
+}"#;
+
+const MAILCHIMP_METADATA_TEMPLATE: &str = r#"
+const METADATA_ENDPOINT: &str = "https://login.mailchimp.com/oauth2/metadata";
+
+/// The account an access token belongs to.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The datacenter the account lives on, for example `us6`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dc: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub role: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub accountname: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_endpoint: String,
+}"#;
+
 const ACCESS_TOKEN_STRUCT_TEMPLATE: &str = r#"use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -1923,6 +2295,10 @@ pub struct GeneratedServers {
     pub count: u64,
     pub output: Option<String>,
     pub top_level_type: Option<String>,
+    /// The variables a single server needs filled in, e.g. `shop` for Shopify.
+    pub variables: Vec<String>,
+    /// Those of `variables` without a default, which its `new` takes.
+    pub required_variables: Vec<String>,
 }
 
 impl GeneratedServers {
@@ -1936,13 +2312,15 @@ impl GeneratedServers {
                 "server: impl Into<{}>,",
                 self.top_level_type.as_ref().unwrap()
             )
+        } else if !self.variables.is_empty() {
+            format!("server: {},", self.top_level_type.as_ref().unwrap())
         } else {
             String::new()
         }
     }
 
     pub fn server_param(&self) -> String {
-        if self.count > 1 {
+        if self.count > 1 || !self.variables.is_empty() {
             "server,".to_string()
         } else {
             String::new()
@@ -1952,6 +2330,8 @@ impl GeneratedServers {
     pub fn host_from_server(&self) -> String {
         if self.count > 1 {
             String::from("let host = server.into().default_url().to_string();")
+        } else if !self.variables.is_empty() {
+            String::from("let host = server.default_url();")
         } else if self.count == 1 {
             format!(
                 "let host = {}::default().default_url().to_string();",
@@ -1961,6 +2341,27 @@ impl GeneratedServers {
             String::from("let host = FALLBACK_HOST.to_string();")
         }
     }
+
+    /// An example of the server argument for the docs, if the client takes one.
+    pub fn doc_example(&self) -> String {
+        if self.count > 1 || self.variables.is_empty() {
+            return String::new();
+        }
+
+        let top_level_type = self.top_level_type.as_ref().unwrap();
+        if self.required_variables.is_empty() {
+            return format!("{}::default()", top_level_type);
+        }
+
+        let args = self
+            .required_variables
+            .iter()
+            .map(|v| format!("\"{}\"", v.replace('_', "-")))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{}::new({})", top_level_type, args)
+    }
 }
 
 pub fn generate_servers(servers: &[openapiv3::Server], server_prefix: &str) -> GeneratedServers {
@@ -2020,6 +2421,8 @@ impl {server_enum} {{
 "#
             )),
             top_level_type: Some(server_enum),
+            variables: Default::default(),
+            required_variables: Default::default(),
         }
     } else if servers.len() == 1 {
         let server = &servers[0];
@@ -2030,36 +2433,152 @@ impl {server_enum} {{
             count: servers.len() as u64,
             output: Some(server_struct),
             top_level_type: Some(server_struct_name),
+            variables: server
+                .variables
+                .as_ref()
+                .map(|v| v.keys().cloned().collect())
+                .unwrap_or_default(),
+            required_variables: server
+                .variables
+                .iter()
+                .flatten()
+                .filter(|(_, v)| v.default.is_empty())
+                .map(|(k, _)| k.clone())
+                .collect(),
         }
     } else {
         GeneratedServers::default()
     }
 }
 
-// TODO: Only default server urls are supported
 fn generate_server(server_name: &str, server: &openapiv3::Server) -> String {
-    let mut url = server.url.clone();
+    let variables = match &server.variables {
+        Some(variables) if !variables.is_empty() => variables,
+        _ => {
+            return format!(
+                r#"
+#[derive(Debug, Default, Clone)]
+pub struct {server_name} {{
+}}
 
-    if let Some(variables) = &server.variables {
-        for (variable_name, variable) in variables {
-            let key = format!(r#"{{{variable_name}}}"#);
-            url = url.replace(&key, &variable.default);
+impl {server_name} {{
+    pub fn default_url(&self) -> &str {{
+        "{}"
+    }}
+}}
+"#,
+                server.url
+            );
+        }
+    };
+
+    // Servers with variables hold a value for each of them, which is filled
+    // into the url (and anything else on the same host) when it is used.
+    let mut fields = String::new();
+    let mut params = Vec::new();
+    let mut values = String::new();
+    let mut replacements = String::new();
+    for (variable_name, variable) in variables {
+        let field = to_snake_case(variable_name);
+        if let Some(description) = &variable.description {
+            fields.push_str(&format!("/// {}\n", description.replace('\n', "\n/// ")));
+        }
+        fields.push_str(&format!("pub {field}: String,\n"));
+        if variable.default.is_empty() {
+            params.push(format!("{field}: impl ToString"));
+            values.push_str(&format!("{field}: {field}.to_string(),\n"));
+        } else {
+            values.push_str(&format!("{field}: \"{}\".to_string(),\n", variable.default));
         }
+        replacements.push_str(&format!(".replace(\"{{{variable_name}}}\", &self.{field})"));
+    }
+
+    // A variable without a default has no sensible empty value (an empty shop
+    // makes for a host of `https://.myshopify.com`), so it has to be given.
+    let (default_impl, constructor) = if params.is_empty() {
+        (
+            format!(
+                r#"impl Default for {server_name} {{
+    fn default() -> Self {{
+        {server_name} {{
+            {values}
+        }}
+    }}
+}}"#
+            ),
+            String::new(),
+        )
+    } else {
+        (
+            String::new(),
+            format!(
+                r#"pub fn new({}) -> Self {{
+        {server_name} {{
+            {values}
+        }}
+    }}
+"#,
+                params.join(", ")
+            ),
+        )
     };
 
-    let server_struct = format!(
+    format!(
         r#"
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct {server_name} {{
+    {fields}
 }}
 
+{default_impl}
+
 impl {server_name} {{
-    pub fn default_url(&self) -> &str {{
-        "{url}"
+    {constructor}
+    pub fn default_url(&self) -> String {{
+        self.substitute("{}")
+    }}
+
+    /// Fill the server variables into `url`.
+    pub fn substitute(&self, url: &str) -> String {{
+        url{replacements}
     }}
 }}
-"#
-    );
+"#,
+        server.url
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(default: &str) -> openapiv3::Server {
+        serde_json::from_value(serde_json::json!({
+            "url": "https://{shop}.myshopify.com",
+            "variables": { "shop": { "default": default } },
+        }))
+        .unwrap()
+    }
 
-    server_struct
+    #[test]
+    fn test_server_variable_without_default_is_required() {
+        let servers = generate_servers(&[server("")], "Root");
+        let output = servers.server_block();
+
+        assert!(!output.contains("Default,"));
+        assert!(!output.contains("impl Default"));
+        assert!(output.contains("pub fn new(shop: impl ToString) -> Self"));
+        assert_eq!(servers.doc_example(), r#"RootDefaultServer::new("shop")"#);
+    }
+
+    #[test]
+    fn test_server_variable_with_default_is_defaulted() {
+        let servers = generate_servers(&[server("acme")], "Root");
+        let output = servers.server_block();
+
+        assert!(output.contains("impl Default for RootDefaultServer"));
+        assert!(output.contains(r#"shop: "acme".to_string(),"#));
+        assert!(!output.contains("pub fn new("));
+        assert_eq!(servers.doc_example(), "RootDefaultServer::default()");
+    }
 }
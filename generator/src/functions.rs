@@ -13,6 +13,8 @@ use crate::{
 pub struct FileOutput {
     pub head: String,
     pub impl_content: String,
+    /// Types the functions need which aren't in the type space, for types.rs.
+    pub types: String,
 }
 
 impl FileOutput {
@@ -20,6 +22,10 @@ impl FileOutput {
         self.head.push_str(head);
     }
 
+    fn add_types(&mut self, types: &str) {
+        self.types.push_str(types);
+    }
+
     fn add_content(&mut self, content: &str) {
         self.impl_content.push_str(content);
     }
@@ -204,15 +210,36 @@ pub fn generate_files(
                                 (None, None)
                             }
                         } else if ct == "multipart/form-data" {
-                            println!("got multipart/formdata for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
+                            // reqwest sets the content type, along with the boundary.
+                            body_content_type_header = None;
+
+                            if let Some((form, code)) = get_multipart_form(ts, &od, mt)? {
+                                out.add_types(&code);
+                                (Some(form), Some("multipart".to_string()))
+                            } else {
+                                println!("got multipart/formdata without properties for {}", oid);
+                                (None, None)
+                            }
                         } else if ct == "application/x-www-form-urlencoded" {
-                            println!("got application/x-www-form-urlencoded for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
+                            if let Some(s) = &mt.schema {
+                                let object_name = format!("{} request", oid_to_object_name(&od));
+                                let id = ts.select(Some(&object_name), s, "")?;
+                                let et = ts.id_to_entry.get(&id).unwrap();
+                                if let crate::TypeDetails::Object(p, _) = &et.details {
+                                    // We want to make sure we actally have properties
+                                    // in our object.
+                                    if p.is_empty() {
+                                        (None, None)
+                                    } else {
+                                        let rt = ts.render_type(&id, false)?;
+                                        (Some(format!("&{}", rt)), Some("form".to_string()))
+                                    }
+                                } else {
+                                    (None, None)
+                                }
+                            } else {
+                                (None, None)
+                            }
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
@@ -231,7 +258,30 @@ pub fn generate_files(
                     let object_name = format!("{} request", oid_to_object_name(&od));
                     let id = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
                     let rt = ts.render_type(&id, false)?;
-                    (Some(format!("&{}", rt)), Some("json".to_string()))
+
+                    // Referenced request bodies can be form encoded as well, so
+                    // make sure we send them the way the spec describes.
+                    let is_form = api
+                        .components
+                        .as_ref()
+                        .and_then(|c| {
+                            c.request_bodies
+                                .get(reference.trim_start_matches("#/components/requestBodies/"))
+                        })
+                        .and_then(|r| r.item().ok())
+                        .map(|r| {
+                            r.content.len() == 1
+                                && r.content.contains_key("application/x-www-form-urlencoded")
+                        })
+                        .unwrap_or_default();
+
+                    if is_form {
+                        body_content_type_header =
+                            Some("application/x-www-form-urlencoded".to_string());
+                        (Some(format!("&{}", rt)), Some("form".to_string()))
+                    } else {
+                        (Some(format!("&{}", rt)), Some("json".to_string()))
+                    }
                 } else {
                     (None, None)
                 }
@@ -270,8 +320,17 @@ pub fn generate_files(
             /*
              * Get the response type.
              */
-            let (mut response_type, tid, inner_response_type, pagination_property) =
-                get_response_type(&od, ts, o)?;
+            let (mut response_type, tid, mut inner_response_type, mut pagination_property) =
+                get_response_type(proper_name, &od, ts, o)?;
+
+            // Shopify wraps its lists in an object keyed by the resource, for
+            // example `{"orders": [...]}`, and links to the next page in its headers.
+            if proper_name == "Shopify" && inner_response_type.is_empty() {
+                if let Some((rt, property)) = get_list_property(ts, &tid)? {
+                    inner_response_type = rt;
+                    pagination_property = property;
+                }
+            }
 
             if proper_name == "GitHub" && response_type == "crate::types::Data" {
                 response_type = "()".to_string();
@@ -284,16 +343,36 @@ pub fn generate_files(
                     .to_string();
             }
 
+            // Google methods that can hand back a file, but don't describe a
+            // response of their own, only ever answer with the file.
+            let google_media = is_google_media_download(proper_name, &op_id);
+            let download = if google_media && response_type == "()" {
+                response_type = "crate::utils::Download".to_string();
+                Some("*/*".to_string())
+            } else {
+                binary_response_content_type(o)
+            };
+
+            // Slack's responses carry more than the items (the `ok` envelope,
+            // `response_metadata`, etc), so a single page hands back the whole thing.
+            // So does Shopify's, since the cursor for the next page isn't in it.
+            let single_page_inner = if proper_name == "Slack" || proper_name == "Shopify" {
+                ""
+            } else {
+                inner_response_type.as_str()
+            };
+
             let mut fn_inner = get_fn_inner(
                 proper_name,
                 &oid,
                 m,
                 &body_func,
                 &response_type,
-                &inner_response_type,
+                single_page_inner,
                 &pagination_property,
                 false,
                 body_content_type_header.as_deref(),
+                download.as_deref(),
             )?;
 
             // TODO: don't special case this.
@@ -332,9 +411,24 @@ pub fn generate_files(
             // This is specifically for Ramp.
             // We do this directly before we print the other function.
             let mut frt = response_type.to_string();
+            if !single_page_inner.is_empty() {
+                frt = single_page_inner.to_string();
+            }
+
+            // What getting all the pages returns.
+            let mut all_rt = frt.to_string();
             if !inner_response_type.is_empty() {
-                frt = inner_response_type.to_string();
+                all_rt = inner_response_type.to_string();
             }
+            // Slack only pages by cursor for the methods which take one.
+            let paginates = proper_name != "Slack"
+                || inner_response_type.is_empty()
+                || o.parameters.iter().any(|par| match par {
+                    openapiv3::ReferenceOr::Item(item) => {
+                        get_parameter_data(item).map(|d| d.name.as_str()) == Some("cursor")
+                    }
+                    openapiv3::ReferenceOr::Reference { .. } => false,
+                });
 
             let mut fn_name = oid
                 .trim_start_matches(&tag)
@@ -382,14 +476,127 @@ pub fn generate_files(
                 &fn_name,
             ));
 
+            // Requests that can be sent as a form as well, usually to upload a
+            // file rather than point at one, get a function for that too.
+            let alternative_form = o
+                .request_body
+                .as_ref()
+                .and_then(|b| b.item().ok())
+                .and_then(|b| b.content.get("multipart/form-data"));
+            if let Some(mt) = alternative_form.filter(|_| body_func.as_deref() != Some("multipart"))
+            {
+                if let Some((form, code)) = get_multipart_form(ts, &od, mt)? {
+                    out.add_types(&code);
+
+                    let form_fn_name = format!("{}_multipart", fn_name);
+                    fn_names.push(form_fn_name.clone() + &tag);
+
+                    out.add_content(&print_fn(
+                        &docs,
+                        &Vec::new(),
+                        &fn_params_str,
+                        &Some(form),
+                        &frt,
+                        &template,
+                        &get_fn_inner(
+                            proper_name,
+                            &oid,
+                            m,
+                            &Some("multipart".to_string()),
+                            &response_type,
+                            single_page_inner,
+                            &pagination_property,
+                            false,
+                            None,
+                            None,
+                        )?,
+                        &form_fn_name,
+                    ));
+                }
+            }
+
+            if google_media {
+                let message = "crate::Message { body: None, content_type: None }";
+
+                // Methods with metadata of their own hand back the file when
+                // asked for `alt=media`.
+                let mut media_fn_name = fn_name.to_string();
+                let mut media_url = String::new();
+                if download.is_none() {
+                    media_fn_name = fn_name.replacen("get", "download", 1);
+                    media_url =
+                        "let url = crate::utils::add_query_param(&url, \"alt\", \"media\");\n"
+                            .to_string();
+
+                    let docs = get_fn_docs_all(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function returns the content of the file rather than its metadata.",
+                            fn_name
+                        ),
+                    )?;
+
+                    out.add_content(&print_fn(
+                        &docs,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        "crate::utils::Download",
+                        &template,
+                        &format!(
+                            r#"{}self.client.request_download(reqwest::Method::{}, &url, {}, "*/*").await"#,
+                            media_url, m, message
+                        ),
+                        &media_fn_name,
+                    ));
+                }
+
+                // Files can be far too big to hold in memory, so they can be
+                // written out as they arrive instead.
+                let docs = get_fn_docs_all(
+                    o,
+                    m,
+                    p,
+                    &format!(
+                        "As opposed to `{}`, this function writes the file to `writer` as it arrives, \
+                         rather than holding all of it in memory, and returns the number of bytes written.",
+                        media_fn_name
+                    ),
+                )?;
+
+                let mut writer_bounds = bounds.clone();
+                writer_bounds.push("W: tokio::io::AsyncWrite + Unpin + Send + ?Sized".to_string());
+                let mut writer_params = fn_params_str.clone();
+                writer_params.push("writer: &mut W,".to_string());
+
+                out.add_content(&print_fn(
+                    &docs,
+                    &writer_bounds,
+                    &writer_params,
+                    &body_param,
+                    "u64",
+                    &template,
+                    &format!(
+                        r#"{}self.client.request_download_to_writer(reqwest::Method::{}, &url, {}, "*/*", writer).await"#,
+                        media_url, m, message
+                    ),
+                    &format!("{}_to_writer", media_fn_name),
+                ));
+            }
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
-            if frt.starts_with("Vec<") && http::Method::GET == m {
+            if all_rt.starts_with("Vec<") && http::Method::GET == m && paginates {
                 let docs = get_fn_docs_all(
                     o,
                     m,
                     p,
-                    oid.trim_start_matches(&tag).trim_start_matches('_'),
+                    &format!(
+                        "As opposed to `{}`, this function returns all the pages of the request at once.",
+                        oid.trim_start_matches(&tag).trim_start_matches('_')
+                    ),
                 )?;
 
                 let (fn_params_str, query_params) =
@@ -408,6 +615,7 @@ pub fn generate_files(
                     &pagination_property,
                     true,
                     body_content_type_header.as_deref(),
+                    None,
                 )?;
 
                 let mut fn_name = oid
@@ -448,17 +656,72 @@ pub fn generate_files(
                 }
                 fn_names.push(fn_name.clone() + &tag);
 
+                // Getting all the pages is just collecting the stream of them.
+                let stream_fn_name = format!("{}_stream", fn_name);
+                let args = fn_params_str
+                    .iter()
+                    .map(|p| p.split(':').next().unwrap_or_default().trim())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
                 // Now let's print the new function.
                 out.add_content(&print_fn(
                     &docs,
                     &bounds,
                     &fn_params_str,
                     &body_param,
-                    &frt,
-                    &template,
-                    &fn_inner,
+                    &all_rt,
+                    "",
+                    &format!(
+                        "futures::TryStreamExt::try_collect(self.{}({})).await",
+                        stream_fn_name, args
+                    ),
                     &fn_name,
                 ));
+
+                // And the stream itself, which only fetches pages as they are needed.
+                let mut content = get_fn_docs_all(
+                    o,
+                    m,
+                    p,
+                    &format!(
+                        "As opposed to `{}`, this function returns a stream of the items, fetching \
+                         each page only as the stream is polled.",
+                        fn_name
+                    ),
+                )?;
+                content.push_str(&format!("pub fn {}(&self,", stream_fn_name));
+                content.push_str(&fn_params_str.join(" "));
+                content.push_str(&format!(
+                    ") -> impl futures::Stream<Item = ClientResult<{}>> + '_ {{",
+                    all_rt
+                        .strip_prefix("Vec<")
+                        .and_then(|t| t.strip_suffix('>'))
+                        .unwrap_or(&all_rt)
+                ));
+                if template.contains("crate::utils::deep_object_query_args(") {
+                    // A stream has nowhere to return an error from before its first page,
+                    // so one from encoding a deep object argument is the stream's only item.
+                    content.push_str(
+                        &template
+                            .replace(
+                                "query_args.extend(crate::utils::deep_object_query_args(",
+                                "match crate::utils::deep_object_query_args(",
+                            )
+                            .replace(
+                                ", v)?); }",
+                                ", v) { Ok(args) => query_args.extend(args), Err(e) => return \
+                                 futures::future::Either::Left(futures::stream::once(\
+                                 futures::future::ready(Err(e)))), } }",
+                            ),
+                    );
+                    content.push_str(&format!("futures::future::Either::Right({})", fn_inner));
+                } else {
+                    content.push_str(&template);
+                    content.push_str(&fn_inner);
+                }
+                content.push('}');
+                out.add_content(&content);
             }
 
             // Add this to our map of functions based on the tag name.
@@ -481,6 +744,7 @@ pub fn generate_files(
 }
 
 fn get_response_type_from_object(
+    proper_name: &str,
     od: &str,
     ts: &mut TypeSpace,
     s: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
@@ -518,6 +782,33 @@ fn get_response_type_from_object(
     }
 
     if let crate::TypeDetails::Object(p, _) = &et.details {
+        // For Slack, the cursor for the next page is passed _in_ the resulting
+        // struct as `response_metadata.next_cursor`, alongside the `ok` envelope.
+        if proper_name == "Slack"
+            && p.get("ok").is_some()
+            && (p.get("response_metadata").is_some() || p.get("has_more").is_some())
+        {
+            if let Some(mid) = p.get("messages") {
+                let rt = ts.render_type(mid, false)?;
+                if rt.starts_with("Vec<") {
+                    return Ok((og_rt, mid.clone(), rt, "messages".to_string()));
+                }
+            }
+
+            // Otherwise the items are the only list in the response. With more
+            // than one, we can't tell which the cursor pages through.
+            let mut lists = Vec::new();
+            for (n, id) in p {
+                let rt = ts.render_type(id, false)?;
+                if rt.starts_with("Vec<") && n != "response_metadata" {
+                    lists.push((n, id, rt));
+                }
+            }
+            if let [(n, id, rt)] = lists.as_slice() {
+                return Ok((og_rt, (*id).clone(), rt.to_string(), to_snake_case(n)));
+            }
+        }
+
         // For Ramp, the pagination values are passed _in_ the resulting
         // struct, so we want to ignore them and just get the data.
         if let Some(pid) = p.get("page") {
@@ -598,7 +889,32 @@ fn get_response_type_from_object(
     Ok((og_rt, tid, "".to_string(), "".to_string()))
 }
 
+/// Get the type and name of the only property of an object, if it is a list.
+fn get_list_property(ts: &TypeSpace, tid: &crate::TypeId) -> Result<Option<(String, String)>> {
+    let mut et = if let Some(et) = ts.id_to_entry.get(tid) {
+        et
+    } else {
+        return Ok(None);
+    };
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
+    if let crate::TypeDetails::Object(p, _) = &et.details {
+        if p.len() == 1 {
+            let (n, id) = p.iter().next().unwrap();
+            let rt = ts.render_type(id, false)?;
+            if rt.starts_with("Vec<") {
+                return Ok(Some((rt, to_snake_case(n))));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
 fn get_response_type(
+    proper_name: &str,
     od: &str,
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
@@ -646,10 +962,19 @@ fn get_response_type(
                 }
 
                 // Get response type from object.
-                return get_response_type_from_object(od, ts, Some(s), None);
+                return get_response_type_from_object(proper_name, od, ts, Some(s), None);
             }
         }
 
+        if binary_response_content_type(o).is_some() {
+            return Ok((
+                "crate::utils::Download".to_string(),
+                crate::TypeId(0),
+                "".to_string(),
+                "".to_string(),
+            ));
+        }
+
         // Get the first response.
         let (ct, mt) = i.content.first().unwrap();
         if ct == "text/plain"
@@ -676,7 +1001,7 @@ fn get_response_type(
         }
     } else if let openapiv3::ReferenceOr::Reference { reference: _ } = first.1 {
         // Get response type from object.
-        return get_response_type_from_object(od, ts, None, Some(first.1));
+        return get_response_type_from_object(proper_name, od, ts, None, Some(first.1));
     }
 
     // Basically if we get here, likely its just an empty struct or something.
@@ -689,6 +1014,128 @@ fn get_response_type(
     ))
 }
 
+/// Generate the typed form for a `multipart/form-data` request body.
+///
+/// Returns the name of the form and its code. Binary properties are files,
+/// everything else is sent as text.
+fn get_multipart_form(
+    ts: &mut TypeSpace,
+    od: &str,
+    mt: &openapiv3::MediaType,
+) -> Result<Option<(String, String)>> {
+    use openapiv3::{SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty};
+
+    let o = match mt
+        .schema
+        .as_ref()
+        .and_then(|s| s.item().ok())
+        .map(|s| &s.schema_kind)
+    {
+        Some(SchemaKind::Type(Type::Object(o))) if !o.properties.is_empty() => o,
+        _ => return Ok(None),
+    };
+
+    let form = struct_name(&format!("{} form", oid_to_object_name(od)));
+
+    let mut fields = String::new();
+    let mut parts = String::new();
+    let mut derive_default = true;
+    for (n, prop) in o.properties.iter() {
+        let field = to_snake_case(&clean_name(n));
+        let required = o.required.contains(n);
+
+        let item = prop.as_item();
+        let is_file = matches!(
+            item.map(|s| &s.schema_kind),
+            Some(SchemaKind::Type(Type::String(st)))
+                if matches!(st.format, VariantOrUnknownOrEmpty::Item(StringFormat::Binary))
+        );
+
+        let typ = if is_file {
+            "crate::utils::FilePart".to_string()
+        } else {
+            let id = ts.select_box(Some(n), prop, &form)?;
+            ts.render_type(&id, false)?
+        };
+
+        if let Some(description) = item.and_then(|s| s.schema_data.description.as_ref()) {
+            fields.push_str(&format!(
+                "/// {}\n",
+                description.trim().replace('\n', "\n/// ")
+            ));
+        }
+
+        let part = if is_file {
+            format!(r#"form = form.part("{}", {}.into_part()?);"#, n, field)
+        } else {
+            format!(
+                r#"form = form.text("{}", crate::utils::form_text(&{})?);"#,
+                n, field
+            )
+        };
+
+        if required {
+            if !is_file && typ != "String" {
+                derive_default = false;
+            }
+            fields.push_str(&format!("pub {}: {},\n", field, typ));
+            parts.push_str(&format!("let {} = self.{};\n{}\n", field, field, part));
+        } else {
+            fields.push_str(&format!("pub {}: Option<{}>,\n", field, typ));
+            parts.push_str(&format!(
+                "if let Some({}) = self.{} {{\n{}\n}}\n",
+                field, field, part
+            ));
+        }
+    }
+
+    let derive = if derive_default {
+        "#[derive(Debug, Clone, Default)]"
+    } else {
+        "#[derive(Debug, Clone)]"
+    };
+
+    Ok(Some((
+        format!("crate::types::{}", form),
+        format!(
+            r#"
+/// The `multipart/form-data` body of a request.
+{}
+pub struct {} {{
+{}
+}}
+
+impl {} {{
+    /// Turn this into a form to send.
+    pub fn into_form(self) -> crate::ClientResult<reqwest::multipart::Form> {{
+        let mut form = reqwest::multipart::Form::new();
+        {}
+        Ok(form)
+    }}
+}}
+"#,
+            derive, form, fields, form, parts
+        ),
+    )))
+}
+
+/// The content type of a response that is a file, which we hand back as raw
+/// bytes rather than trying to parse.
+fn binary_response_content_type(o: &openapiv3::Operation) -> Option<String> {
+    let (_, first) = o.responses.responses.first()?;
+    let i = first.item().ok()?;
+    if i.content.contains_key("application/json") {
+        return None;
+    }
+
+    let (ct, _) = i.content.first()?;
+    if ct == "application/pdf" || ct == "application/octet-stream" {
+        Some(ct.to_string())
+    } else {
+        None
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn get_fn_params(
     ts: &mut TypeSpace,
@@ -749,7 +1196,6 @@ fn get_fn_params(
                 && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
                 && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
                 && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
             {
                 if typ == "chrono::DateTime<chrono::Utc>" {
                     fn_params_str.push(format!("{}: Option<{}>,", nam, typ));
@@ -800,7 +1246,6 @@ fn get_fn_params(
                     && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
                     && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
                     && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                    && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
                 {
                     if typ == "chrono::DateTime<chrono::Utc>" {
                         query_params.insert(
@@ -814,6 +1259,25 @@ fn get_fn_params(
                         );
                     }
                 }
+            } else if let openapiv3::Parameter::Query {
+                style: openapiv3::QueryStyle::DeepObject,
+                ..
+            } = item
+            {
+                // Arrays in a deep object are sent as `name[]=a&name[]=b`, this is
+                // how Stripe takes `expand` for example.
+                if typ == "&[String]" && fn_params.contains(nam) {
+                    query_params.insert(
+                        nam.to_string(),
+                        (typ.to_string(), format!("{}[]", parameter_data.name)),
+                    );
+                } else if typ.starts_with("Option<crate::types::") && fn_params.contains(nam) {
+                    // Objects are sent as `name[key]=value`, for example `created[gte]`.
+                    query_params.insert(
+                        nam.to_string(),
+                        (typ.to_string(), parameter_data.name.to_string()),
+                    );
+                }
             }
         }
     }
@@ -836,10 +1300,24 @@ fn get_fn_inner(
     pagination_property: &str,
     all_pages: bool,
     content_type: Option<&str>,
+    download: Option<&str>,
 ) -> Result<String> {
+    // Forms go out on their own, reqwest takes care of the headers.
+    if body_func.as_deref() == Some("multipart") {
+        return Ok(format!(
+            "self.client.request_form(reqwest::Method::{}, &url, body.into_form()?).await",
+            m.to_uppercase()
+        ));
+    }
+
     let body = if let Some(f) = &body_func {
         if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
+        } else if f == "form" && proper_name == "Stripe" {
+            // Stripe wants nested objects and arrays as bracketed keys.
+            "Some(reqwest::Body::from(crate::utils::encode_form(body)?))"
+        } else if f == "form" {
+            "Some(reqwest::Body::from(serde_urlencoded::to_string(body)?))"
         } else {
             "Some(body.into())"
         }
@@ -851,243 +1329,204 @@ fn get_fn_inner(
         .map(|c| format!(r#"Some("{c}".to_string())"#))
         .unwrap_or_else(|| String::from("None"));
 
-    if all_pages && pagination_property.is_empty() {
-        return Ok(format!("self.client.get_all_pages(&url, crate::Message {{ body: {}, content_type: None }}).await", body));
-    } else if all_pages && proper_name.starts_with("Stripe") {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = resp.{};
-            let mut has_more = resp.has_more;
-            let mut page = "".to_string();
-
-            // Paginate if we should.
-            while has_more {{
-                if !{}.is_empty() {{
-                   let last = {}.last().unwrap();
-                   let j = serde_json::json!(last);
-                   if let serde_json::Value::Object(o) = j {{
-                       if let Some(serde_json::Value::String(s)) = o.get("id") {{
-                            page = s.to_string();
-                       }}
-                   }}
-                }}
-
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-
-                {}.append(&mut resp.{});
-
-                has_more = resp.has_more;
-            }}
-
-            // Return our response data.
-            Ok({}.to_vec())"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
+    // Files come back as they are, along with their content type and filename.
+    if let Some(accept) = download {
+        return Ok(format!(
+            r#"self.client.request_download(reqwest::Method::{}, &url, crate::Message {{ body: {}, content_type: {content_type} }}, "{}").await"#,
+            m.to_uppercase(),
             body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name.starts_with("Google") {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = resp.{};
-            let mut page = resp.next_page_token;
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?pageToken={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&pageToken={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-
-                {}.append(&mut resp.{});
-
-                if !resp.next_page_token.is_empty() && resp.next_page_token != page {{
-                    page = resp.next_page_token.to_string();
-                }} else {{
-                    page = "".to_string();
-                }}
-            }}
+            accept
+        ));
+    }
 
-            // Return our response data.
-            Ok({})"#,
+    if all_pages && pagination_property.is_empty() {
+        return Ok("self.client.get_all_pages_stream(&url)".to_string());
+    } else if all_pages {
+        // Every other style hands `paginate` a function that fetches one page, and
+        // works out the url of the next one from the response.
+        let message = format!("crate::Message {{ body: {}, content_type: None }}", body);
+        let get = format!(
+            "let resp: {} = self.client.{}(&page_url, {}).await?;",
             response_type,
             m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
+            message
         );
 
-        return Ok(inner);
-    } else if all_pages && proper_name == "Ramp" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = resp.{};
-            let mut page = resp.page.next.to_string();
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                match self.client.{}::<{}>(page.trim_start_matches(&self.client.host), crate::Message {{ body: {}, content_type: None }}).await {{
-                    Ok(mut resp) => {{
-                        {}.append(&mut resp.{});
-
-                        page = if resp.page.next != page {{
-                            resp.page.next.to_string()
-                        }} else {{
-                        "".to_string()
-                        }};
-                    }},
-                    Err(e) => {{
-                        if e.to_string().contains("404 Not Found") {{
-                            page = "".to_string();
-                        }} else {{
-                            return Err(e);
+        // Stripe's lists of anyOfs are boxed, see types.rs. Either way, the page is
+        // moved out of the response rather than copied.
+        let items = if proper_name.starts_with("Stripe")
+            && inner_response_type.trim_end_matches('>').ends_with("AnyOf")
+        {
+            format!("*resp.{}", pagination_property)
+        } else {
+            format!("resp.{}", pagination_property)
+        };
+
+        let (first, fetch) = if proper_name.starts_with("Stripe")
+            && response_type.ends_with("SearchResult")
+        {
+            (
+                "url.to_string()".to_string(),
+                format!(
+                    r#"{}
+
+                    // Search results hand us the cursor for the next page.
+                    let next = if resp.has_more && !resp.next_page.is_empty() {{
+                        Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                    }} else {{
+                        None
+                    }};
+
+                    Ok(({}, next))"#,
+                    get, items
+                ),
+            )
+        } else if proper_name.starts_with("Stripe") {
+            (
+                "url.to_string()".to_string(),
+                format!(
+                    r#"{}
+
+                    // Stripe pages by the id of the last item we were given.
+                    let mut next = None;
+                    if resp.has_more {{
+                        if let Some(serde_json::Value::Object(o)) = resp.{}.last().map(|last| serde_json::json!(last)) {{
+                            if let Some(serde_json::Value::String(s)) = o.get("id") {{
+                                next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                            }}
                         }}
                     }}
-                }}
-            }}
-
-            // Return our response data.
-            Ok({})"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            response_type,
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name == "TripActions" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"
-            let mut resp: {} = if !url.contains('?') {{
-                self.client.{}(&format!("{{}}?page=0&size=100", url), crate::Message {{ body: {}, content_type: None }}).await?
-            }} else {{
-                self.client.{}(&format!("{{}}&page=0&size=100", url), crate::Message {{ body: {}, content_type: None }}).await?
-            }};
-
-            let mut {} = resp.{};
-            let mut page = resp.page.current_page + 1;
-
-            // Paginate if we should.
-            while page <= (resp.page.total_pages - 1) {{
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?page={{}}&size=100", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&page={{}}&size=100", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
 
-                {}.append(&mut resp.{});
-
-                page = resp.page.current_page + 1;
-            }}
-
-            // Return our response data.
-            Ok({})"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
+                    Ok(({}, next))"#,
+                    get, pagination_property, items
+                ),
+            )
+        } else if proper_name.starts_with("Google") || proper_name == "Zoom" {
+            let (key, token) = if proper_name == "Zoom" {
+                ("next_page_token", "next_page_token")
+            } else {
+                ("pageToken", "next_page_token")
+            };
+            (
+                "url.to_string()".to_string(),
+                format!(
+                    r#"{}
+
+                    // Keep going for as long as we are handed a new page token.
+                    let mut next = None;
+                    if !resp.{token}.is_empty() {{
+                        let next_url = crate::utils::add_query_param(&url, "{key}", &resp.{token});
+                        if next_url != page_url {{
+                            next = Some(next_url);
+                        }}
+                    }}
 
-        return Ok(inner);
-    } else if all_pages && proper_name == "Zoom" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = resp.{};
-            let mut page = resp.next_page_token;
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                // Check if we already have URL params and need to concat the token.
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?next_page_token={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&next_page_token={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
+                    Ok((resp.{}, next))"#,
+                    get, pagination_property
+                ),
+            )
+        } else if proper_name == "Ramp" {
+            (
+                "url.to_string()".to_string(),
+                format!(
+                    r#"match self.client.{}::<{}>(&page_url, {}).await {{
+                        Ok(resp) => {{
+                            let next = if !resp.page.next.is_empty() && resp.page.next != page_url {{
+                                Some(resp.page.next.to_string())
+                            }} else {{
+                                None
+                            }};
+
+                            Ok((resp.{}, next))
+                        }}
+                        // Walking off the last page gives us a 404.
+                        Err(e) if page_url != url && e.to_string().contains("404 Not Found") => Ok((Vec::new(), None)),
+                        Err(e) => Err(e),
+                    }}"#,
+                    m.to_lowercase(),
+                    response_type,
+                    message,
+                    pagination_property
+                ),
+            )
+        } else if proper_name == "Slack" {
+            (
+                "url.to_string()".to_string(),
+                format!(
+                    r#"// Read the page loosely first, the cursor isn't always where the spec says.
+                    let value: serde_json::Value = self.client.{}(&page_url, {}).await?;
+
+                    let next = crate::utils::slack_next_cursor(&value)
+                        .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                        .filter(|next_url| *next_url != page_url);
+
+                    let resp: {} = serde_json::from_value(value)?;
+
+                    Ok((resp.{}, next))"#,
+                    m.to_lowercase(),
+                    message,
+                    response_type,
+                    pagination_property
+                ),
+            )
+        } else if proper_name == "Shopify" {
+            (
+                "crate::utils::shopify_first_page(&url)".to_string(),
+                format!(
+                    r#"let (link, resp): (Option<crate::utils::NextLink>, {}) = self.client.request_with_links(http::Method::GET, &page_url, crate::Message::default()).await?;
+
+                    // Follow the `page_info` cursor Shopify links to, if there is one.
+                    let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                    Ok((resp.{}, next))"#,
+                    response_type, pagination_property
+                ),
+            )
+        } else if proper_name == "TripActions" {
+            (
+                r#"crate::utils::add_query_param(&crate::utils::add_query_param(&url, "page", "0"), "size", "100")"#.to_string(),
+                format!(
+                    r#"{}
+
+                    let page = resp.page.current_page + 1;
+                    let next = if page < resp.page.total_pages {{
+                        Some(crate::utils::add_query_param(
+                            &crate::utils::add_query_param(&url, "page", &page.to_string()),
+                            "size",
+                            "100",
+                        ))
+                    }} else {{
+                        None
+                    }};
+
+                    Ok((resp.{}, next))"#,
+                    get, pagination_property
+                ),
+            )
+        } else {
+            bail!(
+                "must implement custom pagination function for {} {}",
+                proper_name,
+                pagination_property
+            );
+        };
 
-                {}.append(&mut resp.{});
+        // Shopify works out the next page from the page before it alone.
+        let url = if proper_name == "Shopify" {
+            ""
+        } else {
+            "let url = url.to_string();"
+        };
 
-                if !resp.next_page_token.is_empty() && resp.next_page_token != page {{
-                    page = resp.next_page_token.to_string();
-                }} else {{
-                    page = "".to_string();
+        return Ok(format!(
+            r#"crate::utils::paginate({}, move |page_url| {{
+                {}
+                async move {{
+                    {}
                 }}
-            }}
-
-            // Return our response data.
-            Ok({})"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && !pagination_property.is_empty() {
-        bail!(
-            "must implement custom pagination function for {} {}",
-            proper_name,
-            pagination_property
-        );
+            }})"#,
+            first, url, fetch
+        ));
     }
 
     if (m == http::Method::GET
@@ -1234,7 +1673,7 @@ fn get_fn_docs(
     Ok(out.trim().to_string())
 }
 
-fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, about: &str) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -1252,10 +1691,7 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
         m, p
     ));
     a("*");
-    a(&format!(
-        "* As opposed to `{}`, this function returns all the pages of the request at once.",
-        fn_name
-    ));
+    a(&format!("* {}", about));
     if let Some(description) = &o.description {
         a("*");
         a(&format!("* {}", description.replace('\n', "\n* ")));
@@ -1281,10 +1717,22 @@ fn is_page_param(s: &str, proper_name: &str) -> bool {
         || s == "page_number"
         || s == "start"
         || s == "sync_token"
-        || s == "limit"
+        || (s == "limit" && proper_name != "Slack")
         || s == "ending_before"
         || (s == "after" && proper_name == "Okta")
         || (s == "starting_after" && proper_name == "Stripe")
+        || (s == "cursor" && proper_name == "Slack")
+}
+
+/// The Google methods that can answer with the file itself.
+///
+/// The discovery documents call these out with `supportsMediaDownload`, but
+/// that doesn't survive the conversion to OpenAPI.
+fn is_google_media_download(proper_name: &str, op_id: &str) -> bool {
+    proper_name == "Google Drive"
+        && (op_id == "drive.files.get"
+            || op_id == "drive.files.export"
+            || op_id == "drive.revisions.get")
 }
 
 fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
@@ -1326,7 +1774,3 @@ fn is_okta_unnecessary_param(s: &str) -> bool {
 fn is_shipbob_unnecessary_param(s: &str) -> bool {
     s == "shipbob_channel_id"
 }
-
-fn is_stripe_unnecessary_param(s: &str) -> bool {
-    s == "expand"
-}
@@ -1,11 +1,14 @@
+mod api_version;
 mod client;
 mod functions;
+mod overlay;
+mod security;
 mod template;
 mod types;
 mod utils;
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ffi::OsStr,
     fs::{File, OpenOptions},
     io::Write,
@@ -41,6 +44,28 @@ impl TemplateType {
     }
 }
 
+/// The hand-written modules that receive what an API sends us, if it sends
+/// us webhooks.
+fn webhook_modules(proper_name: &str) -> &'static [&'static str] {
+    match proper_name {
+        "GitHub" | "Slack" | "Stripe" => &["webhooks"],
+        // SendGrid's own `webhooks` tag is the settings of its webhooks.
+        "SendGrid" => &["event_webhook", "inbound_parse"],
+        "DocuSign" => &["connect_listener"],
+        _ => &[],
+    }
+}
+
+/// APIs that send us webhooks, which get a `webhooks` feature.
+fn has_webhooks(proper_name: &str) -> bool {
+    !webhook_modules(proper_name).is_empty()
+}
+
+/// APIs that timestamp their webhook signatures, so we can turn away replays.
+fn has_webhook_timestamps(proper_name: &str) -> bool {
+    matches!(proper_name, "Slack" | "Stripe")
+}
+
 fn save<P>(p: P, data: &str) -> Result<()>
 where
     P: AsRef<Path>,
@@ -85,19 +110,11 @@ where
         println!("unexpected version {}", api.openapi);
     }
 
-    if !api.servers.is_empty() {
-        println!("Only default server urls are supported. Variables are not configurable");
-    }
-
-    if api.security.is_some() {
-        println!("security not presently supported");
+    if api.servers.len() > 1 {
+        println!("Server variables are only configurable with a single server");
     }
 
     if let Some(components) = api.components.as_ref() {
-        if !components.security_schemes.is_empty() {
-            println!("component security schemes not supported");
-        }
-
         if !components.responses.is_empty() {
             println!("component responses not supported");
         }
@@ -367,6 +384,16 @@ impl ParameterDataExt for openapiv3::ParameterData {
                                     format!("i{}", width)
                                 }
                             }
+                            openapiv3::SchemaKind::AnyOf { any_of } => {
+                                // Stripe takes range filters like `created[gte]` as an anyOf
+                                // of an exact value and a range.
+                                if is_range_query(any_of) {
+                                    let id = ts.select_schema(None, s, "", "")?;
+                                    return Ok(format!("Option<{}>", ts.render_type(&id, false)?));
+                                }
+
+                                "&str".to_string()
+                            }
                             openapiv3::SchemaKind::OneOf { one_of: _ } => "&str".to_string(), /* TODO: make this smarter. */
                             openapiv3::SchemaKind::Any(_) => "&str".to_string(), /* TODO: make this smarter. */
                             // Any thing weird just make it a string.
@@ -533,6 +560,36 @@ impl ExtractJsonMediaType for openapiv3::RequestBody {
     }
 }
 
+/*
+ * Name a Stripe search result after the type of its `data`, so that
+ * searching customers gives a `CustomerSearchResult` and so on.
+ */
+fn search_result_name(o: &openapiv3::ObjectType) -> String {
+    if let Some(openapiv3::ReferenceOr::Item(data)) = o.properties.get("data") {
+        if let openapiv3::SchemaKind::Type(openapiv3::Type::Array(at)) = &data.schema_kind {
+            if let Some(openapiv3::ReferenceOr::Reference { reference }) = &at.items {
+                return format!(
+                    "{} search result",
+                    reference.trim_start_matches("#/components/schemas/")
+                );
+            }
+        }
+    }
+
+    String::new()
+}
+
+/*
+ * Whether an anyOf is one of Stripe's range filters, an exact value or a
+ * `range_query_specs` object.
+ */
+fn is_range_query(any_of: &[openapiv3::ReferenceOr<openapiv3::Schema>]) -> bool {
+    any_of.iter().any(|s| {
+        matches!(s, openapiv3::ReferenceOr::Item(s)
+            if s.schema_data.title.as_deref() == Some("range_query_specs"))
+    })
+}
+
 trait ReferenceOrExt<T> {
     fn item(&self) -> Result<&T>;
 }
@@ -673,8 +730,7 @@ impl PartialEq for TypeDetails {
                 if let TypeDetails::Object(os, od) = other {
                     // Fix for stripe.
                     if (d.title == od.title && d.title.is_some())
-                        && (Some("range_query_specs".to_string()) == od.title
-                            || Some("SearchResult".to_string()) == od.title)
+                        && Some("range_query_specs".to_string()) == od.title
                     {
                         return true;
                     }
@@ -1498,7 +1554,12 @@ impl TypeSpace {
                 }
                 openapiv3::Type::Object(o) => {
                     // Object types must have a consistent name.
+                    let search_result = search_result_name(o);
                     let mut name = clean_name(match (name, s.schema_data.title.as_deref()) {
+                        // Stripe's range filters are all the same type.
+                        (_, Some("range_query_specs")) => "range query",
+                        // Stripe's search results share a title, name them for what they hold.
+                        (_, Some("SearchResult")) if !search_result.is_empty() => &search_result,
                         (Some(n), None) => n,
                         (Some(n), Some("")) => n,
                         (None, Some(t)) => t,
@@ -1945,6 +2006,10 @@ impl TypeSpace {
                 // Iterate over each one of and add each of them to our typeset.
                 // AnyOf types must have a consistent name.
                 let mut any_of_name = nam;
+                if is_range_query(any_of) {
+                    // Stripe's range filters are all the same type, an exact value or a range.
+                    any_of_name = "range query".to_string();
+                }
                 if any_of_name.is_empty() && !parent_name.is_empty() {
                     any_of_name = parent_name.to_string();
                 }
@@ -2290,6 +2355,7 @@ fn gen(
     user_consent_endpoint: &str,
     add_post_header: &str,
     servers: &GeneratedServers,
+    api_version: Option<&str>,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -2314,6 +2380,11 @@ fn gen(
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
     }
+    for module in webhook_modules(proper_name) {
+        a(r#"#[cfg(feature = "webhooks")]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]"#);
+        a(&format!("pub mod {};", module));
+    }
     if proper_name == "Google Drive"
         || proper_name == "Google Sheets"
         || proper_name == "SendGrid"
@@ -2321,6 +2392,9 @@ fn gen(
     {
         a("pub mod traits;");
     }
+    if proper_name == "Shopify" {
+        a("pub mod throttle;");
+    }
     // Hopefully there is never a "tag" named after these reserved libs.
     a("pub mod types;");
     a("#[doc(hidden)]");
@@ -2421,7 +2495,86 @@ pub enum ClientError {"#);
         DriveNotFound{name: String},
         /// str convertion error
         #[error(transparent)]
-        ToStrError(#[from] reqwest::header::ToStrError),"#);
+        ToStrError(#[from] reqwest::header::ToStrError),
+        /// Errors reading an upload or writing out a download
+        #[error(transparent)]
+        IoError(#[from] std::io::Error),
+        /// The upload ended before the server had all of the file
+        #[error("Upload incomplete, the server has {committed} of {size} bytes")]
+        UploadIncomplete{committed: u64, size: u64},"#);
+    }
+
+    // Rev.ai only due to traits.rs
+    if proper_name == "Rev.ai" {
+        a(r#"
+        /// Errors reading a file to upload
+        #[error(transparent)]
+        IoError(#[from] std::io::Error),
+        /// The job failed rather than being transcribed
+        #[error("Job {id} failed: {detail}")]
+        JobFailed{id: String, failure: Option<crate::types::Failure>, detail: String},
+        /// The job was still in progress when we stopped waiting for it
+        #[error("Job {id} is still in progress after {waited:?}")]
+        JobTimedOut{id: String, waited: std::time::Duration},"#);
+    }
+
+    if proper_name == "Slack" {
+        a(r#"
+        /// Slack answered with `"ok": false`
+        #[error("Slack API Error: {error}")]
+        SlackApi {
+            error: crate::types::SlackErrorCode,
+            warning: String,
+            response_metadata: Option<crate::types::ObjsResponseMetadata>,
+        },"#);
+    }
+
+    // Due to webhooks.rs
+    if has_webhooks(proper_name) {
+        a(r#"/// A webhook delivery without a header we need
+        #[cfg(feature = "webhooks")]
+        #[error("Webhook delivery is missing the {header} header")]
+        MissingWebhookHeader{
+            header: &'static str,
+        },
+        /// A webhook delivery whose signature doesn't match its body
+        #[cfg(feature = "webhooks")]
+        #[error("Webhook signature is invalid")]
+        InvalidWebhookSignature,"#);
+    }
+    if has_webhook_timestamps(proper_name) {
+        a(
+            r#"/// A webhook delivery signed too long ago, which may be a replay
+        #[cfg(feature = "webhooks")]
+        #[error("Webhook timestamp {timestamp} is outside the replay window")]
+        StaleWebhook{
+            timestamp: i64,
+        },"#,
+        );
+    }
+    if proper_name == "SendGrid" {
+        a(
+            r#"/// The Event Webhook public key isn't a base64 P-256 key, from event_webhook.rs
+        #[cfg(feature = "webhooks")]
+        #[error("Event Webhook public key is invalid")]
+        InvalidWebhookPublicKey,
+        /// An Inbound Parse request that isn't the form we expect, from inbound_parse.rs
+        #[cfg(feature = "webhooks")]
+        #[error("Inbound Parse request is malformed: {reason}")]
+        MalformedInboundEmail{
+            reason: String,
+        },"#,
+        );
+    }
+    if proper_name == "DocuSign" {
+        a(
+            r#"/// A legacy XML Connect notification we can't read, from connect_listener.rs
+        #[cfg(feature = "webhooks")]
+        #[error("Connect XML notification is malformed: {reason}")]
+        MalformedConnectXml{
+            reason: String,
+        },"#,
+        );
     }
 
     a(r#"/// URL Parsing Error
@@ -2430,6 +2583,9 @@ pub enum ClientError {"#);
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde URL encoding error
+    #[error(transparent)]
+    SerdeUrlencodedSerError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -2515,6 +2671,7 @@ pub(crate) struct Message {
                 proper_name,
                 add_post_header,
                 servers,
+                &crate::security::ApiKeyAuth::from_spec(api),
             ));
         }
         TemplateType::GenericClientCredentials => {
@@ -2532,6 +2689,7 @@ pub(crate) struct Message {
                 user_consent_endpoint,
                 add_post_header,
                 servers,
+                api_version,
             ));
         }
     }
@@ -2928,6 +3086,18 @@ fn main() -> Result<()> {
         "A header to add to post requests",
         "ADD_POST_HEADER",
     );
+    opts.optopt(
+        "",
+        "overlay",
+        "Schemas and bodies to add to the OpenAPI definition (JSON | YAML)",
+        "OVERLAY",
+    );
+    opts.optopt(
+        "",
+        "api-version",
+        "Default API version, for APIs with it in their paths, e.g. /admin/api/2021-07/",
+        "API_VERSION",
+    );
     opts.optflag("", "debug", "Print debug output");
 
     let args = match opts.parse(std::env::args().skip(1)) {
@@ -2944,9 +3114,33 @@ fn main() -> Result<()> {
         }
     };
 
-    let api = load_api(args.opt_str("i").unwrap())?;
+    let mut api = load_api(args.opt_str("i").unwrap())?;
+    let api_version = args.opt_str("api-version");
+    if api_version.is_some() {
+        api_version::collapse(&mut api)?;
+    }
+    if let Some(overlay) = args.opt_str("overlay") {
+        overlay::apply(&mut api, &load(overlay)?)?;
+    }
 
-    let servers = client::generate_servers(&api.servers, "Root");
+    // Specs without servers get one from the host we were given, along with any
+    // variables in it, for example `{shop}.myshopify.com` for Shopify.
+    let mut api_servers = api.servers.clone();
+    let host = args.opt_str("host").unwrap_or_default();
+    if api_servers.is_empty() && host.contains('{') {
+        let mut server = openapiv3::Server {
+            url: format!("https://{}", host.trim_start_matches("https://")),
+            variables: Some(Default::default()),
+            ..Default::default()
+        };
+        if let Some(variables) = server.variables.as_mut() {
+            for v in host.split('{').skip(1).filter_map(|v| v.split_once('}')) {
+                variables.insert(v.0.to_string(), Default::default());
+            }
+        }
+        api_servers.push(server);
+    }
+    let servers = client::generate_servers(&api_servers, "Root");
 
     let debug = |s: &str| {
         if args.opt_present("debug") {
@@ -3167,7 +3361,7 @@ fn main() -> Result<()> {
                 let mut req: Vec<String> = Default::default();
                 if let Some(openapiv3::ReferenceOr::Item(body)) = &o.request_body {
                     for (ct, mt) in &body.content {
-                        if ct == "application/json" {
+                        if ct == "application/json" || ct == "application/x-www-form-urlencoded" {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
@@ -3304,6 +3498,7 @@ fn main() -> Result<()> {
         &user_consent_endpoint,
         &add_post_header,
         &servers,
+        api_version.as_deref(),
     ) {
         Ok(out) => {
             let description = args.opt_str("d").unwrap();
@@ -3318,7 +3513,6 @@ fn main() -> Result<()> {
              * Write the Cargo.toml file:
              */
             let mut uuid_lib = "".to_string();
-            let mut yup_oauth2_lib = "".to_string();
             if proper_name != "GitHub" {
                 uuid_lib = r#"
 bytes = { version = "1", features = ["serde"] }
@@ -3327,11 +3521,66 @@ uuid = { version = "1.1", features = ["serde", "v4"] }"#
                     .to_string();
             }
 
+            // Dependencies only some clients need. A set, so that two reasons for
+            // the same one only list it once, in order.
+            let mut extra_deps: BTreeSet<&str> = Default::default();
             if proper_name.starts_with("Google") {
-                yup_oauth2_lib = r#"
-base64 = "^0.21"
-yup-oauth2 = "^8""#
-                    .to_string();
+                extra_deps.insert(r#"base64 = "^0.21""#);
+                extra_deps.insert(r#"yup-oauth2 = "^8""#);
+            }
+
+            // MailChimp sends API keys with basic auth.
+            if proper_name == "MailChimp" {
+                extra_deps.insert(r#"base64 = "^0.13""#);
+            }
+
+            // API keys sent with basic auth are base64 encoded.
+            if matches!(
+                TemplateType::from_proper_name(&proper_name),
+                TemplateType::GenericApiKey
+            ) && security::ApiKeyAuth::from_spec(&api) == security::ApiKeyAuth::Basic
+            {
+                extra_deps.insert(r#"base64 = "^0.13""#);
+            }
+
+            // SendGrid's Event Webhook signatures and keys are base64 encoded.
+            if proper_name == "SendGrid" {
+                extra_deps.insert(r#"base64 = "^0.13""#);
+            }
+
+            // DocuSign's Connect signatures are base64 encoded, and its legacy
+            // notifications are XML.
+            if proper_name == "DocuSign" {
+                extra_deps.insert(r#"base64 = "^0.13""#);
+                extra_deps.insert(r#"roxmltree = { version = "0.20", optional = true }"#);
+            }
+
+            // Shopify's throttle is a middleware, which sees the request extensions,
+            // and it brings its own retry middleware, which decides with a retry policy.
+            if proper_name == "Shopify" {
+                extra_deps.insert(r#"retry-policies = "0.1.1""#);
+                extra_deps.insert(r#"task-local-extensions = "0.1.1""#);
+            }
+
+            // Webhook signatures are checked with ring, which is otherwise only
+            // there for rustls.
+            let (mut default_features, mut webhooks_feature) = ("", "");
+            if has_webhooks(&proper_name) {
+                default_features = r#", "webhooks""#;
+                webhooks_feature = r#"
+# verify and parse webhook deliveries
+webhooks = ["ring"]"#;
+            }
+            if proper_name == "DocuSign" {
+                webhooks_feature = r#"
+# verify and parse webhook deliveries
+webhooks = ["ring", "roxmltree"]"#;
+            }
+
+            // Rev.ai streams local files up from disk.
+            let mut reqwest_features = r#""json", "multipart""#.to_string();
+            if proper_name == "Rev.ai" {
+                reqwest_features.push_str(r#", "stream""#);
             }
 
             let mut toml = root.clone();
@@ -3348,16 +3597,17 @@ edition = "2021"
 license = "MIT"
 
 [features]
-default = ["rustls-tls", "reqwest-tracing/opentelemetry_0_17"]
+default = ["rustls-tls", "reqwest-tracing/opentelemetry_0_17"{default_features}]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
 native-tls = ["reqwest/default-tls", "openssl"]
-rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
+rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]{webhooks_feature}
 
 [dependencies]
 async-recursion = "^1.0"
 chrono = {{ version = "0.4", default-features = false, features = ["serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
@@ -3366,7 +3616,7 @@ openssl = {{ version = "0.10", default-features = false, optional = true }}
 parse_link_header = "0.3.3"
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.2"
-reqwest = {{ version = "0.11.14", default-features = false, features = ["json", "multipart"] }}
+reqwest = {{ version = "0.11.14", default-features = false, features = [{}] }}
 reqwest-conditional-middleware = "0.1.0"
 reqwest-middleware = "0.1.5"
 reqwest-retry = "0.1.4"
@@ -3393,7 +3643,17 @@ wiremock = "0.5.17"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                reqwest_features,
+                uuid_lib,
+                extra_deps
+                    .iter()
+                    .map(|dep| format!("\n{}", dep))
+                    .collect::<String>()
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3432,6 +3692,7 @@ rustdoc-args = ["--cfg", "docsrs"]
                     &proper_name,
                     &spec_link,
                     &add_post_header,
+                    &servers,
                 ),
             };
 
@@ -3476,7 +3737,17 @@ rustdoc-args = ["--cfg", "docsrs"]
             /*
              * Create the Rust source types file containing the generated types:
              */
-            let types = types::generate_types(&mut ts, &proper_name)?;
+            let mut types = types::generate_types(&mut ts, &proper_name)?;
+            if proper_name == "Slack" {
+                types.push_str(&types::generate_slack_error_codes(&api));
+            }
+            let files = functions::generate_files(&api, &proper_name, &mut ts, &parameters);
+            if let Ok(files) = &files {
+                // Along with the types the functions made for themselves.
+                for output in files.values() {
+                    types.push_str(&output.types);
+                }
+            }
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
             save(typesrs, types.as_str())?;
@@ -3485,7 +3756,7 @@ rustdoc-args = ["--cfg", "docsrs"]
              * Create the Rust source files for each of the tags functions:
              */
 
-            match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
+            match files {
                 Ok(files) => {
                     // We have a map of our files, let's write to them.
                     for (f, output) in files {
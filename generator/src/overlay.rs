@@ -0,0 +1,374 @@
+/*
+ * Some specs don't describe the bodies their operations send and receive at
+ * all, Shopify's being one. An overlay fills them in with schemas we maintain
+ * ourselves.
+ */
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Overlay {
+    /// Schemas to add to the spec's components.
+    #[serde(default)]
+    pub schemas: BTreeMap<String, openapiv3::ReferenceOr<openapiv3::Schema>>,
+    /// REST resources, which get list and envelope schemas of their own, and
+    /// are wired up to every path that serves them.
+    #[serde(default)]
+    pub resources: Vec<Resource>,
+    /// The bodies of operations that don't follow the shape of a resource,
+    /// keyed by the end of their path and then by method.
+    #[serde(default)]
+    pub operations: BTreeMap<String, BTreeMap<String, Bodies>>,
+}
+
+/// A resource, like Shopify's orders, which are listed at `orders.json`,
+/// counted at `orders/count.json`, and fetched and updated at
+/// `orders/{order_id}.json`, all wrapped in an object keyed by their name.
+#[derive(Debug, Default, Deserialize)]
+pub struct Resource {
+    /// The schema of the resource itself.
+    pub schema: String,
+    /// The key a single resource is wrapped in, e.g. `order`.
+    pub key: String,
+    /// The key a list of resources is wrapped in, and the last part of their
+    /// path, e.g. `orders`.
+    #[serde(default)]
+    pub plural: String,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Bodies {
+    /// The schema of the request body.
+    #[serde(default)]
+    pub request: Option<String>,
+    /// The schema of the response.
+    #[serde(default)]
+    pub response: Option<String>,
+}
+
+impl Resource {
+    fn list(&self) -> String {
+        format!("{}List", self.schema)
+    }
+
+    fn envelope(&self) -> String {
+        format!("{}Envelope", self.schema)
+    }
+
+    /// The bodies for an operation on a path, if it is one of ours.
+    fn bodies(&self, path: &str, method: &str) -> Option<Bodies> {
+        if self.plural.is_empty() {
+            return None;
+        }
+
+        let mut parts = path.rsplit('/');
+        let last = parts.next()?.strip_suffix(".json")?;
+        let parent = parts.next().unwrap_or_default();
+
+        let (request, response) = if last == self.plural {
+            match method {
+                "get" => (None, Some(self.list())),
+                "post" => (Some(self.envelope()), Some(self.envelope())),
+                _ => return None,
+            }
+        } else if parent == self.plural && last == "count" {
+            match method {
+                "get" => (None, Some("Count".to_string())),
+                _ => return None,
+            }
+        } else if parent == self.plural && last.starts_with('{') && last.ends_with('}') {
+            match method {
+                "get" => (None, Some(self.envelope())),
+                "put" => (Some(self.envelope()), Some(self.envelope())),
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+
+        Some(Bodies { request, response })
+    }
+}
+
+fn schema_ref(name: &str) -> serde_json::Value {
+    serde_json::json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn object(
+    key: &str,
+    schema: serde_json::Value,
+) -> Result<openapiv3::ReferenceOr<openapiv3::Schema>> {
+    Ok(serde_json::from_value(serde_json::json!({
+        "type": "object",
+        "properties": { key: schema },
+    }))?)
+}
+
+/// Add the overlay to the spec.
+pub fn apply(api: &mut openapiv3::OpenAPI, overlay: &Overlay) -> Result<()> {
+    let components = api.components.get_or_insert_with(Default::default);
+
+    for (name, schema) in &overlay.schemas {
+        components.schemas.insert(name.to_string(), schema.clone());
+    }
+
+    for r in &overlay.resources {
+        if !overlay.schemas.contains_key(&r.schema) {
+            bail!("overlay resource {} has no schema", r.schema);
+        }
+
+        components
+            .schemas
+            .insert(r.envelope(), object(&r.key, schema_ref(&r.schema))?);
+
+        if !r.plural.is_empty() {
+            components.schemas.insert(
+                r.list(),
+                object(
+                    &r.plural,
+                    serde_json::json!({ "type": "array", "items": schema_ref(&r.schema) }),
+                )?,
+            );
+        }
+    }
+
+    if overlay.resources.iter().any(|r| !r.plural.is_empty()) {
+        components.schemas.insert(
+            "Count".to_string(),
+            object(
+                "count",
+                serde_json::json!({ "type": "integer", "format": "int64" }),
+            )?,
+        );
+    }
+
+    for (path, item) in api.paths.paths.iter_mut() {
+        let item = match item {
+            openapiv3::ReferenceOr::Item(item) => item,
+            openapiv3::ReferenceOr::Reference { .. } => continue,
+        };
+
+        for (method, op) in [
+            ("get", item.get.as_mut()),
+            ("put", item.put.as_mut()),
+            ("post", item.post.as_mut()),
+        ] {
+            let op = if let Some(op) = op {
+                op
+            } else {
+                continue;
+            };
+
+            // Operations called out on their own win over the resources, and
+            // the longest path that matches wins over the rest.
+            let operation = overlay
+                .operations
+                .iter()
+                .filter(|(p, _)| path == *p || path.ends_with(&format!("/{}", p)))
+                .max_by_key(|(p, _)| p.len())
+                .and_then(|(_, methods)| methods.get(method))
+                .cloned();
+
+            let bodies = if let Some(bodies) = operation {
+                bodies
+            } else if let Some(bodies) = overlay
+                .resources
+                .iter()
+                .find_map(|r| r.bodies(path, method))
+            {
+                bodies
+            } else {
+                continue;
+            };
+
+            if let Some(request) = &bodies.request {
+                set_request(op, request)?;
+            }
+            if let Some(response) = &bodies.response {
+                set_response(op, response)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn media_type(content: &mut openapiv3::Content, name: &str) -> Result<()> {
+    content
+        .entry("application/json".to_string())
+        .or_default()
+        .schema = Some(serde_json::from_value(schema_ref(name))?);
+    Ok(())
+}
+
+fn set_request(op: &mut openapiv3::Operation, name: &str) -> Result<()> {
+    let body = op
+        .request_body
+        .get_or_insert_with(|| openapiv3::ReferenceOr::Item(Default::default()));
+    match body {
+        openapiv3::ReferenceOr::Item(body) => {
+            body.required = true;
+            media_type(&mut body.content, name)
+        }
+        openapiv3::ReferenceOr::Reference { reference } => {
+            bail!("cannot overlay a request body reference: {}", reference)
+        }
+    }
+}
+
+fn set_response(op: &mut openapiv3::Operation, name: &str) -> Result<()> {
+    let response = op
+        .responses
+        .responses
+        .entry(openapiv3::StatusCode::Code(200))
+        .or_insert_with(|| openapiv3::ReferenceOr::Item(Default::default()));
+    match response {
+        openapiv3::ReferenceOr::Item(response) => media_type(&mut response.content, name),
+        openapiv3::ReferenceOr::Reference { reference } => {
+            bail!("cannot overlay a response reference: {}", reference)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply, Overlay};
+
+    fn spec() -> openapiv3::OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "test", "version": "1" },
+            "paths": {
+                "/admin/api/2021-01/orders.json": {
+                    "get": { "responses": { "200": { "description": "" } } },
+                    "post": {
+                        "requestBody": { "content": { "application/json": { "schema": {} } } },
+                        "responses": { "200": { "description": "" } }
+                    }
+                },
+                "/admin/api/2021-01/orders/count.json": {
+                    "get": { "responses": { "200": { "description": "" } } }
+                },
+                "/admin/api/2021-01/orders/{order_id}.json": {
+                    "get": { "responses": { "200": { "description": "" } } },
+                    "delete": { "responses": { "200": { "description": "" } } }
+                },
+                "/admin/api/2021-01/orders/{order_id}/cancel.json": {
+                    "post": { "responses": { "200": { "description": "" } } }
+                },
+                "/admin/api/2021-01/customers/{customer_id}/orders.json": {
+                    "get": { "responses": { "200": { "description": "" } } }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn overlay() -> Overlay {
+        serde_yaml::from_str(
+            r#"
+schemas:
+  Order:
+    type: object
+    properties:
+      id:
+        type: integer
+resources:
+  - schema: Order
+    key: order
+    plural: orders
+operations:
+  orders/{order_id}/cancel.json:
+    post:
+      response: OrderEnvelope
+"#,
+        )
+        .unwrap()
+    }
+
+    fn response(api: &openapiv3::OpenAPI, path: &str, method: &str) -> Option<String> {
+        let item = api.paths.paths.get(path)?.as_item()?;
+        let op = item.iter().find(|(m, _)| *m == method)?.1;
+        let response = op.responses.responses.first()?.1.as_item()?;
+        match response.content.get("application/json")?.schema.as_ref()? {
+            openapiv3::ReferenceOr::Reference { reference } => Some(reference.to_string()),
+            openapiv3::ReferenceOr::Item(_) => None,
+        }
+    }
+
+    #[test]
+    fn test_overlay_resources() {
+        let mut api = spec();
+        apply(&mut api, &overlay()).unwrap();
+
+        let schemas = &api.components.as_ref().unwrap().schemas;
+        for name in ["Order", "OrderList", "OrderEnvelope", "Count"] {
+            assert!(schemas.contains_key(name), "missing schema {}", name);
+        }
+
+        let tests = [
+            ("/admin/api/2021-01/orders.json", "get", Some("OrderList")),
+            (
+                "/admin/api/2021-01/orders.json",
+                "post",
+                Some("OrderEnvelope"),
+            ),
+            ("/admin/api/2021-01/orders/count.json", "get", Some("Count")),
+            (
+                "/admin/api/2021-01/orders/{order_id}.json",
+                "get",
+                Some("OrderEnvelope"),
+            ),
+            ("/admin/api/2021-01/orders/{order_id}.json", "delete", None),
+            (
+                "/admin/api/2021-01/orders/{order_id}/cancel.json",
+                "post",
+                Some("OrderEnvelope"),
+            ),
+            (
+                "/admin/api/2021-01/customers/{customer_id}/orders.json",
+                "get",
+                Some("OrderList"),
+            ),
+        ];
+        for (path, method, expected) in tests {
+            assert_eq!(
+                response(&api, path, method),
+                expected.map(|e| format!("#/components/schemas/{}", e)),
+                "{} {}",
+                method,
+                path
+            );
+        }
+
+        let post = api.paths.paths["/admin/api/2021-01/orders.json"]
+            .as_item()
+            .unwrap()
+            .post
+            .as_ref()
+            .unwrap();
+        let body = post.request_body.as_ref().unwrap().as_item().unwrap();
+        assert!(body.required);
+        assert!(matches!(
+            body.content["application/json"].schema,
+            Some(openapiv3::ReferenceOr::Reference { ref reference })
+                if reference == "#/components/schemas/OrderEnvelope"
+        ));
+    }
+
+    #[test]
+    fn test_overlay_resource_without_schema() {
+        let overlay: Overlay = serde_yaml::from_str(
+            r#"
+resources:
+  - schema: Order
+    key: order
+"#,
+        )
+        .unwrap();
+
+        assert!(apply(&mut spec(), &overlay).is_err());
+    }
+}
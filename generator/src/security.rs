@@ -0,0 +1,256 @@
+/*
+ * Clients that are handed an API key send it the way the spec's
+ * `securitySchemes` say to: in a query parameter, in a header, or as the
+ * username of HTTP basic auth.
+ */
+use openapiv3::{APIKeyLocation, OpenAPI, ReferenceOr, SecurityScheme};
+
+/// How an API key client authenticates its requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiKeyAuth {
+    /// `?{name}={key}`.
+    Query(String),
+    /// `{name}: {scheme} {key}`, or `{name}: {key}` without a scheme.
+    Header {
+        name: String,
+        scheme: Option<String>,
+    },
+    /// HTTP basic auth, with the key as the username.
+    Basic,
+}
+
+impl Default for ApiKeyAuth {
+    fn default() -> Self {
+        ApiKeyAuth::Header {
+            name: "Authorization".to_string(),
+            scheme: Some("Bearer".to_string()),
+        }
+    }
+}
+
+impl ApiKeyAuth {
+    /// The first scheme we can send an API key with, preferring the ones the
+    /// spec requires of every operation. Falls back to a bearer token.
+    pub fn from_spec(api: &OpenAPI) -> Self {
+        let schemes = match &api.components {
+            Some(components) => &components.security_schemes,
+            None => return ApiKeyAuth::default(),
+        };
+
+        let required = api
+            .security
+            .iter()
+            .flatten()
+            .flat_map(|requirement| requirement.keys());
+
+        required
+            .chain(schemes.keys())
+            .filter_map(|name| match schemes.get(name) {
+                Some(ReferenceOr::Item(scheme)) => Self::from_scheme(scheme),
+                _ => None,
+            })
+            .next()
+            .unwrap_or_default()
+    }
+
+    fn from_scheme(scheme: &SecurityScheme) -> Option<Self> {
+        match scheme {
+            SecurityScheme::APIKey {
+                location: APIKeyLocation::Query,
+                name,
+                ..
+            } => Some(ApiKeyAuth::Query(name.to_string())),
+            SecurityScheme::APIKey {
+                location: APIKeyLocation::Header,
+                name,
+                description,
+            } => {
+                // An `Authorization` header needs a scheme, which specs like
+                // Okta's only give in the description, e.g. `SSWS {API Token}`.
+                let scheme = if name.eq_ignore_ascii_case("Authorization") {
+                    let described = description
+                        .as_deref()
+                        .and_then(|d| d.trim().split_once(' '))
+                        .filter(|(scheme, rest)| {
+                            rest.starts_with('{')
+                                && scheme.chars().all(|c| c.is_ascii_alphanumeric())
+                        })
+                        .map(|(scheme, _)| scheme.to_string());
+                    Some(described.unwrap_or_else(|| "Bearer".to_string()))
+                } else {
+                    None
+                };
+
+                Some(ApiKeyAuth::Header {
+                    name: name.to_string(),
+                    scheme,
+                })
+            }
+            SecurityScheme::HTTP { scheme, .. } if scheme.eq_ignore_ascii_case("basic") => {
+                Some(ApiKeyAuth::Basic)
+            }
+            SecurityScheme::HTTP { scheme, .. } if scheme.eq_ignore_ascii_case("bearer") => {
+                Some(ApiKeyAuth::default())
+            }
+            _ => None,
+        }
+    }
+
+    /// The header the generated client sends the key in.
+    pub fn header(&self) -> String {
+        match self {
+            ApiKeyAuth::Header { name, .. } if !name.eq_ignore_ascii_case("Authorization") => {
+                format!(
+                    "http::header::HeaderName::from_static(\"{}\")",
+                    name.to_lowercase()
+                )
+            }
+            _ => "http::header::AUTHORIZATION".to_string(),
+        }
+    }
+
+    /// The body of the generated client's `url_and_auth`, which has the key in
+    /// `self.token`.
+    pub fn url_and_auth(&self) -> String {
+        match self {
+            ApiKeyAuth::Query(name) => format!(
+                r#"let mut parsed_url = uri.parse::<reqwest::Url>()?;
+    parsed_url.query_pairs_mut().append_pair("{}", &self.token);
+    Ok((parsed_url, None))"#,
+                name
+            ),
+            ApiKeyAuth::Header {
+                scheme: Some(scheme),
+                ..
+            } => format!(
+                r#"let parsed_url = uri.parse::<reqwest::Url>()?;
+    let auth = format!("{} {{}}", self.token);
+    Ok((parsed_url, Some(auth)))"#,
+                scheme
+            ),
+            ApiKeyAuth::Header { scheme: None, .. } => {
+                r#"let parsed_url = uri.parse::<reqwest::Url>()?;
+    Ok((parsed_url, Some(self.token.to_string())))"#
+                    .to_string()
+            }
+            ApiKeyAuth::Basic => r#"let parsed_url = uri.parse::<reqwest::Url>()?;
+    let auth = format!("Basic {}", base64::encode(format!("{}:", self.token)));
+    Ok((parsed_url, Some(auth)))"#
+                .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ApiKeyAuth;
+
+    fn spec(security: serde_json::Value, schemes: serde_json::Value) -> openapiv3::OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.3",
+            "info": { "title": "test", "version": "1" },
+            "paths": {},
+            "security": security,
+            "components": { "securitySchemes": schemes },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_api_key_in_query() {
+        let api = spec(
+            serde_json::json!([{ "api_key": [] }]),
+            serde_json::json!({ "api_key": { "type": "apiKey", "in": "query", "name": "api_key" } }),
+        );
+
+        assert_eq!(
+            ApiKeyAuth::from_spec(&api),
+            ApiKeyAuth::Query("api_key".to_string())
+        );
+    }
+
+    #[test]
+    fn test_api_key_in_authorization_header() {
+        let api = spec(
+            serde_json::json!([]),
+            serde_json::json!({
+                "api_token": {
+                    "type": "apiKey",
+                    "in": "header",
+                    "name": "Authorization",
+                    "description": "SSWS {API Token}",
+                },
+            }),
+        );
+        assert_eq!(
+            ApiKeyAuth::from_spec(&api),
+            ApiKeyAuth::Header {
+                name: "Authorization".to_string(),
+                scheme: Some("SSWS".to_string()),
+            }
+        );
+
+        let api = spec(
+            serde_json::json!([]),
+            serde_json::json!({
+                "Authorization": { "type": "apiKey", "in": "header", "name": "Authorization" },
+            }),
+        );
+        assert_eq!(ApiKeyAuth::from_spec(&api), ApiKeyAuth::default());
+    }
+
+    #[test]
+    fn test_api_key_in_other_header() {
+        let api = spec(
+            serde_json::json!([]),
+            serde_json::json!({ "key": { "type": "apiKey", "in": "header", "name": "X-API-Key" } }),
+        );
+        let auth = ApiKeyAuth::from_spec(&api);
+
+        assert_eq!(
+            auth,
+            ApiKeyAuth::Header {
+                name: "X-API-Key".to_string(),
+                scheme: None,
+            }
+        );
+        assert_eq!(
+            auth.header(),
+            "http::header::HeaderName::from_static(\"x-api-key\")"
+        );
+    }
+
+    #[test]
+    fn test_required_scheme_comes_first() {
+        let api = spec(
+            serde_json::json!([{ "basicAuth": [] }, { "bearerAuth": [] }]),
+            serde_json::json!({
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+                "basicAuth": { "type": "http", "scheme": "basic" },
+            }),
+        );
+
+        assert_eq!(ApiKeyAuth::from_spec(&api), ApiKeyAuth::Basic);
+    }
+
+    #[test]
+    fn test_unsupported_schemes_fall_back_to_bearer() {
+        let api = spec(
+            serde_json::json!([]),
+            serde_json::json!({
+                "oauth2": {
+                    "type": "oauth2",
+                    "flows": {
+                        "authorizationCode": {
+                            "authorizationUrl": "https://example.com/authorize",
+                            "tokenUrl": "https://example.com/token",
+                            "scopes": {},
+                        },
+                    },
+                },
+            }),
+        );
+
+        assert_eq!(ApiKeyAuth::from_spec(&api), ApiKeyAuth::default());
+    }
+}
@@ -7,8 +7,15 @@ use inflector::cases::{kebabcase::to_kebab_case, snakecase::to_snake_case};
 enum Component {
     Constant(String),
     Parameter(String),
+    /// Text straight after a parameter, without a slash between them, e.g. the
+    /// `.json` in Shopify's `/orders/{order_id}.json`.
+    Suffix(String),
 }
 
+/// Path parameters the client fills in itself, rather than taking them as
+/// function arguments.
+const CLIENT_PARAMETERS: &[(&str, &str)] = &[("api_version", "self.client.api_version()")];
+
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct Template {
     components: Vec<Component>,
@@ -67,6 +74,16 @@ impl Template {
                         r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
+                } else if value.starts_with("Option<crate::types::") {
+                    a(&format!(
+                        r#"if let Some(v) = &{} {{ query_args.extend(crate::utils::deep_object_query_args("{}", v)?); }}"#,
+                        nam, prop
+                    ));
+                } else if value == "&[String]" && prop.ends_with("[]") {
+                    a(&format!(
+                        r#"for v in {} {{ query_args.push(("{}".to_string(), v.to_string())); }}"#,
+                        nam, prop
+                    ));
                 } else if value == "&[String]" {
                     // TODO: I have no idea how these should be seperated and the docs
                     // don't give any answers either, for an array sent through query
@@ -94,24 +111,17 @@ impl Template {
             return out.to_string();
         }
 
-        let mut has_params = false;
-        for c in self.components.iter() {
-            match c {
-                Component::Constant(_) => (),
-                Component::Parameter(_) => {
-                    has_params = true;
-                    break;
-                }
-            }
-        }
+        let has_params = self
+            .components
+            .iter()
+            .any(|c| matches!(c, Component::Parameter(_)));
 
         if !has_params && query_params.is_empty() {
             out.push_str(r#"&""#);
             for c in self.components.iter() {
-                out.push('/');
-                match c {
-                    Component::Constant(n) => out.push_str(n),
-                    Component::Parameter(_) => (),
+                if let Component::Constant(n) = c {
+                    out.push('/');
+                    out.push_str(n);
                 }
             }
             out.push_str(&format!(r#"".to_string(), {server_arg});"#));
@@ -121,12 +131,13 @@ impl Template {
 
         out.push_str("&format!(\"");
         for c in self.components.iter() {
-            out.push('/');
             match c {
-                Component::Constant(n) => out.push_str(n),
-                Component::Parameter(_) => {
-                    out.push_str("{}");
+                Component::Constant(n) => {
+                    out.push('/');
+                    out.push_str(n);
                 }
+                Component::Parameter(_) => out.push_str("/{}"),
+                Component::Suffix(n) => out.push_str(n),
             }
         }
 
@@ -137,7 +148,9 @@ impl Template {
         out.push_str("\",\n");
         for c in self.components.iter() {
             if let Component::Parameter(n) = &c {
-                if n == "type"
+                if let Some((_, value)) = CLIENT_PARAMETERS.iter().find(|(p, _)| p == n) {
+                    out.push_str(&format!("{},", value));
+                } else if n == "type"
                     || n == "ref"
                     || n == "foo"
                     || n == "enum"
@@ -178,6 +191,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         Parameter,
         ParameterSlash,
         Constant,
+        Suffix,
     }
 
     let mut s = State::Start;
@@ -226,15 +240,29 @@ fn parse_inner(t: &str) -> Result<Template> {
                 }
             }
             State::ParameterSlash => {
-                if c == '/' || c == ':' || c == '.' {
+                if c == '/' {
+                    s = State::ConstantOrParameter;
+                } else if c == ':' || c == '.' {
                     // Google Admin API has ":issueCommand" so we want to allow that!
                     // Shopify sometimes ends after a parameter with ".json", so we want to allow
                     // that.
-                    s = State::ConstantOrParameter;
+                    a.push(c);
+                    s = State::Suffix;
                 } else {
                     bail!("expected a slash after parameter");
                 }
             }
+            State::Suffix => {
+                if c == '/' {
+                    components.push(Component::Suffix(a));
+                    a = String::new();
+                    s = State::ConstantOrParameter;
+                } else if c == '{' || c == '}' {
+                    bail!("unexpected parameter");
+                } else {
+                    a.push(c);
+                }
+            }
         }
     }
 
@@ -242,6 +270,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         State::Start => bail!("empty path"),
         State::ConstantOrParameter | State::ParameterSlash => (),
         State::Constant => components.push(Component::Constant(a)),
+        State::Suffix => components.push(Component::Suffix(a)),
         State::Parameter => bail!("unterminated parameter"),
     }
 
@@ -282,6 +311,27 @@ mod test {
                     ],
                 },
             ),
+            (
+                "/orders/{order_id}.json",
+                Template {
+                    components: vec![
+                        Component::Constant("orders".into()),
+                        Component::Parameter("order_id".into()),
+                        Component::Suffix(".json".into()),
+                    ],
+                },
+            ),
+            (
+                "/devices/{deviceId}:issueCommand/x",
+                Template {
+                    components: vec![
+                        Component::Constant("devices".into()),
+                        Component::Parameter("deviceId".into()),
+                        Component::Suffix(":issueCommand".into()),
+                        Component::Constant("x".into()),
+                    ],
+                },
+            ),
         ];
 
         for (path, want) in trials.iter() {
@@ -299,6 +349,30 @@ mod test {
         let want = r#"let url = self.client.url(
 &format!("/measure/{}",
 crate::progenitor_support::encode_path(&number.to_string()),), None);
+"#;
+        assert_eq!(want, &out);
+        Ok(())
+    }
+
+    #[test]
+    fn compile_suffix() -> Result<()> {
+        let t = parse("/v2/{name}:move")?;
+        let out = t.compile(Default::default(), "None");
+        let want = r#"let url = self.client.url(
+&format!("/v2/{}:move",
+crate::progenitor_support::encode_path(&name.to_string()),), None);
+"#;
+        assert_eq!(want, &out);
+        Ok(())
+    }
+
+    #[test]
+    fn compile_suffix_and_client_parameter() -> Result<()> {
+        let t = parse("/admin/api/{api_version}/orders/{order_id}.json")?;
+        let out = t.compile(Default::default(), "None");
+        let want = r#"let url = self.client.url(
+&format!("/admin/api/{}/orders/{}.json",
+self.client.api_version(),crate::progenitor_support::encode_path(&order_id.to_string()),), None);
 "#;
         assert_eq!(want, &out);
         Ok(())
@@ -595,10 +669,11 @@ pub fn generate_docs_generic_token(
     proper_name: &str,
     spec_link: &str,
     add_post_header: &str,
+    servers: &crate::client::GeneratedServers,
 ) -> String {
     let info = generate_docs_openapi_info(api, proper_name, spec_link, name);
 
-    let add_post_header_args = if !add_post_header.is_empty() {
+    let mut add_post_header_args = if !add_post_header.is_empty() {
         format!(
             ",\n//!     String::from(\"{}\")",
             to_snake_case(add_post_header)
@@ -607,12 +682,25 @@ pub fn generate_docs_generic_token(
         String::new()
     };
 
-    let add_post_header_var = if !add_post_header.is_empty() {
+    let mut add_post_header_var = if !add_post_header.is_empty() {
         r#", """#.to_string()
     } else {
         String::new()
     };
 
+    // Clients for a server with variables need to be told which server to use.
+    let server_example = servers.doc_example();
+    let client_use = if server_example.is_empty() {
+        "Client".to_string()
+    } else {
+        add_post_header_args.push_str(&format!(",\n//!     {}", server_example));
+        add_post_header_var.push_str(&format!(", {}", server_example));
+        format!(
+            "{{Client, {}}}",
+            servers.top_level_type.as_deref().unwrap_or_default()
+        )
+    };
+
     format!(
         r#"{}
 //!
@@ -629,7 +717,7 @@ pub fn generate_docs_generic_token(
 //! a user agent string and set of credentials.
 //!
 //! ```
-//! use {}::Client;
+//! use {}::{client_use};
 //!
 //! let {} = Client::new(
 //!     String::from("client-id"),
@@ -650,7 +738,7 @@ pub fn generate_docs_generic_token(
 //! And then you can create a client from the environment.
 //!
 //! ```
-//! use {}::Client;
+//! use {}::{client_use};
 //!
 //! let {} = Client::new_from_env(
 //!     String::from("token"),
@@ -664,7 +752,7 @@ pub fn generate_docs_generic_token(
 //! To start off a fresh client and get a `token` and `refresh_token`, use the following.
 //!
 //! ```
-//! use {}::Client;
+//! use {}::{client_use};
 //!
 //! async fn do_call() {{
 //!     let mut {} = Client::new_from_env("", ""{});
@@ -100,6 +100,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                         || sn == "MinimalRepository"
                         || sn == "WorkflowRun"
                         || sn == "CheckAnnotation"
+                        || sn == "RangeQuery"
+                        // Slack and Stripe form bodies are mostly optional fields.
+                        || (sn.ends_with("Request")
+                            && (proper_name == "Slack" || proper_name == "Stripe"))
                     {
                         a(
                             "#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, \
@@ -122,8 +126,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 && proper_name == "Stripe"
                             {
                                 // Stripe uses anyof, but we want oneof.
+                                // Expandable fields are optional and may be left out of
+                                // the response entirely.
                                 rt = format!(
-                                    "Box<Option<{}>>",
+                                    "Option<Box<{}>>",
                                     rt.trim_start_matches("Option<").trim_end_matches('>')
                                 );
                             } else if rt.ends_with("AnyOf>")
@@ -136,6 +142,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                     rt.trim_start_matches("Vec<").trim_end_matches('>')
                                 );
                             }
+                            if name == "metadata" && proper_name == "Stripe" {
+                                // Stripe metadata is a map of key-value pairs, not a string.
+                                rt = "std::collections::HashMap<String, String>".to_string();
+                            }
                             let mut prop = name.trim().to_string();
                             if prop == "next" {
                                 rt = "String".to_string();
@@ -179,7 +189,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                             if rt == "String"
                                 || rt.starts_with("Vec<")
                                 || rt.starts_with("Option<")
-                                || rt.starts_with("HashMap<")
+                                || rt.starts_with("std::collections::HashMap<")
                             {
                                 a(r#"#[serde(default,"#);
                                 if rt == "String" {
@@ -190,7 +200,8 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                       deserialize_with = "crate::utils::deserialize_null_vector::deserialize","#);
                                 } else if rt.starts_with("std::collections::HashMap<") {
                                     a(
-                                        r#"skip_serializing_if = "std::collections::HashMap::is_empty","#,
+                                        r#"skip_serializing_if = "std::collections::HashMap::is_empty",
+                                      deserialize_with = "crate::utils::deserialize_null_map::deserialize","#,
                                     );
                                 } else if rt.starts_with("Option<url::Url") {
                                     a(r#"skip_serializing_if = "Option::is_none",
@@ -235,6 +246,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 a(r#"#[serde(default,
                                     skip_serializing_if = "crate::utils::zero_i32",
                                     deserialize_with = "crate::utils::deserialize_null_i32::deserialize","#);
+                            } else if rt == "i64" && sn == "RangeQuery" {
+                                // Zero is a bound like any other in a range filter.
+                                a(r#"#[serde(default, skip_serializing_if = "Option::is_none","#);
+                                rt = "Option<i64>".to_string();
                             } else if rt == "i64" {
                                 a(r#"#[serde(default,
                                     skip_serializing_if = "crate::utils::zero_i64",
@@ -566,3 +581,118 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
     out
 }
+
+/*
+ * Slack answers failures with `"ok": false` and an error code. Each method
+ * lists the codes it may return in its default response, so collect them all
+ * into one enum for `ClientError::SlackApi`.
+ */
+pub fn generate_slack_error_codes(api: &openapiv3::OpenAPI) -> String {
+    let mut codes: Vec<String> = Default::default();
+    for (_, p) in api.paths.iter() {
+        let openapiv3::ReferenceOr::Item(p) = p else {
+            continue;
+        };
+
+        for (_, o) in p.iter() {
+            let Some(openapiv3::ReferenceOr::Item(r)) = &o.responses.default else {
+                continue;
+            };
+            let Some(openapiv3::ReferenceOr::Item(s)) = r
+                .content
+                .get("application/json")
+                .and_then(|mt| mt.schema.as_ref())
+            else {
+                continue;
+            };
+            let openapiv3::SchemaKind::Type(openapiv3::Type::Object(ot)) = &s.schema_kind else {
+                continue;
+            };
+            let Some(openapiv3::ReferenceOr::Item(e)) = ot.properties.get("error") else {
+                continue;
+            };
+            if let openapiv3::SchemaKind::Type(openapiv3::Type::String(st)) = &e.schema_kind {
+                codes.extend(st.enumeration.iter().flatten().cloned());
+            }
+        }
+    }
+    codes.sort_unstable();
+    codes.dedup();
+    let mut names: Vec<String> = Default::default();
+    codes.retain(|c| {
+        // Skip anything that would give us an empty or duplicate variant.
+        let n = struct_name(c);
+        if n.is_empty() || names.contains(&n) {
+            return false;
+        }
+        names.push(n);
+        true
+    });
+
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/// The error code Slack answers with when `ok` is `false`.");
+    a("///");
+    a("/// Codes Slack documents are given their own variant, anything else is kept in `Other`.");
+    a("#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]");
+    a(r#"#[serde(from = "String", into = "String")]"#);
+    a("pub enum SlackErrorCode {");
+    for c in &codes {
+        a(&format!("{},", struct_name(c)));
+    }
+    a("Other(String),");
+    a("}");
+    a("");
+
+    a("impl SlackErrorCode {");
+    a("pub fn as_str(&self) -> &str {");
+    a("match self {");
+    for c in &codes {
+        a(&format!(
+            r#"SlackErrorCode::{} => "{}","#,
+            struct_name(c),
+            c
+        ));
+    }
+    a("SlackErrorCode::Other(s) => s,");
+    a("}");
+    a("}");
+    a("}");
+    a("");
+
+    a("impl From<String> for SlackErrorCode {");
+    a("fn from(s: String) -> Self {");
+    a("match s.as_str() {");
+    for c in &codes {
+        a(&format!(
+            r#""{}" => SlackErrorCode::{},"#,
+            c,
+            struct_name(c)
+        ));
+    }
+    a("_ => SlackErrorCode::Other(s),");
+    a("}");
+    a("}");
+    a("}");
+    a("");
+
+    a("impl From<SlackErrorCode> for String {");
+    a("fn from(code: SlackErrorCode) -> Self {");
+    a("code.as_str().to_string()");
+    a("}");
+    a("}");
+    a("");
+
+    a("impl std::fmt::Display for SlackErrorCode {");
+    a("fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {");
+    a("self.as_str().fmt(f)");
+    a("}");
+    a("}");
+
+    out
+}
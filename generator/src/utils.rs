@@ -9,6 +9,46 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
     l.get(&Some("next".to_string())).map(|link| link.raw_uri.to_string()).map(NextLink)
 }
 
+/// Add a query parameter to a url, whether or not it already has a query string.
+pub fn add_query_param(url: &str, key: &str, value: &str) -> String {
+    let value = percent_encoding::utf8_percent_encode(value, percent_encoding::NON_ALPHANUMERIC);
+    if url.contains('?') {
+        format!("{}&{}={}", url, key, value)
+    } else {
+        format!("{}?{}={}", url, key, value)
+    }
+}
+
+/// Lazily walk the pages of a paginated request.
+///
+/// `fetch` is handed the url of a page and returns the items on it, along with
+/// the url of the next page if there is one. Pages are only requested as the
+/// stream is polled, so dropping the stream, or capping it with
+/// `futures::StreamExt::take`, stops any further requests.
+pub fn paginate<'a, D, F, Fut>(
+    url: String,
+    mut fetch: F,
+) -> impl futures::Stream<Item = crate::ClientResult<D>> + 'a
+where
+    D: 'a,
+    F: FnMut(String) -> Fut + 'a,
+    Fut: std::future::Future<Output = crate::ClientResult<(Vec<D>, Option<String>)>> + 'a,
+{
+    use futures::TryStreamExt;
+
+    futures::stream::try_unfold(Some(url), move |next| {
+        let page = next.map(&mut fetch);
+        async move {
+            match page {
+                Some(page) => page.await.map(Some),
+                None => Ok(None),
+            }
+        }
+    })
+    .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 pub mod date_format {
     use chrono::{NaiveDate};
     use serde::{self, Deserialize, Deserializer};
@@ -635,9 +675,33 @@ pub mod deserialize_null_vector {
     }
 }
 
+pub mod deserialize_null_map {
+    use serde::{self, Deserialize, Deserializer};
+
+    // The signature of a deserialize_with function must follow the pattern:
+    //
+    //    fn deserialize<'de, D>(D) -> Result<T, D::Error>
+    //    where
+    //        D: Deserializer<'de>
+    //
+    // although it may also be generic over the output types T.
+    pub fn deserialize<'de, K, V, D>(
+        deserializer: D,
+    ) -> Result<std::collections::HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + std::hash::Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let m: Option<std::collections::HashMap<K, V>> = Option::deserialize(deserializer)?;
+
+        Ok(m.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::next_link;
+    use super::{add_query_param, next_link, paginate};
 
     #[test]
     fn test_hyperx_next_link_compat() {
@@ -648,6 +712,39 @@ mod tests {
 
         assert_eq!("https://next-link", next);
     }
+
+    #[test]
+    fn test_add_query_param() {
+        assert_eq!(add_query_param("https://x/y", "page", "a b"), "https://x/y?page=a%20b");
+        assert_eq!(add_query_param("https://x/y?q=1", "page", "2"), "https://x/y?q=1&page=2");
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        use futures::{StreamExt, TryStreamExt};
+
+        let fetched = std::cell::Cell::new(0);
+        let pages = || {
+            paginate("1".to_string(), |page: String| {
+                fetched.set(fetched.get() + 1);
+                async move {
+                    let n: u32 = page.parse().unwrap();
+                    let next = if n < 3 { Some((n + 1).to_string()) } else { None };
+                    Ok((vec![n * 10, n * 10 + 1], next))
+                }
+            })
+        };
+
+        let all: Vec<u32> = pages().try_collect().await.unwrap();
+        assert_eq!(all, vec![10, 11, 20, 21, 30, 31]);
+        assert_eq!(fetched.get(), 3);
+
+        // Stopping early does not fetch the rest of the pages.
+        fetched.set(0);
+        let some: Vec<_> = pages().take(3).collect().await;
+        assert_eq!(some.len(), 3);
+        assert_eq!(fetched.get(), 2);
+    }
 }
 
 "#;
@@ -762,10 +859,294 @@ mod github_tests {
 
 "#;
 
+const FILE_TEMPLATE: &str = r#"/// A file returned by the API, rather than a JSON document.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The raw response body.
+    pub body: bytes::Bytes,
+    /// The `Content-Type` the server sent the file with.
+    pub content_type: Option<String>,
+    /// The filename from the `Content-Disposition` header.
+    pub filename: Option<String>,
+}
+
+/// Get the filename out of a `Content-Disposition` header.
+///
+/// The RFC 5987 `filename*` parameter wins over a plain `filename` when a
+/// server sends both.
+pub fn content_disposition_filename(value: &str) -> Option<String> {
+    let mut filename = None;
+    for param in value.split(';').skip(1) {
+        if let Some((key, val)) = param.split_once('=') {
+            let val = val.trim().trim_matches('"');
+            match key.trim().to_ascii_lowercase().as_str() {
+                "filename*" => {
+                    // This looks like `UTF-8''file%20name.pdf`.
+                    if let Some(encoded) = val.splitn(3, '\'').nth(2) {
+                        if let Ok(decoded) = percent_encoding::percent_decode_str(encoded).decode_utf8() {
+                            return Some(decoded.to_string());
+                        }
+                    }
+                }
+                "filename" if !val.is_empty() => filename = Some(val.to_string()),
+                _ => (),
+            }
+        }
+    }
+
+    filename
+}
+
+/// A file to send as part of a `multipart/form-data` request.
+#[derive(Debug, Clone, Default)]
+pub struct FilePart {
+    /// The content of the file.
+    pub content: bytes::Bytes,
+    /// The name of the file.
+    pub filename: String,
+    /// The MIME type of the file, for example `image/png`.
+    pub content_type: String,
+}
+
+impl FilePart {
+    /// Turn this into a part of a form.
+    pub fn into_part(self) -> crate::ClientResult<reqwest::multipart::Part> {
+        let mut part = reqwest::multipart::Part::bytes(self.content.to_vec()).file_name(self.filename);
+        if !self.content_type.is_empty() {
+            part = part.mime_str(&self.content_type)?;
+        }
+
+        Ok(part)
+    }
+}
+
+/// Get the text of a `multipart/form-data` part.
+///
+/// Strings (and enums) are sent as they are, anything else as JSON.
+pub fn form_text<T: serde::Serialize + ?Sized>(value: &T) -> crate::ClientResult<String> {
+    Ok(match serde_json::to_value(value)? {
+        serde_json::Value::String(s) => s,
+        v => v.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod file_tests {
+    use super::{content_disposition_filename, form_text};
+
+    #[test]
+    fn test_content_disposition_filename() {
+        assert_eq!(
+            content_disposition_filename("attachment; filename=\"signed.pdf\"").as_deref(),
+            Some("signed.pdf")
+        );
+        assert_eq!(
+            content_disposition_filename("attachment; filename=old.pdf; filename*=UTF-8''sign%C3%A9.pdf").as_deref(),
+            Some("signé.pdf")
+        );
+        assert_eq!(content_disposition_filename("inline"), None);
+    }
+
+    #[test]
+    fn test_form_text() {
+        assert_eq!(form_text("en").unwrap(), "en");
+        assert_eq!(form_text(&3).unwrap(), "3");
+        assert_eq!(form_text(&serde_json::json!({"a": true})).unwrap(), "{\"a\":true}");
+    }
+}
+
+"#;
+
+const STRIPE_TEMPLATE: &str = r#"/// Encode a request body the way Stripe expects form data.
+///
+/// Nested objects and arrays are flattened into bracketed keys, for example
+/// `metadata[order_id]=6735` or `items[0][price]=price_123`.
+pub fn encode_form<T: serde::Serialize + ?Sized>(body: &T) -> crate::ClientResult<String> {
+    let mut pairs: Vec<(String, String)> = Default::default();
+    if let serde_json::Value::Object(o) = serde_json::to_value(body)? {
+        for (k, v) in o {
+            flatten_form_value(k, v, &mut pairs);
+        }
+    }
+
+    Ok(serde_urlencoded::to_string(&pairs)?)
+}
+
+/// Flatten a deep object query parameter into bracketed query pairs, for
+/// example `created[gte]=1680000000`. Plain values are sent as they are, as in
+/// `created=1680000000`.
+pub fn deep_object_query_args<T: serde::Serialize + ?Sized>(
+    name: &str,
+    value: &T,
+) -> crate::ClientResult<Vec<(String, String)>> {
+    let mut pairs: Vec<(String, String)> = Default::default();
+    flatten_form_value(name.to_string(), serde_json::to_value(value)?, &mut pairs);
+
+    Ok(pairs)
+}
+
+fn flatten_form_value(key: String, value: serde_json::Value, pairs: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Bool(b) => pairs.push((key, b.to_string())),
+        serde_json::Value::Number(n) => pairs.push((key, n.to_string())),
+        serde_json::Value::String(s) => pairs.push((key, s)),
+        serde_json::Value::Array(a) => {
+            for (i, v) in a.into_iter().enumerate() {
+                flatten_form_value(format!("{}[{}]", key, i), v, pairs);
+            }
+        }
+        serde_json::Value::Object(o) => {
+            for (k, v) in o {
+                flatten_form_value(format!("{}[{}]", key, k), v, pairs);
+            }
+        }
+    }
+}
+"#;
+
+const SLACK_TEMPLATE: &str = r#"/// Check the `ok` envelope Slack wraps every response in.
+///
+/// Slack answers most failures with a `200 OK` and `"ok": false`, so this
+/// turns those into a [`crate::ClientError::SlackApi`] before we try to read
+/// the success type. Bodies that are not a JSON envelope are let through.
+pub fn slack_ok(body: &[u8]) -> crate::ClientResult<()> {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        ok: Option<bool>,
+        #[serde(default)]
+        error: String,
+        #[serde(default)]
+        warning: String,
+        #[serde(default)]
+        response_metadata: Option<serde_json::Value>,
+    }
+
+    match serde_json::from_slice::<Envelope>(body) {
+        Ok(Envelope {
+            ok: Some(false),
+            error,
+            warning,
+            response_metadata,
+        }) => Err(crate::ClientError::SlackApi {
+            error: error.into(),
+            warning,
+            response_metadata: response_metadata.and_then(|m| serde_json::from_value(m).ok()),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Get the cursor for the next page out of a Slack response, if there is one.
+///
+/// An empty `response_metadata.next_cursor` means we are on the last page.
+pub fn slack_next_cursor(value: &serde_json::Value) -> Option<String> {
+    value
+        .get("response_metadata")
+        .and_then(|m| m.get("next_cursor"))
+        .and_then(serde_json::Value::as_str)
+        .filter(|cursor| !cursor.is_empty())
+        .map(|cursor| cursor.to_string())
+}
+"#;
+
+const SHOPIFY_TEMPLATE: &str = r#"/// The most items Shopify hands back on a page.
+pub const SHOPIFY_MAX_LIMIT: u32 = 250;
+
+/// The url of the first page when walking all the pages of a Shopify list, with
+/// as many items to a page as Shopify allows.
+pub fn shopify_first_page(url: &str) -> String {
+    add_query_param(url, "limit", &SHOPIFY_MAX_LIMIT.to_string())
+}
+
+/// Get the url of the page after `page_url` from the `page_info` cursor in the
+/// `next` link Shopify sent with it.
+///
+/// Shopify rejects follow-up pages that repeat the filters of the first page,
+/// so the next page only carries the cursor, along with the `limit` and
+/// `fields` of the page before it.
+pub fn shopify_next_page(page_url: &str, link: &str) -> Option<String> {
+    let link = url::Url::parse(link).ok()?;
+    let (_, page_info) = link.query_pairs().find(|(k, _)| k == "page_info")?;
+
+    let (path, query) = page_url.split_once('?').unwrap_or((page_url, ""));
+    let mut next = path.to_string();
+    for (k, v) in url::form_urlencoded::parse(query.as_bytes()) {
+        if k == "limit" || k == "fields" {
+            next = add_query_param(&next, &k, &v);
+        }
+    }
+
+    Some(add_query_param(&next, "page_info", &page_info))
+}
+"#;
+
+const WEBHOOK_TEMPLATE: &str = r#"/// The HMAC-SHA256 of a message with a webhook secret.
+#[cfg(feature = "webhooks")]
+pub fn hmac_sha256(secret: &[u8], message: &[u8]) -> Vec<u8> {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret);
+    ring::hmac::sign(&key, message).as_ref().to_vec()
+}
+
+/// Check a message against signatures, with any of the secrets, in constant
+/// time. There's more than one of each while a secret is being rolled.
+#[cfg(feature = "webhooks")]
+pub fn verify_hmac_sha256<S, T>(secrets: &[S], signatures: &[T], message: &[u8]) -> bool
+where
+    S: AsRef<[u8]>,
+    T: AsRef<[u8]>,
+{
+    secrets.iter().any(|secret| {
+        let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_ref());
+        signatures
+            .iter()
+            .any(|signature| ring::hmac::verify(&key, message, signature.as_ref()).is_ok())
+    })
+}
+
+/// Lowercase hex, the way signatures are written in webhook headers.
+#[cfg(feature = "webhooks")]
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The bytes of a hex string, or `None` if it isn't one.
+#[cfg(feature = "webhooks")]
+pub fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [hi, lo] if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                u8::from_str_radix(std::str::from_utf8(&[*hi, *lo]).ok()?, 16).ok()
+            }
+            _ => None,
+        })
+        .collect()
+}
+"#;
+
 pub fn generate_utils(proper_name: &str) -> String {
     let mut optional = String::new();
     if proper_name == "GitHub" {
         optional = GITHUB_TEMPLATE.to_string();
+    } else if proper_name == "Stripe" {
+        optional = STRIPE_TEMPLATE.to_string();
+    } else if proper_name == "Slack" {
+        optional = SLACK_TEMPLATE.to_string();
+    } else if proper_name == "Shopify" {
+        optional = SHOPIFY_TEMPLATE.to_string();
+    }
+
+    // These sign their webhooks with an HMAC-SHA256 of the body.
+    if matches!(proper_name, "GitHub" | "Slack" | "Stripe" | "DocuSign") {
+        optional.push('\n');
+        optional.push_str(WEBHOOK_TEMPLATE);
+    }
+
+    // GitHub's client doesn't send or download files.
+    if proper_name != "GitHub" {
+        optional.push('\n');
+        optional.push_str(FILE_TEMPLATE);
     }
 
     format!("{}\n{}", optional, TEMPLATE)
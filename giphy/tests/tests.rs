@@ -1 +1,30 @@
+use giphy_api::Client;
+use wiremock::{
+    matchers::{header_exists, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_api_key_is_sent_as_a_query_param() {
+    let server = MockServer::start().await;
+
+    // The key only goes in the query.
+    Mock::given(header_exists("authorization"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/gifs/3"))
+        .and(query_param("api_key", "giphy-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut giphy = Client::new("giphy-key");
+    giphy.with_host_override(server.uri());
+
+    giphy.gifs().get_gifs(3).await.unwrap();
+}
@@ -1018,6 +1018,26 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        futures::TryStreamExt::try_collect(self.list_all_runner_applications_for_org_stream(org))
+            .await
+    }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_all_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/downloads",
@@ -1025,15 +1045,7 @@ impl Actions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a registration token for an organization.
@@ -2164,6 +2176,29 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_runner_applications_for_repo_stream(owner, repo),
+        )
+        .await
+    }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_all_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/downloads",
@@ -2172,15 +2207,7 @@ impl Actions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a registration token for a repository.
@@ -2576,6 +2603,26 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<Vec<crate::types::EnvironmentApproval>> {
+        futures::TryStreamExt::try_collect(self.get_all_reviews_for_run_stream(owner, repo, run_id))
+            .await
+    }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_all_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EnvironmentApproval>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/approvals",
@@ -2585,15 +2632,7 @@ impl Actions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Approve a workflow run for a fork pull request.
@@ -2934,6 +2973,30 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<Vec<crate::types::PendingDeployment>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_pending_deployments_for_run_stream(owner, repo, run_id),
+        )
+        .await
+    }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all deployment environments for a workflow run that are waiting for protection rules to pass.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_all_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PendingDeployment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/pending_deployments",
@@ -2943,15 +3006,7 @@ impl Actions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Review pending deployments for a workflow run.
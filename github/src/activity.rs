@@ -61,16 +61,24 @@ impl Activity {
      * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
      */
     pub async fn list_all_public_events(&self) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_events_stream()).await
+    }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * We delay the public events feed by five minutes, which means the most recent event returned by the public events API actually occurred at least five minutes ago.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_all_public_events_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url("/events", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get feeds.
@@ -169,6 +177,27 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_public_events_for_repo_network_stream(owner, repo),
+        )
+        .await
+    }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_all_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/networks/{}/{}/events",
@@ -177,15 +206,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List notifications for the authenticated user.
@@ -263,6 +284,34 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Thread>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_notifications_for_authenticated_user_stream(
+                all,
+                participating,
+                since,
+                before,
+            ),
+        )
+        .await
+    }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all notifications for the current user, sorted by most recently updated.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_notifications_for_authenticated_user_stream(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
             query_args.push(("all".to_string(), all.to_string()));
@@ -278,15 +327,7 @@ impl Activity {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/notifications?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Mark notifications as read.
@@ -542,6 +583,23 @@ impl Activity {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_org_events_stream(org)).await
+    }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_all_public_org_events`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_all_public_org_events_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/events",
@@ -549,15 +607,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List repository events.
@@ -625,6 +675,24 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_repo_events_stream(owner, repo)).await
+    }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_all_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/events",
@@ -633,15 +701,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List repository notifications for the authenticated user.
@@ -733,6 +793,38 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Thread>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_repo_notifications_for_authenticated_user_stream(
+                owner,
+                repo,
+                all,
+                participating,
+                since,
+                before,
+            ),
+        )
+        .await
+    }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all notifications for the current user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
             query_args.push(("all".to_string(), all.to_string()));
@@ -756,15 +848,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Mark repository notifications as read.
@@ -922,6 +1006,25 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_watchers_for_repo_stream(owner, repo))
+            .await
+    }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the people watching the specified repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_all_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/subscribers",
@@ -930,15 +1033,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a repository subscription.
@@ -1118,6 +1213,29 @@ impl Activity {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::Repository>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_repos_starred_by_authenticated_user_stream(sort, direction),
+        )
+        .await
+    }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists repositories the authenticated user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_all_repos_starred_by_authenticated_user_stream(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1127,15 +1245,7 @@ impl Activity {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a repository is starred by the authenticated user.
@@ -1302,16 +1412,27 @@ impl Activity {
     pub async fn list_all_watched_repos_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_watched_repos_for_authenticated_user_stream(),
+        )
+        .await
+    }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists repositories the authenticated user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_all_watched_repos_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url("/user/subscriptions", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List events for the authenticated user.
@@ -1375,6 +1496,26 @@ impl Activity {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_events_for_authenticated_user_stream(username),
+        )
+        .await
+    }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * If you are authenticated as the given user, you will see your private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_all_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/events",
@@ -1382,15 +1523,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List organization events for the authenticated user.
@@ -1458,6 +1591,27 @@ impl Activity {
         username: &str,
         org: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_org_events_for_authenticated_user_stream(username, org),
+        )
+        .await
+    }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * This is the user's organization dashboard. You must be authenticated as the user to view this.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_all_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/events/orgs/{}",
@@ -1466,15 +1620,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List public events for a user.
@@ -1538,6 +1684,24 @@ impl Activity {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_events_for_user_stream(username))
+            .await
+    }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_all_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/events/public",
@@ -1545,15 +1709,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List events received by the authenticated user.
@@ -1617,6 +1773,24 @@ impl Activity {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_received_events_for_user_stream(username))
+            .await
+    }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * These are events that you've received by watching repos and following users. If you are authenticated as the given user, you will see private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_all_received_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/received_events",
@@ -1624,15 +1798,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List public events received by a user.
@@ -1696,6 +1862,26 @@ impl Activity {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_received_public_events_for_user_stream(username),
+        )
+        .await
+    }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_all_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/received_events/public",
@@ -1703,15 +1889,7 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List repositories starred by a user.
@@ -1836,6 +2014,24 @@ impl Activity {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_repos_watched_by_user_stream(username))
+            .await
+    }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists repositories a user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_all_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/subscriptions",
@@ -1843,14 +2039,6 @@ impl Activity {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -176,6 +176,25 @@ impl Apps {
         &self,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        futures::TryStreamExt::try_collect(self.list_all_webhook_deliveries_stream(cursor)).await
+    }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns a list of webhook deliveries for the webhook configured for a GitHub App.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -184,15 +203,7 @@ impl Apps {
         let url = self
             .client
             .url(&format!("/app/hook/deliveries?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a delivery for an app webhook.
@@ -333,6 +344,27 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> ClientResult<Vec<crate::types::Installation>> {
+        futures::TryStreamExt::try_collect(self.list_all_installations_stream(since, outdated))
+            .await
+    }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * The permissions the installation has are included under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_all_installations_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Installation>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !outdated.is_empty() {
             query_args.push(("outdated".to_string(), outdated.to_string()));
@@ -344,15 +376,7 @@ impl Apps {
         let url = self
             .client
             .url(&format!("/app/installations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an installation for the authenticated app.
@@ -1052,16 +1076,26 @@ impl Apps {
      * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
      */
     pub async fn list_all_plans(&self) -> ClientResult<Vec<crate::types::MarketplaceListingPlan>> {
+        futures::TryStreamExt::try_collect(self.list_all_plans_stream()).await
+    }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_all_plans_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + '_ {
         let url = self.client.url("/marketplace_listing/plans", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List accounts for a plan.
@@ -1143,6 +1177,30 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::MarketplacePurchaseData>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_accounts_for_plan_stream(plan_id, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns user and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_all_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1159,15 +1217,7 @@ impl Apps {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a subscription plan for an account (stubbed).
@@ -1264,16 +1314,26 @@ impl Apps {
     pub async fn list_all_plans_stubbed(
         &self,
     ) -> ClientResult<Vec<crate::types::MarketplaceListingPlan>> {
+        futures::TryStreamExt::try_collect(self.list_all_plans_stubbed_stream()).await
+    }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_all_plans_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + '_ {
         let url = self.client.url("/marketplace_listing/stubbed/plans", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List accounts for a plan (stubbed).
@@ -1355,6 +1415,30 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::MarketplacePurchaseData>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_accounts_for_plan_stubbed_stream(plan_id, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns repository and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_all_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1371,15 +1455,7 @@ impl Apps {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an organization installation for the authenticated app.
@@ -1732,16 +1808,27 @@ impl Apps {
     pub async fn list_all_subscriptions_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::UserMarketplacePurchase>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_subscriptions_for_authenticated_user_stream(),
+        )
+        .await
+    }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + '_ {
         let url = self.client.url("/user/marketplace_purchases", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List subscriptions for the authenticated user (stubbed).
@@ -1798,16 +1885,27 @@ impl Apps {
     pub async fn list_all_subscriptions_for_authenticated_user_stubbed(
         &self,
     ) -> ClientResult<Vec<crate::types::UserMarketplacePurchase>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_subscriptions_for_authenticated_user_stubbed_stream(),
+        )
+        .await
+    }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + '_ {
         let url = self.client.url("/user/marketplace_purchases/stubbed", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a user installation for the authenticated app.
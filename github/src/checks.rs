@@ -208,6 +208,30 @@ impl Checks {
         repo: &str,
         check_run_id: i64,
     ) -> ClientResult<Vec<crate::types::CheckAnnotation>> {
+        futures::TryStreamExt::try_collect(self.list_all_annotations_stream(
+            owner,
+            repo,
+            check_run_id,
+        ))
+        .await
+    }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists annotations for a check run using the annotation `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get annotations for a check run. OAuth Apps and authenticated users must have the `repo` scope to get annotations for a check run in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_all_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CheckAnnotation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-runs/{}/annotations",
@@ -217,15 +241,7 @@ impl Checks {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a check suite.
@@ -117,6 +117,39 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> ClientResult<Vec<crate::types::CodeScanningAlertItems>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_alerts_for_repo_stream(owner, repo, tool_name, tool_guid, ref_, state),
+        )
+        .await
+    }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all open code scanning alerts for the default branch (usually `main`
+     * or `master`). You must use an access token with the `security_events` scope to use
+     * this endpoint. GitHub Apps must have the `security_events` read permission to use
+     * this endpoint.
+     *
+     * The response includes a `most_recent_instance` object.
+     * This provides details of the most recent instance of this alert
+     * for the default branch or for the specified Git reference
+     * (if you used `ref` in the request).
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertItems>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -140,15 +173,7 @@ impl CodeScanning {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a code scanning alert.
@@ -310,6 +335,33 @@ impl CodeScanning {
         alert_number: i64,
         ref_: &str,
     ) -> ClientResult<Vec<crate::types::CodeScanningAlertInstance>> {
+        futures::TryStreamExt::try_collect(self.list_all_alert_instances_stream(
+            owner,
+            repo,
+            alert_number,
+            ref_,
+        ))
+        .await
+    }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_all_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertInstance>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -325,15 +377,7 @@ impl CodeScanning {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List code scanning analyses for a repository.
@@ -455,6 +499,46 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> ClientResult<Vec<crate::types::CodeScanningAnalysis>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_recent_analyses_stream(owner, repo, tool_name, tool_guid, ref_, sarif_id),
+        )
+        .await
+    }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the details of all code scanning analyses for a repository,
+     * starting with the most recent.
+     * The response is paginated and you can use the `page` and `per_page` parameters
+     * to list the analyses you're interested in.
+     * By default 30 analyses are listed per page.
+     *
+     * The `rules_count` field in the response give the number of rules
+     * that were run in the analysis.
+     * For very old analyses this data is not available,
+     * and `0` is returned in this field.
+     *
+     * You must use an access token with the `security_events` scope to use this endpoint.
+     * GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * **Deprecation notice**:
+     * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_all_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAnalysis>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -478,15 +562,7 @@ impl CodeScanning {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a code scanning analysis for a repository.
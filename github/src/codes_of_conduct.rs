@@ -46,16 +46,24 @@ impl CodesOfConduct {
     pub async fn get_all_all_codes_of_conduct(
         &self,
     ) -> ClientResult<Vec<crate::types::CodeOfConduct>> {
+        futures::TryStreamExt::try_collect(self.get_all_all_codes_of_conduct_stream()).await
+    }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_all_codes_of_conduct_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeOfConduct>> + '_ {
         let url = self.client.url("/codes_of_conduct", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a code of conduct.
@@ -982,6 +982,28 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_runner_applications_for_enterprise_stream(enterprise),
+        )
+        .await
+    }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_all_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + '_ {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/downloads",
@@ -989,15 +1011,7 @@ impl EnterpriseAdmin {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a registration token for an enterprise.
@@ -1266,6 +1280,31 @@ impl EnterpriseAdmin {
         before: &str,
         order: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::AuditLogEvent>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_audit_log_stream(enterprise, phrase, include, after, before, order),
+        )
+        .await
+    }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1291,15 +1330,7 @@ impl EnterpriseAdmin {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List provisioned SCIM groups for an enterprise.
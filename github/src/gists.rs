@@ -69,21 +69,30 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(since)).await
+    }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a gist.
@@ -173,21 +182,32 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_stream(since)).await
+    }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List public gists sorted by most recently updated to least recently updated.
+     *
+     * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists/public?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List starred gists.
@@ -247,21 +267,30 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        futures::TryStreamExt::try_collect(self.list_all_starred_stream(since)).await
+    }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the authenticated user's starred gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_all_starred_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists/starred?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a gist.
@@ -413,6 +442,23 @@ impl Gists {
         &self,
         gist_id: &str,
     ) -> ClientResult<Vec<crate::types::GistComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_comments_stream(gist_id)).await
+    }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistComment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/gists/{}/comments",
@@ -420,15 +466,7 @@ impl Gists {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a gist comment.
@@ -635,6 +673,23 @@ impl Gists {
         &self,
         gist_id: &str,
     ) -> ClientResult<Vec<crate::types::GistCommit>> {
+        futures::TryStreamExt::try_collect(self.list_all_commits_stream(gist_id)).await
+    }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistCommit>> + '_ {
         let url = self.client.url(
             &format!(
                 "/gists/{}/commits",
@@ -642,15 +697,7 @@ impl Gists {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List gist forks.
@@ -714,6 +761,23 @@ impl Gists {
         &self,
         gist_id: &str,
     ) -> ClientResult<Vec<crate::types::GistSimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_forks_stream(gist_id)).await
+    }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistSimple>> + '_ {
         let url = self.client.url(
             &format!(
                 "/gists/{}/forks",
@@ -721,15 +785,7 @@ impl Gists {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Fork a gist.
@@ -960,6 +1016,24 @@ impl Gists {
         username: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_user_stream(username, since)).await
+    }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists public gists for the specified user:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
@@ -973,14 +1047,6 @@ impl Gists {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -309,6 +309,32 @@ impl Git {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<Vec<crate::types::GitRef>> {
+        futures::TryStreamExt::try_collect(self.list_all_matching_refs_stream(owner, repo, ref_))
+            .await
+    }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns an array of references from your Git database that match the supplied name. The `:ref` in the URL must be formatted as `heads/<branch name>` for branches and `tags/<tag name>` for tags. If the `:ref` doesn't exist in the repository, but existing refs start with `:ref`, they will be returned as an array.
+     *
+     * When you use this endpoint without providing a `:ref`, it will return an array of all the references from your Git database, including notes and stashes if they exist on the server. Anything in the namespace is returned, not just `heads` and `tags`.
+     *
+     * **Note:** You need to explicitly [request a pull request](https://docs.github.com/rest/reference/pulls#get-a-pull-request) to trigger a test merge commit, which checks the mergeability of pull requests. For more information, see "[Checking mergeability of pull requests](https://docs.github.com/rest/guides/getting-started-with-the-git-database-api#checking-mergeability-of-pull-requests)".
+     *
+     * If you request matching references for a branch named `feature` but the branch `feature` doesn't exist, the response can still include other matching head refs that start with the word `feature`, such as `featureA` and `featureB`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_all_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitRef>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/matching-refs/{}",
@@ -318,15 +344,7 @@ impl Git {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a reference.
@@ -44,16 +44,24 @@ impl Gitignore {
      * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
      */
     pub async fn get_all_all_templates(&self) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.get_all_all_templates_stream()).await
+    }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all templates available to pass as an option when [creating a repository](https://docs.github.com/rest/reference/repos#create-a-repository-for-the-authenticated-user).
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_all_templates_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
         let url = self.client.url("/gitignore/templates", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a gitignore template.
@@ -146,6 +146,43 @@ impl Issues {
         owned: bool,
         pulls: bool,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            filter, state, labels, sort, direction, since, collab, orgs, owned, pulls,
+        ))
+        .await
+    }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List issues assigned to the authenticated user across all visible repositories including owned repositories, member
+     * repositories, and organization repositories. You can use the `filter` query parameter to fetch issues that are not
+     * necessarily assigned to you.
+     *
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if collab {
             query_args.push(("collab".to_string(), collab.to_string()));
@@ -179,15 +216,7 @@ impl Issues {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/issues?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List organization issues assigned to the authenticated user.
@@ -304,6 +333,37 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_org_stream(org, filter, state, labels, sort, direction, since),
+        )
+        .await
+    }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List issues in an organization assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -332,15 +392,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List assignees.
@@ -408,6 +460,24 @@ impl Issues {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_assignees_stream(owner, repo)).await
+    }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the [available assignees](https://help.github.com/articles/assigning-issues-and-pull-requests-to-other-github-users/) for issues in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_all_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/assignees",
@@ -416,15 +486,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a user can be assigned.
@@ -602,6 +664,42 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::IssueSimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_repo_stream(
+            owner, repo, milestone, state, assignee, creator, mentioned, labels, sort, direction,
+            since,
+        ))
+        .await
+    }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List issues in a repository.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueSimple>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !assignee.is_empty() {
             query_args.push(("assignee".to_string(), assignee.to_string()));
@@ -640,15 +738,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an issue.
@@ -776,6 +866,30 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::IssueComment>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_comments_for_repo_stream(owner, repo, sort, direction, since),
+        )
+        .await
+    }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * By default, Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_all_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -796,15 +910,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an issue comment.
@@ -993,6 +1099,24 @@ impl Issues {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::IssueEvent>> {
+        futures::TryStreamExt::try_collect(self.list_all_events_for_repo_stream(owner, repo)).await
+    }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_all_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEvent>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/events",
@@ -1001,15 +1125,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an issue event.
@@ -1300,6 +1416,32 @@ impl Issues {
         issue_number: i64,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::IssueComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_comments_stream(
+            owner,
+            repo,
+            issue_number,
+            since,
+        ))
+        .await
+    }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
@@ -1315,15 +1457,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an issue comment.
@@ -1436,6 +1570,26 @@ impl Issues {
         repo: &str,
         issue_number: i64,
     ) -> ClientResult<Vec<crate::types::IssueEventAnyOf>> {
+        futures::TryStreamExt::try_collect(self.list_all_events_stream(owner, repo, issue_number))
+            .await
+    }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_all_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEventAnyOf>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/events",
@@ -1445,15 +1599,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List labels for an issue.
@@ -1525,6 +1671,30 @@ impl Issues {
         repo: &str,
         issue_number: i64,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        futures::TryStreamExt::try_collect(self.list_all_labels_on_issue_stream(
+            owner,
+            repo,
+            issue_number,
+        ))
+        .await
+    }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_all_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/labels",
@@ -1534,15 +1704,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set labels for an issue.
@@ -1857,6 +2019,30 @@ impl Issues {
         repo: &str,
         issue_number: i64,
     ) -> ClientResult<Vec<crate::types::Data>> {
+        futures::TryStreamExt::try_collect(self.list_all_events_for_timeline_stream(
+            owner,
+            repo,
+            issue_number,
+        ))
+        .await
+    }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_all_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/timeline",
@@ -1866,15 +2052,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List labels for a repository.
@@ -1942,6 +2120,24 @@ impl Issues {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        futures::TryStreamExt::try_collect(self.list_all_labels_for_repo_stream(owner, repo)).await
+    }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_all_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/labels",
@@ -1950,15 +2146,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a label.
@@ -2200,6 +2388,30 @@ impl Issues {
         sort: crate::types::IssuesListMilestonesSort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::Milestone>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_milestones_stream(owner, repo, state, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_all_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Milestone>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2220,15 +2432,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a milestone.
@@ -2459,6 +2663,30 @@ impl Issues {
         repo: &str,
         milestone_number: i64,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        futures::TryStreamExt::try_collect(self.list_all_labels_for_milestone_stream(
+            owner,
+            repo,
+            milestone_number,
+        ))
+        .await
+    }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_all_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/milestones/{}/labels",
@@ -2468,15 +2696,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List user account issues assigned to the authenticated user.
@@ -2583,6 +2803,38 @@ impl Issues {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_authenticated_user_stream(
+                filter, state, labels, sort, direction, since,
+            ),
+        )
+        .await
+    }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List issues across owned and member repositories assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2604,14 +2856,6 @@ impl Issues {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/issues?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -241,6 +241,9 @@ pub mod types;
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhooks;
 
 use thiserror::Error;
 type ClientResult<T> = Result<T, ClientError>;
@@ -260,12 +263,23 @@ pub enum ClientError {
     #[error(transparent)]
     #[cfg(feature = "httpcache")]
     IoError(#[from] std::io::Error),
+    /// A webhook delivery without a header we need
+    #[cfg(feature = "webhooks")]
+    #[error("Webhook delivery is missing the {header} header")]
+    MissingWebhookHeader { header: &'static str },
+    /// A webhook delivery whose signature doesn't match its body
+    #[cfg(feature = "webhooks")]
+    #[error("Webhook signature is invalid")]
+    InvalidWebhookSignature,
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde URL encoding error
+    #[error(transparent)]
+    SerdeUrlencodedSerError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -712,11 +726,26 @@ impl Client {
         .await
     }
 
-    async fn get_all_pages<D>(&self, uri: &str, _message: Message) -> ClientResult<Vec<D>>
+    /// Lazily walk the pages of a request that links to the next page in its headers.
+    fn get_all_pages_stream<D>(
+        &self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + '_
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.unfold(uri).await
+        crate::utils::paginate(uri.to_string(), move |url| async move {
+            let (link, items) = self.get_pages(&url).await?;
+
+            // An empty page is the last page, whatever the headers say.
+            let next = if items.is_empty() {
+                None
+            } else {
+                link.map(|l| l.0)
+            };
+
+            Ok((items, next))
+        })
     }
 
     async fn get_pages<D>(
@@ -736,23 +765,6 @@ impl Client {
         .await
     }
 
-    async fn get_pages_url<D>(
-        &self,
-        url: &reqwest::Url,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request(
-            http::Method::GET,
-            url.as_str(),
-            Message::default(),
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        )
-        .await
-    }
-
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -848,28 +860,6 @@ impl Client {
         .await
     }
 
-    /// "unfold" paginated results of a vector of items
-    async fn unfold<D>(&self, uri: &str) -> ClientResult<Vec<D>>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
-
-        Ok(global_items)
-    }
-
     /// Endpoints to manage GitHub Actions using the REST API.
     pub fn actions(&self) -> actions::Actions {
         actions::Actions::new(self.clone())
@@ -69,21 +69,30 @@ impl Licenses {
         &self,
         featured: bool,
     ) -> ClientResult<Vec<crate::types::LicenseSimple>> {
+        futures::TryStreamExt::try_collect(self.get_all_all_commonly_used_stream(featured)).await
+    }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_all_commonly_used_stream(
+        &self,
+        featured: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LicenseSimple>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if featured {
             query_args.push(("featured".to_string(), featured.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/licenses?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a license.
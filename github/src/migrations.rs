@@ -79,6 +79,24 @@ impl Migrations {
         org: &str,
         exclude: &[String],
     ) -> ClientResult<Vec<crate::types::Migration>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_org_stream(org, exclude)).await
+    }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the most recent migrations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        exclude: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude.is_empty() {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
@@ -92,15 +110,7 @@ impl Migrations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Start an organization migration.
@@ -361,6 +371,25 @@ impl Migrations {
         org: &str,
         migration_id: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_repos_for_org_stream(org, migration_id))
+            .await
+    }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all the repositories for this organization migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_all_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations/{}/repositories",
@@ -369,15 +398,7 @@ impl Migrations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an import status.
@@ -625,6 +646,28 @@ impl Migrations {
         repo: &str,
         since: i64,
     ) -> ClientResult<Vec<crate::types::PorterAuthor>> {
+        futures::TryStreamExt::try_collect(self.get_all_commit_authors_stream(owner, repo, since))
+            .await
+    }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Each type of source control system represents authors in a different way. For example, a Git commit author has a display name and an email address, but a Subversion commit author just has a username. The GitHub Importer will make the author information valid, but the author might not be correct. For example, it will change the bare Subversion username `hubot` into something like `hubot <hubot@12341234-abab-fefe-8787-fedcba987654>`.
+     *
+     * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_all_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterAuthor>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
@@ -639,15 +682,7 @@ impl Migrations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Map a commit author.
@@ -743,6 +778,24 @@ impl Migrations {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::PorterLargeFile>> {
+        futures::TryStreamExt::try_collect(self.get_all_large_files_stream(owner, repo)).await
+    }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List files larger than 100MB found during the import
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_all_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterLargeFile>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import/large_files",
@@ -751,15 +804,7 @@ impl Migrations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Update Git LFS preference.
@@ -853,16 +898,24 @@ impl Migrations {
     pub async fn list_all_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::Migration>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_authenticated_user_stream()).await
+    }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all migrations a user has started.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + '_ {
         let url = self.client.url("/user/migrations", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Start a user migration.
@@ -1121,6 +1174,23 @@ impl Migrations {
         &self,
         migration_id: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_repos_for_user_stream(migration_id)).await
+    }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all the repositories for this user migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_all_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url(
             &format!(
                 "/user/migrations/{}/repositories",
@@ -1128,14 +1198,6 @@ impl Migrations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
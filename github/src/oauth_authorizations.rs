@@ -75,6 +75,25 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> ClientResult<Vec<crate::types::ApplicationGrant>> {
+        futures::TryStreamExt::try_collect(self.list_all_grants_stream(client_id)).await
+    }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations/), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/developers/apps/authorizing-oauth-apps#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGrant>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -83,15 +102,7 @@ impl OauthAuthorizations {
         let url = self
             .client
             .url(&format!("/applications/grants?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a single grant.
@@ -217,6 +228,23 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> ClientResult<Vec<crate::types::Authorization>> {
+        futures::TryStreamExt::try_collect(self.list_all_authorizations_stream(client_id)).await
+    }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_all_authorizations_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Authorization>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -225,15 +253,7 @@ impl OauthAuthorizations {
         let url = self
             .client
             .url(&format!("/authorizations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a new authorization.
@@ -68,21 +68,32 @@ impl Orgs {
         &self,
         since: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(since)).await
+    }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all organizations, in the order that they were created on GitHub.
+     *
+     * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/organizations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an organization.
@@ -252,6 +263,33 @@ impl Orgs {
         before: &str,
         order: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::AuditLogEvent>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_audit_log_stream(org, phrase, include, after, before, order),
+        )
+        .await
+    }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Gets the audit log for an organization. For more information, see "[Reviewing the audit log for your organization](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization)."
+     *
+     * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -277,15 +315,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List users blocked by an organization.
@@ -336,6 +366,23 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_blocked_users_stream(org)).await
+    }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the users blocked by an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_all_blocked_users_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/blocks",
@@ -343,15 +390,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a user is blocked by an organization.
@@ -505,6 +544,25 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::CredentialAuthorization>> {
+        futures::TryStreamExt::try_collect(self.list_all_saml_sso_authorizations_stream(org)).await
+    }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Listing and deleting credential authorizations is available to organizations with GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products).
+     *
+     * An authenticated organization owner with the `read:org` scope can list all credential authorizations for an organization that uses SAML single sign-on (SSO). The credentials are either personal access tokens or SSH keys that organization members have authorized for the organization. For more information, see [About authentication with SAML single sign-on](https://help.github.com/en/articles/about-authentication-with-saml-single-sign-on).
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_all_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CredentialAuthorization>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/credential-authorizations",
@@ -512,15 +570,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Remove a SAML SSO authorization for an organization.
@@ -623,6 +673,23 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        futures::TryStreamExt::try_collect(self.list_all_failed_invitations_stream(org)).await
+    }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The return hash contains `failed_at` and `failed_reason` fields which represent the time at which the invitation failed and the reason for the failure.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_all_failed_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/failed_invitations",
@@ -630,15 +697,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List organization webhooks.
@@ -699,6 +758,23 @@ impl Orgs {
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
      */
     pub async fn list_all_webhooks(&self, org: &str) -> ClientResult<Vec<crate::types::OrgHook>> {
+        futures::TryStreamExt::try_collect(self.list_all_webhooks_stream(org)).await
+    }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgHook>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks",
@@ -706,15 +782,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an organization webhook.
@@ -1005,6 +1073,28 @@ impl Orgs {
         hook_id: i64,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_webhook_deliveries_stream(org, hook_id, cursor),
+        )
+        .await
+    }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -1019,15 +1109,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a webhook delivery for an organization webhook.
@@ -1251,6 +1333,23 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        futures::TryStreamExt::try_collect(self.list_all_pending_invitations_stream(org)).await
+    }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_all_pending_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/invitations",
@@ -1258,15 +1357,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an organization invitation.
@@ -1406,6 +1497,27 @@ impl Orgs {
         org: &str,
         invitation_id: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_invitation_teams_stream(org, invitation_id),
+        )
+        .await
+    }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all teams associated with an invitation. In order to see invitations in an organization, the authenticated user must be an organization owner.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_all_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/invitations/{}/teams",
@@ -1414,15 +1526,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List organization members.
@@ -1503,6 +1607,25 @@ impl Orgs {
         filter: crate::types::OrgsListMembersFilter,
         role: crate::types::OrgsListMembersRole,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_members_stream(org, filter, role)).await
+    }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_all_members_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.to_string().is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -1519,15 +1642,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check organization membership for a user.
@@ -1783,6 +1898,25 @@ impl Orgs {
         org: &str,
         filter: crate::types::OrgsListMembersFilter,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_outside_collaborators_stream(org, filter))
+            .await
+    }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all users who are outside collaborators of an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_all_outside_collaborators_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.to_string().is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -1796,15 +1930,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Convert an organization member to outside collaborator.
@@ -1938,6 +2064,23 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_members_stream(org)).await
+    }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Members of an organization can choose to have their membership publicized or not.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_all_public_members_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/public_members",
@@ -1945,15 +2088,7 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check public organization membership for a user.
@@ -2128,6 +2263,26 @@ impl Orgs {
         &self,
         state: crate::types::OrgMembershipState,
     ) -> ClientResult<Vec<crate::types::OrgMembership>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_memberships_for_authenticated_user_stream(state),
+        )
+        .await
+    }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_all_memberships_for_authenticated_user_stream(
+        &self,
+        state: crate::types::OrgMembershipState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgMembership>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -2136,15 +2291,7 @@ impl Orgs {
         let url = self
             .client
             .url(&format!("/user/memberships/orgs?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an organization membership for the authenticated user.
@@ -2275,16 +2422,28 @@ impl Orgs {
     pub async fn list_all_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_authenticated_user_stream()).await
+    }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List organizations for the authenticated user.
+     *
+     * **OAuth scope requirements**
+     *
+     * This only lists organizations that your authorization allows you to operate on in some way (e.g., you can list teams with `read:org` scope, you can publicize your organization membership with `user` scope, etc.). Therefore, this API requires at least `user` or `read:org` scope. OAuth requests with insufficient scope receive a `403 Forbidden` response.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + '_ {
         let url = self.client.url("/user/orgs", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List organizations for a user.
@@ -2352,6 +2511,25 @@ impl Orgs {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_user_stream(username)).await
+    }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List [public organization memberships](https://help.github.com/articles/publicizing-or-concealing-organization-membership) for the specified user.
+     *
+     * This method only lists _public_ memberships, regardless of authentication. If you need to fetch all of the organization memberships (public and private) for the authenticated user, use the [List organizations for the authenticated user](https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user) API instead.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/orgs",
@@ -2359,14 +2537,6 @@ impl Orgs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -236,6 +236,37 @@ impl Packages {
         org: &str,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_all_package_versions_for_package_owned_by_org_stream(
+                package_type,
+                package_name,
+                org,
+                state,
+            ),
+        )
+        .await
+    }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns all package versions for a package owned by an organization.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -251,15 +282,7 @@ impl Packages {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a package version for an organization.
@@ -614,6 +637,35 @@ impl Packages {
         package_name: &str,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_all_package_versions_for_package_owned_by_authenticated_user_stream(
+                package_type,
+                package_name,
+                state,
+            ),
+        )
+        .await
+    }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns all package versions for a package owned by the authenticated user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -628,15 +680,7 @@ impl Packages {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a package version for the authenticated user.
@@ -876,6 +920,35 @@ impl Packages {
         package_name: &str,
         username: &str,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_all_package_versions_for_package_owned_by_user_stream(
+                package_type,
+                package_name,
+                username,
+            ),
+        )
+        .await
+    }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns all package versions for a public package owned by a specified user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/packages/{}/{}/versions",
@@ -885,15 +958,7 @@ impl Packages {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a package version for a user.
@@ -79,6 +79,24 @@ impl Projects {
         org: &str,
         state: crate::types::IssuesListState,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_org_stream(org, state)).await
+    }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -92,15 +110,7 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an organization project.
@@ -434,6 +444,25 @@ impl Projects {
         column_id: i64,
         archived_state: crate::types::ArchivedState,
     ) -> ClientResult<Vec<crate::types::ProjectCard>> {
+        futures::TryStreamExt::try_collect(self.list_all_cards_stream(column_id, archived_state))
+            .await
+    }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_all_cards_stream(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectCard>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
             query_args.push(("archived_state".to_string(), archived_state.to_string()));
@@ -447,15 +476,7 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a project card.
@@ -695,6 +716,27 @@ impl Projects {
         project_id: i64,
         affiliation: crate::types::Affiliation,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_collaborators_stream(project_id, affiliation),
+        )
+        .await
+    }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -708,15 +750,7 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add project collaborator.
@@ -888,6 +922,23 @@ impl Projects {
         &self,
         project_id: i64,
     ) -> ClientResult<Vec<crate::types::ProjectColumn>> {
+        futures::TryStreamExt::try_collect(self.list_all_columns_stream(project_id)).await
+    }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_all_columns_stream(
+        &self,
+        project_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectColumn>> + '_ {
         let url = self.client.url(
             &format!(
                 "/projects/{}/columns",
@@ -895,15 +946,7 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a project column.
@@ -1012,6 +1055,25 @@ impl Projects {
         repo: &str,
         state: crate::types::IssuesListState,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_repo_stream(owner, repo, state)).await
+    }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -1026,15 +1088,7 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a repository project.
@@ -1166,6 +1220,24 @@ impl Projects {
         username: &str,
         state: crate::types::IssuesListState,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_user_stream(username, state)).await
+    }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !state.to_string().is_empty() {
             query_args.push(("state".to_string(), state.to_string()));
@@ -1179,14 +1251,6 @@ impl Projects {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -109,6 +109,32 @@ impl Pulls {
         sort: crate::types::PullsListSort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::PullRequestSimple>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_stream(owner, repo, state, head, base, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_all_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !base.is_empty() {
             query_args.push(("base".to_string(), base.to_string()));
@@ -135,15 +161,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a pull request.
@@ -275,6 +293,31 @@ impl Pulls {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewComment>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_review_comments_for_repo_stream(owner, repo, sort, direction, since),
+        )
+        .await
+    }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_all_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -295,15 +338,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a review comment for a pull request.
@@ -613,6 +648,37 @@ impl Pulls {
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_review_comments_stream(
+            owner,
+            repo,
+            pull_number,
+            sort,
+            direction,
+            since,
+        ))
+        .await
+    }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_all_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -634,15 +700,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a review comment for a pull request.
@@ -808,6 +866,26 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
     ) -> ClientResult<Vec<crate::types::CommitDataType>> {
+        futures::TryStreamExt::try_collect(self.list_all_commits_stream(owner, repo, pull_number))
+            .await
+    }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a maximum of 250 commits for a pull request. To receive a complete commit list for pull requests with more than 250 commits, use the [List commits](https://docs.github.com/rest/reference/repos#list-commits) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/commits",
@@ -817,15 +895,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List pull requests files.
@@ -897,6 +967,26 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
     ) -> ClientResult<Vec<crate::types::DiffEntry>> {
+        futures::TryStreamExt::try_collect(self.list_all_files_stream(owner, repo, pull_number))
+            .await
+    }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Note:** Responses include a maximum of 3000 files. The paginated response returns 30 files per page by default.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_all_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DiffEntry>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/files",
@@ -906,15 +996,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a pull request has been merged.
@@ -1202,6 +1284,26 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewData>> {
+        futures::TryStreamExt::try_collect(self.list_all_reviews_stream(owner, repo, pull_number))
+            .await
+    }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The list of reviews returns in chronological order.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_all_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewData>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews",
@@ -1211,15 +1313,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a review for a pull request.
@@ -1472,6 +1566,32 @@ impl Pulls {
         pull_number: i64,
         review_id: i64,
     ) -> ClientResult<Vec<crate::types::ReviewComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_comments_for_review_stream(
+            owner,
+            repo,
+            pull_number,
+            review_id,
+        ))
+        .await
+    }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List comments for a specific pull request review.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_all_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReviewComment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}/comments",
@@ -1482,15 +1602,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Dismiss a review for a pull request.
@@ -95,6 +95,36 @@ impl Reactions {
         comment_number: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_team_discussion_comment_in_org_stream(
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            content,
+        ))
+        .await
+    }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments/). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_all_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -111,15 +141,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a team discussion comment.
@@ -295,6 +317,34 @@ impl Reactions {
         discussion_number: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_team_discussion_in_org_stream(
+            org,
+            team_slug,
+            discussion_number,
+            content,
+        ))
+        .await
+    }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_all_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -310,15 +360,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a team discussion.
@@ -517,6 +559,29 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_commit_comment_stream(owner, repo, comment_id, content),
+        )
+        .await
+    }
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub fn list_all_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -532,15 +597,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a commit comment.
@@ -704,6 +761,29 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_issue_comment_stream(owner, repo, comment_id, content),
+        )
+        .await
+    }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_all_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -719,15 +799,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for an issue comment.
@@ -891,6 +963,32 @@ impl Reactions {
         issue_number: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_issue_stream(
+            owner,
+            repo,
+            issue_number,
+            content,
+        ))
+        .await
+    }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_all_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -906,15 +1004,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for an issue.
@@ -1078,6 +1168,29 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_pull_request_review_comment_stream(owner, repo, comment_id, content),
+        )
+        .await
+    }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_all_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1093,15 +1206,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a pull request review comment.
@@ -1310,6 +1415,34 @@ impl Reactions {
         comment_number: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_team_discussion_comment_legacy_stream(
+            team_id,
+            discussion_number,
+            comment_number,
+            content,
+        ))
+        .await
+    }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion comment`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment) endpoint.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_all_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1325,15 +1458,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a team discussion comment (Legacy).
@@ -1454,6 +1579,32 @@ impl Reactions {
         discussion_number: i64,
         content: crate::types::Content,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_team_discussion_legacy_stream(
+            team_id,
+            discussion_number,
+            content,
+        ))
+        .await
+    }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion) endpoint.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_all_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1468,15 +1619,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create reaction for a team discussion (Legacy).
@@ -102,6 +102,29 @@ impl Repos {
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_org_stream(org, type_, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists repositories for the specified organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -121,15 +144,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an organization repository.
@@ -347,6 +362,26 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Autolink>> {
+        futures::TryStreamExt::try_collect(self.list_all_autolinks_stream(owner, repo)).await
+    }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * This returns a list of autolinks configured for the given repository.
+     *
+     * Information about autolinks are only available to repository administrators.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_all_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Autolink>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/autolinks",
@@ -355,15 +390,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an autolink reference for a repository.
@@ -633,6 +660,26 @@ impl Repos {
         repo: &str,
         protected: bool,
     ) -> ClientResult<Vec<crate::types::ShortBranch>> {
+        futures::TryStreamExt::try_collect(self.list_all_branches_stream(owner, repo, protected))
+            .await
+    }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_all_branches`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_all_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShortBranch>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if protected {
             query_args.push(("protected".to_string(), protected.to_string()));
@@ -647,15 +694,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a branch.
@@ -1381,6 +1420,28 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_all_status_check_contexts_stream(owner, repo, branch),
+        )
+        .await
+    }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub fn get_all_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1390,15 +1451,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set status check contexts.
@@ -1670,6 +1723,30 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::GitHubApp>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_apps_with_access_to_protected_branch_stream(owner, repo, branch),
+        )
+        .await
+    }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the GitHub Apps that have push access to this branch. Only installed GitHub Apps with `write` access to the `contents` permission can be added as authorized actors on a protected branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitHubApp>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/apps",
@@ -1679,15 +1756,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set app access restrictions.
@@ -1891,6 +1960,30 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_teams_with_access_to_protected_branch_stream(owner, repo, branch),
+        )
+        .await
+    }
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the teams who have push access to this branch. The list includes child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1900,15 +1993,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set team access restrictions.
@@ -2112,6 +2197,30 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_users_with_access_to_protected_branch_stream(owner, repo, branch),
+        )
+        .await
+    }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the people who have push access to this branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -2121,15 +2230,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set user access restrictions.
@@ -2406,6 +2507,32 @@ impl Repos {
         repo: &str,
         affiliation: crate::types::Affiliation,
     ) -> ClientResult<Vec<crate::types::Collaborator>> {
+        futures::TryStreamExt::try_collect(self.list_all_collaborators_stream(
+            owner,
+            repo,
+            affiliation,
+        ))
+        .await
+    }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collaborator>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -2420,15 +2547,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a user is a repository collaborator.
@@ -2673,6 +2792,29 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::CommitComment>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_commit_comments_for_repo_stream(owner, repo),
+        )
+        .await
+    }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Commit Comments use [these custom media types](https://docs.github.com/rest/reference/repos#custom-media-types). You can read more about the use of media types in the API [here](https://docs.github.com/rest/overview/media-types/).
+     *
+     * Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_all_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/comments",
@@ -2681,15 +2823,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a commit comment.
@@ -2962,46 +3096,91 @@ impl Repos {
         since: Option<chrono::DateTime<chrono::Utc>>,
         until: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::CommitDataType>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !sha.is_empty() {
-            query_args.push(("sha".to_string(), sha.to_string()));
-        }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
-        }
-        if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/repos/{}/{}/commits?{}",
-                crate::progenitor_support::encode_path(owner),
-                crate::progenitor_support::encode_path(repo),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        futures::TryStreamExt::try_collect(
+            self.list_all_commits_stream(owner, repo, sha, path, author, since, until),
+        )
+        .await
     }
     /**
-     * List branches for HEAD commit.
+     * List commits.
      *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !author.is_empty() {
+            query_args.push(("author".to_string(), author.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !sha.is_empty() {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(&url)
+    }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
      *
      * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
@@ -3059,6 +3238,30 @@ impl Repos {
         repo: &str,
         commit_sha: &str,
     ) -> ClientResult<Vec<crate::types::BranchShort>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_branches_for_head_commit_stream(owner, repo, commit_sha),
+        )
+        .await
+    }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     *
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Returns all branches where the given commit SHA is the HEAD, or latest commit for the branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub fn list_all_branches_for_head_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BranchShort>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/branches-where-head",
@@ -3068,15 +3271,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List commit comments.
@@ -3148,6 +3343,28 @@ impl Repos {
         repo: &str,
         commit_sha: &str,
     ) -> ClientResult<Vec<crate::types::CommitComment>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_comments_for_commit_stream(owner, repo, commit_sha),
+        )
+        .await
+    }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_all_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/comments",
@@ -3157,15 +3374,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a commit comment.
@@ -3280,6 +3489,28 @@ impl Repos {
         repo: &str,
         commit_sha: &str,
     ) -> ClientResult<Vec<crate::types::PullRequestSimple>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_pull_requests_associated_with_commit_stream(owner, repo, commit_sha),
+        )
+        .await
+    }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the merged pull request that introduced the commit to the repository. If the commit is not present in the default branch, additionally returns open pull requests associated with the commit. The results may include open and closed pull requests. Additional preview headers may be required to see certain details for associated pull requests, such as whether a pull request is in a draft state. For more information about previews that might affect this endpoint, see the [List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_all_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/pulls",
@@ -3289,15 +3520,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a commit.
@@ -3522,6 +3745,30 @@ impl Repos {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<Vec<crate::types::StatusData>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_commit_statuses_for_ref_stream(owner, repo, ref_),
+        )
+        .await
+    }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Users with pull access in a repository can view commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name. Statuses are returned in reverse chronological order. The first status in the list will be the latest one.
+     *
+     * This resource is also available via a legacy route: `GET /repos/:owner/:repo/statuses/:ref`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_all_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::StatusData>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/statuses",
@@ -3531,15 +3778,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get community profile metrics.
@@ -4240,6 +4479,28 @@ impl Repos {
         repo: &str,
         anon: &str,
     ) -> ClientResult<Vec<crate::types::Contributor>> {
+        futures::TryStreamExt::try_collect(self.list_all_contributors_stream(owner, repo, anon))
+            .await
+    }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists contributors to the specified repository and sorts them by the number of commits per contributor in descending order. This endpoint may return information that is a few hours old because the GitHub REST API v3 caches contributor data to improve performance.
+     *
+     * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_all_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contributor>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !anon.is_empty() {
             query_args.push(("anon".to_string(), anon.to_string()));
@@ -4254,15 +4515,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List deployments.
@@ -4354,6 +4607,36 @@ impl Repos {
         task: &str,
         environment: &str,
     ) -> ClientResult<Vec<crate::types::Deployment>> {
+        futures::TryStreamExt::try_collect(self.list_all_deployments_stream(
+            owner,
+            repo,
+            sha,
+            ref_,
+            task,
+            environment,
+        ))
+        .await
+    }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Simple filtering of deployments is available via query parameters:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_all_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Deployment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !environment.is_empty() {
             query_args.push(("environment".to_string(), environment.to_string()));
@@ -4377,15 +4660,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a deployment.
@@ -4626,6 +4901,30 @@ impl Repos {
         repo: &str,
         deployment_id: i64,
     ) -> ClientResult<Vec<crate::types::DeploymentStatus>> {
+        futures::TryStreamExt::try_collect(self.list_all_deployment_statuses_stream(
+            owner,
+            repo,
+            deployment_id,
+        ))
+        .await
+    }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Users with pull access can view deployment statuses for a deployment:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_all_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeploymentStatus>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}/statuses",
@@ -4635,15 +4934,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a deployment status.
@@ -5016,29 +5307,40 @@ impl Repos {
         repo: &str,
         sort: crate::types::ReposListForksSort,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/repos/{}/{}/forks?{}",
-                crate::progenitor_support::encode_path(owner),
-                crate::progenitor_support::encode_path(repo),
-                query_
-            ),
+        futures::TryStreamExt::try_collect(self.list_all_forks_stream(owner, repo, sort)).await
+    }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/forks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a fork.
@@ -5146,6 +5448,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Hook>> {
+        futures::TryStreamExt::try_collect(self.list_all_webhooks_stream(owner, repo)).await
+    }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Hook>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks",
@@ -5154,15 +5474,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a repository webhook.
@@ -5475,6 +5787,29 @@ impl Repos {
         hook_id: i64,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_webhook_deliveries_stream(owner, repo, hook_id, cursor),
+        )
+        .await
+    }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -5490,15 +5825,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a delivery for a repository webhook.
@@ -5729,6 +6056,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::RepositoryInvitation>> {
+        futures::TryStreamExt::try_collect(self.list_all_invitations_stream(owner, repo)).await
+    }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * When authenticating as a user with admin rights to a repository, this endpoint will list all currently open repository invitations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_all_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/invitations",
@@ -5737,15 +6082,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a repository invitation.
@@ -5894,6 +6231,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::DeployKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_deploy_keys_stream(owner, repo)).await
+    }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_all_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeployKey>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/keys",
@@ -5902,15 +6257,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a deploy key.
@@ -6309,6 +6656,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::PageBuild>> {
+        futures::TryStreamExt::try_collect(self.list_all_pages_builds_stream(owner, repo)).await
+    }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_all_pages_builds`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_all_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PageBuild>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages/builds",
@@ -6317,15 +6682,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Request a GitHub Pages build.
@@ -6651,6 +7008,26 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Release>> {
+        futures::TryStreamExt::try_collect(self.list_all_releases_stream(owner, repo)).await
+    }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_all_releases`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * This returns a list of releases, which does not include regular Git tags that have not been associated with a release. To get a list of Git tags, use the [Repository Tags API](https://docs.github.com/rest/reference/repos#list-repository-tags).
+     *
+     * Information about published releases are available to everyone. Only users with push access will receive listings for draft releases.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_all_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Release>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases",
@@ -6659,15 +7036,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a release.
@@ -7100,6 +7469,28 @@ impl Repos {
         repo: &str,
         release_id: i64,
     ) -> ClientResult<Vec<crate::types::ReleaseAsset>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_release_assets_stream(owner, repo, release_id),
+        )
+        .await
+    }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_all_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReleaseAsset>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/{}/assets",
@@ -7109,15 +7500,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Upload a release asset.
@@ -7243,6 +7626,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<Vec<i64>>> {
+        futures::TryStreamExt::try_collect(self.get_all_code_frequency_stats_stream(owner, repo))
+            .await
+    }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns a weekly aggregate of the number of additions and deletions pushed to a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_all_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/code_frequency",
@@ -7251,15 +7653,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get the last year of commit activity.
@@ -7314,6 +7708,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::CommitActivity>> {
+        futures::TryStreamExt::try_collect(self.get_all_commit_activity_stats_stream(owner, repo))
+            .await
+    }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns the last year of commit activity grouped by week. The `days` array is a group of commits per day, starting on `Sunday`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_all_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitActivity>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/commit_activity",
@@ -7322,15 +7735,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get all contributor commit activity.
@@ -7397,6 +7802,31 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ContributorActivity>> {
+        futures::TryStreamExt::try_collect(self.get_all_contributors_stats_stream(owner, repo))
+            .await
+    }
+    /**
+     * Get all contributor commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_all_contributors_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     * Returns the `total` number of commits authored by the contributor. In addition, the response includes a Weekly Hash (`weeks` array) with the following information:
+     *
+     * *   `w` - Start of the week, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     * *   `a` - Number of additions
+     * *   `d` - Number of deletions
+     * *   `c` - Number of commits
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub fn get_all_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContributorActivity>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/contributors",
@@ -7405,15 +7835,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get the weekly commit count.
@@ -7519,6 +7941,30 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<Vec<i64>>> {
+        futures::TryStreamExt::try_collect(self.get_all_punch_card_stats_stream(owner, repo)).await
+    }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Each array contains the day number, hour number, and number of commits:
+     *
+     * *   `0-6`: Sunday - Saturday
+     * *   `0-23`: Hour of day
+     * *   Number of commits
+     *
+     * For example, `[2, 14, 25]` indicates that there were 25 total commits, during the 2:00pm hour on Tuesdays. All times are based on the time zone of individual commits.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_all_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/punch_card",
@@ -7527,15 +7973,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a commit status.
@@ -7646,6 +8084,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Tag>> {
+        futures::TryStreamExt::try_collect(self.list_all_tags_stream(owner, repo)).await
+    }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_all_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Tag>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/tags",
@@ -7654,15 +8110,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Download a repository archive (tar).
@@ -7773,6 +8221,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(self.list_all_teams_stream(owner, repo)).await
+    }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_all_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/teams",
@@ -7781,15 +8247,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get all repository topics.
@@ -7977,6 +8435,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ContentTraffic>> {
+        futures::TryStreamExt::try_collect(self.get_all_top_paths_stream(owner, repo)).await
+    }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get the top 10 popular contents over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_all_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContentTraffic>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/traffic/popular/paths",
@@ -7985,15 +8461,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get top referral sources.
@@ -8048,6 +8516,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ReferrerTraffic>> {
+        futures::TryStreamExt::try_collect(self.get_all_top_referrers_stream(owner, repo)).await
+    }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get the top 10 referrers over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_all_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReferrerTraffic>> + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/traffic/popular/referrers",
@@ -8056,15 +8542,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get page views.
@@ -8392,21 +8870,34 @@ impl Repos {
         &self,
         since: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_stream(since)).await
+    }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all public repositories in the order that they were created.
+     *
+     * Note:
+     * - For GitHub Enterprise Server, this endpoint will only list repositories available to all users on the enterprise.
+     * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/repositories?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List repositories for the authenticated user.
@@ -8513,6 +9004,40 @@ impl Repos {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Repository>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_authenticated_user_stream(
+            visibility,
+            affiliation,
+            type_,
+            sort,
+            direction,
+            since,
+            before,
+        ))
+        .await
+    }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -8537,15 +9062,7 @@ impl Repos {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/repos?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a repository for the authenticated user.
@@ -8632,16 +9149,27 @@ impl Repos {
     pub async fn list_all_invitations_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::RepositoryInvitation>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_invitations_for_authenticated_user_stream(),
+        )
+        .await
+    }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * When authenticating as a user, this endpoint will list all currently open repository invitations for that user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_all_invitations_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + '_ {
         let url = self.client.url("/user/repository_invitations", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Decline a repository invitation.
@@ -8787,6 +9315,29 @@ impl Repos {
         sort: crate::types::ReposListOrgSort,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_for_user_stream(username, type_, sort, direction),
+        )
+        .await
+    }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -8806,14 +9357,6 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -93,6 +93,34 @@ impl SecretScanning {
         state: crate::types::SecretScanningAlertState,
         secret_type: &str,
     ) -> ClientResult<Vec<crate::types::SecretScanningAlert>> {
+        futures::TryStreamExt::try_collect(self.list_all_alerts_for_repo_stream(
+            owner,
+            repo,
+            state,
+            secret_type,
+        ))
+        .await
+    }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all secret scanning alerts for a private repository, from newest to oldest. To use this endpoint, you must be an administrator for the repository or organization, and you must use an access token with the `repo` scope or `security_events` scope.
+     *
+     * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecretScanningAlert>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !secret_type.is_empty() {
             query_args.push(("secret_type".to_string(), secret_type.to_string()));
@@ -110,15 +138,7 @@ impl SecretScanning {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a secret scanning alert.
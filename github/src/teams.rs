@@ -121,6 +121,23 @@ impl Teams {
      * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
      */
     pub async fn list_all(&self, org: &str) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(org)).await
+    }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all teams in an organization that are visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_all_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams",
@@ -128,15 +145,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a team.
@@ -375,6 +384,31 @@ impl Teams {
         direction: crate::types::Order,
         pinned: &str,
     ) -> ClientResult<Vec<crate::types::TeamDiscussion>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_discussions_in_org_stream(org, team_slug, direction, pinned),
+        )
+        .await
+    }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_all_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        pinned: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -392,15 +426,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a discussion.
@@ -653,6 +679,34 @@ impl Teams {
         discussion_number: i64,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::TeamDiscussionComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_discussion_comments_in_org_stream(
+            org,
+            team_slug,
+            discussion_number,
+            direction,
+        ))
+        .await
+    }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_all_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -668,15 +722,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a discussion comment.
@@ -929,6 +975,29 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_pending_invitations_in_org_stream(org, team_slug),
+        )
+        .await
+    }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/invitations`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_all_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/invitations",
@@ -937,15 +1006,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List team members.
@@ -1026,6 +1087,30 @@ impl Teams {
         team_slug: &str,
         role: crate::types::TeamsListMembersInOrgRole,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_members_in_org_stream(org, team_slug, role),
+        )
+        .await
+    }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_all_members_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Team members will include the members of child teams.
+     *
+     * To list members in a team, the team must be visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_all_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !role.to_string().is_empty() {
             query_args.push(("role".to_string(), role.to_string()));
@@ -1040,15 +1125,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get team membership for a user.
@@ -1266,6 +1343,27 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        futures::TryStreamExt::try_collect(self.list_all_projects_in_org_stream(org, team_slug))
+            .await
+    }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the organization projects for a team.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/projects`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_all_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/projects",
@@ -1274,15 +1372,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check team permissions for a project.
@@ -1481,6 +1571,26 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_repos_in_org_stream(org, team_slug)).await
+    }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a team's repositories visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/repos`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_all_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/repos",
@@ -1489,15 +1599,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check team permissions for a repository.
@@ -1794,6 +1896,26 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(self.list_all_child_in_org_stream(org, team_slug)).await
+    }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_in_org`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_all_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/teams",
@@ -1802,15 +1924,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a team (Legacy).
@@ -1991,6 +2105,29 @@ impl Teams {
         team_id: i64,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::TeamDiscussion>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_discussions_legacy_stream(team_id, direction),
+        )
+        .await
+    }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List discussions`](https://docs.github.com/rest/reference/teams#list-discussions) endpoint.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_all_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2004,15 +2141,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a discussion (Legacy).
@@ -2249,6 +2378,32 @@ impl Teams {
         discussion_number: i64,
         direction: crate::types::Order,
     ) -> ClientResult<Vec<crate::types::TeamDiscussionComment>> {
+        futures::TryStreamExt::try_collect(self.list_all_discussion_comments_legacy_stream(
+            team_id,
+            discussion_number,
+            direction,
+        ))
+        .await
+    }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List discussion comments](https://docs.github.com/rest/reference/teams#list-discussion-comments) endpoint.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_all_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2263,15 +2418,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a discussion comment (Legacy).
@@ -2508,6 +2655,26 @@ impl Teams {
         &self,
         team_id: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        futures::TryStreamExt::try_collect(self.list_all_pending_invitations_legacy_stream(team_id))
+            .await
+    }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List pending team invitations`](https://docs.github.com/rest/reference/teams#list-pending-team-invitations) endpoint.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_all_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/teams/{}/invitations",
@@ -2515,15 +2682,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List team members (Legacy).
@@ -2600,6 +2759,26 @@ impl Teams {
         team_id: i64,
         role: crate::types::TeamsListMembersInOrgRole,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_members_legacy_stream(team_id, role)).await
+    }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team members`](https://docs.github.com/rest/reference/teams#list-team-members) endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_all_members_legacy_stream(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !role.to_string().is_empty() {
             query_args.push(("role".to_string(), role.to_string()));
@@ -2613,15 +2792,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get team member (Legacy).
@@ -2947,6 +3118,25 @@ impl Teams {
         &self,
         team_id: i64,
     ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        futures::TryStreamExt::try_collect(self.list_all_projects_legacy_stream(team_id)).await
+    }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_all_projects_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + '_ {
         let url = self.client.url(
             &format!(
                 "/teams/{}/projects",
@@ -2954,15 +3144,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check team permissions for a project (Legacy).
@@ -3140,6 +3322,23 @@ impl Teams {
         &self,
         team_id: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        futures::TryStreamExt::try_collect(self.list_all_repos_legacy_stream(team_id)).await
+    }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List team repositories](https://docs.github.com/rest/reference/teams#list-team-repositories) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_all_repos_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + '_ {
         let url = self.client.url(
             &format!(
                 "/teams/{}/repos",
@@ -3147,15 +3346,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check team permissions for a repository (Legacy).
@@ -3427,6 +3618,23 @@ impl Teams {
         &self,
         team_id: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        futures::TryStreamExt::try_collect(self.list_all_child_legacy_stream(team_id)).await
+    }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List child teams`](https://docs.github.com/rest/reference/teams#list-child-teams) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_all_child_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + '_ {
         let url = self.client.url(
             &format!(
                 "/teams/{}/teams",
@@ -3434,15 +3642,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List teams for the authenticated user.
@@ -3496,15 +3696,23 @@ impl Teams {
     pub async fn list_all_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::FullTeam>> {
+        futures::TryStreamExt::try_collect(self.list_all_for_authenticated_user_stream()).await
+    }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FullTeam>> + '_ {
         let url = self.client.url("/user/teams", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
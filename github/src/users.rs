@@ -143,16 +143,24 @@ impl Users {
     pub async fn list_all_blocked_by_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_blocked_by_authenticated_stream()).await
+    }
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_by_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the users you've blocked on your personal account.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub fn list_all_blocked_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url("/user/blocks", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a user is blocked by the authenticated user.
@@ -323,16 +331,24 @@ impl Users {
     pub async fn list_all_emails_for_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::Email>> {
+        futures::TryStreamExt::try_collect(self.list_all_emails_for_authenticated_stream()).await
+    }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_all_emails_for_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all of your email addresses, and specifies which one is visible to the public. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + '_ {
         let url = self.client.url("/user/emails", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add an email address for the authenticated user.
@@ -436,17 +452,26 @@ impl Users {
     pub async fn list_all_followers_for_authenticated_user(
         &self,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
-        let url = self.client.url("/user/followers", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+        futures::TryStreamExt::try_collect(self.list_all_followers_for_authenticated_user_stream())
             .await
     }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_authenticated_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the people following the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_all_followers_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
+        let url = self.client.url("/user/followers", None);
+        self.client.get_all_pages_stream(&url)
+    }
     /**
      * List the people the authenticated user follows.
      *
@@ -501,16 +526,24 @@ impl Users {
     pub async fn list_all_followed_by_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_followed_by_authenticated_stream()).await
+    }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_all_followed_by_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the people who the authenticated user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_all_followed_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url("/user/following", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a person is followed by the authenticated user.
@@ -662,16 +695,24 @@ impl Users {
     pub async fn list_all_gpg_keys_for_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::GpgKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_gpg_keys_for_authenticated_stream()).await
+    }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the current user's GPG keys. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:gpg_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_gpg_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + '_ {
         let url = self.client.url("/user/gpg_keys", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a GPG key for the authenticated user.
@@ -814,17 +855,26 @@ impl Users {
     pub async fn list_all_public_ssh_keys_for_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::Key>> {
-        let url = self.client.url("/user/keys", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+        futures::TryStreamExt::try_collect(self.list_all_public_ssh_keys_for_authenticated_stream())
             .await
     }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_all_public_ssh_keys_for_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the public SSH keys for the authenticated user's GitHub account. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:public_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_public_ssh_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Key>> + '_ {
+        let url = self.client.url("/user/keys", None);
+        self.client.get_all_pages_stream(&url)
+    }
     /**
      * Create a public SSH key for the authenticated user.
      *
@@ -968,17 +1018,26 @@ impl Users {
     pub async fn list_all_public_emails_for_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::Email>> {
-        let url = self.client.url("/user/public_emails", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
+        futures::TryStreamExt::try_collect(self.list_all_public_emails_for_authenticated_stream())
             .await
     }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_all_public_emails_for_authenticated`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists your publicly visible email address, which you can set with the [Set primary email visibility for the authenticated user](https://docs.github.com/rest/reference/users#set-primary-email-visibility-for-the-authenticated-user) endpoint. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_public_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + '_ {
+        let url = self.client.url("/user/public_emails", None);
+        self.client.get_all_pages_stream(&url)
+    }
     /**
      * List users.
      *
@@ -1033,21 +1092,32 @@ impl Users {
      * FROM: <https://docs.github.com/rest/reference/users#list-users>
      */
     pub async fn list_all(&self, since: i64) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(since)).await
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all users, in the order that they signed up on GitHub. This list includes personal user accounts and organization accounts.
+     *
+     * Note: Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of users.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a user.
@@ -1231,6 +1301,23 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_followers_for_user_stream(username)).await
+    }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the people following the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_all_followers_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/followers",
@@ -1238,15 +1325,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * List the people a user follows.
@@ -1310,6 +1389,23 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_following_for_user_stream(username)).await
+    }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_all_following_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the people who the specified user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_all_following_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/following",
@@ -1317,15 +1413,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Check if a user follows another user.
@@ -1426,6 +1514,23 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::GpgKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_gpg_keys_for_user_stream(username)).await
+    }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the GPG keys for a user. This information is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_all_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/gpg_keys",
@@ -1433,15 +1538,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get contextual information for a user.
@@ -1559,6 +1656,24 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<Vec<crate::types::KeySimple>> {
+        futures::TryStreamExt::try_collect(self.list_all_public_keys_for_user_stream(username))
+            .await
+    }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_all_public_keys_for_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the _verified_ public SSH keys for a user. This is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_all_public_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::KeySimple>> + '_ {
         let url = self.client.url(
             &format!(
                 "/users/{}/keys",
@@ -1566,14 +1681,6 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -0,0 +1,394 @@
+//! Receive webhook deliveries: check they came from GitHub and parse them.
+//!
+//! GitHub signs each delivery with the webhook's secret, in
+//! `X-Hub-Signature-256`, and names the event in `X-GitHub-Event`.
+//! See <https://docs.github.com/en/webhooks-and-events/webhooks/securing-your-webhooks>.
+//!
+//! ```
+//! # fn run(headers: &http::HeaderMap, body: &[u8]) -> Result<(), octorust::ClientError> {
+//! use octorust::webhooks::{WebhookEvent, WebhookHandler};
+//!
+//! let handler = WebhookHandler::new("my-webhook-secret");
+//! match handler.handle(headers, body)?.event {
+//!     WebhookEvent::Push(push) => println!("pushed to {}", push.ref_),
+//!     WebhookEvent::PullRequest(pr) => println!("pull request {} {}", pr.number, pr.action),
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    types,
+    utils::{decode_hex, encode_hex, hmac_sha256, verify_hmac_sha256},
+    ClientError, ClientResult,
+};
+
+/// The header GitHub signs deliveries in, as `sha256=<hex digest>`.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// The header naming the event a delivery is for, e.g. `push`.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+
+/// The header with the unique ID of a delivery.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+/// Check a `X-Hub-Signature-256` value against the body of the delivery, in
+/// constant time.
+pub fn verify_signature(secret: &[u8], signature: &str, body: &[u8]) -> ClientResult<()> {
+    let tag = signature
+        .trim()
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+        .ok_or(ClientError::InvalidWebhookSignature)?;
+
+    if !verify_hmac_sha256(&[secret], &[tag], body) {
+        return Err(ClientError::InvalidWebhookSignature);
+    }
+
+    Ok(())
+}
+
+/// Sign a body the way GitHub does, for `X-Hub-Signature-256`.
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    format!("sha256={}", encode_hex(&hmac_sha256(secret, body)))
+}
+
+/// The installation of a GitHub App a delivery is for.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InstallationLite {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub node_id: String,
+}
+
+/// `ping`: the webhook was created.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PingEvent {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub zen: String,
+    #[serde(default)]
+    pub hook_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `push`: commits or tags were pushed to a repository.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushEvent {
+    #[serde(default, rename = "ref")]
+    pub ref_: String,
+    #[serde(default)]
+    pub before: String,
+    #[serde(default)]
+    pub after: String,
+    #[serde(default)]
+    pub created: bool,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub forced: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub compare: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commits: Vec<types::SimpleCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<types::SimpleCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pusher: Option<types::Author>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `pull_request`: a pull request was opened, edited, closed, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestEvent {
+    #[serde(default)]
+    pub action: String,
+    #[serde(default)]
+    pub number: i64,
+    pub pull_request: types::PullRequestData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `pull_request_review`: a review on a pull request was submitted, edited or
+/// dismissed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestReviewEvent {
+    #[serde(default)]
+    pub action: String,
+    pub review: types::PullRequestReviewData,
+    pub pull_request: types::PullRequestSimple,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `issues`: an issue was opened, edited, closed, labeled, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesEvent {
+    #[serde(default)]
+    pub action: String,
+    pub issue: types::Issue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `issue_comment`: a comment on an issue or pull request was created, edited
+/// or deleted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueCommentEvent {
+    #[serde(default)]
+    pub action: String,
+    pub issue: types::Issue,
+    pub comment: types::IssueComment,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `check_run`: a check run was created, completed or rerequested.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckRunEvent {
+    #[serde(default)]
+    pub action: String,
+    pub check_run: types::CheckRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `check_suite`: a check suite was completed, requested or rerequested.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckSuiteEvent {
+    #[serde(default)]
+    pub action: String,
+    pub check_suite: types::CheckSuiteData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `installation`: a GitHub App was installed, uninstalled, suspended, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationEvent {
+    #[serde(default)]
+    pub action: String,
+    pub installation: types::Installation,
+    /// The repositories the installation can access, when it was created.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories: Vec<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `installation_repositories`: repositories were added to or removed from an
+/// installation.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationRepositoriesEvent {
+    #[serde(default)]
+    pub action: String,
+    pub installation: types::Installation,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub repository_selection: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories_added: Vec<types::Repository>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repositories_removed: Vec<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `workflow_run`: a GitHub Actions workflow run was requested, started or
+/// completed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRunEvent {
+    #[serde(default)]
+    pub action: String,
+    pub workflow_run: types::WorkflowRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<types::Workflow>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// `release`: a release was published, edited, deleted, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReleaseEvent {
+    #[serde(default)]
+    pub action: String,
+    pub release: types::Release,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<InstallationLite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// The payload of a webhook delivery, by the event in `X-GitHub-Event`.
+#[derive(PartialEq, Debug, Clone)]
+pub enum WebhookEvent {
+    Ping(Box<PingEvent>),
+    Push(Box<PushEvent>),
+    PullRequest(Box<PullRequestEvent>),
+    PullRequestReview(Box<PullRequestReviewEvent>),
+    Issues(Box<IssuesEvent>),
+    IssueComment(Box<IssueCommentEvent>),
+    CheckRun(Box<CheckRunEvent>),
+    CheckSuite(Box<CheckSuiteEvent>),
+    Installation(Box<InstallationEvent>),
+    InstallationRepositories(Box<InstallationRepositoriesEvent>),
+    WorkflowRun(Box<WorkflowRunEvent>),
+    Release(Box<ReleaseEvent>),
+    /// An event we don't have a type for.
+    Other {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Parse the body of a delivery for the event named in `X-GitHub-Event`.
+    pub fn parse(event: &str, body: &[u8]) -> ClientResult<Self> {
+        fn parse<T: DeserializeOwned>(body: &[u8]) -> ClientResult<Box<T>> {
+            Ok(Box::new(serde_json::from_slice(body)?))
+        }
+
+        Ok(match event {
+            "ping" => WebhookEvent::Ping(parse(body)?),
+            "push" => {
+                // Push payloads give the repository's timestamps in seconds,
+                // rather than as the dates everywhere else has.
+                let mut payload: serde_json::Value = serde_json::from_slice(body)?;
+                if let Some(repository) = payload.get_mut("repository") {
+                    for field in ["created_at", "pushed_at", "updated_at"] {
+                        if let Some(at) = repository.get_mut(field) {
+                            if let Some(t) = at
+                                .as_i64()
+                                .and_then(|secs| chrono::NaiveDateTime::from_timestamp_opt(secs, 0))
+                            {
+                                *at = t.format("%Y-%m-%dT%H:%M:%SZ").to_string().into();
+                            }
+                        }
+                    }
+                }
+                WebhookEvent::Push(Box::new(serde_json::from_value(payload)?))
+            }
+            "pull_request" => WebhookEvent::PullRequest(parse(body)?),
+            "pull_request_review" => WebhookEvent::PullRequestReview(parse(body)?),
+            "issues" => WebhookEvent::Issues(parse(body)?),
+            "issue_comment" => WebhookEvent::IssueComment(parse(body)?),
+            "check_run" => WebhookEvent::CheckRun(parse(body)?),
+            "check_suite" => WebhookEvent::CheckSuite(parse(body)?),
+            "installation" => WebhookEvent::Installation(parse(body)?),
+            "installation_repositories" => WebhookEvent::InstallationRepositories(parse(body)?),
+            "workflow_run" => WebhookEvent::WorkflowRun(parse(body)?),
+            "release" => WebhookEvent::Release(parse(body)?),
+            _ => WebhookEvent::Other {
+                event: event.to_string(),
+                payload: serde_json::from_slice(body)?,
+            },
+        })
+    }
+}
+
+/// A verified webhook delivery.
+#[derive(PartialEq, Debug, Clone)]
+pub struct WebhookDelivery {
+    /// The unique ID of the delivery, from `X-GitHub-Delivery`.
+    pub id: String,
+    pub event: WebhookEvent,
+}
+
+/// Holds the webhook's secret, and parses signed deliveries into the event
+/// `X-GitHub-Event` names.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    secret: Vec<u8>,
+}
+
+impl WebhookHandler {
+    /// Create a handler for deliveries signed with the webhook's secret.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: AsRef<[u8]>,
+    {
+        WebhookHandler {
+            secret: secret.as_ref().to_vec(),
+        }
+    }
+
+    /// Verify a delivery from its headers and raw body, then parse it.
+    pub fn handle(&self, headers: &http::HeaderMap, body: &[u8]) -> ClientResult<WebhookDelivery> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .ok_or(ClientError::MissingWebhookHeader { header: name })
+        };
+
+        verify_signature(&self.secret, header(SIGNATURE_HEADER)?, body)?;
+
+        Ok(WebhookDelivery {
+            id: header(DELIVERY_HEADER).unwrap_or_default().to_string(),
+            event: WebhookEvent::parse(header(EVENT_HEADER)?, body)?,
+        })
+    }
+}
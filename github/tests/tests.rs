@@ -2,6 +2,8 @@ use rand::RngCore;
 use rsa::{pkcs1::EncodeRsaPrivateKey, RsaPrivateKey};
 use std::{mem, time::Duration, time::SystemTime};
 
+use futures::StreamExt;
+
 use wiremock::{
     http::{HeaderName, HeaderValue},
     matchers::{bearer_token, header, method, path, query_param},
@@ -165,6 +167,68 @@ async fn test_follows_next_links_during_unfold() {
     assert_eq!(res.unwrap().len(), 3)
 }
 
+#[tokio::test]
+async fn test_stream_only_fetches_pages_it_needs() {
+    let server = MockServer::start().await;
+
+    let next_url = format!("{}/issues?per_page=100&page=2; rel=\"next\"", server.uri());
+
+    let with_next = ResponseTemplate::new(200)
+        .append_header(
+            HeaderName::from_bytes("link".as_bytes().to_vec()).unwrap(),
+            HeaderValue::from_bytes(next_url.into_bytes()).unwrap(),
+        )
+        .set_body_json(vec![empty_issue(), empty_issue()]);
+
+    Mock::given(method("GET"))
+        .and(path("/issues".to_string()))
+        .and(query_param("filter", "all"))
+        .respond_with(with_next)
+        .expect(1)
+        .named("Get first page")
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/issues".to_string()))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![empty_issue()]))
+        .expect(0)
+        .named("Get second page")
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_string()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+
+    let issues = client.issues();
+    let res: Vec<_> = issues
+        .list_all_stream(
+            octorust::types::Filter::All,
+            octorust::types::IssuesListState::All,
+            "",
+            octorust::types::IssuesListSort::Created,
+            octorust::types::Order::Asc,
+            None,
+            false,
+            false,
+            false,
+            false,
+        )
+        .take(2)
+        .collect()
+        .await;
+
+    mem::drop(server);
+
+    assert_eq!(res.len(), 2);
+    assert!(res.iter().all(|issue| issue.is_ok()));
+}
+
 #[tokio::test]
 async fn test_refreshes_installation_token_once() {
     let installation_id = installation_id();
@@ -303,3 +367,198 @@ async fn test_ratelimit_error() {
         unreachable!("Expected Ratelimiting error, got {:?}", err)
     }
 }
+
+#[cfg(feature = "webhooks")]
+mod webhooks {
+    use octorust::{
+        webhooks::{sign, verify_signature, WebhookDelivery, WebhookEvent, WebhookHandler},
+        ClientError,
+    };
+
+    const WEBHOOK_SECRET: &str = "It's a Secret to Everybody";
+
+    fn delivery_headers(event: &str, body: &[u8]) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("X-GitHub-Event", event.parse().unwrap());
+        headers.insert(
+            "X-GitHub-Delivery",
+            "72d3162e-cc78-11e3-81ab-4c9367dc0958".parse().unwrap(),
+        );
+        headers.insert(
+            "X-Hub-Signature-256",
+            sign(WEBHOOK_SECRET.as_bytes(), body).parse().unwrap(),
+        );
+        headers
+    }
+
+    /// Sign a delivery of `event` the way GitHub would, and handle it.
+    fn deliver(event: &str, body: &[u8]) -> WebhookDelivery {
+        WebhookHandler::new(WEBHOOK_SECRET)
+            .handle(&delivery_headers(event, body), body)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        // The example from GitHub's docs.
+        let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+        verify_signature(WEBHOOK_SECRET.as_bytes(), signature, b"Hello, World!").unwrap();
+        assert_eq!(sign(WEBHOOK_SECRET.as_bytes(), b"Hello, World!"), signature);
+
+        let rejected = |signature: &str| {
+            matches!(
+                verify_signature(WEBHOOK_SECRET.as_bytes(), signature, b"Hello, World!"),
+                Err(ClientError::InvalidWebhookSignature)
+            )
+        };
+        assert!(rejected(
+            "sha256=857107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+        ));
+        // A prefix of the right digest isn't enough.
+        assert!(rejected("sha256=757107ea"));
+        assert!(rejected("sha256=not hex"));
+        // Nor is the right digest under the old SHA-1 header's prefix.
+        assert!(rejected(
+            "sha1=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+        ));
+    }
+
+    #[test]
+    fn test_handle_push() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "ref": "refs/heads/main",
+            "before": "6113728f27ae82c7b1a177c8d03f9e96e0adf246",
+            "after": "0000000000000000000000000000000000000000",
+            "created": false,
+            "deleted": false,
+            "forced": false,
+            "base_ref": null,
+            "compare": "https://github.com/Codertocat/Hello-World/compare/6113728f27ae...000000000000",
+            "commits": [{
+                "id": "6113728f27ae82c7b1a177c8d03f9e96e0adf246",
+                "tree_id": "c2b5f2f3b2c1c5d6e7f8a9b0c1d2e3f4a5b6c7d8",
+                "distinct": true,
+                "message": "Update README.md",
+                "timestamp": "2019-05-15T15:20:41Z",
+                "author": { "name": "Codertocat", "email": "21031067+Codertocat@users.noreply.github.com" },
+                "committer": { "name": "GitHub", "email": "noreply@github.com" },
+                "added": [],
+                "removed": [],
+                "modified": ["README.md"],
+            }],
+            "pusher": { "name": "Codertocat", "email": "21031067+Codertocat@users.noreply.github.com" },
+            "repository": {
+                "id": 186853002,
+                "name": "Hello-World",
+                "full_name": "Codertocat/Hello-World",
+                "created_at": 1557933565,
+                "updated_at": "2019-05-15T15:20:41Z",
+                "pushed_at": 1557933657,
+            },
+            "sender": { "login": "Codertocat", "id": 21031067 },
+        }))
+        .unwrap();
+
+        let delivery = deliver("push", &body);
+
+        assert_eq!(delivery.id, "72d3162e-cc78-11e3-81ab-4c9367dc0958");
+        let push = match delivery.event {
+            WebhookEvent::Push(push) => push,
+            event => panic!("expected a push, got {:?}", event),
+        };
+        assert_eq!(push.ref_, "refs/heads/main");
+        assert_eq!(push.commits[0].message, "Update README.md");
+        assert_eq!(push.pusher.unwrap().name, "Codertocat");
+        let repository = push.repository.unwrap();
+        assert_eq!(repository.full_name, "Codertocat/Hello-World");
+        assert_eq!(
+            repository.created_at.unwrap().to_rfc3339(),
+            "2019-05-15T15:19:25+00:00"
+        );
+        assert_eq!(push.sender.unwrap().login, "Codertocat");
+    }
+
+    #[test]
+    fn test_handle_pull_request() {
+        let link = serde_json::json!({ "href": "https://api.github.com/repos/Codertocat/Hello-World/pulls/2" });
+        let user = serde_json::json!({ "login": "Codertocat", "id": 21031067 });
+        let body = serde_json::to_vec(&serde_json::json!({
+            "action": "opened",
+            "number": 2,
+            "pull_request": {
+                "number": 2,
+                "state": "open",
+                "title": "Update the README with new information.",
+                "user": user,
+                "author_association": "OWNER",
+                "_links": {
+                    "self": link, "html": link, "issue": link, "comments": link,
+                    "review_comments": link, "review_comment": link, "commits": link, "statuses": link,
+                },
+                "base": { "label": "Codertocat:master", "ref": "master", "sha": "f95f852bd8fca8fcc58a9a2d6c842781e32a215e", "user": user },
+                "head": { "label": "Codertocat:changes", "ref": "changes", "sha": "ec26c3e57ca3a959ca5aad62de7213c562f8c821", "user": user },
+            },
+            "repository": { "id": 186853002, "full_name": "Codertocat/Hello-World" },
+            "installation": { "id": 2311213, "node_id": "MDIzOkludGVncmF0aW9uSW5zdGFsbGF0aW9uMjMxMTIxMw==" },
+            "sender": user,
+        }))
+        .unwrap();
+
+        let delivery = deliver("pull_request", &body);
+
+        let pr = match delivery.event {
+            WebhookEvent::PullRequest(pr) => pr,
+            event => panic!("expected a pull request, got {:?}", event),
+        };
+        assert_eq!(pr.action, "opened");
+        assert_eq!(pr.number, 2);
+        assert_eq!(pr.pull_request.head.ref_, "changes");
+        assert_eq!(pr.installation.unwrap().id, 2311213);
+    }
+
+    #[test]
+    fn test_handle_other_events() {
+        let body = br#"{"action":"created","starred_at":"2019-05-15T15:20:40Z"}"#;
+
+        let delivery = deliver("star", body);
+
+        match delivery.event {
+            WebhookEvent::Other { event, payload } => {
+                assert_eq!(event, "star");
+                assert_eq!(payload["action"], "created");
+            }
+            event => panic!("expected another event, got {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_handle_rejects_unsigned_deliveries() {
+        let body = br#"{"zen":"Design for failure.","hook_id":30}"#;
+        let handler = WebhookHandler::new(WEBHOOK_SECRET);
+
+        let mut forged = delivery_headers("ping", body);
+        forged.insert(
+            "X-Hub-Signature-256",
+            sign(b"another secret", body).parse().unwrap(),
+        );
+        assert!(matches!(
+            handler.handle(&forged, body),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+
+        let mut unsigned = delivery_headers("ping", body);
+        unsigned.remove("X-Hub-Signature-256");
+        assert!(matches!(
+            handler.handle(&unsigned, body),
+            Err(ClientError::MissingWebhookHeader {
+                header: "X-Hub-Signature-256"
+            })
+        ));
+
+        let delivery = handler
+            .handle(&delivery_headers("ping", body), body)
+            .unwrap();
+        assert!(matches!(delivery.event, WebhookEvent::Ping(ping) if ping.hook_id == 30));
+    }
+}
@@ -65,7 +65,7 @@ impl Customer {
     ) -> ClientResult<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/chromeos/{}/issueCommand",
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}:issueCommand",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(device_id),
             ),
@@ -274,6 +274,26 @@ impl Customers {
         filter: &str,
         org_unit_id: &str,
     ) -> ClientResult<Vec<crate::types::Printer>> {
+        futures::TryStreamExt::try_collect(self.admin_chrome_printers_list_all_stream(
+            parent,
+            filter,
+            org_unit_id,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List printers configs.
+     */
+    pub fn admin_chrome_printers_list_all_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Printer>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -290,57 +310,33 @@ impl Customers {
             ),
             None,
         );
-        let mut resp: crate::types::ListPrintersResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut printers = resp.printers;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListPrintersResponse = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            printers.append(&mut resp.printers);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.printers, next))
             }
-        }
-
-        // Return our response data.
-        Ok(printers)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
@@ -499,6 +495,23 @@ impl Customers {
         parent: &str,
         filter: &str,
     ) -> ClientResult<Vec<crate::types::PrinterModel>> {
+        futures::TryStreamExt::try_collect(
+            self.admin_chrome_printers_list_all_printer_models_stream(parent, filter),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the supported printer models.
+     */
+    pub fn admin_chrome_printers_list_all_printer_models_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PrinterModel>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -512,56 +525,32 @@ impl Customers {
             ),
             None,
         );
-        let mut resp: crate::types::ListPrinterModelsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut printer_models = resp.printer_models;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListPrinterModelsResponse = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            printer_models.append(&mut resp.printer_models);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.printer_models, next))
             }
-        }
-
-        // Return our response data.
-        Ok(printer_models)
+        })
     }
 }
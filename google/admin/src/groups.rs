@@ -97,6 +97,27 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_stream(customer, domain, order_by, query, sort_order, user_key),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -120,57 +141,33 @@ impl Groups {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/groups?{}", query_), None);
-        let mut resp: crate::types::Groups = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut groups = resp.groups;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Groups = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            groups.append(&mut resp.groups);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.groups, next))
             }
-        }
-
-        // Return our response data.
-        Ok(groups)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
@@ -117,6 +117,26 @@ impl Members {
         include_derived_membership: bool,
         roles: &str,
     ) -> ClientResult<Vec<crate::types::Member>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            group_key,
+            include_derived_membership,
+            roles,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a paginated list of all members in a group.
+     */
+    pub fn list_all_stream(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Member>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_derived_membership {
             query_args.push((
@@ -136,57 +156,33 @@ impl Members {
             ),
             None,
         );
-        let mut resp: crate::types::Members = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut members = resp.members;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Members = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            members.append(&mut resp.members);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.members, next))
             }
-        }
-
-        // Return our response data.
-        Ok(members)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
@@ -93,6 +93,30 @@ impl Mobiledevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> ClientResult<Vec<crate::types::MobileDevice>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            customer_id,
+            order_by,
+            projection,
+            query,
+            sort_order,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a paginated list of all mobile devices for an account.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MobileDevice>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order_by.to_string().is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
@@ -115,57 +139,33 @@ impl Mobiledevices {
             ),
             None,
         );
-        let mut resp: crate::types::MobileDevices = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut mobiledevices = resp.mobiledevices;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::MobileDevices = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            mobiledevices.append(&mut resp.mobiledevices);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.mobiledevices, next))
             }
-        }
-
-        // Return our response data.
-        Ok(mobiledevices)
+        })
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
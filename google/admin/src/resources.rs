@@ -69,6 +69,19 @@ impl Resources {
         &self,
         customer: &str,
     ) -> ClientResult<Vec<crate::types::Building>> {
+        futures::TryStreamExt::try_collect(self.buildings_list_all_stream(customer)).await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a list of buildings for an account.
+     */
+    pub fn buildings_list_all_stream(
+        &self,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Building>> + '_ {
         let url = self.client.url(
             &format!(
                 "/admin/directory/v1/customer/{}/resources/buildings",
@@ -76,57 +89,33 @@ impl Resources {
             ),
             None,
         );
-        let mut resp: crate::types::Buildings = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut buildings = resp.buildings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Buildings = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            buildings.append(&mut resp.buildings);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.buildings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(buildings)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
@@ -392,6 +381,24 @@ impl Resources {
         order_by: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::CalendarResource>> {
+        futures::TryStreamExt::try_collect(
+            self.calendars_list_all_stream(customer, order_by, query),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a list of calendar resources for an account.
+     */
+    pub fn calendars_list_all_stream(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarResource>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order_by.is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
@@ -408,57 +415,33 @@ impl Resources {
             ),
             None,
         );
-        let mut resp: crate::types::CalendarResources = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CalendarResources = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
@@ -683,6 +666,19 @@ impl Resources {
         &self,
         customer: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        futures::TryStreamExt::try_collect(self.features_list_all_stream(customer)).await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a list of features for an account.
+     */
+    pub fn features_list_all_stream(
+        &self,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + '_ {
         let url = self.client.url(
             &format!(
                 "/admin/directory/v1/customer/{}/resources/features",
@@ -690,57 +686,33 @@ impl Resources {
             ),
             None,
         );
-        let mut resp: crate::types::Features = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut features = resp.features;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Features = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            features.append(&mut resp.features);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.features, next))
             }
-        }
-
-        // Return our response data.
-        Ok(features)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
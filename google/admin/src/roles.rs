@@ -66,6 +66,19 @@ impl Roles {
      * Retrieves a paginated list of all the roles in a domain.
      */
     pub async fn list_all(&self, customer: &str) -> ClientResult<Vec<crate::types::Role>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(customer)).await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a paginated list of all the roles in a domain.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + '_ {
         let url = self.client.url(
             &format!(
                 "/admin/directory/v1/customer/{}/roles",
@@ -73,57 +86,33 @@ impl Roles {
             ),
             None,
         );
-        let mut resp: crate::types::Roles = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Roles = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
@@ -116,6 +116,38 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> ClientResult<Vec<crate::types::User>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            customer,
+            domain,
+            event,
+            order_by,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves a paginated list of either deleted users or all users in a domain.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -148,57 +180,33 @@ impl Users {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/users?{}", query_), None);
-        let mut resp: crate::types::Users = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut users = resp.users;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Users = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            users.append(&mut resp.users);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.users, next))
             }
-        }
-
-        // Return our response data.
-        Ok(users)
+        })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
@@ -79,6 +79,20 @@ impl Acl {
         calendar_id: &str,
         show_deleted: bool,
     ) -> ClientResult<Vec<crate::types::AclRule>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(calendar_id, show_deleted)).await
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AclRule>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
@@ -92,57 +106,33 @@ impl Acl {
             ),
             None,
         );
-        let mut resp: crate::types::Acl = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Acl = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
@@ -84,6 +84,26 @@ impl CalendarList {
         show_deleted: bool,
         show_hidden: bool,
     ) -> ClientResult<Vec<crate::types::CalendarListEntry>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            min_access_role,
+            show_deleted,
+            show_hidden,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub fn list_all_stream(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarListEntry>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !min_access_role.to_string().is_empty() {
             query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
@@ -98,57 +118,33 @@ impl CalendarList {
         let url = self
             .client
             .url(&format!("/users/me/calendarList?{}", query_), None);
-        let mut resp: crate::types::CalendarList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CalendarList = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/users/me/calendarList` endpoint.
@@ -171,6 +171,48 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            order_by,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !i_cal_uid.is_empty() {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
@@ -229,57 +271,33 @@ impl Events {
             ),
             None,
         );
-        let mut resp: crate::types::Events = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Events = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
@@ -932,6 +950,36 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        futures::TryStreamExt::try_collect(self.get_all_instances_stream(
+            calendar_id,
+            event_id,
+            max_attendees,
+            original_start,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns instances of the specified recurring event.
+     */
+    pub fn get_all_instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
@@ -961,57 +1009,33 @@ impl Events {
             ),
             None,
         );
-        let mut resp: crate::types::Events = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut items = resp.items;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Events = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            items.append(&mut resp.items);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.items, next))
             }
-        }
-
-        // Return our response data.
-        Ok(items)
+        })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
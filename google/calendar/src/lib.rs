@@ -140,6 +140,9 @@ pub enum ClientError {
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde URL encoding error
+    #[error(transparent)]
+    SerdeUrlencodedSerError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -750,6 +753,19 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request_form(http::Method::POST, uri, form).await
+    }
+
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: http::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -757,7 +773,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -953,6 +969,84 @@ impl Client {
         }
     }
 
+    /* TODO: make this more DRY */
+    /// Make a request for a file, handing back the raw body rather than parsing it.
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some(content_type) = &message.content_type {
+            req = req.header(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_str(content_type)?,
+            );
+        }
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = message.body {
+            req = req.body(body);
+        }
+
+        let response = req.send().await?;
+
+        let status = response.status();
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+        let filename = header(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|v| crate::utils::content_disposition_filename(&v));
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!("Received successful response. Read payload.");
+            Ok(crate::utils::Download {
+                body: response_body,
+                content_type,
+                filename,
+            })
+        } else {
+            let error = if response_body.is_empty() {
+                ClientError::HttpError {
+                    status,
+                    error: "empty response".into(),
+                }
+            } else {
+                ClientError::HttpError {
+                    status,
+                    error: String::from_utf8_lossy(&response_body).into(),
+                }
+            };
+
+            Err(error)
+        }
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -974,36 +1068,27 @@ impl Client {
         self.request_entity(http::Method::GET, uri, message).await
     }
 
+    /// Lazily walk the pages of a request that links to the next page in its headers.
     #[allow(dead_code)]
-    async fn get_all_pages<D>(&self, uri: &str, _message: Message) -> ClientResult<Vec<D>>
+    fn get_all_pages_stream<D>(
+        &self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + '_
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        // TODO: implement this.
-        self.unfold(uri).await
-    }
+        crate::utils::paginate(uri.to_string(), move |url| async move {
+            let (link, items) = self.get_pages(&url).await?;
 
-    /// "unfold" paginated results of a vector of items
-    #[allow(dead_code)]
-    async fn unfold<D>(&self, uri: &str) -> ClientResult<Vec<D>>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref() {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
+            // An empty page is the last page, whatever the headers say.
+            let next = if items.is_empty() {
+                None
+            } else {
+                link.map(|l| l.0)
+            };
 
-        Ok(global_items)
+            Ok((items, next))
+        })
     }
 
     #[allow(dead_code)]
@@ -1018,18 +1103,6 @@ impl Client {
             .await
     }
 
-    #[allow(dead_code)]
-    async fn get_pages_url<D>(
-        &self,
-        url: &reqwest::Url,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request_with_links(http::Method::GET, url.as_str(), Message::default())
-            .await
-    }
-
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
     where
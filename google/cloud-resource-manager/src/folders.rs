@@ -71,6 +71,20 @@ impl Folders {
         parent: &str,
         show_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Folder>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(parent, show_deleted)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     */
+    pub fn list_all_stream(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Folder>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !parent.is_empty() {
             query_args.push(("parent".to_string(), parent.to_string()));
@@ -80,57 +94,33 @@ impl Folders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v2/folders?{}", query_), None);
-        let mut resp: crate::types::ListFoldersResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut folders = resp.folders;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListFoldersResponse = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            folders.append(&mut resp.folders);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.folders, next))
             }
-        }
-
-        // Return our response data.
-        Ok(folders)
+        })
     }
     /**
      * This function performs a `POST` to the `/v2/folders` endpoint.
@@ -288,7 +278,7 @@ impl Folders {
         body: &crate::types::MoveFolderRequest,
     ) -> ClientResult<crate::types::Operation> {
         let url = self.client.url(
-            &format!("/v2/{}/move", crate::progenitor_support::encode_path(name),),
+            &format!("/v2/{}:move", crate::progenitor_support::encode_path(name),),
             None,
         );
         self.client
@@ -317,7 +307,7 @@ impl Folders {
     ) -> ClientResult<crate::types::Folder> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/undelete",
+                "/v2/{}:undelete",
                 crate::progenitor_support::encode_path(name),
             ),
             None,
@@ -348,7 +338,7 @@ impl Folders {
     ) -> ClientResult<crate::types::Policy> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/getIamPolicy",
+                "/v2/{}:getIamPolicy",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
@@ -379,7 +369,7 @@ impl Folders {
     ) -> ClientResult<crate::types::Policy> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/setIamPolicy",
+                "/v2/{}:setIamPolicy",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
@@ -410,7 +400,7 @@ impl Folders {
     ) -> ClientResult<crate::types::TestIamPermissionsResponse> {
         let url = self.client.url(
             &format!(
-                "/v2/{}/testIamPermissions",
+                "/v2/{}:testIamPermissions",
                 crate::progenitor_support::encode_path(resource),
             ),
             None,
@@ -146,6 +146,42 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::Change>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the changes for a user or shared drive.
+     */
+    pub fn list_all_stream(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Change>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
@@ -203,57 +239,33 @@ impl Changes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes?{}", query_), None);
-        let mut resp: crate::types::ChangeList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut changes = resp.changes;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ChangeList = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            changes.append(&mut resp.changes);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.changes, next))
             }
-        }
-
-        // Return our response data.
-        Ok(changes)
+        })
     }
     /**
      * This function performs a `GET` to the `/changes/startPageToken` endpoint.
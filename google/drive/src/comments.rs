@@ -84,6 +84,26 @@ impl Comments {
         include_deleted: bool,
         start_modified_time: &str,
     ) -> ClientResult<Vec<crate::types::Comment>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            file_id,
+            include_deleted,
+            start_modified_time,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a file's comments.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Comment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
@@ -103,57 +123,33 @@ impl Comments {
             ),
             None,
         );
-        let mut resp: crate::types::CommentList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut comments = resp.comments;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CommentList = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            comments.append(&mut resp.comments);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.comments, next))
             }
-        }
-
-        // Return our response data.
-        Ok(comments)
+        })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments` endpoint.
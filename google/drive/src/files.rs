@@ -141,6 +141,42 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::File>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists or searches files.
+     */
+    pub fn list_all_stream(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::File>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !corpora.is_empty() {
             query_args.push(("corpora".to_string(), corpora.to_string()));
@@ -192,57 +228,33 @@ impl Files {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
-        let mut resp: crate::types::FileList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut files = resp.files;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::FileList = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            files.append(&mut resp.files);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.files, next))
             }
-        }
-
-        // Return our response data.
-        Ok(files)
+        })
     }
     /**
      * This function performs a `POST` to the `/files` endpoint.
@@ -450,6 +462,132 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the content of the file rather than its metadata.
+     *
+     * Gets a file's metadata or content by ID.
+     */
+    pub async fn download(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> ClientResult<crate::utils::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        let url = crate::utils::add_query_param(&url, "alt", "media");
+        self.client
+            .request_download(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+                "*/*",
+            )
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `download`, this function writes the file to `writer` as it arrives, rather than holding all of it in memory, and returns the number of bytes written.
+     *
+     * Gets a file's metadata or content by ID.
+     */
+    pub async fn download_to_writer<W: tokio::io::AsyncWrite + Unpin + Send + ?Sized>(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        writer: &mut W,
+    ) -> ClientResult<u64> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        let url = crate::utils::add_query_param(&url, "alt", "media");
+        self.client
+            .request_download_to_writer(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+                "*/*",
+                writer,
+            )
+            .await
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
      *
@@ -680,7 +818,11 @@ impl Files {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `mime_type: &str` -- The MIME type of the format requested for this export.
      */
-    pub async fn export(&self, file_id: &str, mime_type: &str) -> ClientResult<()> {
+    pub async fn export(
+        &self,
+        file_id: &str,
+        mime_type: &str,
+    ) -> ClientResult<crate::utils::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !mime_type.is_empty() {
             query_args.push(("mimeType".to_string(), mime_type.to_string()));
@@ -695,12 +837,53 @@ impl Files {
             None,
         );
         self.client
-            .get(
+            .request_download(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+                "*/*",
+            )
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
+     *
+     * As opposed to `export`, this function writes the file to `writer` as it arrives, rather than holding all of it in memory, and returns the number of bytes written.
+     *
+     * Exports a Google Doc to the requested MIME type and returns the exported content. Please note that the exported content is limited to 10MB.
+     */
+    pub async fn export_to_writer<W: tokio::io::AsyncWrite + Unpin + Send + ?Sized>(
+        &self,
+        file_id: &str,
+        mime_type: &str,
+        writer: &mut W,
+    ) -> ClientResult<u64> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !mime_type.is_empty() {
+            query_args.push(("mimeType".to_string(), mime_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/export?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .request_download_to_writer(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
+                "*/*",
+                writer,
             )
             .await
     }
@@ -105,6 +105,30 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::Permission>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            file_id,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a file's or shared drive's permissions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Permission>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_permissions_for_view.is_empty() {
             query_args.push((
@@ -139,57 +163,33 @@ impl Permissions {
             ),
             None,
         );
-        let mut resp: crate::types::PermissionList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut permissions = resp.permissions;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PermissionList = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            permissions.append(&mut resp.permissions);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.permissions, next))
             }
-        }
-
-        // Return our response data.
-        Ok(permissions)
+        })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
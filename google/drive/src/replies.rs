@@ -79,6 +79,26 @@ impl Replies {
         comment_id: &str,
         include_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Reply>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            file_id,
+            comment_id,
+            include_deleted,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a comment's replies.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reply>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
@@ -93,57 +113,33 @@ impl Replies {
             ),
             None,
         );
-        let mut resp: crate::types::ReplyList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut replies = resp.replies;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ReplyList = self
                     .client
                     .get(
-                        &format!("{}?pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            replies.append(&mut resp.replies);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.replies, next))
             }
-        }
-
-        // Return our response data.
-        Ok(replies)
+        })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
@@ -66,6 +66,19 @@ impl Revisions {
      * Lists a file's revisions.
      */
     pub async fn list_all(&self, file_id: &str) -> ClientResult<Vec<crate::types::Revision>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(file_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists a file's revisions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Revision>> + '_ {
         let url = self.client.url(
             &format!(
                 "/files/{}/revisions",
@@ -73,57 +86,33 @@ impl Revisions {
             ),
             None,
         );
-        let mut resp: crate::types::RevisionList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut revisions = resp.revisions;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::RevisionList = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            revisions.append(&mut resp.revisions);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.revisions, next))
             }
-        }
-
-        // Return our response data.
-        Ok(revisions)
+        })
     }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
@@ -169,6 +158,94 @@ impl Revisions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the content of the file rather than its metadata.
+     *
+     * Gets a revision's metadata or content by ID.
+     */
+    pub async fn download(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> ClientResult<crate::utils::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/revisions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id),
+                query_
+            ),
+            None,
+        );
+        let url = crate::utils::add_query_param(&url, "alt", "media");
+        self.client
+            .request_download(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+                "*/*",
+            )
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `download`, this function writes the file to `writer` as it arrives, rather than holding all of it in memory, and returns the number of bytes written.
+     *
+     * Gets a revision's metadata or content by ID.
+     */
+    pub async fn download_to_writer<W: tokio::io::AsyncWrite + Unpin + Send + ?Sized>(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+        writer: &mut W,
+    ) -> ClientResult<u64> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if acknowledge_abuse {
+            query_args.push((
+                "acknowledgeAbuse".to_string(),
+                acknowledge_abuse.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/revisions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id),
+                query_
+            ),
+            None,
+        );
+        let url = crate::utils::add_query_param(&url, "alt", "media");
+        self.client
+            .request_download_to_writer(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+                "*/*",
+                writer,
+            )
+            .await
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -74,6 +74,20 @@ impl Teamdrives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::TeamDrive>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(q, use_domain_admin_access)).await
+    }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Deprecated use drives.list instead.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDrive>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
@@ -86,57 +100,33 @@ impl Teamdrives {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
-        let mut resp: crate::types::TeamDriveList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut team_drives = resp.team_drives;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::TeamDriveList = self
                     .client
                     .get(
-                        &format!("{}&pageToken={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            team_drives.append(&mut resp.team_drives);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url =
+                        crate::utils::add_query_param(&url, "pageToken", &resp.next_page_token);
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.team_drives, next))
             }
-        }
-
-        // Return our response data.
-        Ok(team_drives)
+        })
     }
     /**
      * This function performs a `POST` to the `/teamdrives` endpoint.
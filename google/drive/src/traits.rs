@@ -153,6 +153,87 @@ pub trait FileOps {
     /// Delete a file by its name.
     async fn delete_by_name(&self, drive_id: &str, parent_id: &str, name: &str)
         -> ClientResult<()>;
+
+    /// Upload a file's metadata and content in a single request.
+    ///
+    /// This is the best fit for small files, anything that would hurt to send
+    /// twice should use a resumable upload instead. An empty `file_id` creates
+    /// a new file, otherwise that file's content is replaced.
+    async fn upload_multipart(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        contents: &[u8],
+    ) -> ClientResult<crate::types::File>;
+
+    /// Start a resumable upload of `size` bytes.
+    ///
+    /// An empty `file_id` creates a new file, otherwise that file's content
+    /// is replaced. Nothing is uploaded yet, hand the session to
+    /// `upload_resumable`.
+    async fn start_resumable_upload(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        size: u64,
+    ) -> ClientResult<UploadSession>;
+
+    /// Ask how much of a resumable upload the server has.
+    async fn upload_status(&self, session: &UploadSession) -> ClientResult<UploadStatus>;
+
+    /// Send one chunk of a resumable upload, starting at `offset`.
+    ///
+    /// Every chunk but the last must be a multiple of 256 KiB.
+    async fn upload_chunk(
+        &self,
+        session: &UploadSession,
+        offset: u64,
+        chunk: &[u8],
+    ) -> ClientResult<UploadStatus>;
+
+    /// Upload the content of a resumable upload from `reader`, in chunks of
+    /// `UPLOAD_CHUNK_SIZE`.
+    ///
+    /// This picks up from wherever the server got to, so after a failure it
+    /// can be called again with the same session, even from another process.
+    async fn upload_resumable<R>(
+        &self,
+        session: &UploadSession,
+        reader: &mut R,
+    ) -> ClientResult<crate::types::File>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin + Send;
+}
+
+/// How much of a resumable upload is sent at a time.
+///
+/// Google wants chunks in multiples of 256 KiB.
+pub const UPLOAD_CHUNK_SIZE: usize = 32 * 256 * 1024;
+
+/// A resumable upload that has been started.
+///
+/// The session URI is all Google needs to carry on with an upload, so this
+/// can be saved and loaded again to resume an upload that was interrupted.
+/// Sessions expire after a week.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UploadSession {
+    /// Where the content of the file is sent.
+    pub uri: String,
+    /// The MIME type of the content.
+    pub mime_type: String,
+    /// The size of the file, in bytes.
+    pub size: u64,
+}
+
+/// Where a resumable upload has got to.
+#[derive(Clone, Debug)]
+pub enum UploadStatus {
+    /// The server has all of the file.
+    Complete(Box<crate::types::File>),
+    /// The server has the first `committed` bytes of the file.
+    Incomplete { committed: u64 },
 }
 
 #[async_trait::async_trait]
@@ -277,16 +358,16 @@ impl FileOps for crate::files::Files {
 
     /// Download a file by it's ID.
     async fn download_by_id(&self, id: &str) -> ClientResult<bytes::Bytes> {
-        let resp = self
-            .client
-            .request_raw(
-                reqwest::Method::GET,
-                &format!("/files/{}?supportsAllDrives=true&alt=media", id),
-                crate::Message::default(),
+        let file = self
+            .download(
+                id, false, // acknowledge_abuse
+                "",    // include_permissions_for_view
+                true,  // supports_all_drives
+                false, // supports_team_drives
             )
             .await?;
 
-        Ok(resp.bytes().await?)
+        Ok(file.body)
     }
 
     /// Create a folder, if it doesn't exist, returns the ID of the folder.
@@ -401,6 +482,282 @@ impl FileOps for crate::files::Files {
         )
         .await
     }
+
+    /// Upload a file's metadata and content in a single request.
+    async fn upload_multipart(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        contents: &[u8],
+    ) -> ClientResult<crate::types::File> {
+        let boundary = format!("upload-{}", uuid::Uuid::new_v4().simple());
+
+        let mut body = format!(
+            "--{}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n",
+            boundary
+        )
+        .into_bytes();
+        body.extend(serde_json::to_vec(file)?);
+        body.extend(format!("\r\n--{}\r\nContent-Type: {}\r\n\r\n", boundary, mime_type).bytes());
+        body.extend_from_slice(contents);
+        body.extend(format!("\r\n--{}--", boundary).bytes());
+
+        let (method, uri) = upload_uri(&self.client, file_id, "multipart");
+        let resp = send_upload(
+            &self.client,
+            method,
+            &uri,
+            crate::Message {
+                body: Some(body.into()),
+                content_type: Some(format!("multipart/related; boundary={}", boundary)),
+            },
+            vec![],
+        )
+        .await?;
+
+        match upload_status_from_response(resp).await? {
+            UploadStatus::Complete(file) => Ok(*file),
+            UploadStatus::Incomplete { committed } => Err(ClientError::UploadIncomplete {
+                committed,
+                size: contents.len() as u64,
+            }),
+        }
+    }
+
+    /// Start a resumable upload of `size` bytes.
+    async fn start_resumable_upload(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        mime_type: &str,
+        size: u64,
+    ) -> ClientResult<UploadSession> {
+        let (method, uri) = upload_uri(&self.client, file_id, "resumable");
+        let resp = send_upload(
+            &self.client,
+            method,
+            &uri,
+            crate::Message {
+                body: Some(reqwest::Body::from(serde_json::to_vec(file)?)),
+                content_type: Some("application/json; charset=UTF-8".to_string()),
+            },
+            vec![
+                ("x-upload-content-type", mime_type.to_string()),
+                ("x-upload-content-length", size.to_string()),
+            ],
+        )
+        .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(ClientError::HttpError {
+                status,
+                error: resp.text().await?,
+            });
+        }
+
+        // The session lives at the "Location" header.
+        let location = resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .ok_or(ClientError::HttpError {
+                status,
+                error: "Missing Location header".to_string(),
+            })?
+            .to_str()?;
+
+        Ok(UploadSession {
+            uri: location.to_string(),
+            mime_type: mime_type.to_string(),
+            size,
+        })
+    }
+
+    /// Ask how much of a resumable upload the server has.
+    async fn upload_status(&self, session: &UploadSession) -> ClientResult<UploadStatus> {
+        let resp = send_upload(
+            &self.client,
+            reqwest::Method::PUT,
+            &session.uri,
+            crate::Message {
+                body: Some(reqwest::Body::from(Vec::new())),
+                content_type: Some(session.mime_type.to_string()),
+            },
+            vec![("content-range", format!("bytes */{}", session.size))],
+        )
+        .await?;
+
+        upload_status_from_response(resp).await
+    }
+
+    /// Send one chunk of a resumable upload, starting at `offset`.
+    async fn upload_chunk(
+        &self,
+        session: &UploadSession,
+        offset: u64,
+        chunk: &[u8],
+    ) -> ClientResult<UploadStatus> {
+        let range = if chunk.is_empty() {
+            format!("bytes */{}", session.size)
+        } else {
+            format!(
+                "bytes {}-{}/{}",
+                offset,
+                offset + chunk.len() as u64 - 1,
+                session.size
+            )
+        };
+
+        let resp = send_upload(
+            &self.client,
+            reqwest::Method::PUT,
+            &session.uri,
+            crate::Message {
+                body: Some(bytes::Bytes::copy_from_slice(chunk).into()),
+                content_type: Some(session.mime_type.to_string()),
+            },
+            vec![("content-range", range)],
+        )
+        .await?;
+
+        upload_status_from_response(resp).await
+    }
+
+    /// Upload the content of a resumable upload from `reader`.
+    async fn upload_resumable<R>(
+        &self,
+        session: &UploadSession,
+        reader: &mut R,
+    ) -> ClientResult<crate::types::File>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin + Send,
+    {
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+        // Pick up wherever the server got to, for a new session that's the start.
+        let mut offset = match self.upload_status(session).await? {
+            UploadStatus::Complete(file) => return Ok(*file),
+            UploadStatus::Incomplete { committed } => committed,
+        };
+        reader.seek(std::io::SeekFrom::Start(offset)).await?;
+
+        let mut chunk = vec![0; UPLOAD_CHUNK_SIZE];
+        loop {
+            // Fill the chunk, a short read doesn't mean we're at the end.
+            let mut len = 0;
+            while len < chunk.len() {
+                let n = reader.read(&mut chunk[len..]).await?;
+                if n == 0 {
+                    break;
+                }
+                len += n;
+            }
+
+            match self.upload_chunk(session, offset, &chunk[..len]).await? {
+                UploadStatus::Complete(file) => return Ok(*file),
+                UploadStatus::Incomplete { committed } if len == 0 => {
+                    // We ran out of file before the server had all of it.
+                    return Err(ClientError::UploadIncomplete {
+                        committed,
+                        size: session.size,
+                    });
+                }
+                UploadStatus::Incomplete { committed } => {
+                    // The server may have kept less than we sent.
+                    if committed != offset + len as u64 {
+                        reader.seek(std::io::SeekFrom::Start(committed)).await?;
+                    }
+                    offset = committed;
+                }
+            }
+        }
+    }
+}
+
+/// Where to send an upload, and how.
+///
+/// Uploads have their own endpoint, `/upload/drive/v3` rather than `/drive/v3`.
+fn upload_uri(
+    client: &crate::Client,
+    file_id: &str,
+    upload_type: &str,
+) -> (reqwest::Method, String) {
+    let base = client
+        .url("/files", None)
+        .replacen("/drive/v3/", "/upload/drive/v3/", 1);
+    let query = format!("uploadType={}&supportsAllDrives=true", upload_type);
+
+    if file_id.is_empty() {
+        (reqwest::Method::POST, format!("{}?{}", base, query))
+    } else {
+        (
+            reqwest::Method::PATCH,
+            format!(
+                "{}/{}?{}",
+                base,
+                crate::progenitor_support::encode_path(file_id),
+                query
+            ),
+        )
+    }
+}
+
+/// Send one request of an upload, with the headers it needs on top of the
+/// usual ones.
+async fn send_upload(
+    client: &crate::Client,
+    method: reqwest::Method,
+    uri: &str,
+    message: crate::Message,
+    headers: Vec<(&'static str, String)>,
+) -> ClientResult<reqwest::Response> {
+    if client.auto_refresh && client.is_expired().await == Some(true) {
+        client.refresh_access_token().await?;
+    }
+
+    let mut req = client.make_request(&method, uri, message).await?;
+    for (name, value) in headers {
+        req.headers_mut().insert(
+            reqwest::header::HeaderName::from_static(name),
+            reqwest::header::HeaderValue::from_str(&value)?,
+        );
+    }
+
+    Ok(client.client.execute(req).await?)
+}
+
+/// Work out where an upload has got to from the server's response.
+async fn upload_status_from_response(resp: reqwest::Response) -> ClientResult<UploadStatus> {
+    let status = resp.status();
+
+    // Google answers with a `308 Resume Incomplete` until it has all of the file.
+    if status == http::StatusCode::PERMANENT_REDIRECT {
+        // The "Range" header looks like `bytes=0-42`, and is missing if we
+        // haven't sent anything yet.
+        let committed = resp
+            .headers()
+            .get(reqwest::header::RANGE)
+            .and_then(|r| r.to_str().ok())
+            .and_then(|r| r.rsplit('-').next())
+            .and_then(|end| end.parse::<u64>().ok())
+            .map(|end| end + 1)
+            .unwrap_or_default();
+
+        return Ok(UploadStatus::Incomplete { committed });
+    }
+
+    let body = resp.bytes().await?;
+    if !status.is_success() {
+        return Err(ClientError::HttpError {
+            status,
+            error: String::from_utf8_lossy(&body).into(),
+        });
+    }
+
+    Ok(UploadStatus::Complete(Box::new(serde_json::from_slice(
+        &body,
+    )?)))
 }
 
 #[async_trait::async_trait]
@@ -1 +1,258 @@
+use google_drive::Client;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
 
+fn client(server: &MockServer) -> Client {
+    let mut client = Client::new("client-id", "client-secret", "", "test-token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_export_returns_the_content() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/doc/export"))
+        .and(query_param("mimeType", "text/csv"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("a,b\n1,2\n", "text/csv"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let export = client(&server)
+        .files()
+        .export("doc", "text/csv")
+        .await
+        .unwrap();
+
+    assert_eq!(export.body.as_ref(), b"a,b\n1,2\n");
+    assert_eq!(export.content_type.as_deref(), Some("text/csv"));
+}
+
+#[tokio::test]
+async fn test_download_asks_for_media() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/report"))
+        .and(query_param("alt", "media"))
+        .and(query_param("supportsAllDrives", "true"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(vec![0u8, 159, 146, 150], "application/octet-stream"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let file = client(&server)
+        .files()
+        .download("report", false, "", true, false)
+        .await
+        .unwrap();
+
+    assert_eq!(file.body.as_ref(), &[0u8, 159, 146, 150]);
+}
+
+#[tokio::test]
+async fn test_download_to_writer_streams_the_file() {
+    let server = MockServer::start().await;
+
+    let content = vec![7u8; 256 * 1024];
+
+    Mock::given(method("GET"))
+        .and(path("/files/big"))
+        .and(query_param("alt", "media"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(content.clone(), "application/octet-stream"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut out: Vec<u8> = Vec::new();
+    let written = client(&server)
+        .files()
+        .download_to_writer("big", false, "", false, false, &mut out)
+        .await
+        .unwrap();
+
+    assert_eq!(written, content.len() as u64);
+    assert_eq!(out, content);
+}
+
+#[tokio::test]
+async fn test_download_to_writer_error_writes_nothing() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files/missing/export"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("File not found"))
+        .mount(&server)
+        .await;
+
+    let mut out: Vec<u8> = Vec::new();
+    let err = client(&server)
+        .files()
+        .export_to_writer("missing", "application/pdf", &mut out)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        google_drive::ClientError::HttpError { status, .. } if status == http::StatusCode::NOT_FOUND
+    ));
+    assert!(out.is_empty());
+}
+
+#[tokio::test]
+async fn test_upload_multipart_sends_metadata_and_content() {
+    use google_drive::traits::FileOps;
+    use wiremock::matchers::{body_string_contains, header_regex};
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/files"))
+        .and(query_param("uploadType", "multipart"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/related; boundary=",
+        ))
+        .and(body_string_contains(r#""name":"backup.tar""#))
+        .and(body_string_contains("tarball contents"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "file-1",
+            "name": "backup.tar",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let file = google_drive::types::File {
+        name: "backup.tar".to_string(),
+        ..Default::default()
+    };
+
+    let uploaded = client(&server)
+        .files()
+        .upload_multipart("", &file, "application/x-tar", b"tarball contents")
+        .await
+        .unwrap();
+
+    assert_eq!(uploaded.id, "file-1");
+}
+
+#[tokio::test]
+async fn test_start_resumable_upload_returns_the_session() {
+    use google_drive::traits::FileOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/files/file-1"))
+        .and(query_param("uploadType", "resumable"))
+        .and(header("x-upload-content-type", "application/x-tar"))
+        .and(header("x-upload-content-length", "10"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("location", format!("{}/session/1", server.uri()).as_str()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let session = client(&server)
+        .files()
+        .start_resumable_upload("file-1", &Default::default(), "application/x-tar", 10)
+        .await
+        .unwrap();
+
+    assert_eq!(session.uri, format!("{}/session/1", server.uri()));
+    assert_eq!(session.size, 10);
+
+    // Sessions can be saved and picked up again later.
+    let saved = serde_json::to_string(&session).unwrap();
+    let loaded: google_drive::traits::UploadSession = serde_json::from_str(&saved).unwrap();
+    assert_eq!(loaded, session);
+}
+
+#[tokio::test]
+async fn test_upload_resumable_picks_up_where_the_server_got_to() {
+    use google_drive::traits::{FileOps, UploadSession};
+
+    let server = MockServer::start().await;
+
+    // The server already has the first 4 bytes.
+    Mock::given(method("PUT"))
+        .and(path("/session/1"))
+        .and(header("content-range", "bytes */10"))
+        .respond_with(ResponseTemplate::new(308).insert_header("range", "bytes=0-3"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/session/1"))
+        .and(header("content-range", "bytes 4-9/10"))
+        .and(wiremock::matchers::body_bytes(b"456789".to_vec()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "file-1",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let session = UploadSession {
+        uri: format!("{}/session/1", server.uri()),
+        mime_type: "application/x-tar".to_string(),
+        size: 10,
+    };
+
+    let mut reader = std::io::Cursor::new(b"0123456789".to_vec());
+    let file = client(&server)
+        .files()
+        .upload_resumable(&session, &mut reader)
+        .await
+        .unwrap();
+
+    assert_eq!(file.id, "file-1");
+}
+
+#[tokio::test]
+async fn test_upload_resumable_short_file_is_an_error() {
+    use google_drive::traits::{FileOps, UploadSession};
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/session/1"))
+        .respond_with(ResponseTemplate::new(308).insert_header("range", "bytes=0-3"))
+        .mount(&server)
+        .await;
+
+    let session = UploadSession {
+        uri: format!("{}/session/1", server.uri()),
+        mime_type: "application/x-tar".to_string(),
+        size: 10,
+    };
+
+    let mut reader = std::io::Cursor::new(b"0123".to_vec());
+    let err = client(&server)
+        .files()
+        .upload_resumable(&session, &mut reader)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        google_drive::ClientError::UploadIncomplete {
+            committed: 4,
+            size: 10
+        }
+    ));
+}
@@ -1 +1,32 @@
+use sheets::Client;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_batch_update_keeps_the_custom_method_on_the_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/spreadsheets/sheet-id:batchUpdate"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "spreadsheetId": "sheet-id" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client-id", "client-secret", "", "test-token", "");
+    client.with_host_override(server.uri());
+    let body = serde_json::from_value(serde_json::json!({ "requests": [] })).unwrap();
+    let response = client
+        .spreadsheets()
+        .batch_update("sheet-id", &body)
+        .await
+        .unwrap();
+
+    assert_eq!(response.spreadsheet_id, "sheet-id");
+}
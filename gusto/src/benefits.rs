@@ -44,16 +44,24 @@ impl Benefits {
      * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
      */
     pub async fn get_all(&self) -> ClientResult<Vec<crate::types::SupportedBenefit>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream()).await
+    }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns all benefits supported by Gusto.
+     *
+     * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SupportedBenefit>> + '_ {
         let url = self.client.url("/v1/benefits", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a supported benefit by ID.
@@ -127,6 +135,23 @@ impl Benefits {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::CompanyBenefit>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(company_id)).await
+    }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Company benefits represent the benefits that a company is offering to employees. This ties together a particular supported benefit with the company-specific information for the offering of that benefit.
+     *
+     * Note that company benefits can be deactivated only when no employees are enrolled.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBenefit>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/company_benefits",
@@ -134,15 +159,7 @@ impl Benefits {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a company benefit.
@@ -281,6 +298,23 @@ impl Benefits {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::EmployeeBenefit>> {
+        futures::TryStreamExt::try_collect(self.get_all_employee_stream(employee_id)).await
+    }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
+     *
+     * Returns an array of all employee benefits for this employee
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EmployeeBenefit>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/employee_benefits",
@@ -288,15 +322,7 @@ impl Benefits {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an employee benefit.
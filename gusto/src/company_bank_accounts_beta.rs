@@ -56,6 +56,26 @@ impl CompanyBankAccountsBeta {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::CompanyBankAccount>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_company_bank_accounts_stream(company_id_or_uuid),
+        )
+        .await
+    }
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_company_bank_accounts`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns all company bank accounts
+     */
+    pub fn get_all_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBankAccount>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/bank_accounts",
@@ -63,15 +83,7 @@ impl CompanyBankAccountsBeta {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a company bank account.
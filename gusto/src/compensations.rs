@@ -113,6 +113,25 @@ impl Compensations {
      * Use the `flsa_status` to determine if an employee is elibgle for overtime.
      */
     pub async fn get_all_job(&self, job_id: &str) -> ClientResult<Vec<crate::types::Compensation>> {
+        futures::TryStreamExt::try_collect(self.get_all_job_stream(job_id)).await
+    }
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_all_job`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Compensations contain information on how much is paid out for a job. Jobs may have many compensations, but only one that is active. The current compensation is the one with the most recent `effective_date`.
+     *
+     * Note: Currently, jobs are arbitrarily limited to a single compensation as multiple compensations per job are not yet available in Gusto. The API is architected as if multiple compensations may exist, so integrations should integrate under the same assumption. The only exception is that creating a compensation with the same `job_id` as another will fail with a relevant error.
+     *
+     * Use the `flsa_status` to determine if an employee is elibgle for overtime.
+     */
+    pub fn get_all_job_stream(
+        &self,
+        job_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Compensation>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}/compensations",
@@ -120,14 +139,6 @@ impl Compensations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
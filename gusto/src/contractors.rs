@@ -106,6 +106,21 @@ impl Contractors {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::Contractor>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(company_id_or_uuid)).await
+    }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all contractors, active and inactive, individual and business, for a company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contractor>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/contractors",
@@ -113,15 +128,7 @@ impl Contractors {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a contractor.
@@ -148,6 +148,28 @@ impl Employees {
         terminated: bool,
         include: &[String],
     ) -> ClientResult<Vec<crate::types::Employee>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(
+            company_id_or_uuid,
+            terminated,
+            include,
+        ))
+        .await
+    }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all of the employees, onboarding, active and terminated, for a given company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Employee>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.join(" ")));
@@ -164,15 +186,7 @@ impl Employees {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an employee.
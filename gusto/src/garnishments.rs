@@ -52,6 +52,21 @@ impl Garnishments {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::Garnishment>> {
+        futures::TryStreamExt::try_collect(self.get_all_employee_stream(employee_id)).await
+    }
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Garnishment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/garnishments",
@@ -59,15 +74,7 @@ impl Garnishments {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a garnishment.
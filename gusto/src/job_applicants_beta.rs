@@ -56,6 +56,24 @@ impl JobApplicantsBeta {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::JobApplicant>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_job_applicants_stream(company_id))
+            .await
+    }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * *This endpoint is in beta - we will be making breaking changes based on developer feedback.
+     *
+     * Returns all job applicants for a company.
+     */
+    pub fn get_all_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobApplicant>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants",
@@ -63,15 +81,7 @@ impl JobApplicantsBeta {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a job applicant.
@@ -128,6 +128,21 @@ impl Jobs {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::Job>> {
+        futures::TryStreamExt::try_collect(self.get_all_employee_stream(employee_id)).await
+    }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all of the jobs that an employee holds.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Job>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/jobs",
@@ -135,15 +150,7 @@ impl Jobs {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a job.
@@ -56,6 +56,23 @@ impl Locations {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::Location>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(company_id_or_uuid)).await
+    }
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Company locations represent all addresses associated with a company. These can be filing addesses, mailing addresses, and/or work locations; one address may serve multiple, or all, purposes.
+     *
+     * Since all company locations are subsets of locations, retrieving or updating an individual record should be done via the locations endpoints.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/locations",
@@ -63,15 +80,7 @@ impl Locations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a company location.
@@ -52,6 +52,21 @@ impl PaySchedules {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::PaySchedule>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(company_id)).await
+    }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The pay schedule object in Gusto captures the details of when employees work and when they should be paid. A company can have multiple pay schedules.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaySchedule>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/pay_schedules",
@@ -59,15 +74,7 @@ impl PaySchedules {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a pay schedule.
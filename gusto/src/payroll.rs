@@ -76,6 +76,31 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::PayPeriod>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_pay_periods_stream(
+            company_id_or_uuid,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_all_company_pay_periods`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Pay periods are the foundation of payroll. Compensation, time & attendance, taxes, and expense reports all rely on when they happened. To begin submitting information for a given payroll, we need to agree on the time period.
+     *
+     *
+     * By default, this endpoint returns every current and past pay period for a company. Since companies can process payroll as often as every week, there can be up to 53 pay periods a year. If a company has been running payroll with Gusto for five years, this endpoint could return up to 265 pay periods. Use the `start_date` and `end_date` parameters to reduce the scope of the response.
+     */
+    pub fn get_all_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayPeriod>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -92,15 +117,7 @@ impl Payroll {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get all payrolls for a company.
@@ -192,6 +209,39 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::PayrollData>> {
+        futures::TryStreamExt::try_collect(self.get_all_company_stream(
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns all payrolls, current and past for a company.
+     *
+     * Notes:
+     * * Hour and dollar amounts are returned as string representations of numeric decimals.
+     * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
+     * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayrollData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -220,15 +270,7 @@ impl Payroll {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an Off-Cycle Payroll (Beta).
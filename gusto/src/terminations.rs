@@ -56,6 +56,23 @@ impl Terminations {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::Termination>> {
+        futures::TryStreamExt::try_collect(self.get_all_employee_stream(employee_id)).await
+    }
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Terminations are created whenever an employee is scheduled to leave the company. The only things required are an effective date (their last day of work) and whether they should receive their wages in a one-off termination payroll or with the rest of the company.
+     *
+     * Note that some states require employees to receive their final wages within 24 hours (unless they consent otherwise,) in which case running a one-off payroll may be the only option.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Termination>> + '_ {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/terminations",
@@ -63,15 +80,7 @@ impl Terminations {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an employee termination.
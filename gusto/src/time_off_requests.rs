@@ -104,6 +104,43 @@ impl TimeOffRequests {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::TimeOffRequest>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_company_stream(company_id, start_date, end_date),
+        )
+        .await
+    }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all time off requests, past and present, for a company.
+     *
+     * In order to reduce the number of time off requests returned in a single response, or to retrieve time off requests from a time period of interest, you may use the `start_date` and `end_date` parameters.
+     *
+     * You may provide both or either parameters to scope the returned data. For example:
+     *
+     * `?start_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request start date is equal to or after January 1, 2019.
+     *
+     * `?end_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request end date is equal to or before January 1, 2019.
+     *
+     * `?start_date='2019-05-01'&end_date='2019-08-31'`
+     *
+     * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
+     *
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TimeOffRequest>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -120,15 +157,7 @@ impl TimeOffRequests {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a specific time off request.
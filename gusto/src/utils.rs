@@ -1,3 +1,108 @@
+/// A file returned by the API, rather than a JSON document.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The raw response body.
+    pub body: bytes::Bytes,
+    /// The `Content-Type` the server sent the file with.
+    pub content_type: Option<String>,
+    /// The filename from the `Content-Disposition` header.
+    pub filename: Option<String>,
+}
+
+/// Get the filename out of a `Content-Disposition` header.
+///
+/// The RFC 5987 `filename*` parameter wins over a plain `filename` when a
+/// server sends both.
+pub fn content_disposition_filename(value: &str) -> Option<String> {
+    let mut filename = None;
+    for param in value.split(';').skip(1) {
+        if let Some((key, val)) = param.split_once('=') {
+            let val = val.trim().trim_matches('"');
+            match key.trim().to_ascii_lowercase().as_str() {
+                "filename*" => {
+                    // This looks like `UTF-8''file%20name.pdf`.
+                    if let Some(encoded) = val.splitn(3, '\'').nth(2) {
+                        if let Ok(decoded) =
+                            percent_encoding::percent_decode_str(encoded).decode_utf8()
+                        {
+                            return Some(decoded.to_string());
+                        }
+                    }
+                }
+                "filename" if !val.is_empty() => filename = Some(val.to_string()),
+                _ => (),
+            }
+        }
+    }
+
+    filename
+}
+
+/// A file to send as part of a `multipart/form-data` request.
+#[derive(Debug, Clone, Default)]
+pub struct FilePart {
+    /// The content of the file.
+    pub content: bytes::Bytes,
+    /// The name of the file.
+    pub filename: String,
+    /// The MIME type of the file, for example `image/png`.
+    pub content_type: String,
+}
+
+impl FilePart {
+    /// Turn this into a part of a form.
+    pub fn into_part(self) -> crate::ClientResult<reqwest::multipart::Part> {
+        let mut part =
+            reqwest::multipart::Part::bytes(self.content.to_vec()).file_name(self.filename);
+        if !self.content_type.is_empty() {
+            part = part.mime_str(&self.content_type)?;
+        }
+
+        Ok(part)
+    }
+}
+
+/// Get the text of a `multipart/form-data` part.
+///
+/// Strings (and enums) are sent as they are, anything else as JSON.
+pub fn form_text<T: serde::Serialize + ?Sized>(value: &T) -> crate::ClientResult<String> {
+    Ok(match serde_json::to_value(value)? {
+        serde_json::Value::String(s) => s,
+        v => v.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod file_tests {
+    use super::{content_disposition_filename, form_text};
+
+    #[test]
+    fn test_content_disposition_filename() {
+        assert_eq!(
+            content_disposition_filename("attachment; filename=\"signed.pdf\"").as_deref(),
+            Some("signed.pdf")
+        );
+        assert_eq!(
+            content_disposition_filename(
+                "attachment; filename=old.pdf; filename*=UTF-8''sign%C3%A9.pdf"
+            )
+            .as_deref(),
+            Some("signé.pdf")
+        );
+        assert_eq!(content_disposition_filename("inline"), None);
+    }
+
+    #[test]
+    fn test_form_text() {
+        assert_eq!(form_text("en").unwrap(), "en");
+        assert_eq!(form_text(&3).unwrap(), "3");
+        assert_eq!(
+            form_text(&serde_json::json!({"a": true})).unwrap(),
+            "{\"a\":true}"
+        );
+    }
+}
+
 use std::{fmt, str::FromStr};
 
 use parse_link_header::LinkMap;
@@ -11,6 +116,46 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
         .map(NextLink)
 }
 
+/// Add a query parameter to a url, whether or not it already has a query string.
+pub fn add_query_param(url: &str, key: &str, value: &str) -> String {
+    let value = percent_encoding::utf8_percent_encode(value, percent_encoding::NON_ALPHANUMERIC);
+    if url.contains('?') {
+        format!("{}&{}={}", url, key, value)
+    } else {
+        format!("{}?{}={}", url, key, value)
+    }
+}
+
+/// Lazily walk the pages of a paginated request.
+///
+/// `fetch` is handed the url of a page and returns the items on it, along with
+/// the url of the next page if there is one. Pages are only requested as the
+/// stream is polled, so dropping the stream, or capping it with
+/// `futures::StreamExt::take`, stops any further requests.
+pub fn paginate<'a, D, F, Fut>(
+    url: String,
+    mut fetch: F,
+) -> impl futures::Stream<Item = crate::ClientResult<D>> + 'a
+where
+    D: 'a,
+    F: FnMut(String) -> Fut + 'a,
+    Fut: std::future::Future<Output = crate::ClientResult<(Vec<D>, Option<String>)>> + 'a,
+{
+    use futures::TryStreamExt;
+
+    futures::stream::try_unfold(Some(url), move |next| {
+        let page = next.map(&mut fetch);
+        async move {
+            match page {
+                Some(page) => page.await.map(Some),
+                None => Ok(None),
+            }
+        }
+    })
+    .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -633,9 +778,33 @@ pub mod deserialize_null_vector {
     }
 }
 
+pub mod deserialize_null_map {
+    use serde::{self, Deserialize, Deserializer};
+
+    // The signature of a deserialize_with function must follow the pattern:
+    //
+    //    fn deserialize<'de, D>(D) -> Result<T, D::Error>
+    //    where
+    //        D: Deserializer<'de>
+    //
+    // although it may also be generic over the output types T.
+    pub fn deserialize<'de, K, V, D>(
+        deserializer: D,
+    ) -> Result<std::collections::HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + std::hash::Hash,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let m: Option<std::collections::HashMap<K, V>> = Option::deserialize(deserializer)?;
+
+        Ok(m.unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::next_link;
+    use super::{add_query_param, next_link, paginate};
 
     #[test]
     fn test_hyperx_next_link_compat() {
@@ -646,4 +815,47 @@ mod tests {
 
         assert_eq!("https://next-link", next);
     }
+
+    #[test]
+    fn test_add_query_param() {
+        assert_eq!(
+            add_query_param("https://x/y", "page", "a b"),
+            "https://x/y?page=a%20b"
+        );
+        assert_eq!(
+            add_query_param("https://x/y?q=1", "page", "2"),
+            "https://x/y?q=1&page=2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        use futures::{StreamExt, TryStreamExt};
+
+        let fetched = std::cell::Cell::new(0);
+        let pages = || {
+            paginate("1".to_string(), |page: String| {
+                fetched.set(fetched.get() + 1);
+                async move {
+                    let n: u32 = page.parse().unwrap();
+                    let next = if n < 3 {
+                        Some((n + 1).to_string())
+                    } else {
+                        None
+                    };
+                    Ok((vec![n * 10, n * 10 + 1], next))
+                }
+            })
+        };
+
+        let all: Vec<u32> = pages().try_collect().await.unwrap();
+        assert_eq!(all, vec![10, 11, 20, 21, 30, 31]);
+        assert_eq!(fetched.get(), 3);
+
+        // Stopping early does not fetch the rest of the pages.
+        fetched.set(0);
+        let some: Vec<_> = pages().take(3).collect().await;
+        assert_eq!(some.len(), 3);
+        assert_eq!(fetched.get(), 2);
+    }
 }
@@ -150,6 +150,9 @@ pub enum ClientError {
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde URL encoding error
+    #[error(transparent)]
+    SerdeUrlencodedSerError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -223,7 +226,7 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-
+    api_key: String,
     auto_refresh: bool,
     client: reqwest_middleware::ClientWithMiddleware,
 }
@@ -266,6 +269,44 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+const METADATA_ENDPOINT: &str = "https://login.mailchimp.com/oauth2/metadata";
+
+/// The account an access token belongs to.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The datacenter the account lives on, for example `us6`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dc: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub role: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub accountname: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_endpoint: String,
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -325,7 +366,7 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
-
+                    api_key: String::new(),
                     auto_refresh: false,
                     client,
                 }
@@ -443,6 +484,66 @@ impl Client {
         Client::new(client_id, client_secret, redirect_uri, token, refresh_token)
     }
 
+    /// Create a new Client struct from an API key, which is sent with basic auth.
+    ///
+    /// The datacenter the account lives on is taken from the end of the key, for
+    /// example `us6` for `0123456789abcdef-us6`.
+    pub fn new_with_api_key<K>(api_key: K) -> Self
+    where
+        K: ToString,
+    {
+        let api_key = api_key.to_string();
+
+        let mut client = Client::new("", "", "", "", "");
+        if let Some((_, dc)) = api_key.rsplit_once('-') {
+            client.set_datacenter(dc);
+        }
+        client.api_key = api_key;
+
+        client
+    }
+
+    /// Create a new Client struct from the `MAILCHIMP_API_KEY` environment variable.
+    pub fn new_with_api_key_from_env() -> Self {
+        let api_key = env::var("MAILCHIMP_API_KEY").expect("must set MAILCHIMP_API_KEY");
+
+        Client::new_with_api_key(api_key)
+    }
+
+    /// Point the client at the datacenter an account lives on, for example `us6`.
+    pub fn set_datacenter(&mut self, dc: &str) -> &mut Self {
+        self.host = format!("https://{}.api.mailchimp.com/3.0", dc);
+        self
+    }
+
+    /// Get the metadata for the current access token, including the datacenter
+    /// the account lives on.
+    pub async fn get_metadata(&self) -> ClientResult<Metadata> {
+        let client = reqwest::Client::new();
+        let resp = client
+            .get(METADATA_ENDPOINT)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("OAuth {}", self.token.read().await.access_token),
+            )
+            .header(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(ClientError::HttpError {
+                status,
+                error: resp.text().await?,
+            });
+        }
+
+        Ok(resp.json().await?)
+    }
+
     /// Return a user consent url with an optional set of scopes.
     /// If no scopes are provided, they will not be passed in the url.
     pub fn user_consent_url(&self, scopes: &[String]) -> String {
@@ -543,13 +644,24 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        // Point the client at the datacenter the account lives in.
+        let metadata = self.get_metadata().await?;
+        self.set_datacenter(&metadata.dc);
+
         Ok(t)
     }
 
     async fn url_and_auth(&self, uri: &str) -> ClientResult<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>()?;
 
-        let auth = format!("Bearer {}", self.token.read().await.access_token);
+        let auth = if self.api_key.is_empty() {
+            format!("Bearer {}", self.token.read().await.access_token)
+        } else {
+            format!(
+                "Basic {}",
+                base64::encode(format!("anystring:{}", self.api_key))
+            )
+        };
         Ok((parsed_url, Some(auth)))
     }
 
@@ -727,6 +839,19 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request_form(http::Method::POST, uri, form).await
+    }
+
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: http::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -734,7 +859,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -930,6 +1055,84 @@ impl Client {
         }
     }
 
+    /* TODO: make this more DRY */
+    /// Make a request for a file, handing back the raw body rather than parsing it.
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some(content_type) = &message.content_type {
+            req = req.header(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_str(content_type)?,
+            );
+        }
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = message.body {
+            req = req.body(body);
+        }
+
+        let response = req.send().await?;
+
+        let status = response.status();
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+        let filename = header(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|v| crate::utils::content_disposition_filename(&v));
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!("Received successful response. Read payload.");
+            Ok(crate::utils::Download {
+                body: response_body,
+                content_type,
+                filename,
+            })
+        } else {
+            let error = if response_body.is_empty() {
+                ClientError::HttpError {
+                    status,
+                    error: "empty response".into(),
+                }
+            } else {
+                ClientError::HttpError {
+                    status,
+                    error: String::from_utf8_lossy(&response_body).into(),
+                }
+            };
+
+            Err(error)
+        }
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -951,36 +1154,27 @@ impl Client {
         self.request_entity(http::Method::GET, uri, message).await
     }
 
+    /// Lazily walk the pages of a request that links to the next page in its headers.
     #[allow(dead_code)]
-    async fn get_all_pages<D>(&self, uri: &str, _message: Message) -> ClientResult<Vec<D>>
+    fn get_all_pages_stream<D>(
+        &self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + '_
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        // TODO: implement this.
-        self.unfold(uri).await
-    }
+        crate::utils::paginate(uri.to_string(), move |url| async move {
+            let (link, items) = self.get_pages(&url).await?;
 
-    /// "unfold" paginated results of a vector of items
-    #[allow(dead_code)]
-    async fn unfold<D>(&self, uri: &str) -> ClientResult<Vec<D>>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref() {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
+            // An empty page is the last page, whatever the headers say.
+            let next = if items.is_empty() {
+                None
+            } else {
+                link.map(|l| l.0)
+            };
 
-        Ok(global_items)
+            Ok((items, next))
+        })
     }
 
     #[allow(dead_code)]
@@ -995,18 +1189,6 @@ impl Client {
             .await
     }
 
-    #[allow(dead_code)]
-    async fn get_pages_url<D>(
-        &self,
-        url: &reqwest::Url,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request_with_links(http::Method::GET, url.as_str(), Message::default())
-            .await
-    }
-
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
     where
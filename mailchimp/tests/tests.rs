@@ -1 +1,30 @@
+use mailchimp_api::Client;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_api_key_is_sent_with_basic_auth() {
+    let server = MockServer::start().await;
+
+    let expected = format!("Basic {}", base64::encode("anystring:0123456789abcdef-us6"));
+
+    Mock::given(method("GET"))
+        .and(path("/ping"))
+        .and(header("authorization", expected.as_str()))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"health_status": "Everything's Chimpy!"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new_with_api_key("0123456789abcdef-us6");
+    client.with_host_override(server.uri());
+
+    let status = client.ping().get().await.unwrap();
+
+    assert_eq!(status.health_status, "Everything's Chimpy!");
+}
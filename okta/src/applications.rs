@@ -86,6 +86,30 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Application>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(
+            q,
+            filter,
+            expand,
+            include_non_deleted,
+        ))
+        .await
+    }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -104,15 +128,7 @@ impl Applications {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add Application.
@@ -284,6 +300,21 @@ impl Applications {
      * Enumerates Certificate Signing Requests for an application
      */
     pub async fn list_all_csrs_fors(&self, app_id: &str) -> ClientResult<Vec<crate::types::Csr>> {
+        futures::TryStreamExt::try_collect(self.list_all_csrs_fors_stream(app_id)).await
+    }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates Certificate Signing Requests for an application
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs",
@@ -291,15 +322,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Generate Certificate Signing Request for Application.
@@ -458,6 +481,21 @@ impl Applications {
      * Enumerates key credentials for an application
      */
     pub async fn list_all_keys(&self, app_id: &str) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_keys_stream(app_id)).await
+    }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates key credentials for an application
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/keys",
@@ -465,15 +503,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
@@ -641,6 +671,23 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_scope_consent_grants_stream(app_id, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_all_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -654,15 +701,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
@@ -839,6 +878,27 @@ impl Applications {
         q: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::ApplicationGroupAssignment>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_group_assignments_stream(app_id, q, expand),
+        )
+        .await
+    }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_all_group_assignments_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGroupAssignment>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -855,15 +915,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get Assigned Group for Application.
@@ -1093,6 +1145,23 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Token>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_o_auth_2_tokens_fors_stream(app_id, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_all_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Token>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1106,15 +1175,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
@@ -1299,6 +1360,32 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::AppUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_users_stream(
+            app_id,
+            q,
+            query_scope,
+            filter,
+            expand,
+        ))
+        .await
+    }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppUser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1321,15 +1408,7 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Assign User to Application for SSO & Provisioning.
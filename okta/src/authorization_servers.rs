@@ -60,6 +60,19 @@ impl AuthorizationServers {
      * Success
      */
     pub async fn list_all(&self, q: &str) -> ClientResult<Vec<crate::types::AuthorizationServer>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(q)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServer>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
@@ -68,15 +81,7 @@ impl AuthorizationServers {
         let url = self
             .client
             .url(&format!("/api/v1/authorizationServers?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
@@ -227,6 +232,20 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Claim>> {
+        futures::TryStreamExt::try_collect(self.list_all_o_auth_2_claims_stream(auth_server_id))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Claim>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims",
@@ -234,15 +253,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
@@ -416,6 +427,22 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Client>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_o_auth_2_clients_fors_stream(auth_server_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/clients",
@@ -423,15 +450,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
@@ -497,6 +516,26 @@ impl AuthorizationServers {
         client_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2RefreshToken>> {
+        futures::TryStreamExt::try_collect(self.list_all_refresh_tokens_for_and_clients_stream(
+            auth_server_id,
+            client_id,
+            expand,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -511,15 +550,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
@@ -675,6 +706,19 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_keys_stream(auth_server_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/credentials/keys",
@@ -682,15 +726,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
@@ -818,6 +854,20 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::AuthorizationServerPolicy>> {
+        futures::TryStreamExt::try_collect(self.list_all_policies_stream(auth_server_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_policies_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicy>> + '_
+    {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies",
@@ -825,15 +875,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
@@ -1069,6 +1111,24 @@ impl AuthorizationServers {
         policy_id: &str,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_policy_rules_stream(policy_id, auth_server_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all policy rules for the specified Custom Authorization Server and Policy.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicyRule>> + '_
+    {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules",
@@ -1077,15 +1137,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
@@ -1369,6 +1421,28 @@ impl AuthorizationServers {
         filter: &str,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Scope>> {
+        futures::TryStreamExt::try_collect(self.list_all_o_auth_2_scopes_stream(
+            auth_server_id,
+            q,
+            filter,
+            cursor,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Scope>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -1388,15 +1462,7 @@ impl AuthorizationServers {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
@@ -36,16 +36,20 @@ impl EventHooks {
      * Success
      */
     pub async fn list_all(&self) -> ClientResult<Vec<crate::types::EventHook>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream()).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EventHook>> + '_ {
         let url = self.client.url("/api/v1/eventHooks", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
@@ -36,16 +36,20 @@ impl Features {
      * Success
      */
     pub async fn list_all(&self) -> ClientResult<Vec<crate::types::Feature>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream()).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + '_ {
         let url = self.client.url("/api/v1/features", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
@@ -115,6 +119,19 @@ impl Features {
         &self,
         feature_id: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        futures::TryStreamExt::try_collect(self.list_all_dependencies_stream(feature_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_dependencies_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/features/{}/dependencies",
@@ -122,15 +139,7 @@ impl Features {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
@@ -173,6 +182,19 @@ impl Features {
         &self,
         feature_id: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        futures::TryStreamExt::try_collect(self.list_all_dependents_stream(feature_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_dependents_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/features/{}/dependents",
@@ -180,15 +202,7 @@ impl Features {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
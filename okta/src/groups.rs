@@ -77,6 +77,23 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(q, search, expand)).await
+    }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -89,15 +106,7 @@ impl Groups {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/groups?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add Group.
@@ -180,6 +189,22 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::GroupRule>> {
+        futures::TryStreamExt::try_collect(self.list_all_rules_stream(search, expand)).await
+    }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all group rules for your organization.
+     */
+    pub fn list_all_rules_stream(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GroupRule>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -191,15 +216,7 @@ impl Groups {
         let url = self
             .client
             .url(&format!("/api/v1/groups/rules?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create Group Rule.
@@ -537,6 +554,24 @@ impl Groups {
         &self,
         group_id: &str,
     ) -> ClientResult<Vec<crate::types::Application>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_assigned_applications_fors_stream(group_id),
+        )
+        .await
+    }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all applications that are assigned to a group.
+     */
+    pub fn list_all_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/apps",
@@ -544,15 +579,7 @@ impl Groups {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
@@ -604,6 +631,21 @@ impl Groups {
         group_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Role>> {
+        futures::TryStreamExt::try_collect(self.list_all_assigned_roles_stream(group_id, expand))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -617,15 +659,7 @@ impl Groups {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
@@ -789,6 +823,23 @@ impl Groups {
         group_id: &str,
         role_id: &str,
     ) -> ClientResult<Vec<crate::types::CatalogApplication>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_application_targets_for_administrator_roles_stream(group_id, role_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a Group. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
@@ -797,15 +848,7 @@ impl Groups {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
@@ -1019,6 +1062,23 @@ impl Groups {
         group_id: &str,
         role_id: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_targets_for_roles_stream(group_id, role_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/groups",
@@ -1027,15 +1087,7 @@ impl Groups {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
@@ -1164,6 +1216,21 @@ impl Groups {
      * Enumerates all users that are a member of a group.
      */
     pub async fn list_all_users(&self, group_id: &str) -> ClientResult<Vec<crate::types::User>> {
+        futures::TryStreamExt::try_collect(self.list_all_users_stream(group_id)).await
+    }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all users that are a member of a group.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/users",
@@ -1171,15 +1238,7 @@ impl Groups {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add User to Group.
@@ -71,6 +71,22 @@ impl IdentityProviders {
         q: &str,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProvider>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(q, type_)).await
+    }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
@@ -80,15 +96,7 @@ impl IdentityProviders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add Identity Provider.
@@ -160,16 +168,22 @@ impl IdentityProviders {
      * Enumerates IdP key credentials.
      */
     pub async fn list_all_keys(&self) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_keys_stream()).await
+    }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + '_ {
         let url = self.client.url("/api/v1/idps/credentials/keys", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add X.509 Certificate Public Key.
@@ -381,6 +395,21 @@ impl IdentityProviders {
      * Enumerates Certificate Signing Requests for an IdP
      */
     pub async fn list_all_csrs_fors(&self, idp_id: &str) -> ClientResult<Vec<crate::types::Csr>> {
+        futures::TryStreamExt::try_collect(self.list_all_csrs_fors_stream(idp_id)).await
+    }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates Certificate Signing Requests for an IdP
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs",
@@ -388,15 +417,7 @@ impl IdentityProviders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Generate Certificate Signing Request for IdP.
@@ -567,6 +588,21 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        futures::TryStreamExt::try_collect(self.list_all_signing_keys_stream(idp_id)).await
+    }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates signing key credentials for an IdP
+     */
+    pub fn list_all_signing_keys_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/keys",
@@ -574,15 +610,7 @@ impl IdentityProviders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Generate New IdP Signing Key Credential.
@@ -805,6 +833,22 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProviderApplicationUser>> {
+        futures::TryStreamExt::try_collect(self.list_all_application_users_stream(idp_id)).await
+    }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Find all the users linked to an identity provider
+     */
+    pub fn list_all_application_users_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProviderApplicationUser>> + '_
+    {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users",
@@ -812,15 +856,7 @@ impl IdentityProviders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
@@ -971,6 +1007,23 @@ impl IdentityProviders {
         idp_id: &str,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::SocialAuthToken>> {
+        futures::TryStreamExt::try_collect(self.list_all_social_auth_tokens_stream(idp_id, user_id))
+            .await
+    }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Fetches the tokens minted by the Social Authentication Provider when the user authenticates with Okta via Social Auth.
+     */
+    pub fn list_all_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SocialAuthToken>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users/{}/credentials/tokens",
@@ -979,14 +1032,6 @@ impl IdentityProviders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -47,6 +47,19 @@ impl InlineHooks {
      * Success
      */
     pub async fn list_all(&self, type_: &str) -> ClientResult<Vec<crate::types::InlineHook>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(type_)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InlineHook>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -55,15 +68,7 @@ impl InlineHooks {
         let url = self
             .client
             .url(&format!("/api/v1/inlineHooks?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
@@ -38,18 +38,22 @@ impl LinkedObjects {
      * Success
      */
     pub async fn list_all_definitions(&self) -> ClientResult<Vec<crate::types::LinkedObject>> {
+        futures::TryStreamExt::try_collect(self.list_all_definitions_stream()).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_definitions_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkedObject>> + '_ {
         let url = self
             .client
             .url("/api/v1/meta/schemas/user/linkedObjects", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
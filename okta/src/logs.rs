@@ -89,6 +89,26 @@ impl Logs {
         q: &str,
         sort_order: &str,
     ) -> ClientResult<Vec<crate::types::LogEvent>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(since, until, filter, q, sort_order))
+            .await
+    }
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * The Okta System Log API provides read access to your organization’s system log. This API provides more functionality than the Events API
+     */
+    pub fn get_all_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LogEvent>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -107,14 +127,6 @@ impl Logs {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/logs?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
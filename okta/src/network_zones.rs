@@ -62,21 +62,28 @@ impl NetworkZones {
      * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
      */
     pub async fn list_all(&self, filter: &str) -> ClientResult<Vec<crate::types::NetworkZone>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(filter)).await
+    }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::NetworkZone>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add Network Zone.
@@ -65,6 +65,21 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Policy>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(type_, status, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Gets all policies with the specified type.
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Policy>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -79,15 +94,7 @@ impl Policies {
         let url = self
             .client
             .url(&format!("/api/v1/policies?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/policies` endpoint.
@@ -312,6 +319,19 @@ impl Policies {
         &self,
         policy_id: &str,
     ) -> ClientResult<Vec<crate::types::PolicyRule>> {
+        futures::TryStreamExt::try_collect(self.list_all_policy_rules_stream(policy_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all policy rules.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PolicyRule>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules",
@@ -319,15 +339,7 @@ impl Policies {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
@@ -69,6 +69,20 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> ClientResult<Vec<crate::types::ProfileMapping>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(source_id, target_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates Profile Mappings in your organization with pagination.
+     */
+    pub fn list_all_stream(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProfileMapping>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !source_id.is_empty() {
             query_args.push(("sourceId".to_string(), source_id.to_string()));
@@ -80,15 +94,7 @@ impl ProfileMappings {
         let url = self
             .client
             .url(&format!("/api/v1/mappings?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get Profile Mapping.
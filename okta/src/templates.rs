@@ -57,6 +57,21 @@ impl Templates {
         &self,
         template_type: &str,
     ) -> ClientResult<Vec<crate::types::SmsTemplate>> {
+        futures::TryStreamExt::try_collect(self.list_all_sms_stream(template_type)).await
+    }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_all_sms_stream(
+        &self,
+        template_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SmsTemplate>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !template_type.is_empty() {
             query_args.push(("templateType".to_string(), template_type.to_string()));
@@ -65,15 +80,7 @@ impl Templates {
         let url = self
             .client
             .url(&format!("/api/v1/templates/sms?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add SMS Template.
@@ -69,6 +69,20 @@ impl TrustedOrigins {
         q: &str,
         filter: &str,
     ) -> ClientResult<Vec<crate::types::TrustedOrigin>> {
+        futures::TryStreamExt::try_collect(self.list_all_origins_stream(q, filter)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_origins_stream(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TrustedOrigin>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -80,15 +94,7 @@ impl TrustedOrigins {
         let url = self
             .client
             .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/trustedOrigins` endpoint.
@@ -49,6 +49,19 @@ impl UserFactors {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::UserFactor>> {
+        futures::TryStreamExt::try_collect(self.list_all_factors_stream(user_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all the enrolled factors for the specified user
+     */
+    pub fn list_all_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors",
@@ -56,15 +69,7 @@ impl UserFactors {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Enroll Factor.
@@ -166,6 +171,19 @@ impl UserFactors {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::UserFactor>> {
+        futures::TryStreamExt::try_collect(self.list_all_supported_factors_stream(user_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all the supported factors that can be enrolled for the specified user
+     */
+    pub fn list_all_supported_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/catalog",
@@ -173,15 +191,7 @@ impl UserFactors {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
@@ -224,6 +234,22 @@ impl UserFactors {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::SecurityQuestion>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_supported_security_questions_stream(user_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Enumerates all available security questions for a user's `question` factor
+     */
+    pub fn list_all_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecurityQuestion>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/questions",
@@ -231,15 +257,7 @@ impl UserFactors {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
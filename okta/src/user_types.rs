@@ -36,16 +36,20 @@ impl UserTypes {
      * Fetches all User Types in your org
      */
     pub async fn list_all(&self) -> ClientResult<Vec<crate::types::UserType>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream()).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Fetches all User Types in your org
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserType>> + '_ {
         let url = self.client.url("/api/v1/meta/types/user", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
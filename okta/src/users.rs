@@ -89,6 +89,28 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> ClientResult<Vec<crate::types::User>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_stream(q, filter, search, sort_by, sort_order),
+        )
+        .await
+    }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -107,15 +129,7 @@ impl Users {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create User.
@@ -380,6 +394,21 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::AppLink>> {
+        futures::TryStreamExt::try_collect(self.list_all_app_links_stream(user_id)).await
+    }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Fetches appLinks for all direct or indirect (via group membership) assigned applications.
+     */
+    pub fn list_all_app_links_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppLink>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/appLinks",
@@ -387,15 +416,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
@@ -438,6 +459,19 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Client>> {
+        futures::TryStreamExt::try_collect(self.list_all_clients_stream(user_id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all client resources for which the specified user has grants or tokens.
+     */
+    pub fn list_all_clients_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/clients",
@@ -445,15 +479,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
@@ -519,6 +545,24 @@ impl Users {
         client_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_grants_for_and_clients_stream(user_id, client_id, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all grants for a specified user and client
+     */
+    pub fn list_all_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -533,15 +577,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
@@ -640,6 +676,24 @@ impl Users {
         client_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2RefreshToken>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_refresh_tokens_for_and_clients_stream(user_id, client_id, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all refresh tokens issued for the specified User and Client.
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -654,15 +708,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
@@ -957,6 +1003,22 @@ impl Users {
         scope_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        futures::TryStreamExt::try_collect(self.list_all_grants_stream(user_id, scope_id, expand))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all grants for the specified user
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -973,15 +1035,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
@@ -1119,6 +1173,21 @@ impl Users {
      * Fetches the groups of which the user is a member.
      */
     pub async fn list_all_groups(&self, user_id: &str) -> ClientResult<Vec<crate::types::Group>> {
+        futures::TryStreamExt::try_collect(self.list_all_groups_stream(user_id)).await
+    }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Fetches the groups of which the user is a member.
+     */
+    pub fn list_all_groups_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/groups",
@@ -1126,15 +1195,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Listing IdPs associated with a user.
@@ -1181,6 +1242,21 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProvider>> {
+        futures::TryStreamExt::try_collect(self.list_all_identity_providers_stream(user_id)).await
+    }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists the IdPs associated with the user.
+     */
+    pub fn list_all_identity_providers_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/idps",
@@ -1188,15 +1264,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Activate User.
@@ -1589,6 +1657,23 @@ impl Users {
         user_id: &str,
         relationship_name: &str,
     ) -> ClientResult<Vec<crate::types::Links>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_linked_objects_fors_stream(user_id, relationship_name),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get linked objects for a user, relationshipName can be a primary or associated relationship name
+     */
+    pub fn get_all_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Links>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/linkedObjects/{}",
@@ -1597,15 +1682,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
@@ -1690,6 +1767,23 @@ impl Users {
         user_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Role>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_assigned_roles_fors_stream(user_id, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all roles assigned to a user.
+     */
+    pub fn list_all_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1703,15 +1797,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
@@ -1842,6 +1928,23 @@ impl Users {
         user_id: &str,
         role_id: &str,
     ) -> ClientResult<Vec<crate::types::CatalogApplication>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_application_targets_for_administrator_roles_stream(user_id, role_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a User. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps",
@@ -1850,15 +1953,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
@@ -2105,6 +2200,23 @@ impl Users {
         user_id: &str,
         role_id: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_group_targets_for_roles_stream(user_id, role_id),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Success
+     */
+    pub fn list_all_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + '_ {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/groups",
@@ -2113,15 +2225,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
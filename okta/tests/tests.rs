@@ -1 +1,26 @@
+use okta::Client;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_api_token_is_sent_with_the_ssws_scheme() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/users/00ub0oNGTSWTBKOLGLNR"))
+        .and(header("authorization", "SSWS okta-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "00ub0oNGTSWTBKOLGLNR",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut okta = Client::new("okta-token");
+    okta.with_host_override(server.uri());
+
+    let user = okta.users().get("00ub0oNGTSWTBKOLGLNR").await.unwrap();
+    assert_eq!(user.id, "00ub0oNGTSWTBKOLGLNR");
+}
@@ -51,13 +51,16 @@ impl Auths {
      *
      * * `authorization: &str` -- Basic \<base64-encoded client_id:client_secret\>.
      */
-    pub async fn post_token(&self) -> ClientResult<crate::types::OAuth2Token> {
+    pub async fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> ClientResult<crate::types::OAuth2Token> {
         let url = self.client.url("/token", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
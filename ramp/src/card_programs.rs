@@ -62,55 +62,52 @@ impl CardPrograms {
      * Retrieve all card programs.
      */
     pub async fn get_all(&self) -> ClientResult<Vec<crate::types::CardProgram>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream()).await
+    }
+    /**
+     * List card programs.
+     *
+     * This function performs a `GET` to the `/card-programs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieve all card programs.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CardProgram>> + '_ {
         let url = self.client.url("/card-programs", None);
-        let resp: crate::types::GetCardProgramsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut card_programs = resp.card_programs;
-        let mut page = resp.page.next.to_string();
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                match self
+                    .client
+                    .get::<crate::types::GetCardProgramsResponse>(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                {
+                    Ok(resp) => {
+                        let next = if !resp.page.next.is_empty() && resp.page.next != page_url {
+                            Some(resp.page.next.to_string())
+                        } else {
+                            None
+                        };
 
-        // Paginate if we should.
-        while !page.is_empty() {
-            match self
-                .client
-                .get::<crate::types::GetCardProgramsResponse>(
-                    page.trim_start_matches(&self.client.host),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await
-            {
-                Ok(mut resp) => {
-                    card_programs.append(&mut resp.card_programs);
-
-                    page = if resp.page.next != page {
-                        resp.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
+                        Ok((resp.card_programs, next))
+                    }
+                    // Walking off the last page gives us a 404.
+                    Err(e) if page_url != url && e.to_string().contains("404 Not Found") => {
+                        Ok((Vec::new(), None))
                     }
+                    Err(e) => Err(e),
                 }
             }
-        }
-
-        // Return our response data.
-        Ok(card_programs)
+        })
     }
     /**
      * Create a card program.
@@ -76,6 +76,22 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> ClientResult<Vec<crate::types::Card>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(user_id, card_program_id)).await
+    }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieve all cards.
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Card>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card_program_id.is_empty() {
             query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
@@ -85,54 +101,37 @@ impl Cards {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/cards?{}", query_), None);
-        let resp: crate::types::GetCardsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                match self
+                    .client
+                    .get::<crate::types::GetCardsResponse>(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                {
+                    Ok(resp) => {
+                        let next = if !resp.page.next.is_empty() && resp.page.next != page_url {
+                            Some(resp.page.next.to_string())
+                        } else {
+                            None
+                        };
 
-        let mut cards = resp.cards;
-        let mut page = resp.page.next.to_string();
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            match self
-                .client
-                .get::<crate::types::GetCardsResponse>(
-                    page.trim_start_matches(&self.client.host),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await
-            {
-                Ok(mut resp) => {
-                    cards.append(&mut resp.cards);
-
-                    page = if resp.page.next != page {
-                        resp.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
+                        Ok((resp.cards, next))
+                    }
+                    // Walking off the last page gives us a 404.
+                    Err(e) if page_url != url && e.to_string().contains("404 Not Found") => {
+                        Ok((Vec::new(), None))
                     }
+                    Err(e) => Err(e),
                 }
             }
-        }
-
-        // Return our response data.
-        Ok(cards)
+        })
     }
     /**
      * GET a card.
@@ -62,55 +62,52 @@ impl Locations {
      * Retrieves all locations for your business.
      */
     pub async fn get_all(&self) -> ClientResult<Vec<crate::types::Location>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream()).await
+    }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves all locations for your business.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + '_ {
         let url = self.client.url("/locations", None);
-        let resp: crate::types::GetLocationResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut page = resp.page.next.to_string();
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                match self
+                    .client
+                    .get::<crate::types::GetLocationResponse>(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                {
+                    Ok(resp) => {
+                        let next = if !resp.page.next.is_empty() && resp.page.next != page_url {
+                            Some(resp.page.next.to_string())
+                        } else {
+                            None
+                        };
 
-        // Paginate if we should.
-        while !page.is_empty() {
-            match self
-                .client
-                .get::<crate::types::GetLocationResponse>(
-                    page.trim_start_matches(&self.client.host),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await
-            {
-                Ok(mut resp) => {
-                    data.append(&mut resp.data);
-
-                    page = if resp.page.next != page {
-                        resp.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
+                        Ok((resp.data, next))
+                    }
+                    // Walking off the last page gives us a 404.
+                    Err(e) if page_url != url && e.to_string().contains("404 Not Found") => {
+                        Ok((Vec::new(), None))
                     }
+                    Err(e) => Err(e),
                 }
             }
-        }
-
-        // Return our response data.
-        Ok(data)
+        })
     }
     /**
      * Create new location.
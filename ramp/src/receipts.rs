@@ -87,6 +87,30 @@ impl Receipts {
         created_after: Option<chrono::DateTime<chrono::Utc>>,
         created_before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Receipt>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            from_date,
+            to_date,
+            created_after,
+            created_before,
+        ))
+        .await
+    }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns description of all receipts of a business.
+     */
+    pub fn get_all_stream(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Receipt>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = created_after {
             query_args.push(("created_after".to_string(), date.to_rfc3339()));
@@ -102,54 +126,37 @@ impl Receipts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/receipts?{}", query_), None);
-        let resp: crate::types::GetReceiptsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                match self
+                    .client
+                    .get::<crate::types::GetReceiptsResponse>(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                {
+                    Ok(resp) => {
+                        let next = if !resp.page.next.is_empty() && resp.page.next != page_url {
+                            Some(resp.page.next.to_string())
+                        } else {
+                            None
+                        };
 
-        let mut data = resp.data;
-        let mut page = resp.page.next.to_string();
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            match self
-                .client
-                .get::<crate::types::GetReceiptsResponse>(
-                    page.trim_start_matches(&self.client.host),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await
-            {
-                Ok(mut resp) => {
-                    data.append(&mut resp.data);
-
-                    page = if resp.page.next != page {
-                        resp.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
+                        Ok((resp.data, next))
+                    }
+                    // Walking off the last page gives us a 404.
+                    Err(e) if page_url != url && e.to_string().contains("404 Not Found") => {
+                        Ok((Vec::new(), None))
                     }
+                    Err(e) => Err(e),
                 }
             }
-        }
-
-        // Return our response data.
-        Ok(data)
+        })
     }
     /**
      * Get details for one receipt.
@@ -160,6 +160,50 @@ impl Transactions {
         max_amount: f64,
         requires_memo: bool,
     ) -> ClientResult<Vec<crate::types::Data>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            requires_memo,
+        ))
+        .await
+    }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     */
+    pub fn get_all_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !department_id.is_empty() {
             query_args.push(("department_id".to_string(), department_id.to_string()));
@@ -217,54 +261,37 @@ impl Transactions {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/transactions?{}", query_), None);
-        let resp: crate::types::GetTransactionResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut page = resp.page.next.to_string();
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            match self
-                .client
-                .get::<crate::types::GetTransactionResponse>(
-                    page.trim_start_matches(&self.client.host),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await
-            {
-                Ok(mut resp) => {
-                    data.append(&mut resp.data);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                match self
+                    .client
+                    .get::<crate::types::GetTransactionResponse>(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                {
+                    Ok(resp) => {
+                        let next = if !resp.page.next.is_empty() && resp.page.next != page_url {
+                            Some(resp.page.next.to_string())
+                        } else {
+                            None
+                        };
 
-                    page = if resp.page.next != page {
-                        resp.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
+                        Ok((resp.data, next))
+                    }
+                    // Walking off the last page gives us a 404.
+                    Err(e) if page_url != url && e.to_string().contains("404 Not Found") => {
+                        Ok((Vec::new(), None))
                     }
+                    Err(e) => Err(e),
                 }
             }
-        }
-
-        // Return our response data.
-        Ok(data)
+        })
     }
     /**
      * GET a transaction.
@@ -60,21 +60,28 @@ impl Jobs {
         &self,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::JobAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_list_all_of_stream(starting_after)).await
+    }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     */
+    pub fn get_all_list_all_of_stream(
+        &self,
+        starting_after: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobAllOf>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !starting_after.is_empty() {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/jobs?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Submit Transcription Job.
@@ -98,6 +105,22 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Submit Transcription Job.
+     *
+     * This function performs a `POST` to the `/jobs` endpoint.
+     *
+     * Starts an asynchronous job to transcribe speech-to-text for a media file. Media files can be specified in two ways, either by including a public url to the media in the transcription job `options` or by uploading a local file as part of a multipart/form request.
+     */
+    pub async fn submit_transcription_multipart(
+        &self,
+        body: crate::types::SubmitTranscriptionJobForm,
+    ) -> ClientResult<crate::types::JobAllOf> {
+        let url = self.client.url("/jobs", None);
+        self.client
+            .request_form(reqwest::Method::POST, &url, body.into_form()?)
+            .await
+    }
     /**
      * Get Job By Id.
      *
@@ -1,11 +1,72 @@
+use std::time::Duration;
+
 use crate::ClientResult;
 
+/// How often to check on a job while waiting for it to finish.
+///
+/// The wait between checks starts at `initial_interval` and is multiplied by
+/// `multiplier` after every check, up to `max_interval`. A `multiplier` of `0`
+/// is taken as `1`, so the wait never shrinks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollBackoff {
+    pub initial_interval: Duration,
+    pub max_interval: Duration,
+    pub multiplier: u32,
+    /// Give up with `ClientError::JobTimedOut` after waiting this long.
+    /// Wait forever if this is `None`.
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for PollBackoff {
+    fn default() -> Self {
+        PollBackoff {
+            initial_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            multiplier: 2,
+            max_elapsed: None,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait JobOps {
     /// Send a plain text email.
     ///
     /// This is a nicer experience than using `post`.
     async fn post(&self, b: bytes::Bytes) -> ClientResult<crate::types::Job>;
+
+    /// Submit a transcription job for a file on local disk.
+    ///
+    /// The file is streamed up as it is read, rather than read into memory first.
+    async fn submit_local_file(
+        &self,
+        path: &std::path::Path,
+        options: crate::types::SubmitJobOptionsAllOf,
+    ) -> ClientResult<crate::types::JobAllOf>;
+
+    /// Wait for a job to be `transcribed`.
+    ///
+    /// Returns `ClientError::JobFailed` if the job `failed` instead.
+    async fn wait_for_transcription(
+        &self,
+        id: &str,
+        backoff: &PollBackoff,
+    ) -> ClientResult<crate::types::JobAllOf>;
+
+    /// Wait for a job to be transcribed and get its transcript.
+    async fn wait_for_transcript(
+        &self,
+        id: &str,
+        backoff: &PollBackoff,
+    ) -> ClientResult<crate::types::Transcript>;
+
+    /// Wait for a job to be transcribed and get its captions, as SRT or VTT.
+    async fn wait_for_captions(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        backoff: &PollBackoff,
+    ) -> ClientResult<String>;
 }
 
 #[async_trait::async_trait]
@@ -24,4 +85,99 @@ impl JobOps for crate::jobs::Jobs {
 
         self.client.post_form("/jobs", form).await
     }
+
+    /// Submit a transcription job for a file on local disk.
+    async fn submit_local_file(
+        &self,
+        path: &std::path::Path,
+        options: crate::types::SubmitJobOptionsAllOf,
+    ) -> ClientResult<crate::types::JobAllOf> {
+        let file = tokio::fs::File::open(path).await?;
+        let size = file.metadata().await?.len();
+
+        let mut media = reqwest::multipart::Part::stream_with_length(file, size);
+        if let Some(name) = path.file_name() {
+            media = media.file_name(name.to_string_lossy().to_string());
+        }
+
+        let form = reqwest::multipart::Form::new()
+            .part("media", media)
+            .text("options", crate::utils::form_text(&options)?);
+
+        let url = self.client.url("/jobs", None);
+        self.client
+            .request_form(reqwest::Method::POST, &url, form)
+            .await
+    }
+
+    /// Wait for a job to be `transcribed`.
+    async fn wait_for_transcription(
+        &self,
+        id: &str,
+        backoff: &PollBackoff,
+    ) -> ClientResult<crate::types::JobAllOf> {
+        let mut interval = backoff.initial_interval;
+        let mut waited = Duration::ZERO;
+
+        loop {
+            let job = self.get(id).await?;
+            match job.job.status {
+                Some(crate::types::Status::Transcribed) => return Ok(job),
+                Some(crate::types::Status::Failed) => {
+                    return Err(crate::ClientError::JobFailed {
+                        id: id.to_string(),
+                        failure: job.job.failure,
+                        detail: job.job.failure_detail,
+                    });
+                }
+                _ => {}
+            }
+
+            if let Some(max_elapsed) = backoff.max_elapsed {
+                if waited >= max_elapsed {
+                    return Err(crate::ClientError::JobTimedOut {
+                        id: id.to_string(),
+                        waited,
+                    });
+                }
+                interval = interval.min(max_elapsed - waited);
+            }
+
+            tokio::time::sleep(interval).await;
+            waited += interval;
+            interval = (interval * backoff.multiplier.max(1)).min(backoff.max_interval);
+        }
+    }
+
+    /// Wait for a job to be transcribed and get its transcript.
+    async fn wait_for_transcript(
+        &self,
+        id: &str,
+        backoff: &PollBackoff,
+    ) -> ClientResult<crate::types::Transcript> {
+        self.wait_for_transcription(id, backoff).await?;
+
+        let transcript = crate::transcript::Transcript::new(self.client.clone())
+            .get(
+                id,
+                crate::types::AcceptTranscript::ApplicationVndRevTranscript0Json,
+            )
+            .await?;
+
+        Ok(serde_json::from_str(&transcript)?)
+    }
+
+    /// Wait for a job to be transcribed and get its captions, as SRT or VTT.
+    async fn wait_for_captions(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        backoff: &PollBackoff,
+    ) -> ClientResult<String> {
+        self.wait_for_transcription(id, backoff).await?;
+
+        crate::captions::Captions::new(self.client.clone())
+            .get(id, accept, 0)
+            .await
+    }
 }
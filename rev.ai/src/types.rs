@@ -915,3 +915,26 @@ impl AcceptTranscript {
         matches!(self, AcceptTranscript::Noop)
     }
 }
+
+/// The `multipart/form-data` body of a request.
+#[derive(Debug, Clone, Default)]
+pub struct SubmitTranscriptionJobForm {
+    /// Limited to files less than 2GB in size. If the file is larger than 2GB, submit a transcription job using `media_url`. **Note:** Media files longer than 17 hours are not supported for English transcription, and media files longer than 12 hours are not supported for non-English transcription. For non-English jobs, expected turnaround time can be up to 6 hours.
+    pub media: Option<crate::utils::FilePart>,
+    pub options: Option<crate::types::SubmitJobOptionsAllOf>,
+}
+
+impl SubmitTranscriptionJobForm {
+    /// Turn this into a form to send.
+    pub fn into_form(self) -> crate::ClientResult<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        if let Some(media) = self.media {
+            form = form.part("media", media.into_part()?);
+        }
+        if let Some(options) = self.options {
+            form = form.text("options", crate::utils::form_text(&options)?);
+        }
+
+        Ok(form)
+    }
+}
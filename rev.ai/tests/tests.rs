@@ -1 +1,316 @@
+use revai::Client;
+use wiremock::{
+    matchers::{body_string_contains, header, header_regex, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+#[tokio::test]
+async fn test_submit_transcription_multipart_sends_the_media() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/jobs"))
+        .and(header("authorization", "Bearer test-token"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains(
+            r#"Content-Disposition: form-data; name="media"; filename="call.mp3""#,
+        ))
+        .and(body_string_contains("Content-Type: audio/mpeg"))
+        .and(body_string_contains("not really an mp3"))
+        .and(body_string_contains(
+            r#"Content-Disposition: form-data; name="options""#,
+        ))
+        .and(body_string_contains(r#""language":"en""#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "in_progress",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let mut options = revai::types::SubmitJobOptionsAllOf::default();
+    options.submit_job_options.language = Some(serde_json::json!("en"));
+
+    let job = client
+        .jobs()
+        .submit_transcription_multipart(revai::types::SubmitTranscriptionJobForm {
+            media: Some(revai::utils::FilePart {
+                content: bytes::Bytes::from_static(b"not really an mp3"),
+                filename: "call.mp3".to_string(),
+                content_type: "audio/mpeg".to_string(),
+            }),
+            options: Some(options),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(job.job.id, "job-1");
+}
+
+fn backoff() -> revai::traits::PollBackoff {
+    revai::traits::PollBackoff {
+        initial_interval: std::time::Duration::from_millis(1),
+        max_interval: std::time::Duration::from_millis(4),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_submit_local_file_streams_the_file() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    let dir = std::env::temp_dir().join(format!("revai-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let media = dir.join("meeting.wav");
+    std::fs::write(&media, b"RIFF not really a wav").unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/jobs"))
+        .and(body_string_contains(
+            r#"Content-Disposition: form-data; name="media"; filename="meeting.wav""#,
+        ))
+        .and(body_string_contains("RIFF not really a wav"))
+        .and(body_string_contains(r#""metadata":"standup""#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-2",
+            "status": "in_progress",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let mut options = revai::types::SubmitJobOptionsAllOf::default();
+    options.submit_job_options.metadata = Some(serde_json::json!("standup"));
+
+    let job = client
+        .jobs()
+        .submit_local_file(&media, options)
+        .await
+        .unwrap();
+
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(job.job.id, "job-2");
+}
+
+#[tokio::test]
+async fn test_submit_local_file_missing_file_is_an_io_error() {
+    use revai::traits::JobOps;
+
+    let client = Client::new("test-token");
+
+    let err = client
+        .jobs()
+        .submit_local_file(
+            std::path::Path::new("/does/not/exist.wav"),
+            Default::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, revai::ClientError::IoError(_)));
+}
+
+#[tokio::test]
+async fn test_wait_for_transcript_polls_until_transcribed() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "in_progress",
+        })))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "transcribed",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1/transcript"))
+        .and(header("accept", "application/vnd.rev.transcript.v1.0+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"monologues":[{"speaker":0,"elements":[{"type":"text","value":"Hello"}]}]}"#,
+            "application/vnd.rev.transcript.v1.0+json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let transcript = client
+        .jobs()
+        .wait_for_transcript("job-1", &backoff())
+        .await
+        .unwrap();
+
+    assert_eq!(transcript.monologues.len(), 1);
+    assert_eq!(transcript.monologues[0].elements[0].value, "Hello");
+}
+
+#[tokio::test]
+async fn test_wait_for_captions_returns_vtt() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "transcribed",
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1/captions"))
+        .and(header("accept", "text/vtt"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            "WEBVTT\n\n1\n00:00:00.000 --> 00:00:01.000\nHello\n",
+            "text/vtt",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let captions = client
+        .jobs()
+        .wait_for_captions("job-1", revai::types::Accept::TextVtt, &backoff())
+        .await
+        .unwrap();
+
+    assert!(captions.starts_with("WEBVTT"));
+}
+
+#[tokio::test]
+async fn test_wait_for_transcription_failed_job_is_an_error() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "failed",
+            "failure": "download_failure",
+            "failure_detail": "Failed to download media file.",
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let err = client
+        .jobs()
+        .wait_for_transcription("job-1", &backoff())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        revai::ClientError::JobFailed {
+            failure: Some(revai::types::Failure::DownloadFailure),
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_wait_for_transcription_gives_up() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "in_progress",
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let backoff = revai::traits::PollBackoff {
+        max_elapsed: Some(std::time::Duration::from_millis(10)),
+        ..backoff()
+    };
+
+    let err = client
+        .jobs()
+        .wait_for_transcription("job-1", &backoff)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, revai::ClientError::JobTimedOut { .. }));
+}
+
+#[tokio::test]
+async fn test_wait_for_transcription_keeps_waiting_with_a_zero_multiplier() {
+    use revai::traits::JobOps;
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/jobs/job-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "job-1",
+            "status": "in_progress",
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("test-token");
+    client.with_host_override(server.uri());
+
+    let backoff = revai::traits::PollBackoff {
+        multiplier: 0,
+        max_elapsed: Some(std::time::Duration::from_millis(10)),
+        ..backoff()
+    };
+
+    // Without a wait between checks the time waited never grows, and this never returns.
+    let err = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.jobs().wait_for_transcription("job-1", &backoff),
+    )
+    .await
+    .expect("busy-polled instead of backing off")
+    .unwrap_err();
+
+    assert!(matches!(err, revai::ClientError::JobTimedOut { .. }));
+}
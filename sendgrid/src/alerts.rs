@@ -57,16 +57,28 @@ impl Alerts {
      * For more information about alerts, please see our [Alerts documentation](https://sendgrid.com/docs/ui/account-and-settings/alerts/).
      */
     pub async fn get_all(&self) -> ClientResult<Vec<crate::types::GetAlertsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream()).await
+    }
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of your alerts.**
+     *
+     * Alerts allow you to specify an email address to receive notifications regarding your email usage or statistics.
+     * * Usage alerts allow you to set the threshold at which an alert will be sent.
+     * * Stats notifications allow you to set how frequently you would like to receive email statistics reports. For example, "daily", "weekly", or "monthly".
+     *
+     * For more information about alerts, please see our [Alerts documentation](https://sendgrid.com/docs/ui/account-and-settings/alerts/).
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAlertsResponse>> + '_ {
         let url = self.client.url("/alerts", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a new Alert.
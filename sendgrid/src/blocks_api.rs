@@ -75,6 +75,26 @@ impl BlocksApi {
         end_time: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::BlocksResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_blocks_stream(start_time, end_time, offset),
+        )
+        .await
+    }
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all email addresses that are currently on your blocks list.**
+     */
+    pub fn get_all_suppression_blocks_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlocksResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -89,15 +109,7 @@ impl BlocksApi {
         let url = self
             .client
             .url(&format!("/suppression/blocks?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete blocks.
@@ -175,6 +187,22 @@ impl BlocksApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::BlocksResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_suppression_blocks_email_stream(email))
+            .await
+    }
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks_email`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a specific email address from your blocks list.**
+     */
+    pub fn get_all_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlocksResponse>> + '_ {
         let url = self.client.url(
             &format!(
                 "/suppression/blocks/{}",
@@ -182,15 +210,7 @@ impl BlocksApi {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a specific block.
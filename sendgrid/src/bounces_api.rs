@@ -65,6 +65,25 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> ClientResult<Vec<crate::types::BounceResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_bounces_stream(start_time, end_time),
+        )
+        .await
+    }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of your bounces.**
+     */
+    pub fn get_all_suppression_bounces_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -76,15 +95,7 @@ impl BouncesApi {
         let url = self
             .client
             .url(&format!("/suppression/bounces?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete bounces.
@@ -162,6 +173,22 @@ impl BouncesApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::BounceResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_suppression_bounces_email_stream(email))
+            .await
+    }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a specific bounce by email address.**
+     */
+    pub fn get_all_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + '_ {
         let url = self.client.url(
             &format!(
                 "/suppression/bounces/{}",
@@ -169,15 +196,7 @@ impl BouncesApi {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a bounce.
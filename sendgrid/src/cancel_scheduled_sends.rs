@@ -79,16 +79,25 @@ impl CancelScheduledSends {
     pub async fn get_all_user_scheduled_sends(
         &self,
     ) -> ClientResult<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_scheduled_sends_stream()).await
+    }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all cancelled and paused scheduled send information.**
+     *
+     * This endpoint will return only the scheduled sends that are associated with a `batch_id`. If you have scheduled a send using the `/mail/send` endpoint and the `send_at` field but no `batch_id`, the send will be scheduled for delivery; however, it will not be returned by this endpoint. For this reason, you should assign a `batch_id` to any scheduled send you may need to pause or cancel in the future.
+     */
+    pub fn get_all_user_scheduled_sends_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + '_
+    {
         let url = self.client.url("/user/scheduled_sends", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Cancel or pause a scheduled send.
@@ -202,6 +211,23 @@ impl CancelScheduledSends {
         &self,
         batch_id: &str,
     ) -> ClientResult<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_scheduled_sends_batch_stream(batch_id))
+            .await
+    }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve the cancel/paused scheduled send information for a specific `batch_id`.**
+     */
+    pub fn get_all_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + '_
+    {
         let url = self.client.url(
             &format!(
                 "/user/scheduled_sends/{}",
@@ -209,15 +235,7 @@ impl CancelScheduledSends {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a cancellation or pause from a scheduled send.
@@ -67,6 +67,22 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetCategoriesResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(category, offset)).await
+    }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all of your categories.**
+     */
+    pub fn get_all_stream(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCategoriesResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !category.is_empty() {
             query_args.push(("category".to_string(), category.to_string()));
@@ -76,15 +92,7 @@ impl Categories {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/categories?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
@@ -235,6 +243,34 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::CategoryStats>> {
+        futures::TryStreamExt::try_collect(self.get_all_stats_stream(
+            start_date,
+            end_date,
+            categories,
+            offset,
+            aggregated_by,
+        ))
+        .await
+    }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_all_stats_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CategoryStats>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -255,14 +291,6 @@ impl Categories {
         let url = self
             .client
             .url(&format!("/categories/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
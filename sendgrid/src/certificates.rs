@@ -78,6 +78,26 @@ impl Certificates {
         &self,
         integration_id: &str,
     ) -> ClientResult<Vec<crate::types::SsoCertificateBody>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_sso_integrations_integration_stream(integration_id),
+        )
+        .await
+    }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all your IdP configurations by configuration ID.**
+     *
+     * The `integration_id` expected by this endpoint is the `id` returned in the response by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn get_all_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoCertificateBody>> + '_ {
         let url = self.client.url(
             &format!(
                 "/sso/integrations/{}/certificates",
@@ -85,15 +105,7 @@ impl Certificates {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get an SSO Certificate.
@@ -81,6 +81,31 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> ClientResult<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_whitelabel_domains_stream(
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        ))
+        .await
+    }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     */
+    pub fn get_all_whitelabel_domains_stream(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
@@ -98,15 +123,7 @@ impl DomainAuthentication {
         let url = self
             .client
             .url(&format!("/whitelabel/domains?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Authenticate a domain.
@@ -290,6 +307,27 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> ClientResult<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_whitelabel_domains_default_stream(domain))
+            .await
+    }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve the default authentication for a domain.**
+     *
+     * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
+     *
+     * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     */
+    pub fn get_all_whitelabel_domains_default_stream(
+        &self,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
@@ -298,15 +336,7 @@ impl DomainAuthentication {
         let url = self
             .client
             .url(&format!("/whitelabel/domains/default?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add an IP to an authenticated domain.
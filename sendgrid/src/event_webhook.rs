@@ -0,0 +1,319 @@
+//! Receive Event Webhook posts: check SendGrid sent them and parse them.
+//!
+//! Once signing is turned on, with
+//! `webhooks().patch_user_event_settings_signed`, SendGrid signs each post
+//! with the account's ECDSA key. `X-Twilio-Email-Event-Webhook-Signature` has
+//! the base64 signature of `{timestamp}{body}`, with the timestamp in
+//! `X-Twilio-Email-Event-Webhook-Timestamp`, which we check against the
+//! public key SendGrid gives back.
+//! See <https://docs.sendgrid.com/for-developers/tracking-events/getting-started-event-webhook-security-features>.
+//!
+//! ```
+//! # fn run(public_key: &str, headers: &http::HeaderMap, body: &[u8]) -> Result<(), sendgrid_api::ClientError> {
+//! use sendgrid_api::event_webhook::{Event, WebhookHandler};
+//!
+//! let handler = WebhookHandler::new(public_key)?;
+//! for event in handler.handle(headers, body)? {
+//!     match event {
+//!         Event::Bounce(bounce) => println!("{} bounced: {}", bounce.common.email, bounce.reason),
+//!         Event::Click(click) => println!("{} clicked {}", click.common.email, click.url),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_ASN1};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The header SendGrid signs posts in, as base64.
+pub const SIGNATURE_HEADER: &str = "X-Twilio-Email-Event-Webhook-Signature";
+
+/// The header with the timestamp SendGrid signed along with the body.
+pub const TIMESTAMP_HEADER: &str = "X-Twilio-Email-Event-Webhook-Timestamp";
+
+/// What every DER encoded P-256 public key starts with, before the point.
+const P256_KEY_PREFIX: &[u8] = &[
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+/// Parse the public key SendGrid gives for the Event Webhook, a base64 DER
+/// P-256 key, into the point we verify signatures with.
+pub fn parse_public_key(public_key: &str) -> ClientResult<Vec<u8>> {
+    let der =
+        base64::decode(public_key.trim()).map_err(|_| ClientError::InvalidWebhookPublicKey)?;
+
+    match der.strip_prefix(P256_KEY_PREFIX) {
+        Some(point) if point.len() == 65 && point[0] == 0x04 => Ok(point.to_vec()),
+        _ => Err(ClientError::InvalidWebhookPublicKey),
+    }
+}
+
+/// Check a `X-Twilio-Email-Event-Webhook-Signature` value against the
+/// timestamp and body of the post, with a point from `parse_public_key`.
+pub fn verify_signature(
+    public_key: &[u8],
+    timestamp: &str,
+    signature: &str,
+    body: &[u8],
+) -> ClientResult<()> {
+    let signature =
+        base64::decode(signature.trim()).map_err(|_| ClientError::InvalidWebhookSignature)?;
+
+    let mut payload = timestamp.as_bytes().to_vec();
+    payload.extend_from_slice(body);
+
+    UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key)
+        .verify(&payload, &signature)
+        .map_err(|_| ClientError::InvalidWebhookSignature)
+}
+
+/// Categories are sent as a string when there's one, and a list otherwise.
+fn deserialize_categories<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Categories {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Categories>::deserialize(deserializer)? {
+        Some(Categories::One(category)) => vec![category],
+        Some(Categories::Many(categories)) => categories,
+        None => Vec::new(),
+    })
+}
+
+/// What every event has.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct EventCommon {
+    /// The recipient the event is about.
+    #[serde(default)]
+    pub email: String,
+    /// When the event happened, in seconds since the epoch.
+    #[serde(default)]
+    pub timestamp: i64,
+    /// Unique to the event, to drop the ones SendGrid sends again.
+    #[serde(default)]
+    pub sg_event_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub sg_message_id: String,
+    #[serde(default, rename = "smtp-id", skip_serializing_if = "String::is_empty")]
+    pub smtp_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_categories"
+    )]
+    pub category: Vec<String>,
+    /// The unsubscribe group of the message, if it was sent with one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asm_group_id: Option<i64>,
+}
+
+/// `processed`: SendGrid accepted the message to send.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProcessedEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pool: String,
+}
+
+/// `delivered`: the receiving server accepted the message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeliveredEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    /// What the receiving server said.
+    #[serde(default)]
+    pub response: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ip: String,
+    /// Whether the message went over TLS, as `0` or `1`.
+    #[serde(default)]
+    pub tls: i64,
+}
+
+/// `open`: the recipient opened the message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OpenEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub useragent: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ip: String,
+    /// Whether the open was by Apple Mail Privacy Protection, rather than the
+    /// recipient.
+    #[serde(default)]
+    pub sg_machine_open: bool,
+}
+
+/// Where in a message a link was.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UrlOffset {
+    #[serde(default)]
+    pub index: i64,
+    /// `html` or `text`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+}
+
+/// `click`: the recipient clicked a link in the message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ClickEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_offset: Option<UrlOffset>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub useragent: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ip: String,
+}
+
+/// `bounce`: the receiving server turned the message away, for good
+/// (`bounce`) or for now (`blocked`).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BounceEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    /// `bounce` or `blocked`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub reason: String,
+    /// The SMTP status code, e.g. `5.0.0`.
+    #[serde(default)]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bounce_classification: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ip: String,
+    #[serde(default)]
+    pub tls: i64,
+}
+
+/// `dropped`: SendGrid didn't send the message, e.g. to a suppressed address.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DroppedEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    #[serde(default)]
+    pub reason: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub status: String,
+}
+
+/// `spamreport`, `unsubscribe` and `group_unsubscribe`: the recipient marked
+/// the message as spam, or unsubscribed from everything or from its group.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RecipientEvent {
+    #[serde(flatten)]
+    pub common: EventCommon,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub useragent: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ip: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+/// An event from the Event Webhook.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Event {
+    Processed(Box<ProcessedEvent>),
+    Delivered(Box<DeliveredEvent>),
+    Open(Box<OpenEvent>),
+    Click(Box<ClickEvent>),
+    Bounce(Box<BounceEvent>),
+    Dropped(Box<DroppedEvent>),
+    SpamReport(Box<RecipientEvent>),
+    Unsubscribe(Box<RecipientEvent>),
+    GroupUnsubscribe(Box<RecipientEvent>),
+    /// An event we don't have a type for, e.g. `deferred`.
+    Other {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl Event {
+    /// Parse an event by its `event`.
+    pub fn parse(payload: serde_json::Value) -> ClientResult<Self> {
+        fn parse<T: DeserializeOwned>(payload: serde_json::Value) -> ClientResult<Box<T>> {
+            Ok(Box::new(serde_json::from_value(payload)?))
+        }
+
+        let event = payload
+            .get("event")
+            .and_then(|e| e.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        Ok(match event.as_str() {
+            "processed" => Event::Processed(parse(payload)?),
+            "delivered" => Event::Delivered(parse(payload)?),
+            "open" => Event::Open(parse(payload)?),
+            "click" => Event::Click(parse(payload)?),
+            "bounce" => Event::Bounce(parse(payload)?),
+            "dropped" => Event::Dropped(parse(payload)?),
+            "spamreport" => Event::SpamReport(parse(payload)?),
+            "unsubscribe" => Event::Unsubscribe(parse(payload)?),
+            "group_unsubscribe" => Event::GroupUnsubscribe(parse(payload)?),
+            _ => Event::Other { event, payload },
+        })
+    }
+
+    /// Parse the body of a post, a list of events.
+    pub fn parse_all(body: &[u8]) -> ClientResult<Vec<Self>> {
+        let events: Vec<serde_json::Value> = serde_json::from_slice(body)?;
+        events.into_iter().map(Event::parse).collect()
+    }
+}
+
+/// Holds the account's Event Webhook public key, to check the ECDSA signature
+/// on each post before parsing the batch of events in it.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    public_key: Vec<u8>,
+}
+
+impl WebhookHandler {
+    /// Create a handler for posts signed with the account's key, given the
+    /// public key SendGrid shows for it.
+    pub fn new(public_key: &str) -> ClientResult<Self> {
+        Ok(WebhookHandler {
+            public_key: parse_public_key(public_key)?,
+        })
+    }
+
+    /// Verify a post from its headers and raw body, then parse its events.
+    pub fn handle(&self, headers: &http::HeaderMap, body: &[u8]) -> ClientResult<Vec<Event>> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .ok_or(ClientError::MissingWebhookHeader { header: name })
+        };
+
+        verify_signature(
+            &self.public_key,
+            header(TIMESTAMP_HEADER)?,
+            header(SIGNATURE_HEADER)?,
+            body,
+        )?;
+
+        Event::parse_all(body)
+    }
+}
@@ -0,0 +1,249 @@
+//! Receive email through the Inbound Parse Webhook.
+//!
+//! SendGrid posts each email it receives for a host set up with
+//! `settings_inbound_parse()` as `multipart/form-data`: the headers, bodies and
+//! envelope in fields, and each attachment as a file.
+//! See <https://docs.sendgrid.com/for-developers/parsing-email/setting-up-the-inbound-parse-webhook>.
+//!
+//! ```
+//! # fn run(headers: &http::HeaderMap, body: &[u8]) -> Result<(), sendgrid_api::ClientError> {
+//! use sendgrid_api::inbound_parse::InboundEmail;
+//!
+//! let email = InboundEmail::parse(headers, body)?;
+//! println!("{} wrote to {:?}: {}", email.from, email.envelope.to, email.subject);
+//! for attachment in &email.attachments {
+//!     println!("{} ({} bytes)", attachment.filename, attachment.data.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// Who SMTP said the email was from and to, rather than its headers.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InboundEnvelope {
+    #[serde(default)]
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub from: String,
+}
+
+/// A file attached to an email.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InboundAttachment {
+    pub filename: String,
+    pub content_type: String,
+    /// The `Content-ID` that inline images are referred to by in the HTML.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub content_id: String,
+    pub data: bytes::Bytes,
+}
+
+/// An email SendGrid received.
+///
+/// Text fields are decoded as UTF-8, which SendGrid converts them to unless
+/// `charsets` says otherwise.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InboundEmail {
+    /// The headers of the email, unfolded, in the order they came.
+    pub headers: Vec<(String, String)>,
+    pub from: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub cc: String,
+    pub subject: String,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub html: String,
+    pub envelope: InboundEnvelope,
+    /// The DKIM results for each signing domain, e.g. `{@example.com : pass}`.
+    pub dkim: String,
+    /// The SPF result, e.g. `pass`.
+    pub spf: String,
+    pub sender_ip: String,
+    /// Only with spam checking turned on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spam_score: Option<f64>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub spam_report: String,
+    /// The character set of each field, by field.
+    pub charsets: HashMap<String, String>,
+    /// The whole MIME message, instead of the fields above, when the host is
+    /// set up to post raw email.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub raw: String,
+    pub attachments: Vec<InboundAttachment>,
+}
+
+impl InboundEmail {
+    /// The first value of a header of the email, whatever its case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Parse a post from its headers and raw body.
+    pub fn parse(headers: &http::HeaderMap, body: &[u8]) -> ClientResult<Self> {
+        let content_type = headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(ClientError::MissingWebhookHeader {
+                header: "Content-Type",
+            })?;
+
+        let boundary = content_type
+            .parse::<mime::Mime>()
+            .ok()
+            .filter(|m| m.type_() == mime::MULTIPART && m.subtype() == mime::FORM_DATA)
+            .and_then(|m| m.get_param(mime::BOUNDARY).map(|b| b.to_string()))
+            .ok_or_else(|| malformed("not multipart/form-data with a boundary"))?;
+
+        let mut email = InboundEmail::default();
+        let mut attachment_info: HashMap<String, serde_json::Value> = HashMap::new();
+        let mut files = Vec::new();
+        for part in parts(body, &boundary)? {
+            if let Some(filename) = part.filename {
+                files.push((part.name, filename, part.content_type, part.data));
+                continue;
+            }
+
+            let value = String::from_utf8_lossy(part.data).into_owned();
+            match part.name.as_str() {
+                "headers" => email.headers = unfold_headers(&value),
+                "from" => email.from = value,
+                "to" => email.to = value,
+                "cc" => email.cc = value,
+                "subject" => email.subject = value,
+                "text" => email.text = value,
+                "html" => email.html = value,
+                "envelope" => email.envelope = serde_json::from_str(&value)?,
+                "dkim" => email.dkim = value,
+                "SPF" => email.spf = value,
+                "sender_ip" => email.sender_ip = value,
+                "spam_score" => email.spam_score = value.trim().parse().ok(),
+                "spam_report" => email.spam_report = value,
+                "charsets" => email.charsets = serde_json::from_str(&value)?,
+                "attachment-info" => attachment_info = serde_json::from_str(&value)?,
+                "email" => email.raw = value,
+                _ => {}
+            }
+        }
+
+        email.attachments = files
+            .into_iter()
+            .map(|(name, filename, content_type, data)| {
+                let info = attachment_info.get(&name);
+                let content_id = info
+                    .and_then(|i| i.get("content-id"))
+                    .and_then(|c| c.as_str())
+                    .unwrap_or_default()
+                    .to_string();
+
+                InboundAttachment {
+                    filename,
+                    content_type,
+                    content_id,
+                    data: bytes::Bytes::copy_from_slice(data),
+                }
+            })
+            .collect();
+
+        Ok(email)
+    }
+}
+
+fn malformed(reason: &str) -> ClientError {
+    ClientError::MalformedInboundEmail {
+        reason: reason.to_string(),
+    }
+}
+
+/// Unfold the `headers` field of a post into names and values.
+fn unfold_headers(raw: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in raw.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+/// One field or file of a `multipart/form-data` body.
+struct Part<'a> {
+    name: String,
+    filename: Option<String>,
+    content_type: String,
+    data: &'a [u8],
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|i| i + from)
+}
+
+/// Split a `multipart/form-data` body into its parts.
+fn parts<'a>(body: &'a [u8], boundary: &str) -> ClientResult<Vec<Part<'a>>> {
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let next_delimiter = format!("\r\n--{}", boundary).into_bytes();
+
+    let mut at =
+        find(body, &delimiter, 0).ok_or_else(|| malformed("no boundary"))? + delimiter.len();
+    let mut parts = Vec::new();
+    loop {
+        // The last delimiter is followed by `--`.
+        if body[at..].starts_with(b"--") {
+            return Ok(parts);
+        }
+        let headers_start = find(body, b"\r\n", at).ok_or_else(|| malformed("truncated"))? + 2;
+        let headers_end = find(body, b"\r\n\r\n", headers_start)
+            .ok_or_else(|| malformed("part without headers"))?;
+        // An empty part's data can be cut short by the delimiter after it, which
+        // then starts with the `\r\n` that ends the headers.
+        let data_start = headers_end + 4;
+        let data_end = find(body, &next_delimiter, headers_end + 2)
+            .ok_or_else(|| malformed("unterminated part"))?;
+
+        let headers = String::from_utf8_lossy(&body[headers_start..headers_end]);
+        let mut part = Part {
+            name: String::new(),
+            filename: None,
+            content_type: String::new(),
+            data: body.get(data_start..data_end).unwrap_or_default(),
+        };
+        for (name, value) in unfold_headers(&headers) {
+            if name.eq_ignore_ascii_case("Content-Disposition") {
+                for param in value.split(';').skip(1) {
+                    if let Some((key, value)) = param.trim().split_once('=') {
+                        let value = value.trim().trim_matches('"').to_string();
+                        match key.trim() {
+                            "name" => part.name = value,
+                            "filename" => part.filename = Some(value),
+                            _ => {}
+                        }
+                    }
+                }
+            } else if name.eq_ignore_ascii_case("Content-Type") {
+                part.content_type = value;
+            }
+        }
+        parts.push(part);
+
+        at = data_end + next_delimiter.len();
+    }
+}
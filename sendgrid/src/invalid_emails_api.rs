@@ -75,6 +75,26 @@ impl InvalidEmailsApi {
         end_time: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::InvalidEmail>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_invalid_emails_stream(start_time, end_time, offset),
+        )
+        .await
+    }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -89,15 +109,7 @@ impl InvalidEmailsApi {
         let url = self
             .client
             .url(&format!("/suppression/invalid_emails?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete invalid emails.
@@ -175,6 +187,24 @@ impl InvalidEmailsApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::InvalidEmail>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_invalid_emails_email_stream(email),
+        )
+        .await
+    }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a specific invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + '_ {
         let url = self.client.url(
             &format!(
                 "/suppression/invalid_emails/{}",
@@ -182,15 +212,7 @@ impl InvalidEmailsApi {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a specific invalid email.
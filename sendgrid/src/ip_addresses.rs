@@ -98,6 +98,36 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> ClientResult<Vec<crate::types::GetIpsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_ips_stream(
+            ip,
+            exclude_whitelabels,
+            offset,
+            subuser,
+            sort_by_direction,
+        ))
+        .await
+    }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all assigned and unassigned IPs.**
+     *
+     * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_stream(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if exclude_whitelabels {
             query_args.push((
@@ -122,15 +152,7 @@ impl IpAddresses {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/ips?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add IPs.
@@ -210,16 +232,24 @@ impl IpAddresses {
     pub async fn get_all_ips_assigned(
         &self,
     ) -> ClientResult<Vec<crate::types::GetIpsAssignedResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_ips_assigned_stream()).await
+    }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve only assigned IP addresses.**
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_assigned_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsAssignedResponse>> + '_ {
         let url = self.client.url("/ips/assigned", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve all IP pools an IP address belongs to.
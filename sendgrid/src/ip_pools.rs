@@ -40,16 +40,22 @@ impl IpPools {
      * **This endpoint allows you to get all of your IP pools.**
      */
     pub async fn get_all_ips_pools(&self) -> ClientResult<Vec<crate::types::IpPoolsPoolResp>> {
+        futures::TryStreamExt::try_collect(self.get_all_ips_pools_stream()).await
+    }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to get all of your IP pools.**
+     */
+    pub fn get_all_ips_pools_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpPoolsPoolResp>> + '_ {
         let url = self.client.url("/ips/pools", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an IP pool.
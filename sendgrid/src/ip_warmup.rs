@@ -40,16 +40,22 @@ impl IpWarmup {
      * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
      */
     pub async fn get_all_ips_warmup(&self) -> ClientResult<Vec<crate::types::IpWarmupResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_ips_warmup_stream()).await
+    }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
+     */
+    pub fn get_all_ips_warmup_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + '_ {
         let url = self.client.url("/ips/warmup", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Start warming up an IP address.
@@ -118,6 +124,24 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> ClientResult<Vec<crate::types::IpWarmupResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_ips_warmup_ip_address_stream(ip_address))
+            .await
+    }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve the warmup status for a specific IP address.**
+     *
+     * You can retrieve all of your warming IPs using the "Retrieve all IPs currently in warmup" endpoint.
+     */
+    pub fn get_all_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + '_ {
         let url = self.client.url(
             &format!(
                 "/ips/warmup/{}",
@@ -125,15 +149,7 @@ impl IpWarmup {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Stop warming up an IP address.
@@ -61,16 +61,24 @@ impl LinkBranding {
     pub async fn get_all_whitelabel_links(
         &self,
     ) -> ClientResult<Vec<crate::types::LinkBranding200Response>> {
+        futures::TryStreamExt::try_collect(self.get_all_whitelabel_links_stream()).await
+    }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all branded links**.
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_all_whitelabel_links_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkBranding200Response>> + '_ {
         let url = self.client.url("/whitelabel/links", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a branded link.
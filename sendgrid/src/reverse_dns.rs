@@ -81,6 +81,28 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> ClientResult<Vec<crate::types::ReverseDns>> {
+        futures::TryStreamExt::try_collect(self.get_all_whitelabel_ips_stream(offset, ip)).await
+    }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_ips`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of the Reverse DNS records created by this account.**
+     *
+     * You may include a search key by using the `ip` query string parameter. This enables you to perform a prefix search for a given IP segment (e.g., `?ip="192."`).
+     *
+     * Use the `limit` query string parameter to reduce the number of records returned. All records will be returned if you have fewer records than the specified limit.
+     *
+     * The `offset` query string parameter allows you to specify a non-zero index from which records will be returned. For example, if you have ten records, `?offset=5` will return the last five records (at indexes 5 through 9). The list starts at index zero.
+     */
+    pub fn get_all_whitelabel_ips_stream(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReverseDns>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ip.is_empty() {
             query_args.push(("ip".to_string(), ip.to_string()));
@@ -92,15 +114,7 @@ impl ReverseDns {
         let url = self
             .client
             .url(&format!("/whitelabel/ips?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Set up reverse DNS.
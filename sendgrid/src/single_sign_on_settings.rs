@@ -61,6 +61,23 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> ClientResult<Vec<crate::types::SsoIntegrationAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_sso_integrations_stream(si)).await
+    }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
+     *
+     * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     */
+    pub fn get_all_sso_integrations_stream(
+        &self,
+        si: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoIntegrationAllOf>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if si {
             query_args.push(("si".to_string(), si.to_string()));
@@ -69,15 +86,7 @@ impl SingleSignOnSettings {
         let url = self
             .client
             .url(&format!("/sso/integrations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create an SSO Integration.
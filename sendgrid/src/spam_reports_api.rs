@@ -75,6 +75,26 @@ impl SpamReportsApi {
         end_time: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::SpamReportsResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_spam_reports_stream(start_time, end_time, offset),
+        )
+        .await
+    }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all spam reports.**
+     */
+    pub fn get_all_suppression_spam_reports_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -89,15 +109,7 @@ impl SpamReportsApi {
         let url = self
             .client
             .url(&format!("/suppression/spam_reports?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete spam reports.
@@ -177,6 +189,24 @@ impl SpamReportsApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::SpamReportsResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_spam_reports_email_stream(email),
+        )
+        .await
+    }
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports_email`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a specific spam report by email address.**
+     */
+    pub fn get_all_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + '_ {
         let url = self.client.url(
             &format!(
                 "/suppression/spam_reports/{}",
@@ -184,15 +214,7 @@ impl SpamReportsApi {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Delete a specific spam report.
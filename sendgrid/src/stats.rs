@@ -79,6 +79,32 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetStatsResponseData>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
+     *
+     * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     */
+    pub fn get_all_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetStatsResponseData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -94,15 +120,7 @@ impl Stats {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve email statistics by country and state/province.
@@ -182,6 +200,36 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetGeoStatsResponseData>> {
+        futures::TryStreamExt::try_collect(self.get_all_geo_stream(
+            country,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by country and state/province.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     */
+    pub fn get_all_geo_stream(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetGeoStatsResponseData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -200,15 +248,7 @@ impl Stats {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/geo/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve email statistics by device type.
@@ -300,6 +340,43 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_devices_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_all_devices`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Device Types
+     * | **Device** | **Description** | **Example** |
+     * |---|---|---|
+     * | Desktop | Email software on desktop computer. | I.E., Outlook, Sparrow, or Apple Mail. |
+     * | Webmail |	A web-based email client. | I.E., Yahoo, Google, AOL, or Outlook.com. |
+     * | Phone | A smart phone. | iPhone, Android, Blackberry, etc.
+     * | Tablet | A tablet computer. | iPad, android based tablet, etc. |
+     * | Other | An unrecognized device. |
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_devices_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -315,15 +392,7 @@ impl Stats {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/devices/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve email statistics by client type.
@@ -390,6 +459,32 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_clients_stream(
+            start_date,
+            end_date,
+            aggregated_by,
+        ))
+        .await
+    }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -402,15 +497,7 @@ impl Stats {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/clients/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve stats by a specific client type.
@@ -498,6 +585,40 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_clients_client_type_stream(
+            client_type,
+            start_date,
+            end_date,
+            aggregated_by,
+        ))
+        .await
+    }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by a specific client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Client Types
+     * - phone
+     * - tablet
+     * - webmail
+     * - desktop
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -517,15 +638,7 @@ impl Stats {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve email statistics by mailbox provider.
@@ -610,6 +723,37 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        futures::TryStreamExt::try_collect(self.get_all_mailbox_providers_stream(
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_mailbox_providers_stream(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetMailboxProvidersStatsResponseData>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -633,15 +777,7 @@ impl Stats {
         let url = self
             .client
             .url(&format!("/mailbox_providers/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve email statistics by browser.
@@ -723,6 +859,37 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        futures::TryStreamExt::try_collect(self.get_all_browsers_stream(
+            browsers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by browser type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_browsers_stream(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetBrowsersStatsResponseData>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -743,14 +910,6 @@ impl Stats {
         let url = self
             .client
             .url(&format!("/browsers/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -70,6 +70,24 @@ impl SubusersApi {
         username: &str,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::Subuser>> {
+        futures::TryStreamExt::try_collect(self.get_all_subusers_stream(username, offset)).await
+    }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all of your subusers.**
+     *
+     * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     */
+    pub fn get_all_subusers_stream(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subuser>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if offset > 0 {
             query_args.push(("offset".to_string(), offset.to_string()));
@@ -79,15 +97,7 @@ impl SubusersApi {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/subusers?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create Subuser.
@@ -220,6 +230,25 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> ClientResult<Vec<crate::types::GetSubusersReputationsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_subusers_reputations_stream(usernames))
+            .await
+    }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to request the reputations for your subusers.**
+     *
+     * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     */
+    pub fn get_all_subusers_reputations_stream(
+        &self,
+        usernames: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSubusersReputationsResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !usernames.is_empty() {
             query_args.push(("usernames".to_string(), usernames.to_string()));
@@ -228,15 +257,7 @@ impl SubusersApi {
         let url = self
             .client
             .url(&format!("/subusers/reputations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Update IPs assigned to a subuser.
@@ -50,6 +50,21 @@ impl Suppressions {
      * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
      */
     pub async fn get_all_asm_groups_group(&self, group_id: &str) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.get_all_asm_groups_group_stream(group_id)).await
+    }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
+     */
+    pub fn get_all_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}/suppressions",
@@ -57,15 +72,7 @@ impl Suppressions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add suppressions to a suppression group.
@@ -170,16 +177,23 @@ impl Suppressions {
      * **This endpoint allows you to retrieve a list of all suppressions.**
      */
     pub async fn get_all_asm(&self) -> ClientResult<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_asm_stream()).await
+    }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all suppressions.**
+     */
+    pub fn get_all_asm_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAsmSuppressionsResponse>> + '_
+    {
         let url = self.client.url("/asm/suppressions", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve all suppression groups for an email address.
@@ -101,6 +101,27 @@ impl SuppressionsGlobal {
         end_time: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_suppression_unsubscribes_stream(start_time, end_time, offset),
+        )
+        .await
+    }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_all_suppression_unsubscribes`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all email address that are globally suppressed.**
+     */
+    pub fn get_all_suppression_unsubscribes_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSuppressionUnsubscribesResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -115,15 +136,7 @@ impl SuppressionsGlobal {
         let url = self
             .client
             .url(&format!("/suppression/unsubscribes?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve a Global Suppression.
@@ -60,21 +60,30 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         id: i64,
     ) -> ClientResult<Vec<crate::types::SuppressionGroup>> {
+        futures::TryStreamExt::try_collect(self.get_all_asm_groups_stream(id)).await
+    }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
+     *
+     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     */
+    pub fn get_all_asm_groups_stream(
+        &self,
+        id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SuppressionGroup>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if id > 0 {
             query_args.push(("id".to_string(), id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/asm/groups?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a new suppression group.
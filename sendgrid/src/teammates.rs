@@ -169,6 +169,24 @@ impl Teammates {
         &self,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetScopesRequestsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_scopes_requests_stream(offset)).await
+    }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_all_scopes_requests`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve a list of all recent access requests.**
+     *
+     * The Response Header's `link` parameter will include pagination info.
+     */
+    pub fn get_all_scopes_requests_stream(
+        &self,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetScopesRequestsResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if offset > 0 {
             query_args.push(("offset".to_string(), offset.to_string()));
@@ -177,15 +195,7 @@ impl Teammates {
         let url = self
             .client
             .url(&format!("/scopes/requests?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve all pending teammates.
@@ -177,6 +177,35 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_parse_stats_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+        .await
+    }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_all_user_parse_stats`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * **This endpoint allows you to retrieve the statistics for your Parse Webhook useage.**
+     *
+     * SendGrid's Inbound Parse Webhook allows you to parse the contents and attachments of incomming emails. The Parse API can then POST the parsed emails to a URL that you specify. The Inbound Parse Webhook cannot parse messages greater than 30MB in size, including all attachments.
+     *
+     * There are a number of pre-made integrations for the SendGrid Parse Webhook which make processing events easy. You can find these integrations in the [Library Index](https://sendgrid.com/docs/Integrate/libraries.html#-Webhook-Libraries).
+     */
+    pub fn get_all_user_parse_stats_stream(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserWebhooksParseStatsResponseData>>
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -194,15 +223,7 @@ impl Webhooks {
         let url = self
             .client
             .url(&format!("/user/webhooks/parse/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Retrieve Signed Webhook Public Key.
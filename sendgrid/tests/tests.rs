@@ -1 +1,355 @@
+#[cfg(feature = "webhooks")]
+mod event_webhook {
+    use ring::{
+        rand::SystemRandom,
+        signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING},
+    };
+    use sendgrid_api::{
+        event_webhook::{parse_public_key, verify_signature, Event, WebhookHandler},
+        ClientError,
+    };
 
+    /// A key pair, and its public key the way SendGrid shows it.
+    fn key_pair() -> (EcdsaKeyPair, String) {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref()).unwrap();
+
+        let mut der = vec![
+            0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06,
+            0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+        ];
+        der.extend_from_slice(key_pair.public_key().as_ref());
+
+        (key_pair, base64::encode(der))
+    }
+
+    fn headers(key_pair: &EcdsaKeyPair, timestamp: &str, body: &[u8]) -> http::HeaderMap {
+        let mut payload = timestamp.as_bytes().to_vec();
+        payload.extend_from_slice(body);
+        let signature = key_pair.sign(&SystemRandom::new(), &payload).unwrap();
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            "X-Twilio-Email-Event-Webhook-Signature",
+            base64::encode(signature.as_ref()).parse().unwrap(),
+        );
+        headers.insert(
+            "X-Twilio-Email-Event-Webhook-Timestamp",
+            timestamp.parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_parse_public_key() {
+        let (key_pair, public_key) = key_pair();
+
+        assert_eq!(
+            parse_public_key(&public_key).unwrap(),
+            key_pair.public_key().as_ref()
+        );
+        for bad in ["not base64!", "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE", ""] {
+            assert!(matches!(
+                parse_public_key(bad),
+                Err(ClientError::InvalidWebhookPublicKey)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let (key_pair, public_key) = key_pair();
+        let public_key = parse_public_key(&public_key).unwrap();
+        let body = br#"[{"email":"example@test.com","event":"processed"}]"#;
+        let headers = headers(&key_pair, "1600112502", body);
+        let signature = headers["X-Twilio-Email-Event-Webhook-Signature"]
+            .to_str()
+            .unwrap();
+
+        verify_signature(&public_key, "1600112502", signature, body).unwrap();
+        for (timestamp, signature, body) in [
+            ("1600112503", signature, &body[..]),
+            ("1600112502", signature, &br#"[]"#[..]),
+            ("1600112502", "bm90IGEgc2lnbmF0dXJl", &body[..]),
+            ("1600112502", "not base64!", &body[..]),
+        ] {
+            assert!(matches!(
+                verify_signature(&public_key, timestamp, signature, body),
+                Err(ClientError::InvalidWebhookSignature)
+            ));
+        }
+
+        let (_, other_key) = self::key_pair();
+        assert!(matches!(
+            verify_signature(
+                &parse_public_key(&other_key).unwrap(),
+                "1600112502",
+                signature,
+                body
+            ),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+    }
+
+    #[test]
+    fn test_handle_events() {
+        let (key_pair, public_key) = key_pair();
+        let body = serde_json::to_vec(&serde_json::json!([
+            {
+                "email": "example@test.com",
+                "timestamp": 1513299569,
+                "smtp-id": "<14c5d75ce93.dfd.64b469@ismtpd-555>",
+                "event": "delivered",
+                "category": "cat facts",
+                "sg_event_id": "rWVYmVk90MjZJ9iohOBa3w==",
+                "sg_message_id": "14c5d75ce93.dfd.64b469.filter0001.16648.5515E0B88.0",
+                "response": "250 OK",
+                "tls": 1,
+            },
+            {
+                "email": "example@test.com",
+                "timestamp": 1513299569,
+                "event": "click",
+                "category": ["cat facts", "newsletter"],
+                "sg_event_id": "kCAi1KttyQdEKHhdC-nuEA==",
+                "useragent": "Mozilla/4.0 (compatible; MSIE 6.1; Windows XP; .NET CLR 1.1.4322; .NET CLR 2.0.50727)",
+                "ip": "255.255.255.255",
+                "url": "http://www.sendgrid.com/",
+                "url_offset": { "index": 0, "type": "html" },
+            },
+            {
+                "email": "example@test.com",
+                "timestamp": 1513299569,
+                "event": "bounce",
+                "sg_event_id": "6g4ZI7SA-xmRDv57GoPIPw==",
+                "reason": "500 unknown recipient",
+                "status": "5.0.0",
+                "type": "bounce",
+                "bounce_classification": "Invalid Address",
+            },
+            {
+                "email": "example@test.com",
+                "timestamp": 1513299569,
+                "event": "group_unsubscribe",
+                "sg_event_id": "Aqb3aPuWEXtPyhW8qJcy1A==",
+                "asm_group_id": 10,
+            },
+            {
+                "email": "example@test.com",
+                "timestamp": 1513299569,
+                "event": "deferred",
+                "attempt": "5",
+            },
+        ]))
+        .unwrap();
+
+        let handler = WebhookHandler::new(&public_key).unwrap();
+        let events = handler
+            .handle(&headers(&key_pair, "1600112502", &body), &body)
+            .unwrap();
+
+        assert_eq!(events.len(), 5);
+        match &events[0] {
+            Event::Delivered(delivered) => {
+                assert_eq!(delivered.common.category, vec!["cat facts"]);
+                assert_eq!(
+                    delivered.common.smtp_id,
+                    "<14c5d75ce93.dfd.64b469@ismtpd-555>"
+                );
+                assert_eq!(delivered.response, "250 OK");
+            }
+            e => panic!("expected delivered, got {:?}", e),
+        }
+        match &events[1] {
+            Event::Click(click) => {
+                assert_eq!(click.common.category, vec!["cat facts", "newsletter"]);
+                assert_eq!(click.url, "http://www.sendgrid.com/");
+                assert_eq!(click.url_offset.as_ref().unwrap().type_, "html");
+            }
+            e => panic!("expected click, got {:?}", e),
+        }
+        match &events[2] {
+            Event::Bounce(bounce) => {
+                assert_eq!(bounce.status, "5.0.0");
+                assert_eq!(bounce.bounce_classification, "Invalid Address");
+            }
+            e => panic!("expected bounce, got {:?}", e),
+        }
+        match &events[3] {
+            Event::GroupUnsubscribe(unsubscribe) => {
+                assert_eq!(unsubscribe.common.asm_group_id, Some(10))
+            }
+            e => panic!("expected group_unsubscribe, got {:?}", e),
+        }
+        assert!(matches!(&events[4], Event::Other { event, .. } if event == "deferred"));
+
+        // Without the signature.
+        assert!(matches!(
+            handler.handle(&http::HeaderMap::new(), &body),
+            Err(ClientError::MissingWebhookHeader { .. })
+        ));
+    }
+}
+
+#[cfg(feature = "webhooks")]
+mod inbound_parse {
+    use sendgrid_api::{inbound_parse::InboundEmail, ClientError};
+
+    const BOUNDARY: &str = "xYzZY";
+
+    fn body(fields: &[(&str, &str)], files: &[(&str, &str, &str, &[u8])]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (name, value) in fields {
+            body.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                    BOUNDARY, name, value
+                )
+                .as_bytes(),
+            );
+        }
+        for (name, filename, content_type, data) in files {
+            body.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                    BOUNDARY, name, filename, content_type
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", BOUNDARY).as_bytes());
+        body
+    }
+
+    fn headers() -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", BOUNDARY)
+                .parse()
+                .unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_parse_inbound_email() {
+        let png: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n', 0x00];
+        let body = body(
+            &[
+                (
+                    "headers",
+                    "Received: by mx0047p1mdw1.sendgrid.net with SMTP id 6WCVv7KAWn\r\n\tWed, 27 Jul 2016 20:53:06 +0000 (UTC)\r\nFrom: Sender <sender@example.com>\r\nSubject: Hello\r\n",
+                ),
+                ("dkim", "{@example.com : pass}"),
+                ("content-ids", r#"{"ii_1562e2169c132d83":"attachment1"}"#),
+                ("to", "inbound@inbound.example.com"),
+                ("html", "<p>Hello <img src=\"cid:ii_1562e2169c132d83\"></p>"),
+                ("from", "Sender <sender@example.com>"),
+                ("text", "Hello\r\n"),
+                ("sender_ip", "209.85.223.69"),
+                ("spam_score", "0.012"),
+                (
+                    "envelope",
+                    r#"{"to":["inbound@inbound.example.com"],"from":"sender@example.com"}"#,
+                ),
+                ("attachments", "1"),
+                ("subject", "Hello"),
+                (
+                    "attachment-info",
+                    r#"{"attachment1":{"filename":"pixel.png","name":"pixel.png","type":"image/png","content-id":"ii_1562e2169c132d83"}}"#,
+                ),
+                ("charsets", r#"{"to":"UTF-8","html":"UTF-8","subject":"UTF-8","from":"UTF-8","text":"UTF-8"}"#),
+                ("SPF", "pass"),
+            ],
+            &[("attachment1", "pixel.png", "image/png", png)],
+        );
+
+        let email = InboundEmail::parse(&headers(), &body).unwrap();
+
+        assert_eq!(email.from, "Sender <sender@example.com>");
+        assert_eq!(email.subject, "Hello");
+        assert_eq!(email.text, "Hello\r\n");
+        assert_eq!(email.envelope.to, vec!["inbound@inbound.example.com"]);
+        assert_eq!(email.envelope.from, "sender@example.com");
+        assert_eq!(email.dkim, "{@example.com : pass}");
+        assert_eq!(email.spf, "pass");
+        assert_eq!(email.spam_score, Some(0.012));
+        assert_eq!(email.charsets["subject"], "UTF-8");
+        assert_eq!(email.header("subject"), Some("Hello"));
+        assert_eq!(
+            email.header("Received"),
+            Some("by mx0047p1mdw1.sendgrid.net with SMTP id 6WCVv7KAWn Wed, 27 Jul 2016 20:53:06 +0000 (UTC)")
+        );
+
+        assert_eq!(email.attachments.len(), 1);
+        let attachment = &email.attachments[0];
+        assert_eq!(attachment.filename, "pixel.png");
+        assert_eq!(attachment.content_type, "image/png");
+        assert_eq!(attachment.content_id, "ii_1562e2169c132d83");
+        assert_eq!(attachment.data.as_ref(), png);
+    }
+
+    #[test]
+    fn test_parse_empty_and_truncated_parts() {
+        // Nothing between the headers and the closing delimiter.
+        let email = InboundEmail::parse(
+            &headers(),
+            b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\n--xYzZY--\r\n",
+        )
+        .unwrap();
+        assert_eq!(email.to, "");
+
+        let email = InboundEmail::parse(
+            &headers(),
+            b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\n\r\n--xYzZY\r\nContent-Disposition: form-data; name=\"subject\"\r\n\r\nHi\r\n--xYzZY--\r\n",
+        )
+        .unwrap();
+        assert_eq!(email.to, "");
+        assert_eq!(email.subject, "Hi");
+
+        for truncated in [
+            &b"--xYzZY"[..],
+            b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"",
+            b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\n",
+            b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\n--xYz",
+        ] {
+            assert!(matches!(
+                InboundEmail::parse(&headers(), truncated),
+                Err(ClientError::MalformedInboundEmail { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_other_bodies() {
+        let mut headers = headers();
+        assert!(matches!(
+            InboundEmail::parse(
+                &headers,
+                b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\nno end"
+            ),
+            Err(ClientError::MalformedInboundEmail { .. })
+        ));
+
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            "application/json".parse().unwrap(),
+        );
+        assert!(matches!(
+            InboundEmail::parse(&headers, b"{}"),
+            Err(ClientError::MalformedInboundEmail { .. })
+        ));
+
+        assert!(matches!(
+            InboundEmail::parse(&http::HeaderMap::new(), b""),
+            Err(ClientError::MissingWebhookHeader {
+                header: "Content-Type"
+            })
+        ));
+    }
+}
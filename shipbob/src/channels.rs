@@ -36,15 +36,19 @@ impl Channels {
      * As opposed to `get`, this function returns all the pages of the request at once.
      */
     pub async fn get_all(&self) -> ClientResult<Vec<crate::types::Channel>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream()).await
+    }
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Channel>> + '_ {
         let url = self.client.url("/channel", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
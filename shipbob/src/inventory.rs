@@ -118,6 +118,26 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> ClientResult<Vec<crate::types::Inventory>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_stream(is_active, is_digital, ids, sort, search),
+        )
+        .await
+    }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_stream(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("IDs".to_string(), ids.join(" ")));
@@ -136,15 +156,7 @@ impl Inventory {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/inventory?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get a list of inventory items by product id.
@@ -185,6 +197,19 @@ impl Inventory {
         &self,
         product_id: i64,
     ) -> ClientResult<Vec<crate::types::Inventory>> {
+        futures::TryStreamExt::try_collect(self.get_all_product_stream(product_id)).await
+    }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_product_stream(
+        &self,
+        product_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + '_ {
         let url = self.client.url(
             &format!(
                 "/product/{}/inventory",
@@ -192,14 +217,6 @@ impl Inventory {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
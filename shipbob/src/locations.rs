@@ -66,6 +66,27 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> ClientResult<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            include_inactive,
+            receiving_enabled,
+            access_granted,
+        ))
+        .await
+    }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_stream(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IntegrationsLocationInternalAllOf>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if access_granted {
             query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
@@ -81,14 +102,6 @@ impl Locations {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/location?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
@@ -174,6 +174,40 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> ClientResult<Vec<crate::types::Order>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        ))
+        .await
+    }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Order>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = end_date {
             query_args.push(("EndDate".to_string(), date.to_rfc3339()));
@@ -207,15 +241,7 @@ impl Orders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/order?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create Order.
@@ -444,6 +470,23 @@ impl Orders {
         order_id: i64,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_shipment_timeline_stream(order_id, shipment_id),
+        )
+        .await
+    }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + '_ {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}/timeline",
@@ -452,15 +495,7 @@ impl Orders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get all Shipments for Order.
@@ -504,6 +539,19 @@ impl Orders {
         &self,
         order_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipment>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipments_stream(order_id)).await
+    }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_shipments_stream(
+        &self,
+        order_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipment>> + '_ {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment",
@@ -511,15 +559,7 @@ impl Orders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get logs for one Shipment by Order Id and Shipment Id.
@@ -567,6 +607,21 @@ impl Orders {
         order_id: i64,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipment_logs_stream(order_id, shipment_id))
+            .await
+    }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + '_ {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}/logs",
@@ -575,15 +630,7 @@ impl Orders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get one Shipment by Shipment Id.
@@ -713,6 +760,20 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipment_timeline_all_stream(shipment_id))
+            .await
+    }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_shipment_timeline_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + '_ {
         let url = self.client.url(
             &format!(
                 "/shipment/{}/timeline",
@@ -720,15 +781,7 @@ impl Orders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get logs for one Shipment by Shipment Id.
@@ -772,6 +825,19 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipment_logs_all_stream(shipment_id)).await
+    }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_shipment_logs_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + '_ {
         let url = self.client.url(
             &format!(
                 "/shipment/{}/logs",
@@ -779,15 +845,7 @@ impl Orders {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get shipping methods.
@@ -839,15 +897,21 @@ impl Orders {
     pub async fn get_all_shipping_method(
         &self,
     ) -> ClientResult<Vec<crate::types::OrdersShipMethodDetail>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipping_method_stream()).await
+    }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all merchants shipping methods
+     */
+    pub fn get_all_shipping_method_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipMethodDetail>> + '_ {
         let url = self.client.url("/shippingmethod", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
 }
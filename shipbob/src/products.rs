@@ -95,6 +95,30 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> ClientResult<Vec<crate::types::Product>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        ))
+        .await
+    }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Product>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !active_status.to_string().is_empty() {
             query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
@@ -113,15 +137,7 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/product?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Add a single product to the store.
@@ -40,16 +40,21 @@ impl Receiving {
     pub async fn get_all_fulfillment_center(
         &self,
     ) -> ClientResult<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        futures::TryStreamExt::try_collect(self.get_all_fulfillment_center_stream()).await
+    }
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_center`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_fulfillment_center_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReceivingFulfillmentCenter>> + '_
+    {
         let url = self.client.url("/fulfillmentCenter", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Get Warehouse Receiving Order.
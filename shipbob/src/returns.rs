@@ -182,6 +182,40 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> ClientResult<Vec<crate::types::ReturnOrder>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        ))
+        .await
+    }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     */
+    pub fn get_all_stream(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReturnOrder>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = end_date {
             query_args.push(("EndDate".to_string(), date.to_rfc3339()));
@@ -221,15 +255,7 @@ impl Returns {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/return?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create Return Order.
@@ -65,21 +65,28 @@ impl Webhooks {
         &self,
         topic: crate::types::WebhooksTopics,
     ) -> ClientResult<Vec<crate::types::Webhook>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(topic)).await
+    }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Webhook>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !topic.to_string().is_empty() {
             query_args.push(("Topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/webhook?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * Create a new webhook subscription.
@@ -18,7 +18,7 @@ impl Access {
      *
      * https://shopify.dev/docs/admin-api/rest/reference/access/accessscope#index-2020-10
      */
-    pub async fn get_admin_oauth_scope(&self) -> ClientResult<()> {
+    pub async fn get_admin_oauth_scope(&self) -> ClientResult<crate::types::AccessScopeList> {
         let url = self.client.url("/admin/oauth/access_scopes.json", None);
         self.client
             .get(
@@ -31,151 +31,66 @@ impl Access {
             .await
     }
     /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/storefront_access_tokens.json` endpoint.
+     * Retrieves a list of access scopes associated to the access token.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-01
-     */
-    pub async fn deprecated_202001_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
+     * This function performs a `GET` to the `/admin/oauth/access_scopes.json` endpoint.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/storefront_access_tokens.json` endpoint.
+     * As opposed to `get_admin_oauth_scopes`, this function returns all the pages of the request at once.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/access/accessscope#index-2020-10
      */
-    pub async fn deprecated_202001_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    pub async fn get_all_admin_oauth_scopes(&self) -> ClientResult<Vec<crate::types::AccessScope>> {
+        futures::TryStreamExt::try_collect(self.get_all_admin_oauth_scopes_stream()).await
     }
     /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     * Retrieves a list of access scopes associated to the access token.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-01
+     * This function performs a `GET` to the `/admin/oauth/access_scopes.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_all_admin_oauth_scopes`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/access/accessscope#index-2020-10
      */
-    pub async fn deprecated_202001_delete_storefront_tokens_param_token(
+    pub fn get_all_admin_oauth_scopes_stream(
         &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AccessScope>> + '_ {
+        let url = self.client.url("/admin/oauth/access_scopes.json", None);
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::AccessScopeList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.access_scopes, next))
+            }
+        })
     }
     /**
      * Retrieves a list of storefront access tokens that have been issued.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-04
-     */
-    pub async fn deprecated_202004_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
+     * This function performs a `GET` to the `/admin/api/{api_version}/storefront_access_tokens.json` endpoint.
      *
-     * This function performs a `POST` to the `/admin/api/2020-04/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-04
-     */
-    pub async fn deprecated_202004_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
      */
-    pub async fn deprecated_202004_delete_storefront_tokens_param_token(
+    pub async fn get_storefront_token(
         &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::StorefrontAccessTokenList> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
+                "/admin/api/{}/storefront_access_tokens.json",
+                self.client.api_version(),
             ),
             None,
         );
         self.client
-            .delete(
+            .get(
                 &url,
                 crate::Message {
                     body: None,
@@ -187,266 +102,71 @@ impl Access {
     /**
      * Retrieves a list of storefront access tokens that have been issued.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/storefront_access_tokens.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-07
-     */
-    pub async fn deprecated_202007_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/storefront_access_tokens.json` endpoint.
+     * As opposed to `get_storefront_tokens`, this function returns all the pages of the request at once.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
      */
-    pub async fn deprecated_202007_create_storefront_tokens(
+    pub async fn get_all_storefront_tokens(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_storefront_tokens_param_token(
-        &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::StorefrontAccessToken>> {
+        futures::TryStreamExt::try_collect(self.get_all_storefront_tokens_stream()).await
     }
     /**
      * Retrieves a list of storefront access tokens that have been issued.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/storefront_access_tokens.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
-     */
-    pub async fn get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/storefront_access_tokens.json` endpoint.
+     * As opposed to `get_all_storefront_tokens`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-10
-     */
-    pub async fn create_storefront_tokens(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
      */
-    pub async fn delete_storefront_tokens_param_token(
+    pub fn get_all_storefront_tokens_stream(
         &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::StorefrontAccessToken>> + '_ {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
+                "/admin/api/{}/storefront_access_tokens.json",
+                self.client.api_version(),
             ),
             None,
         );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2021-01
-     */
-    pub async fn deprecated_202101_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::StorefrontAccessTokenList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.storefront_access_tokens, next))
+            }
+        })
     }
     /**
      * Creates a new storefront access token.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/storefront_access_tokens.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2021-01
-     */
-    pub async fn deprecated_202101_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-10
      */
-    pub async fn deprecated_202101_delete_storefront_tokens_param_token(
+    pub async fn create_storefront_tokens(
         &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
+        body: &crate::types::StorefrontAccessTokenEnvelope,
+    ) -> ClientResult<crate::types::StorefrontAccessTokenEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
+                "/admin/api/{}/storefront_access_tokens.json",
+                self.client.api_version(),
             ),
             None,
         );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-unstable
-     */
-    pub async fn deprecated_unstable_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-unstable
-     */
-    pub async fn deprecated_unstable_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/storefront_access_tokens.json", None);
         self.client
             .post(
                 &url,
@@ -460,21 +180,22 @@ impl Access {
     /**
      * Deletes an existing storefront access token.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-10
      *
      * **Parameters:**
      *
      * * `storefront_access_token_id: &str` -- storefront_access_token_id.
      */
-    pub async fn deprecated_unstable_delete_storefront_tokens_param_token(
+    pub async fn delete_storefront_tokens_param_token(
         &self,
         storefront_access_token_id: &str,
     ) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/storefront_access_tokens/{}/json",
+                "/admin/api/{}/storefront_access_tokens/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(storefront_access_token_id),
             ),
             None,
@@ -14,20 +14,20 @@ impl Billing {
     /**
      * Retrieves a list of application charges.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/application_charges.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_charges.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-10
      *
      * **Parameters:**
      *
      * * `since_id: &str` -- Restrict results to after the specified ID.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202001_get_application_charge(
+    pub async fn get_application_charge(
         &self,
         since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ApplicationChargeList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -36,70 +36,10 @@ impl Billing {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/application_charges.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2020-01
-     */
-    pub async fn deprecated_202001_create_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/application_charges.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves an application charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/application_charges/{application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_application_charges_param_charge(
-        &self,
-        application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(application_charge_id),
+                "/admin/api/{}/application_charges.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -114,238 +54,90 @@ impl Billing {
             )
             .await
     }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    "Activates an accepted application charge. One-time charges are now immediately activated
-    when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/application_charges/{application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#activate-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `application_charge_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_create_application_charges_param_charge_activate(
-        &self,
-        application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
     /**
      * Retrieves a list of application charges.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-04
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_charges.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_application_charges`, this function returns all the pages of the request at once.
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-10
      */
-    pub async fn deprecated_202004_get_application_charge(
+    pub async fn get_all_application_charges(
         &self,
         since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/application_charges.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2020-04
-     */
-    pub async fn deprecated_202004_create_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/application_charges.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::ApplicationCharge>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_application_charges_stream(since_id, fields),
+        )
+        .await
     }
     /**
-     * Retrieves an application charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/application_charges/{application_charge_id}.json` endpoint.
+     * Retrieves a list of application charges.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-04
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_charges.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_all_application_charges`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-10
      */
-    pub async fn deprecated_202004_get_application_charges_param_charge(
+    pub fn get_all_application_charges_stream(
         &self,
-        application_charge_id: &str,
+        since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationCharge>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(application_charge_id),
+                "/admin/api/{}/application_charges.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::ApplicationChargeList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
 
-    "Activates an accepted application charge. One-time charges are now immediately activated
-    when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/application_charges/{application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#activate-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `application_charge_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_create_application_charges_param_charge_activate(
-        &self,
-        application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.application_charges, next))
+            }
+        })
     }
     /**
-     * Retrieves a list of application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-07
+     * Creates an application charge.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/{api_version}/application_charges.json` endpoint.
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2020-10
      */
-    pub async fn deprecated_202007_get_application_charge(
+    pub async fn create_application_charges(
         &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        body: &crate::types::ApplicationChargeEnvelope,
+    ) -> ClientResult<crate::types::ApplicationChargeEnvelope> {
         let url = self.client.url(
-            &format!("/admin/api/2020-07/application_charges.json?{}", query_),
+            &format!(
+                "/admin/api/{}/application_charges.json",
+                self.client.api_version(),
+            ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2020-07
-     */
-    pub async fn deprecated_202007_create_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/application_charges.json", None);
         self.client
             .post(
                 &url,
@@ -359,20 +151,20 @@ impl Billing {
     /**
      * Retrieves an application charge.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/application_charges/{application_charge_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_charges/{application_charge_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-10
      *
      * **Parameters:**
      *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
+     * * `application_charge_id: &str` -- application_charge_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202007_get_application_charges_param_charge(
+    pub async fn get_application_charges_param_charge(
         &self,
         application_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ApplicationChargeEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -380,7 +172,8 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/application_charges/{}/json?{}",
+                "/admin/api/{}/application_charges/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(application_charge_id),
                 query_
             ),
@@ -398,2372 +191,32 @@ impl Billing {
     }
     /**
     * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    "Activates an accepted application charge. One-time charges are now immediately activated
-    when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/application_charges/{application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#activate-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `application_charge_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_create_application_charges_param_charge_activate(
-        &self,
-        application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_application_charge(&self, since_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/application_charges.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2020-10
-     */
-    pub async fn create_application_charges(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/application_charges.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves an application charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/application_charges/{application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_application_charges_param_charge(
-        &self,
-        application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    "Activates an accepted application charge. One-time charges are now immediately activated
-    when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/application_charges/{application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#activate-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `application_charge_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn create_application_charges_param_charge_activate(
-        &self,
-        application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/application_charges.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-2021-01
-     */
-    pub async fn deprecated_202101_create_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/application_charges.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves an application charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/application_charges/{application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_application_charges_param_charge(
-        &self,
-        application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/application_charges.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#create-unstable
-     */
-    pub async fn deprecated_unstable_create_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/application_charges.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves an application charge.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/application_charges/{application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_application_charges_param_charge(
-        &self,
-        application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_application_credit(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2020-01
-     */
-    pub async fn deprecated_202001_create_application_credits(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_application_credit(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2020-04
-     */
-    pub async fn deprecated_202004_create_application_credits(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_application_credit(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2020-07
-     */
-    pub async fn deprecated_202007_create_application_credits(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_application_credit(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2020-10
-     */
-    pub async fn create_application_credits(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_application_credit(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2021-01
-     */
-    pub async fn deprecated_202101_create_application_credits(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all application credits.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_application_credit(
-        &self,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/application_credits.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an application credit.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/application_credits.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-unstable
-     */
-    pub async fn deprecated_unstable_create_application_credits(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/application_credits.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single application credit.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/application_credits/{application_credit_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_application_credits_param_credit(
-        &self,
-        application_credit_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/application_credits/{}/json?{}",
-                crate::progenitor_support::encode_path(application_credit_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2020-01
-     */
-    pub async fn deprecated_202001_create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/2020-01/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202001_delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    Activates a previously accepted recurring application charge. Recurring charges are now
-    immediately activated when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#activate-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-    */
-    pub async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
-        &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2020-04
-     */
-    pub async fn deprecated_202004_create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/2020-04/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202004_delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    Activates a previously accepted recurring application charge. Recurring charges are now
-    immediately activated when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#activate-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-    */
-    pub async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
-        &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2020-07
-     */
-    pub async fn deprecated_202007_create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/2020-07/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202007_delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    Activates a previously accepted recurring application charge. Recurring charges are now
-    immediately activated when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#activate-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-    */
-    pub async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
-        &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2020-10
-     */
-    pub async fn create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/2020-10/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      This endpoint is no longer required and is deprecated as of
-      API version 2021-01.
-
-    Activates a previously accepted recurring application charge. Recurring charges are now
-    immediately activated when approved by a merchant.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/activate.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#activate-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-    */
-    pub async fn create_recurring_application_charges_param_charge_activate(
-        &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/activate.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2021-01
-     */
-    pub async fn deprecated_202101_create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/2021-01/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202101_delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_recurring_application_charge(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/recurring_application_charges.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a recurring application charge.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/recurring_application_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-unstable
-     */
-    pub async fn deprecated_unstable_create_recurring_application_charges(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            "/admin/api/unstable/recurring_application_charges.json",
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a recurring application charge.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
-        &self,
-        recurring_application_charge_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the capped amount of an active recurring application charge.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-unstable
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
-        &self,
-        recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
-            query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
-                recurring_application_charge_capped_amount.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/customize.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of usage charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a usage charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
-        &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single charge.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
-        &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                crate::progenitor_support::encode_path(usage_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of usage charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
-        &self,
-        recurring_application_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a usage charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+      This endpoint is no longer required and is deprecated as of
+      API version 2021-01.
+
+    "Activates an accepted application charge. One-time charges are now immediately activated
+    when approved by a merchant.
+    *
+    * This function performs a `POST` to the `/admin/api/{api_version}/application_charges/{application_charge_id}/activate.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#activate-2020-10
+    *
+    * Only available in API versions 2020-01, 2020-04, 2020-07, 2020-10.
+    *
+    * **Parameters:**
+    *
+    * * `application_charge_id: &str` -- application_charge_id.
+    */
+    pub async fn create_application_charges_param_charge_activate(
         &self,
-        recurring_application_charge_id: &str,
+        application_charge_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ApplicationChargeEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                "/admin/api/{}/application_charges/{}/activate.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(application_charge_id),
             ),
             None,
         );
@@ -2778,24 +231,20 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a single charge.
+     * Retrieves all application credits.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_credits.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-10
      *
      * **Parameters:**
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn get_application_credit(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ApplicationCreditList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -2803,9 +252,8 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                crate::progenitor_support::encode_path(usage_charge_id),
+                "/admin/api/{}/application_credits.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -2821,22 +269,33 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a list of usage charges.
+     * Retrieves all application credits.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_credits.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-07
+     * As opposed to `get_application_credits`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-10
+     */
+    pub async fn get_all_application_credits(
+        &self,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::ApplicationCredit>> {
+        futures::TryStreamExt::try_collect(self.get_all_application_credits_stream(fields)).await
+    }
+    /**
+     * Retrieves all application credits.
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_credits.json` endpoint.
+     *
+     * As opposed to `get_all_application_credits`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-10
      */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+    pub fn get_all_application_credits_stream(
         &self,
-        recurring_application_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationCredit>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -2844,42 +303,44 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                "/admin/api/{}/application_credits.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::ApplicationCreditList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.application_credits, next))
+            }
+        })
     }
     /**
-     * Creates a usage charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2020-07
+     * Creates an application credit.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/{api_version}/application_credits.json` endpoint.
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#create-2020-10
      */
-    pub async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+    pub async fn create_application_credits(
         &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::ApplicationCreditEnvelope,
+    ) -> ClientResult<crate::types::ApplicationCreditEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                "/admin/api/{}/application_credits.json",
+                self.client.api_version(),
             ),
             None,
         );
@@ -2894,24 +355,22 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a single charge.
+     * Retrieves a single application credit.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/application_credits/{application_credit_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-10
      *
      * **Parameters:**
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
+     * * `application_credit_id: &str` -- application_credit_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn get_application_credits_param_credit(
         &self,
-        recurring_application_charge_id: &str,
-        usage_charge_id: &str,
+        application_credit_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ApplicationCreditEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -2919,9 +378,9 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
-                crate::progenitor_support::encode_path(usage_charge_id),
+                "/admin/api/{}/application_credits/{}.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(application_credit_id),
                 query_
             ),
             None,
@@ -2937,31 +396,34 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a list of usage charges.
+     * Retrieves a list of recurring application charges.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-10
      *
      * **Parameters:**
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
+     * * `since_id: &str` -- Restrict results to after the specified ID.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn get_recurring_application_charges_param_charge_usage(
+    pub async fn get_recurring_application_charge(
         &self,
-        recurring_application_charge_id: &str,
+        since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::RecurringApplicationChargeList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json?{}",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                "/admin/api/{}/recurring_application_charges.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -2977,25 +439,87 @@ impl Billing {
             .await
     }
     /**
-     * Creates a usage charge.
+     * Retrieves a list of recurring application charges.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2020-10
+     * As opposed to `get_recurring_application_charges`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-10
+     */
+    pub async fn get_all_recurring_application_charges(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::RecurringApplicationCharge>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_recurring_application_charges_stream(since_id, fields),
+        )
+        .await
+    }
+    /**
+     * Retrieves a list of recurring application charges.
      *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `get_all_recurring_application_charges`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-10
      */
-    pub async fn create_recurring_application_charges_param_charge_usage(
+    pub fn get_all_recurring_application_charges_stream(
         &self,
-        recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        since_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RecurringApplicationCharge>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
-                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                "/admin/api/{}/recurring_application_charges.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::RecurringApplicationChargeList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.recurring_application_charges, next))
+            }
+        })
+    }
+    /**
+     * Creates a recurring application charge.
+     *
+     * This function performs a `POST` to the `/admin/api/{api_version}/recurring_application_charges.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#create-2020-10
+     */
+    pub async fn create_recurring_application_charges(
+        &self,
+        body: &crate::types::RecurringApplicationChargeEnvelope,
+    ) -> ClientResult<crate::types::RecurringApplicationChargeEnvelope> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/recurring_application_charges.json",
+                self.client.api_version(),
             ),
             None,
         );
@@ -3012,22 +536,20 @@ impl Billing {
     /**
      * Retrieves a single charge.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn get_recurring_application_charges_param_charge(
         &self,
         recurring_application_charge_id: &str,
-        usage_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::RecurringApplicationChargeEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -3035,9 +557,9 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                "/admin/api/{}/recurring_application_charges/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
-                crate::progenitor_support::encode_path(usage_charge_id),
                 query_
             ),
             None,
@@ -3053,37 +575,30 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a list of usage charges.
+     * Cancels a recurring application charge.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#destroy-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+    pub async fn delete_recurring_application_charges_param_charge(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json?{}",
+                "/admin/api/{}/recurring_application_charges/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
-                query_
             ),
             None,
         );
         self.client
-            .get(
+            .delete(
                 &url,
                 crate::Message {
                     body: None,
@@ -3093,24 +608,32 @@ impl Billing {
             .await
     }
     /**
-     * Creates a usage charge.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     */
-    pub async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+    * Caution
+      This endpoint is no longer required and is deprecated as of
+      API version 2021-01.
+
+    Activates a previously accepted recurring application charge. Recurring charges are now
+    immediately activated when approved by a merchant.
+    *
+    * This function performs a `POST` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/activate.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#activate-2020-10
+    *
+    * Only available in API versions 2020-01, 2020-04, 2020-07, 2020-10.
+    *
+    * **Parameters:**
+    *
+    * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
+    */
+    pub async fn create_recurring_application_charges_param_charge_activate(
         &self,
         recurring_application_charge_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::RecurringApplicationChargeEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json",
+                "/admin/api/{}/recurring_application_charges/{}/activate.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
             ),
             None,
@@ -3126,40 +649,41 @@ impl Billing {
             .await
     }
     /**
-     * Retrieves a single charge.
+     * Updates the capped amount of an active recurring application charge.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
      */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn update_recurring_application_charges_param_charge_customize(
         &self,
         recurring_application_charge_id: &str,
-        usage_charge_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::types::RecurringApplicationChargeEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if recurring_application_charge_capped_amount > 0 {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                recurring_application_charge_capped_amount.to_string(),
+            ));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                "/admin/api/{}/recurring_application_charges/{}/customize.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
-                crate::progenitor_support::encode_path(usage_charge_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .put(
                 &url,
                 crate::Message {
                     body: None,
@@ -3171,20 +695,20 @@ impl Billing {
     /**
      * Retrieves a list of usage charges.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+    pub async fn get_recurring_application_charges_param_charge_usage(
         &self,
         recurring_application_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::UsageChargeList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -3192,7 +716,8 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json?{}",
+                "/admin/api/{}/recurring_application_charges/{}/usage_charges.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
                 query_
             ),
@@ -3208,25 +733,93 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `get_recurring_application_charges_param_charge_usage`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-10
+     */
+    pub async fn get_all_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::UsageCharge>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_recurring_application_charges_param_charge_usage_stream(
+                recurring_application_charge_id,
+                fields,
+            ),
+        )
+        .await
+    }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `get_all_recurring_application_charges_param_charge_usage`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-10
+     */
+    pub fn get_all_recurring_application_charges_param_charge_usage_stream(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsageCharge>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/recurring_application_charges/{}/usage_charges.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::UsageChargeList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.usage_charges, next))
+            }
+        })
+    }
     /**
      * Creates a usage charge.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#create-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      */
-    pub async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+    pub async fn create_recurring_application_charges_param_charge_usage(
         &self,
         recurring_application_charge_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::UsageChargeEnvelope,
+    ) -> ClientResult<crate::types::UsageChargeEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json",
+                "/admin/api/{}/recurring_application_charges/{}/usage_charges.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
             ),
             None,
@@ -3244,22 +837,22 @@ impl Billing {
     /**
      * Retrieves a single charge.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-10
      *
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `usage_charge_id: &str` -- storefront_access_token_id.
+     * * `usage_charge_id: &str` -- usage_charge_id.
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn get_recurring_application_charges_param_charge_usage_billing(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::UsageChargeEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -3267,7 +860,8 @@ impl Billing {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                "/admin/api/{}/recurring_application_charges/{}/usage_charges/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(recurring_application_charge_id),
                 crate::progenitor_support::encode_path(usage_charge_id),
                 query_
@@ -14,35 +14,20 @@ impl DeprecatedApiCalls {
     /**
      * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/deprecated_api_calls.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/deprecated_api_calls.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-2021-01
-     */
-    pub async fn deprecated_202101_get_call(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/deprecated_api_calls.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/deprecated_api_calls.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-unstable
+     * Only available in API versions 2021-01, unstable.
      */
-    pub async fn deprecated_unstable_get_call(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/deprecated_api_calls.json", None);
+    pub async fn get_deprecated_call(&self) -> ClientResult<()> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/deprecated_api_calls.json",
+                self.client.api_version(),
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
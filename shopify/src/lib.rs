@@ -32,14 +32,15 @@
 //! a user agent string and set of credentials.
 //!
 //! ```
-//! use shopify::Client;
+//! use shopify::{Client, RootDefaultServer};
 //!
 //! let shopify = Client::new(
 //!     String::from("client-id"),
 //!     String::from("client-secret"),
 //!     String::from("redirect-uri"),
 //!     String::from("token"),
-//!     String::from("refresh-token")
+//!     String::from("refresh-token"),
+//!     RootDefaultServer::new("shop")
 //! );
 //! ```
 //!
@@ -53,11 +54,12 @@
 //! And then you can create a client from the environment.
 //!
 //! ```
-//! use shopify::Client;
+//! use shopify::{Client, RootDefaultServer};
 //!
 //! let shopify = Client::new_from_env(
 //!     String::from("token"),
-//!     String::from("refresh-token")
+//!     String::from("refresh-token"),
+//!     RootDefaultServer::new("shop")
 //! );
 //! ```
 //!
@@ -67,10 +69,10 @@
 //! To start off a fresh client and get a `token` and `refresh_token`, use the following.
 //!
 //! ```
-//! use shopify::Client;
+//! use shopify::{Client, RootDefaultServer};
 //!
 //! async fn do_call() {
-//!     let mut shopify = Client::new_from_env("", "");
+//!     let mut shopify = Client::new_from_env("", "", RootDefaultServer::new("shop"));
 //!
 //!     // Get the URL to request consent from the user.
 //!     // You can optionally pass in scopes. If none are provided, then the
@@ -115,6 +117,7 @@ pub mod shipping_and_fulfillment;
 pub mod shopify_payments;
 pub mod store_properties;
 pub mod tendertransaction;
+pub mod throttle;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
@@ -138,6 +141,9 @@ pub enum ClientError {
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde URL encoding error
+    #[error(transparent)]
+    SerdeUrlencodedSerError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -155,7 +161,7 @@ pub enum ClientError {
     },
 }
 
-pub const FALLBACK_HOST: &str = "https://{shop}.myshopify.com/admin/api/2021-07";
+pub const FALLBACK_HOST: &str = "https://{shop}.myshopify.com";
 
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
@@ -193,6 +199,71 @@ use tokio::sync::RwLock;
 const TOKEN_ENDPOINT: &str = "https://{shop}.myshopify.com/admin/oauth/access_token";
 const USER_CONSENT_ENDPOINT: &str = "https://{shop}.myshopify.com/admin/oauth/authorize";
 
+#[derive(Debug, Clone)]
+pub struct RootDefaultServer {
+    pub shop: String,
+}
+
+impl RootDefaultServer {
+    pub fn new(shop: impl ToString) -> Self {
+        RootDefaultServer {
+            shop: shop.to_string(),
+        }
+    }
+
+    pub fn default_url(&self) -> String {
+        self.substitute("https://{shop}.myshopify.com")
+    }
+
+    /// Fill the server variables into `url`.
+    pub fn substitute(&self, url: &str) -> String {
+        url.replace("{shop}", &self.shop)
+    }
+}
+
+/// A release of the API, which every request is made against, e.g. `2021-07`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiVersion(String);
+
+impl ApiVersion {
+    pub fn new<V>(version: V) -> Self
+    where
+        V: ToString,
+    {
+        ApiVersion(version.to_string())
+    }
+
+    /// The release still being worked on, which may change without notice.
+    pub fn unstable() -> Self {
+        ApiVersion::new("unstable")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for ApiVersion {
+    /// The release this crate was generated for.
+    fn default() -> Self {
+        ApiVersion::new("2021-07")
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for ApiVersion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ApiVersion::new(s))
+    }
+}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
@@ -202,7 +273,9 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-
+    token_endpoint: String,
+    user_consent_endpoint: String,
+    api_version: ApiVersion,
     auto_refresh: bool,
     client: reqwest_middleware::ClientWithMiddleware,
 }
@@ -267,6 +340,7 @@ impl Client {
         redirect_uri: R,
         token: T,
         refresh_token: Q,
+        server: RootDefaultServer,
     ) -> Self
     where
         I: ToString,
@@ -286,12 +360,14 @@ impl Client {
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
                     .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
+                        crate::throttle::RetryTransient::new_with_policy(retry_policy),
                         |req: &reqwest::Request| req.try_clone().is_some(),
                     ))
+                    // Keep under the store's rate limit.
+                    .with(crate::throttle::Throttle::default())
                     .build();
 
-                let host = FALLBACK_HOST.to_string();
+                let host = server.default_url();
 
                 Client {
                     host,
@@ -304,7 +380,9 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
-
+                    token_endpoint: server.substitute(TOKEN_ENDPOINT),
+                    user_consent_endpoint: server.substitute(USER_CONSENT_ENDPOINT),
+                    api_version: ApiVersion::default(),
                     auto_refresh: false,
                     client,
                 }
@@ -408,7 +486,7 @@ impl Client {
     /// given a valid API key and your requests will work.
     /// We pass in the token and refresh token to the client so if you are storing
     /// it in a database, you can get it first.
-    pub fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
+    pub fn new_from_env<T, R>(token: T, refresh_token: R, server: RootDefaultServer) -> Self
     where
         T: ToString,
         R: ToString,
@@ -418,7 +496,25 @@ impl Client {
             env::var("SHOPIFY_CLIENT_SECRET").expect("must set SHOPIFY_CLIENT_SECRET");
         let redirect_uri = env::var("SHOPIFY_REDIRECT_URI").expect("must set SHOPIFY_REDIRECT_URI");
 
-        Client::new(client_id, client_secret, redirect_uri, token, refresh_token)
+        Client::new(
+            client_id,
+            client_secret,
+            redirect_uri,
+            token,
+            refresh_token,
+            server,
+        )
+    }
+
+    /// Make requests against another release of the API.
+    pub fn with_api_version(&mut self, api_version: ApiVersion) -> &mut Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// The release of the API requests are made against.
+    pub fn api_version(&self) -> &ApiVersion {
+        &self.api_version
     }
 
     /// Return a user consent url with an optional set of scopes.
@@ -428,7 +524,7 @@ impl Client {
 
         let url = format!(
             "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
+            self.user_consent_endpoint, self.client_id, self.redirect_uri, state
         );
 
         if scopes.is_empty() {
@@ -464,7 +560,7 @@ impl Client {
             ];
             let client = reqwest::Client::new();
             client
-                .post(TOKEN_ENDPOINT)
+                .post(&self.token_endpoint)
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -505,7 +601,7 @@ impl Client {
         ];
         let client = reqwest::Client::new();
         let resp = client
-            .post(TOKEN_ENDPOINT)
+            .post(&self.token_endpoint)
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -705,6 +801,19 @@ impl Client {
     /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        self.request_form(http::Method::POST, uri, form).await
+    }
+
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: http::Method,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<Out>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -712,7 +821,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(http::Method::POST, url);
+        let mut req = instance.client.request(method, url);
 
         // Set the default headers.
         req = req.header(
@@ -908,6 +1017,84 @@ impl Client {
         }
     }
 
+    /* TODO: make this more DRY */
+    /// Make a request for a file, handing back the raw body rather than parsing it.
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        message: Message,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::utils::Download> {
+        let (url, auth) = self.url_and_auth(uri).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        // Set the default headers.
+        req = req.header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+        );
+
+        if let Some(content_type) = &message.content_type {
+            req = req.header(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_str(content_type)?,
+            );
+        }
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = message.body {
+            req = req.body(body);
+        }
+
+        let response = req.send().await?;
+
+        let status = response.status();
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+        let filename = header(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|v| crate::utils::content_disposition_filename(&v));
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!("Received successful response. Read payload.");
+            Ok(crate::utils::Download {
+                body: response_body,
+                content_type,
+                filename,
+            })
+        } else {
+            let error = if response_body.is_empty() {
+                ClientError::HttpError {
+                    status,
+                    error: "empty response".into(),
+                }
+            } else {
+                ClientError::HttpError {
+                    status,
+                    error: String::from_utf8_lossy(&response_body).into(),
+                }
+            };
+
+            Err(error)
+        }
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -929,36 +1116,27 @@ impl Client {
         self.request_entity(http::Method::GET, uri, message).await
     }
 
+    /// Lazily walk the pages of a request that links to the next page in its headers.
     #[allow(dead_code)]
-    async fn get_all_pages<D>(&self, uri: &str, _message: Message) -> ClientResult<Vec<D>>
+    fn get_all_pages_stream<D>(
+        &self,
+        uri: &str,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + '_
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        // TODO: implement this.
-        self.unfold(uri).await
-    }
+        crate::utils::paginate(uri.to_string(), move |url| async move {
+            let (link, items) = self.get_pages(&url).await?;
 
-    /// "unfold" paginated results of a vector of items
-    #[allow(dead_code)]
-    async fn unfold<D>(&self, uri: &str) -> ClientResult<Vec<D>>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref() {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
+            // An empty page is the last page, whatever the headers say.
+            let next = if items.is_empty() {
+                None
+            } else {
+                link.map(|l| l.0)
+            };
 
-        Ok(global_items)
+            Ok((items, next))
+        })
     }
 
     #[allow(dead_code)]
@@ -973,18 +1151,6 @@ impl Client {
             .await
     }
 
-    #[allow(dead_code)]
-    async fn get_pages_url<D>(
-        &self,
-        url: &reqwest::Url,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request_with_links(http::Method::GET, url.as_str(), Message::default())
-            .await
-    }
-
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
     where
@@ -14,13 +14,13 @@ impl OnlineStore {
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}/articles.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
      * * `limit: &str` -- The maximum number of results to retrieve.
      *                     (default: 50, maximum: 250).
      * * `since_id: &str` -- Restrict results to after the specified ID.
@@ -41,7 +41,7 @@ impl OnlineStore {
      * * `author: &str` -- Filter articles by article author.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_article(
+    pub async fn get_blogs_param_blog_article(
         &self,
         blog_id: &str,
         limit: &str,
@@ -57,7 +57,7 @@ impl OnlineStore {
         tag: &str,
         author: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ArticleList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !author.is_empty() {
             query_args.push(("author".to_string(), author.to_string()));
@@ -101,7 +101,8 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles.json?{}",
+                "/admin/api/{}/blogs/{}/articles.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 query_
             ),
@@ -117,25 +118,154 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `get_blogs_param_blog_articles`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-10
+     */
+    pub async fn get_all_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Article>> {
+        futures::TryStreamExt::try_collect(self.get_all_blogs_param_blog_articles_stream(
+            blog_id,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        ))
+        .await
+    }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `get_all_blogs_param_blog_articles`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-10
+     */
+    pub fn get_all_blogs_param_blog_articles_stream(
+        &self,
+        blog_id: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Article>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !author.is_empty() {
+            query_args.push(("author".to_string(), author.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !tag.is_empty() {
+            query_args.push(("tag".to_string(), tag.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/blogs/{}/articles.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::ArticleList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.articles, next))
+            }
+        })
+    }
     /**
      * Creates an article for a blog.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/blogs/{blog_id}/articles.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/blogs/{blog_id}/articles.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
      */
-    pub async fn deprecated_202001_create_blogs_param_blog_articles(
+    pub async fn create_blogs_param_blog_articles(
         &self,
         blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::ArticleEnvelope,
+    ) -> ClientResult<crate::types::ArticleEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles.json",
+                "/admin/api/{}/blogs/{}/articles.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
             ),
             None,
@@ -153,13 +283,13 @@ impl OnlineStore {
     /**
      * Retrieves a count of all articles from a blog.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}/articles/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
      * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
      * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
      * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
@@ -173,7 +303,7 @@ impl OnlineStore {
      *                           unpublished: Count only unpublished articles.
      *                           any: Count all articles.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_articles_count(
+    pub async fn get_blogs_param_blog_articles_count(
         &self,
         blog_id: &str,
         created_at_min: &str,
@@ -183,7 +313,7 @@ impl OnlineStore {
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !created_at_max.is_empty() {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
@@ -209,7 +339,8 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles/count.json?{}",
+                "/admin/api/{}/blogs/{}/articles/count.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 query_
             ),
@@ -228,22 +359,22 @@ impl OnlineStore {
     /**
      * Retrieves a single article.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}/articles/{article_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
+     * * `article_id: &str` -- article_id.
      * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_articles_article(
+    pub async fn get_blogs_param_blog_articles_article(
         &self,
         blog_id: &str,
         article_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ArticleEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -251,7 +382,8 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles/{}/json?{}",
+                "/admin/api/{}/blogs/{}/articles/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 crate::progenitor_support::encode_path(article_id),
                 query_
@@ -271,24 +403,25 @@ impl OnlineStore {
     /**
      * Updates an article.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/blogs/{blog_id}/articles/{article_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
+     * * `article_id: &str` -- article_id.
      */
-    pub async fn deprecated_202001_update_blogs_param_blog_articles_article(
+    pub async fn update_blogs_param_blog_articles_article(
         &self,
         blog_id: &str,
         article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::ArticleEnvelope,
+    ) -> ClientResult<crate::types::ArticleEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles/{}/json",
+                "/admin/api/{}/blogs/{}/articles/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 crate::progenitor_support::encode_path(article_id),
             ),
@@ -307,23 +440,24 @@ impl OnlineStore {
     /**
      * Deletes an article.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/blogs/{blog_id}/articles/{article_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
+     * * `article_id: &str` -- article_id.
      */
-    pub async fn deprecated_202001_delete_blogs_param_blog_articles_article(
+    pub async fn delete_blogs_param_blog_articles_article(
         &self,
         blog_id: &str,
         article_id: &str,
     ) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/blogs/{}/articles/{}/json",
+                "/admin/api/{}/blogs/{}/articles/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 crate::progenitor_support::encode_path(article_id),
             ),
@@ -342,14 +476,18 @@ impl OnlineStore {
     /**
      * Retrieves a list all of article authors.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/articles/authors.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/articles/authors.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2020-10
      */
-    pub async fn deprecated_202001_get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/articles/authors.json", None);
+    pub async fn get_articles_author(&self) -> ClientResult<()> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/articles/authors.json",
+                self.client.api_version(),
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -363,20 +501,16 @@ impl OnlineStore {
     /**
      * Retrieves a list of all the tags.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/articles/tags.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/articles/tags.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-10
      *
      * **Parameters:**
      *
      * * `limit: &str` -- The maximum number of tags to retrieve.
      * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
      */
-    pub async fn deprecated_202001_get_articles_tag(
-        &self,
-        limit: &str,
-        popular: &str,
-    ) -> ClientResult<()> {
+    pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> ClientResult<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -386,7 +520,11 @@ impl OnlineStore {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-01/articles/tags.json?{}", query_),
+            &format!(
+                "/admin/api/{}/articles/tags.json?{}",
+                self.client.api_version(),
+                query_
+            ),
             None,
         );
         self.client
@@ -400,97 +538,39 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_blogs_param_blog_article(
+    * Retrieves a single asset for a theme by its key.
+              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+              For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/{api_version}/themes/{theme_id}/assets.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-10
+    *
+    * **Parameters:**
+    *
+    * * `theme_id: &str` -- theme_id.
+    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `asset_key: &str` -- asset[key].
+    */
+    pub async fn get_themes_param_theme_asset(
         &self,
-        blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
+        theme_id: &str,
         fields: &str,
+        asset_key: &str,
     ) -> ClientResult<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/blogs/{}/articles.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
+                "/admin/api/{}/themes/{}/assets.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(theme_id),
                 query_
             ),
             None,
@@ -506,30 +586,45 @@ impl OnlineStore {
             .await
     }
     /**
-     * Creates an article for a blog.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_blogs_param_blog_articles(
+    * Creates or updates an asset for a theme.
+              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/{api_version}/themes/{theme_id}/assets.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-10
+    *
+    * **Parameters:**
+    *
+    * * `theme_id: &str` -- theme_id.
+    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    */
+    pub async fn update_themes_param_theme_assets(
         &self,
-        blog_id: &str,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !source_key.is_empty() {
+            query_args.push(("source_key".to_string(), source_key.to_string()));
+        }
+        if !src.is_empty() {
+            query_args.push(("src".to_string(), src.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/blogs/{}/articles.json",
-                crate::progenitor_support::encode_path(blog_id),
+                "/admin/api/{}/themes/{}/assets.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(theme_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .put(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -539,72 +634,38 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a count of all articles from a blog.
+     * Deletes an asset from a theme.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles/count.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/themes/{theme_id}/assets.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `theme_id: &str` -- theme_id.
+     * * `asset_key: &str` -- asset[key].
      */
-    pub async fn deprecated_202004_get_blogs_param_blog_articles_count(
+    pub async fn delete_themes_param_theme_assets(
         &self,
-        blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        theme_id: &str,
+        asset_key: &str,
     ) -> ClientResult<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/blogs/{}/articles/count.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
+                "/admin/api/{}/themes/{}/assets.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(theme_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .delete(
                 &url,
                 crate::Message {
                     body: None,
@@ -614,34 +675,47 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a single article.
+     * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#index-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-04
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `limit: &str` -- The maximum number of results to retrieve.
+     *                     (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `handle: &str` -- Filter by blog handle.
+     * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202004_get_blogs_param_blog_articles_article(
+    pub async fn get_blog(
         &self,
-        blog_id: &str,
-        article_id: &str,
+        limit: &str,
+        since_id: &str,
+        handle: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::BlogList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/blogs/{}/articles/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
+                "/admin/api/{}/blogs.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -657,124 +731,114 @@ impl OnlineStore {
             .await
     }
     /**
-     * Updates an article.
+     * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2020-04
+     * As opposed to `get_blogs`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#index-2020-01
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_202004_update_blogs_param_blog_articles_article(
+    pub async fn get_all_blogs(
         &self,
-        blog_id: &str,
-        article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Blog>> {
+        futures::TryStreamExt::try_collect(self.get_all_blogs_stream(since_id, handle, fields))
             .await
     }
     /**
-     * Deletes an article.
+     * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2020-04
+     * As opposed to `get_all_blogs`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#index-2020-01
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_202004_delete_blogs_param_blog_articles_article(
+    pub fn get_all_blogs_stream(
         &self,
-        blog_id: &str,
-        article_id: &str,
-    ) -> ClientResult<()> {
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Blog>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
+                "/admin/api/{}/blogs.json?{}",
+                self.client.api_version(),
+                query_
             ),
             None,
         );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::BlogList) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.blogs, next))
+            }
+        })
     }
     /**
-     * Retrieves a list all of article authors.
+     * Create a new blog.
+     *
+     * This function performs a `POST` to the `/admin/api/{api_version}/blogs.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/articles/authors.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#create-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2020-04
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_202004_get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/articles/authors.json", None);
+    pub async fn create_blogs(
+        &self,
+        body: &crate::types::BlogEnvelope,
+    ) -> ClientResult<crate::types::BlogEnvelope> {
+        let url = self.client.url(
+            &format!("/admin/api/{}/blogs.json", self.client.api_version(),),
+            None,
+        );
         self.client
-            .get(
+            .post(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of all the tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/articles/tags.json` endpoint.
+     * Get a count of all blogs.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-04
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/count.json` endpoint.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#count-2020-01
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_202004_get_articles_tag(
-        &self,
-        limit: &str,
-        popular: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub async fn get_blogs_count(&self) -> ClientResult<crate::types::Count> {
         let url = self.client.url(
-            &format!("/admin/api/2020-04/articles/tags.json?{}", query_),
+            &format!("/admin/api/{}/blogs/count.json", self.client.api_version(),),
             None,
         );
         self.client
@@ -788,96 +852,33 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Get a single blog by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/blogs/{blog_id}.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#show-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-07
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `blog_id: &str` -- blog_id.
+     * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202007_get_blogs_param_blog_article(
+    pub async fn get_blogs_param_blog(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::BlogEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/blogs/{}/articles.json?{}",
+                "/admin/api/{}/blogs/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
                 query_
             ),
@@ -894,30 +895,33 @@ impl OnlineStore {
             .await
     }
     /**
-     * Creates an article for a blog.
+     * Update a blog.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/blogs/{blog_id}/articles.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/blogs/{blog_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#update-2020-01
+     *
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
      */
-    pub async fn deprecated_202007_create_blogs_param_blog_articles(
+    pub async fn update_blogs_param_blog(
         &self,
         blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::BlogEnvelope,
+    ) -> ClientResult<crate::types::BlogEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/blogs/{}/articles.json",
+                "/admin/api/{}/blogs/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
             ),
             None,
         );
         self.client
-            .post(
+            .put(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -927,181 +931,24 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a count of all articles from a blog.
+     * Delete a blog.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles/count.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/blogs/{blog_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#destroy-2020-01
+     *
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
-     */
-    pub async fn deprecated_202007_get_blogs_param_blog_articles_count(
-        &self,
-        blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/blogs/{}/articles/count.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single article.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/blogs/{}/articles/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an article.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an article.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
+     * * `blog_id: &str` -- blog_id.
      */
-    pub async fn deprecated_202007_delete_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-    ) -> ClientResult<()> {
+    pub async fn delete_blogs_param_blog(&self, blog_id: &str) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/blogs/{}/articles/{}/json",
+                "/admin/api/{}/blogs/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
             ),
             None,
         );
@@ -1116,98 +963,42 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a list all of article authors.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/articles/authors.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2020-07
-     */
-    pub async fn deprecated_202007_get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/articles/authors.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all the tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/articles/tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-07
-     *
-     * **Parameters:**
+     * Retrieves a list of comments. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
-     */
-    pub async fn deprecated_202007_get_articles_tag(
-        &self,
-        limit: &str,
-        popular: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/articles/tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * This function performs a `GET` to the `/admin/api/{api_version}/comments.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#index-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-10
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
      * * `limit: &str` -- The maximum number of results to retrieve.
      *                     (default: 50, maximum: 250).
      * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
+     * * `created_at_min: &str` -- Show comments created after date (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Show comments created before date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Show comments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show comments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Show comments published after date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Show comments published before date (format: 2014-04-25T16:15:47-04:00).
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `published_status: &str` -- Filter results by their published status.
      *                     (default: any)
      *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     *                           published: Show only published comments.
+     *                           unpublished: Show only unpublished comments.
+     *                           any: Show comments of any published status.
+     * * `status: &str` -- Filter results by their status.
+     *                       
+     *                           pending: Show only pending comments.
+     *                           published: Show only published comments.
+     *                           unapproved: Show only unapproved comments.
+     * * `article_id: i64` -- article_id.
+     * * `blog_id: i64` -- blog_id.
      */
-    pub async fn get_blogs_param_blog_article(
+    pub async fn get_comment(
         &self,
-        blog_id: &str,
         limit: &str,
         since_id: &str,
         created_at_min: &str,
@@ -1216,15 +1007,18 @@ impl OnlineStore {
         updated_at_max: &str,
         published_at_min: &str,
         published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> ClientResult<crate::types::CommentList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+        if article_id > 0 {
+            query_args.push(("article_id".to_string(), article_id.to_string()));
+        }
+        if blog_id > 0 {
+            query_args.push(("blog_id".to_string(), blog_id.to_string()));
         }
         if !created_at_max.is_empty() {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
@@ -1235,9 +1029,6 @@ impl OnlineStore {
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1253,8 +1044,8 @@ impl OnlineStore {
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
         }
         if !updated_at_max.is_empty() {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
@@ -1265,8 +1056,8 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/blogs/{}/articles.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
+                "/admin/api/{}/comments.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -1282,5156 +1073,152 @@ impl OnlineStore {
             .await
     }
     /**
-     * Creates an article for a blog.
+     * Retrieves a list of comments. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/blogs/{blog_id}/articles.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/comments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2020-10
+     * As opposed to `get_comments`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#index-2020-01
      *
-     * * `blog_id: &str` -- storefront_access_token_id.
+     * Only available in API versions 2020-01.
      */
-    pub async fn create_blogs_param_blog_articles(
+    pub async fn get_all_comments(
         &self,
-        blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/blogs/{}/articles.json",
-                crate::progenitor_support::encode_path(blog_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all articles from a blog.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
-     */
-    pub async fn get_blogs_param_blog_articles_count(
-        &self,
-        blog_id: &str,
+        since_id: &str,
         created_at_min: &str,
         created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
         published_at_min: &str,
         published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/blogs/{}/articles/count.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single article.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
-     */
-    pub async fn get_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/blogs/{}/articles/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an article.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an article.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list all of article authors.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/articles/authors.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2020-10
-     */
-    pub async fn get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/articles/authors.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all the tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/articles/tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
-     */
-    pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/articles/tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_blogs_param_blog_article(
-        &self,
-        blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an article for a blog.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_blogs_param_blog_articles(
-        &self,
-        blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles.json",
-                crate::progenitor_support::encode_path(blog_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all articles from a blog.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
-     */
-    pub async fn deprecated_202101_get_blogs_param_blog_articles_count(
-        &self,
-        blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles/count.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single article.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an article.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an article.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list all of article authors.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/articles/authors.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-2021-01
-     */
-    pub async fn deprecated_202101_get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/articles/authors.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all the tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/articles/tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
-     */
-    pub async fn deprecated_202101_get_articles_tag(
-        &self,
-        limit: &str,
-        popular: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/articles/tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_blogs_param_blog_article(
-        &self,
-        blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates an article for a blog.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/blogs/{blog_id}/articles.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_blogs_param_blog_articles(
-        &self,
-        blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles.json",
-                crate::progenitor_support::encode_path(blog_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all articles from a blog.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
-     */
-    pub async fn deprecated_unstable_get_blogs_param_blog_articles_count(
-        &self,
-        blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles/count.json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single article.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an article.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an article.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `article_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
-        &self,
-        blog_id: &str,
-        article_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/blogs/{}/articles/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-                crate::progenitor_support::encode_path(article_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list all of article authors.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/articles/authors.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#authors-unstable
-     */
-    pub async fn deprecated_unstable_get_articles_author(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/articles/authors.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all the tags.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/articles/tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
-     */
-    pub async fn deprecated_unstable_get_articles_tag(
-        &self,
-        limit: &str,
-        popular: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/articles/tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn deprecated_202001_update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn deprecated_202004_update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn deprecated_202007_update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn deprecated_202101_update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a single asset for a theme by its key.
-              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
-              For more information on the key property, see Asset properties.
-    *
-    * This function performs a `GET` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-unstable
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_get_themes_param_theme_asset(
-        &self,
-        theme_id: &str,
-        fields: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-unstable
-    *
-    * **Parameters:**
-    *
-    * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
-    */
-    pub async fn deprecated_unstable_update_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        src: &str,
-        source_key: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an asset from a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_themes_param_theme_assets(
-        &self,
-        theme_id: &str,
-        asset_key: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/themes/{}/assets.json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `handle: &str` -- Filter by blog handle.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_blog(
-        &self,
-        limit: &str,
-        since_id: &str,
-        handle: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-01/blogs.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new blog.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/blogs.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#create-2020-01
-     */
-    pub async fn deprecated_202001_create_blogs(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/blogs.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all blogs.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#count-2020-01
-     */
-    pub async fn deprecated_202001_get_blogs_count(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/blogs/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single blog by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_blogs_param_blog(
-        &self,
-        blog_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/blogs/{}/json?{}",
-                crate::progenitor_support::encode_path(blog_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update a blog.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/blogs/{blog_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_blogs_param_blog(
-        &self,
-        blog_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/blogs/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a blog.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/blogs/{blog_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `blog_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_blogs_param_blog(
-        &self,
-        blog_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/blogs/{}/json",
-                crate::progenitor_support::encode_path(blog_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of comments. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/comments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show comments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show comments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show comments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show comments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show comments published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show comments published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `published_status: &str` -- Filter results by their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published comments.
-     *                           unpublished: Show only unpublished comments.
-     *                           any: Show comments of any published status.
-     * * `status: &str` -- Filter results by their status.
-     *                       
-     *                           pending: Show only pending comments.
-     *                           published: Show only published comments.
-     *                           unapproved: Show only unapproved comments.
-     * * `article_id: i64` -- recurring_application_charge[capped_amount].
-     * * `blog_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202001_get_comment(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        fields: &str,
-        published_status: &str,
-        status: &str,
-        article_id: i64,
-        blog_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
-        }
-        if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/comments.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a comment for an article.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#create-2020-01
-     */
-    pub async fn deprecated_202001_create_comments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/comments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of comments.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/comments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count comments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count comments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count comments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count comments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count comments published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count comments published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve a count of comments with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published comments.
-     *                           unpublished: Count only unpublished comments.
-     *                           any: Count comments of any published status.
-     * * `status: &str` -- Retrieve a count of comments with a given status.
-     *                       
-     *                           pending: Count pending comments.
-     *                           published: Count published comments.
-     *                           unapproved: Count unapproved comments.
-     * * `article_id: i64` -- recurring_application_charge[capped_amount].
-     * * `blog_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202001_get_comments_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        status: &str,
-        article_id: i64,
-        blog_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
-        }
-        if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/comments/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single comment by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/comments/{comment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_comments_param_comment(
-        &self,
-        comment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/json?{}",
-                crate::progenitor_support::encode_path(comment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a comment of an article.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/comments/{comment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_comments_param_comment(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a comment as spam.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments/{comment_id}/spam.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#spam-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_comments_param_comment_spam(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/spam.json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a comment as not spam.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments/{comment_id}/not_spam.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#not_spam-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_comments_param_comment_not_spam(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/not_spam.json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Approves a comment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments/{comment_id}/approve.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#approve-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_comments_param_comment_approve(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/approve.json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a comment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments/{comment_id}/remove.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#remove-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_comments_param_comment_remove(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/remove.json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Restores a previously removed comment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/comments/{comment_id}/restore.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#restore-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `comment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_comments_param_comment_restore(
-        &self,
-        comment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/comments/{}/restore.json",
-                crate::progenitor_support::encode_path(comment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2020-01
-    */
-    pub async fn deprecated_202001_create_redirects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn deprecated_202001_get_redirects_count(
-        &self,
-        path: &str,
-        target: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2020-04
-    */
-    pub async fn deprecated_202004_create_redirects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn deprecated_202004_get_redirects_count(
-        &self,
-        path: &str,
-        target: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2020-07
-    */
-    pub async fn deprecated_202007_create_redirects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn deprecated_202007_get_redirects_count(
-        &self,
-        path: &str,
-        target: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2020-10
-    */
-    pub async fn create_redirects(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn get_redirects_count(&self, path: &str, target: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2021-01
-    */
-    pub async fn deprecated_202101_create_redirects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn deprecated_202101_get_redirects_count(
-        &self,
-        path: &str,
-        target: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/redirects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/redirects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
-              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/redirects.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-unstable
-    */
-    pub async fn deprecated_unstable_create_redirects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/redirects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of URL redirects.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/redirects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
-     */
-    pub async fn deprecated_unstable_get_redirects_count(
-        &self,
-        path: &str,
-        target: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
-        }
-        if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/redirects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/redirects/{}/json?{}",
-                crate::progenitor_support::encode_path(redirect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing redirect.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a redirect.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/redirects/{redirect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `redirect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_redirects_param_redirect(
-        &self,
-        redirect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/redirects/{}/json",
-                crate::progenitor_support::encode_path(redirect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-01
-     */
-    pub async fn deprecated_202001_create_script_tags(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single script tag.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a script tag.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-04
-     */
-    pub async fn deprecated_202004_create_script_tags(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single script tag.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a script tag.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-07
-     */
-    pub async fn deprecated_202007_create_script_tags(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single script tag.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a script tag.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-10
-     */
-    pub async fn create_script_tags(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single script tag.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a script tag.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2021-01
-     */
-    pub async fn deprecated_202101_create_script_tags(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single script tag.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a script tag.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/script_tags/{script_tag_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_script_tags_param_tag(
-        &self,
-        script_tag_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/script_tags.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/script_tags.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-unstable
-     */
-    pub async fn deprecated_unstable_create_script_tags(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/script_tags.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all script tags.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/script_tags/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `src: &str` -- Count only script tags with a given URL.
-     */
-    pub async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/script_tags/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> ClientResult<Vec<crate::types::Comment>> {
+        futures::TryStreamExt::try_collect(self.get_all_comments_stream(
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            fields,
+            published_status,
+            status,
+            article_id,
+            blog_id,
+        ))
+        .await
     }
     /**
-     * Retrieves a single script tag.
+     * Retrieves a list of comments. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/script_tags/{script_tag_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/comments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-unstable
+     * As opposed to `get_all_comments`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#index-2020-01
      *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_unstable_get_script_tags_param_tag(
+    pub fn get_all_comments_stream(
         &self,
-        script_tag_id: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Comment>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if article_id > 0 {
+            query_args.push(("article_id".to_string(), article_id.to_string()));
+        }
+        if blog_id > 0 {
+            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/script_tags/{}/json?{}",
-                crate::progenitor_support::encode_path(script_tag_id),
+                "/admin/api/{}/comments.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::CommentList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.comments, next))
+            }
+        })
     }
     /**
-     * Updates a script tag.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/script_tags/{script_tag_id}.json` endpoint.
+     * Creates a comment for an article.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-unstable
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments.json` endpoint.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#create-2020-01
      *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
+     * Only available in API versions 2020-01.
      */
-    pub async fn deprecated_unstable_update_script_tags_param_tag(
+    pub async fn create_comments(
         &self,
-        script_tag_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::CommentEnvelope,
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
-            ),
+            &format!("/admin/api/{}/comments.json", self.client.api_version(),),
             None,
         );
         self.client
-            .put(
+            .post(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6441,57 +1228,89 @@ impl OnlineStore {
             .await
     }
     /**
-     * Deletes a script tag.
+     * Retrieves a count of comments.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/comments/count.json` endpoint.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/script_tags/{script_tag_id}.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#count-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-unstable
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `script_tag_id: &str` -- storefront_access_token_id.
+     * * `created_at_min: &str` -- Count comments created after date (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Count comments created before date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Count comments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Count comments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Count comments published after date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Count comments published before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Retrieve a count of comments with a given published status.
+     *                     (default: any)
+     *                       
+     *                           published: Count only published comments.
+     *                           unpublished: Count only unpublished comments.
+     *                           any: Count comments of any published status.
+     * * `status: &str` -- Retrieve a count of comments with a given status.
+     *                       
+     *                           pending: Count pending comments.
+     *                           published: Count published comments.
+     *                           unapproved: Count unapproved comments.
+     * * `article_id: i64` -- article_id.
+     * * `blog_id: i64` -- blog_id.
      */
-    pub async fn deprecated_unstable_delete_script_tags_param_tag(
+    pub async fn get_comments_count(
         &self,
-        script_tag_id: &str,
-    ) -> ClientResult<()> {
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> ClientResult<crate::types::Count> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if article_id > 0 {
+            query_args.push(("article_id".to_string(), article_id.to_string()));
+        }
+        if blog_id > 0 {
+            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/script_tags/{}/json",
-                crate::progenitor_support::encode_path(script_tag_id),
+                "/admin/api/{}/comments/count.json?{}",
+                self.client.api_version(),
+                query_
             ),
             None,
         );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of themes.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/themes.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_theme(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-01/themes.json?{}", query_), None);
         self.client
             .get(
                 &url,
@@ -6503,47 +1322,24 @@ impl OnlineStore {
             .await
     }
     /**
-    * Creates a theme by providing the public URL of a ZIP file that contains the theme.
-              A new theme is always unpublished by default. To publish a theme when you create it, include
-              "role": "main" in the POST request. The theme will be published only after all
-              of its files have been extracted and stored by Shopify, which might take a couple of minutes.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/themes.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2020-01
-    */
-    pub async fn deprecated_202001_create_themes(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/themes.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single theme.
+     * Retrieves a single comment by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/comments/{comment_id}.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/themes/{theme_id}.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#show-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-01
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `comment_id: &str` -- comment_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_themes_param_theme(
+    pub async fn get_comments_param_comment(
         &self,
-        theme_id: &str,
+        comment_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -6551,8 +1347,9 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/themes/{}/json?{}",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/comments/{}.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
                 query_
             ),
             None,
@@ -6568,25 +1365,28 @@ impl OnlineStore {
             .await
     }
     /**
-     * Updates an existing theme.
+     * Updates a comment of an article.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-01/themes/{theme_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/comments/{comment_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#update-2020-01
+     *
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `comment_id: &str` -- comment_id.
      */
-    pub async fn deprecated_202001_update_themes_param_theme(
+    pub async fn update_comments_param_comment(
         &self,
-        theme_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        comment_id: &str,
+        body: &crate::types::CommentEnvelope,
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/comments/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
             ),
             None,
         );
@@ -6601,82 +1401,67 @@ impl OnlineStore {
             .await
     }
     /**
-     * Deletes a theme.
+     * Marks a comment as spam.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/themes/{theme_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments/{comment_id}/spam.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#spam-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2020-01
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `comment_id: &str` -- comment_id.
      */
-    pub async fn deprecated_202001_delete_themes_param_theme(
+    pub async fn create_comments_param_comment_spam(
         &self,
-        theme_id: &str,
-    ) -> ClientResult<()> {
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/comments/{}/spam.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
             ),
             None,
         );
         self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of themes.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/themes.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_theme(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-04/themes.json?{}", query_), None);
-        self.client
-            .get(
+            .post(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-    * Creates a theme by providing the public URL of a ZIP file that contains the theme.
-              A new theme is always unpublished by default. To publish a theme when you create it, include
-              "role": "main" in the POST request. The theme will be published only after all
-              of its files have been extracted and stored by Shopify, which might take a couple of minutes.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/themes.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2020-04
-    */
-    pub async fn deprecated_202004_create_themes(
+     * Marks a comment as not spam.
+     *
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments/{comment_id}/not_spam.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#not_spam-2020-01
+     *
+     * Only available in API versions 2020-01.
+     *
+     * **Parameters:**
+     *
+     * * `comment_id: &str` -- comment_id.
+     */
+    pub async fn create_comments_param_comment_not_spam(
         &self,
+        comment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/themes.json", None);
+    ) -> ClientResult<crate::types::CommentEnvelope> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/comments/{}/not_spam.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
+            ),
+            None,
+        );
         self.client
             .post(
                 &url,
@@ -6688,70 +1473,69 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a single theme.
+     * Approves a comment.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/themes/{theme_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments/{comment_id}/approve.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#approve-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-04
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `comment_id: &str` -- comment_id.
      */
-    pub async fn deprecated_202004_get_themes_param_theme(
+    pub async fn create_comments_param_comment_approve(
         &self,
-        theme_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/themes/{}/json?{}",
-                crate::progenitor_support::encode_path(theme_id),
-                query_
+                "/admin/api/{}/comments/{}/approve.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
             ),
             None,
         );
         self.client
-            .get(
+            .post(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Updates an existing theme.
+     * Removes a comment.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/themes/{theme_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments/{comment_id}/remove.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#remove-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2020-04
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `comment_id: &str` -- comment_id.
      */
-    pub async fn deprecated_202004_update_themes_param_theme(
+    pub async fn create_comments_param_comment_remove(
         &self,
-        theme_id: &str,
+        comment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/comments/{}/remove.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
             ),
             None,
         );
         self.client
-            .put(
+            .post(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6761,57 +1545,90 @@ impl OnlineStore {
             .await
     }
     /**
-     * Deletes a theme.
+     * Restores a previously removed comment.
+     *
+     * This function performs a `POST` to the `/admin/api/{api_version}/comments/{comment_id}/restore.json` endpoint.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/themes/{theme_id}.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#restore-2020-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2020-04
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `comment_id: &str` -- comment_id.
      */
-    pub async fn deprecated_202004_delete_themes_param_theme(
+    pub async fn create_comments_param_comment_restore(
         &self,
-        theme_id: &str,
-    ) -> ClientResult<()> {
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::CommentEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/comments/{}/restore.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(comment_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .post(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of themes.
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/themes.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/redirects.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-10
      *
      * **Parameters:**
      *
+     * * `limit: &str` -- The maximum number of results to show.
+     *                     (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `path: &str` -- Show redirects with a given path.
+     * * `target: &str` -- Show redirects with a given target.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202007_get_theme(&self, fields: &str) -> ClientResult<()> {
+    pub async fn get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::RedirectList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !target.is_empty() {
+            query_args.push(("target".to_string(), target.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-07/themes.json?{}", query_), None);
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/redirects.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -6823,20 +1640,94 @@ impl OnlineStore {
             .await
     }
     /**
-    * Creates a theme by providing the public URL of a ZIP file that contains the theme.
-              A new theme is always unpublished by default. To publish a theme when you create it, include
-              "role": "main" in the POST request. The theme will be published only after all
-              of its files have been extracted and stored by Shopify, which might take a couple of minutes.
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/redirects.json` endpoint.
+     *
+     * As opposed to `get_redirects`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-10
+     */
+    pub async fn get_all_redirects(
+        &self,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Redirect>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_redirects_stream(since_id, path, target, fields),
+        )
+        .await
+    }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/redirects.json` endpoint.
+     *
+     * As opposed to `get_all_redirects`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-10
+     */
+    pub fn get_all_redirects_stream(
+        &self,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Redirect>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !target.is_empty() {
+            query_args.push(("target".to_string(), target.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/redirects.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::RedirectList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.redirects, next))
+            }
+        })
+    }
+    /**
+    * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
+              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
     *
-    * This function performs a `POST` to the `/admin/api/2020-07/themes.json` endpoint.
+    * This function performs a `POST` to the `/admin/api/{api_version}/redirects.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2020-07
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#create-2020-10
     */
-    pub async fn deprecated_202007_create_themes(
+    pub async fn create_redirects(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/themes.json", None);
+        body: &crate::types::RedirectEnvelope,
+    ) -> ClientResult<crate::types::RedirectEnvelope> {
+        let url = self.client.url(
+            &format!("/admin/api/{}/redirects.json", self.client.api_version(),),
+            None,
+        );
         self.client
             .post(
                 &url,
@@ -6848,31 +1739,34 @@ impl OnlineStore {
             .await
     }
     /**
-     * Retrieves a single theme.
+     * Retrieves a count of URL redirects.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/redirects/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `path: &str` -- Count redirects with given path.
+     * * `target: &str` -- Count redirects with given target.
      */
-    pub async fn deprecated_202007_get_themes_param_theme(
+    pub async fn get_redirects_count(
         &self,
-        theme_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::types::Count> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !target.is_empty() {
+            query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/themes/{}/json?{}",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/redirects/count.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -6888,144 +1782,170 @@ impl OnlineStore {
             .await
     }
     /**
-     * Updates an existing theme.
+     * Retrieves a single redirect.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/redirects/{redirect_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `redirect_id: &str` -- redirect_id.
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202007_update_themes_param_theme(
+    pub async fn get_redirects_param_redirect(
         &self,
-        theme_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::RedirectEnvelope> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/redirects/{}.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .get(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Deletes a theme.
+     * Updates an existing redirect.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/themes/{theme_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/redirects/{redirect_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#update-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `redirect_id: &str` -- redirect_id.
      */
-    pub async fn deprecated_202007_delete_themes_param_theme(
+    pub async fn update_redirects_param_redirect(
         &self,
-        theme_id: &str,
-    ) -> ClientResult<()> {
+        redirect_id: &str,
+        body: &crate::types::RedirectEnvelope,
+    ) -> ClientResult<crate::types::RedirectEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/redirects/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(redirect_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .put(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of themes.
+     * Deletes a redirect.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/themes.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/redirects/{redirect_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `redirect_id: &str` -- redirect_id.
      */
-    pub async fn get_theme(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-10/themes.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a theme by providing the public URL of a ZIP file that contains the theme.
-              A new theme is always unpublished by default. To publish a theme when you create it, include
-              "role": "main" in the POST request. The theme will be published only after all
-              of its files have been extracted and stored by Shopify, which might take a couple of minutes.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/themes.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2020-10
-    */
-    pub async fn create_themes(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/themes.json", None);
+    pub async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> ClientResult<()> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/redirects/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(redirect_id),
+            ),
+            None,
+        );
         self.client
-            .post(
+            .delete(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Retrieves a single theme.
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/script_tags.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `limit: &str` -- The number of results to return.
+     *                     (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `src: &str` -- Show script tags with this URL.
+     * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> ClientResult<()> {
+    pub async fn get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::ScriptTagList> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !src.is_empty() {
+            query_args.push(("src".to_string(), src.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/themes/{}/json?{}",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/script_tags.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -7041,87 +1961,149 @@ impl OnlineStore {
             .await
     }
     /**
-     * Updates an existing theme.
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-10/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/script_tags.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2020-10
+     * As opposed to `get_script_tags`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-10
+     */
+    pub async fn get_all_script_tags(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::ScriptTag>> {
+        futures::TryStreamExt::try_collect(self.get_all_script_tags_stream(
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        ))
+        .await
+    }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/script_tags.json` endpoint.
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_all_script_tags`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-10
      */
-    pub async fn update_themes_param_theme(
+    pub fn get_all_script_tags_stream(
         &self,
-        theme_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ScriptTag>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !src.is_empty() {
+            query_args.push(("src".to_string(), src.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/script_tags.json?{}",
+                self.client.api_version(),
+                query_
             ),
             None,
         );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::ScriptTagList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.script_tags, next))
+            }
+        })
     }
     /**
-     * Deletes a theme.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/themes/{theme_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2020-10
+     * Creates a new script tag.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/{api_version}/script_tags.json` endpoint.
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-10
      */
-    pub async fn delete_themes_param_theme(&self, theme_id: &str) -> ClientResult<()> {
+    pub async fn create_script_tags(
+        &self,
+        body: &crate::types::ScriptTagEnvelope,
+    ) -> ClientResult<crate::types::ScriptTagEnvelope> {
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
-            ),
+            &format!("/admin/api/{}/script_tags.json", self.client.api_version(),),
             None,
         );
         self.client
-            .delete(
+            .post(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of themes.
+     * Retrieves a count of all script tags.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/themes.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/script_tags/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-10
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `src: &str` -- Count only script tags with a given URL.
      */
-    pub async fn deprecated_202101_get_theme(&self, fields: &str) -> ClientResult<()> {
+    pub async fn get_script_tags_count(&self, src: &str) -> ClientResult<crate::types::Count> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if !src.is_empty() {
+            query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2021-01/themes.json?{}", query_), None);
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/script_tags/count.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -7133,47 +2115,22 @@ impl OnlineStore {
             .await
     }
     /**
-    * Creates a theme by providing the public URL of a ZIP file that contains the theme.
-              A new theme is always unpublished by default. To publish a theme when you create it, include
-              "role": "main" in the POST request. The theme will be published only after all
-              of its files have been extracted and stored by Shopify, which might take a couple of minutes.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/themes.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2021-01
-    */
-    pub async fn deprecated_202101_create_themes(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/themes.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single theme.
+     * Retrieves a single script tag.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/script_tags/{script_tag_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `script_tag_id: &str` -- script_tag_id.
+     * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202101_get_themes_param_theme(
+    pub async fn get_script_tags_param_tag(
         &self,
-        theme_id: &str,
+        script_tag_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ScriptTagEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7181,8 +2138,9 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/themes/{}/json?{}",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/script_tags/{}.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(script_tag_id),
                 query_
             ),
             None,
@@ -7198,25 +2156,26 @@ impl OnlineStore {
             .await
     }
     /**
-     * Updates an existing theme.
+     * Updates a script tag.
      *
-     * This function performs a `PUT` to the `/admin/api/2021-01/themes/{theme_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/script_tags/{script_tag_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#update-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `script_tag_id: &str` -- script_tag_id.
      */
-    pub async fn deprecated_202101_update_themes_param_theme(
+    pub async fn update_script_tags_param_tag(
         &self,
-        theme_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        script_tag_id: &str,
+        body: &crate::types::ScriptTagEnvelope,
+    ) -> ClientResult<crate::types::ScriptTagEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/script_tags/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(script_tag_id),
             ),
             None,
         );
@@ -7231,24 +2190,22 @@ impl OnlineStore {
             .await
     }
     /**
-     * Deletes a theme.
+     * Deletes a script tag.
      *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/themes/{theme_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/script_tags/{script_tag_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `script_tag_id: &str` -- script_tag_id.
      */
-    pub async fn deprecated_202101_delete_themes_param_theme(
-        &self,
-        theme_id: &str,
-    ) -> ClientResult<()> {
+    pub async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/themes/{}/json",
-                crate::progenitor_support::encode_path(theme_id),
+                "/admin/api/{}/script_tags/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(script_tag_id),
             ),
             None,
         );
@@ -7265,23 +2222,28 @@ impl OnlineStore {
     /**
      * Retrieves a list of themes.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/themes.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/themes.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-10
      *
      * **Parameters:**
      *
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_unstable_get_theme(&self, fields: &str) -> ClientResult<()> {
+    pub async fn get_theme(&self, fields: &str) -> ClientResult<crate::types::ThemeList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/unstable/themes.json?{}", query_), None);
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/themes.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -7292,21 +2254,76 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/themes.json` endpoint.
+     *
+     * As opposed to `get_themes`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-10
+     */
+    pub async fn get_all_themes(&self, fields: &str) -> ClientResult<Vec<crate::types::Theme>> {
+        futures::TryStreamExt::try_collect(self.get_all_themes_stream(fields)).await
+    }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/themes.json` endpoint.
+     *
+     * As opposed to `get_all_themes`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-10
+     */
+    pub fn get_all_themes_stream(
+        &self,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Theme>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/themes.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::ThemeList) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.themes, next))
+            }
+        })
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
               "role": "main" in the POST request. The theme will be published only after all
               of its files have been extracted and stored by Shopify, which might take a couple of minutes.
     *
-    * This function performs a `POST` to the `/admin/api/unstable/themes.json` endpoint.
+    * This function performs a `POST` to the `/admin/api/{api_version}/themes.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-unstable
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#create-2020-10
     */
-    pub async fn deprecated_unstable_create_themes(
+    pub async fn create_themes(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/themes.json", None);
+        body: &crate::types::ThemeEnvelope,
+    ) -> ClientResult<crate::types::ThemeEnvelope> {
+        let url = self.client.url(
+            &format!("/admin/api/{}/themes.json", self.client.api_version(),),
+            None,
+        );
         self.client
             .post(
                 &url,
@@ -7320,20 +2337,20 @@ impl OnlineStore {
     /**
      * Retrieves a single theme.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/themes/{theme_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/themes/{theme_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `theme_id: &str` -- theme_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_unstable_get_themes_param_theme(
+    pub async fn get_themes_param_theme(
         &self,
         theme_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ThemeEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7341,7 +2358,8 @@ impl OnlineStore {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/themes/{}/json?{}",
+                "/admin/api/{}/themes/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(theme_id),
                 query_
             ),
@@ -7360,22 +2378,23 @@ impl OnlineStore {
     /**
      * Updates an existing theme.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/themes/{theme_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/{api_version}/themes/{theme_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#update-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `theme_id: &str` -- theme_id.
      */
-    pub async fn deprecated_unstable_update_themes_param_theme(
+    pub async fn update_themes_param_theme(
         &self,
         theme_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::ThemeEnvelope,
+    ) -> ClientResult<crate::types::ThemeEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/themes/{}/json",
+                "/admin/api/{}/themes/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(theme_id),
             ),
             None,
@@ -7393,21 +2412,19 @@ impl OnlineStore {
     /**
      * Deletes a theme.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/themes/{theme_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/themes/{theme_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `theme_id: &str` -- storefront_access_token_id.
+     * * `theme_id: &str` -- theme_id.
      */
-    pub async fn deprecated_unstable_delete_themes_param_theme(
-        &self,
-        theme_id: &str,
-    ) -> ClientResult<()> {
+    pub async fn delete_themes_param_theme(&self, theme_id: &str) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/themes/{}/json",
+                "/admin/api/{}/themes/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(theme_id),
             ),
             None,
@@ -14,35 +14,18 @@ impl ShopifyPayments {
     /**
      * Retrieves the account's current balance.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/balance.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-01
-     */
-    pub async fn deprecated_202001_get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/shopify_payments/balance.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the account's current balance.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/balance.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-10
      */
-    pub async fn deprecated_202004_get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/shopify_payments/balance.json", None);
+    pub async fn get_balance(&self) -> ClientResult<crate::types::Balance> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/shopify_payments/balance.json",
+                self.client.api_version(),
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -56,94 +39,57 @@ impl ShopifyPayments {
     /**
      * Retrieves the account's current balance.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/shopify_payments/balance.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-07
-     */
-    pub async fn deprecated_202007_get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/shopify_payments/balance.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the account's current balance.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/shopify_payments/balance.json` endpoint.
+     * As opposed to `get_balance`, this function returns all the pages of the request at once.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-10
      */
-    pub async fn get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/shopify_payments/balance.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_all_balance(&self) -> ClientResult<Vec<crate::types::Money>> {
+        futures::TryStreamExt::try_collect(self.get_all_balance_stream()).await
     }
     /**
      * Retrieves the account's current balance.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/shopify_payments/balance.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2021-01
-     */
-    pub async fn deprecated_202101_get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/shopify_payments/balance.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the account's current balance.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/shopify_payments/balance.json` endpoint.
+     * As opposed to `get_all_balance`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/balance#show-2020-10
      */
-    pub async fn deprecated_unstable_get_balance(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/shopify_payments/balance.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub fn get_all_balance_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Money>> + '_ {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/shopify_payments/balance.json",
+                self.client.api_version(),
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::Balance) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.balance, next))
+            }
+        })
     }
     /**
     * Retrieve all disputes ordered by initiated_at date and time (ISO 8601 format), with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/disputes.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/disputes.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-04
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-01
+    * Only available in API versions 2020-01, 2020-04.
     *
     * **Parameters:**
     *
@@ -152,13 +98,13 @@ impl ShopifyPayments {
     * * `status: &str` -- Return only disputes with the specified status.
     * * `initiated_at: &str` -- Return only disputes with the specified initiated_at date (ISO 8601 format).
     */
-    pub async fn deprecated_202001_get_dispute(
+    pub async fn get_dispute(
         &self,
         since_id: &str,
         last_id: &str,
         status: &str,
         initiated_at: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::DisputeList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !initiated_at.is_empty() {
             query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
@@ -175,7 +121,8 @@ impl ShopifyPayments {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/shopify_payments/disputes.json?{}",
+                "/admin/api/{}/shopify_payments/disputes.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -190,126 +137,55 @@ impl ShopifyPayments {
             )
             .await
     }
-    /**
-     * Retrieves a single dispute by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/disputes/{dispute_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `dispute_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_disputes_param_dispute(
-        &self,
-        dispute_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/shopify_payments/disputes/{}/json",
-                crate::progenitor_support::encode_path(dispute_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
     /**
     * Retrieve all disputes ordered by initiated_at date and time (ISO 8601 format), with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/disputes.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/disputes.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-04
+    * As opposed to `get_disputes`, this function returns all the pages of the request at once.
     *
-    * **Parameters:**
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-04
     *
-    * * `since_id: &str` -- Return only disputes after the specified ID.
-    * * `last_id: &str` -- Return only disputes before the specified ID.
-    * * `status: &str` -- Return only disputes with the specified status.
-    * * `initiated_at: &str` -- Return only disputes with the specified initiated_at date (ISO 8601 format).
+    * Only available in API versions 2020-01, 2020-04.
     */
-    pub async fn deprecated_202004_get_dispute(
+    pub async fn get_all_disputes(
         &self,
         since_id: &str,
         last_id: &str,
         status: &str,
         initiated_at: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !initiated_at.is_empty() {
-            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
-        }
-        if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/shopify_payments/disputes.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::Dispute>> {
+        futures::TryStreamExt::try_collect(self.get_all_disputes_stream(
+            since_id,
+            last_id,
+            status,
+            initiated_at,
+        ))
+        .await
     }
     /**
-    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+    * Retrieve all disputes ordered by initiated_at date and time (ISO 8601 format), with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/payouts.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/disputes.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-01
+    * As opposed to `get_all_disputes`, this function returns a stream of the items, fetching each page only as the stream is polled.
     *
-    * **Parameters:**
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-04
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * Only available in API versions 2020-01, 2020-04.
     */
-    pub async fn deprecated_202001_get_payout(
+    pub fn get_all_disputes_stream(
         &self,
         since_id: &str,
         last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
         status: &str,
-    ) -> ClientResult<()> {
+        initiated_at: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Dispute>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
-        }
-        if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
-        }
-        if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+        if !initiated_at.is_empty() {
+            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
         }
         if !last_id.is_empty() {
             query_args.push(("last_id".to_string(), last_id.to_string()));
@@ -323,40 +199,48 @@ impl ShopifyPayments {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/shopify_payments/payouts.json?{}",
+                "/admin/api/{}/shopify_payments/disputes.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::DisputeList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.disputes, next))
+            }
+        })
     }
     /**
-     * Retrieves a single payout by id.
+     * Retrieves a single dispute by ID.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/payouts/{payout_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/disputes/{dispute_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#show-2020-01
+     *
+     * Only available in API versions 2020-01.
      *
      * **Parameters:**
      *
-     * * `payout_id: &str` -- storefront_access_token_id.
+     * * `dispute_id: &str` -- dispute_id.
      */
-    pub async fn deprecated_202001_get_payouts_param_payout(
+    pub async fn get_disputes_param_dispute(
         &self,
-        payout_id: &str,
-    ) -> ClientResult<()> {
+        dispute_id: &str,
+    ) -> ClientResult<crate::types::DisputeEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/shopify_payments/payouts/{}/json",
-                crate::progenitor_support::encode_path(payout_id),
+                "/admin/api/{}/shopify_payments/disputes/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(dispute_id),
             ),
             None,
         );
@@ -374,9 +258,9 @@ impl ShopifyPayments {
     * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/payouts.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/payouts.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-04
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-10
     *
     * **Parameters:**
     *
@@ -387,7 +271,7 @@ impl ShopifyPayments {
     * * `date: &str` -- Filter the response to payouts made on the specified date.
     * * `status: &str` -- Filter the response to payouts made with the specified status.
     */
-    pub async fn deprecated_202004_get_payout(
+    pub async fn get_payout(
         &self,
         since_id: &str,
         last_id: &str,
@@ -395,7 +279,7 @@ impl ShopifyPayments {
         date_max: &str,
         date: &str,
         status: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::PayoutList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -418,7 +302,8 @@ impl ShopifyPayments {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/shopify_payments/payouts.json?{}",
+                "/admin/api/{}/shopify_payments/payouts.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -433,56 +318,17 @@ impl ShopifyPayments {
             )
             .await
     }
-    /**
-     * Retrieves a single payout by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/payouts/{payout_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `payout_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_payouts_param_payout(
-        &self,
-        payout_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/shopify_payments/payouts/{}/json",
-                crate::progenitor_support::encode_path(payout_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
     /**
     * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-07/shopify_payments/payouts.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/payouts.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-07
+    * As opposed to `get_payouts`, this function returns all the pages of the request at once.
     *
-    * **Parameters:**
-    *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-10
     */
-    pub async fn deprecated_202007_get_payout(
+    pub async fn get_all_payouts(
         &self,
         since_id: &str,
         last_id: &str,
@@ -490,94 +336,23 @@ impl ShopifyPayments {
         date_max: &str,
         date: &str,
         status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
-        }
-        if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
-        }
-        if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
-        }
-        if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/shopify_payments/payouts.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single payout by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/shopify_payments/payouts/{payout_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `payout_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_payouts_param_payout(
-        &self,
-        payout_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/shopify_payments/payouts/{}/json",
-                crate::progenitor_support::encode_path(payout_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::Payout>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_payouts_stream(since_id, last_id, date_min, date_max, date, status),
+        )
+        .await
     }
     /**
     * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-10/shopify_payments/payouts.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/payouts.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-10
-    *
-    * **Parameters:**
+    * As opposed to `get_all_payouts`, this function returns a stream of the items, fetching each page only as the stream is polled.
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-10
     */
-    pub async fn get_payout(
+    pub fn get_all_payouts_stream(
         &self,
         since_id: &str,
         last_id: &str,
@@ -585,7 +360,7 @@ impl ShopifyPayments {
         date_max: &str,
         date: &str,
         status: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Payout>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -608,36 +383,45 @@ impl ShopifyPayments {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/shopify_payments/payouts.json?{}",
+                "/admin/api/{}/shopify_payments/payouts.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::PayoutList) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.payouts, next))
+            }
+        })
     }
     /**
      * Retrieves a single payout by id.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/shopify_payments/payouts/{payout_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/payouts/{payout_id}.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-2020-10
      *
      * **Parameters:**
      *
-     * * `payout_id: &str` -- storefront_access_token_id.
+     * * `payout_id: &str` -- payout_id.
      */
-    pub async fn get_payouts_param_payout(&self, payout_id: &str) -> ClientResult<()> {
+    pub async fn get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::types::PayoutEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/shopify_payments/payouts/{}/json",
+                "/admin/api/{}/shopify_payments/payouts/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(payout_id),
             ),
             None,
@@ -653,54 +437,53 @@ impl ShopifyPayments {
             .await
     }
     /**
-    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    * Retrieves a list of all balance transactions ordered by processing
+    time, with the most recent being first.
+    Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance/transactions.json` endpoint.
     *
-    * This function performs a `GET` to the `/admin/api/2021-01/shopify_payments/payouts.json` endpoint.
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/transaction#index-2020-01
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2021-01
+    * Only available in API versions 2020-01.
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `since_id: &str` -- Filter response to transactions exclusively after the specified ID.
+    * * `last_id: &str` -- Filter response to transactions exclusively before the specified ID.
+    * * `test: &str` -- Filter response to transactions placed in test mode.
+    * * `payout_id: &str` -- Filter response to transactions paid out in the specified payout.
+    * * `payout_status: &str` -- Filter response to transactions with the specified payout status.
     */
-    pub async fn deprecated_202101_get_payout(
+    pub async fn get_balance_transaction(
         &self,
         since_id: &str,
         last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
-    ) -> ClientResult<()> {
+        test: &str,
+        payout_id: &str,
+        payout_status: &str,
+    ) -> ClientResult<crate::types::BalanceTransactionList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
-        }
-        if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
-        }
-        if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
-        }
         if !last_id.is_empty() {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
+        if !payout_id.is_empty() {
+            query_args.push(("payout_id".to_string(), payout_id.to_string()));
+        }
+        if !payout_status.is_empty() {
+            query_args.push(("payout_status".to_string(), payout_status.to_string()));
+        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if !test.is_empty() {
+            query_args.push(("test".to_string(), test.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/shopify_payments/payouts.json?{}",
+                "/admin/api/{}/shopify_payments/balance/transactions.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
@@ -716,157 +499,56 @@ impl ShopifyPayments {
             .await
     }
     /**
-     * Retrieves a single payout by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/shopify_payments/payouts/{payout_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `payout_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_payouts_param_payout(
-        &self,
-        payout_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/shopify_payments/payouts/{}/json",
-                crate::progenitor_support::encode_path(payout_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    * Retrieves a list of all balance transactions ordered by processing
+    time, with the most recent being first.
+    Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/unstable/shopify_payments/payouts.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance/transactions.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-unstable
+    * As opposed to `get_balance_transactions`, this function returns all the pages of the request at once.
     *
-    * **Parameters:**
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/transaction#index-2020-01
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * Only available in API versions 2020-01.
     */
-    pub async fn deprecated_unstable_get_payout(
+    pub async fn get_all_balance_transactions(
         &self,
         since_id: &str,
         last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
-        }
-        if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
-        }
-        if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
-        }
-        if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/shopify_payments/payouts.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single payout by id.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/shopify_payments/payouts/{payout_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `payout_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_payouts_param_payout(
-        &self,
+        test: &str,
         payout_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/shopify_payments/payouts/{}/json",
-                crate::progenitor_support::encode_path(payout_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        payout_status: &str,
+    ) -> ClientResult<Vec<crate::types::BalanceTransaction>> {
+        futures::TryStreamExt::try_collect(self.get_all_balance_transactions_stream(
+            since_id,
+            last_id,
+            test,
+            payout_id,
+            payout_status,
+        ))
+        .await
     }
     /**
     * Retrieves a list of all balance transactions ordered by processing
     time, with the most recent being first.
     Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
-    * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/balance/transactions.json` endpoint.
+    * This function performs a `GET` to the `/admin/api/{api_version}/shopify_payments/balance/transactions.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/transaction#index-2020-01
+    * As opposed to `get_all_balance_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
     *
-    * **Parameters:**
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/transaction#index-2020-01
     *
-    * * `since_id: &str` -- Filter response to transactions exclusively after the specified ID.
-    * * `last_id: &str` -- Filter response to transactions exclusively before the specified ID.
-    * * `test: &str` -- Filter response to transactions placed in test mode.
-    * * `payout_id: &str` -- Filter response to transactions paid out in the specified payout.
-    * * `payout_status: &str` -- Filter response to transactions with the specified payout status.
+    * Only available in API versions 2020-01.
     */
-    pub async fn deprecated_202001_get_balance_transaction(
+    pub fn get_all_balance_transactions_stream(
         &self,
         since_id: &str,
         last_id: &str,
         test: &str,
         payout_id: &str,
         payout_status: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BalanceTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !last_id.is_empty() {
             query_args.push(("last_id".to_string(), last_id.to_string()));
@@ -886,19 +568,27 @@ impl ShopifyPayments {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/shopify_payments/balance/transactions.json?{}",
+                "/admin/api/{}/shopify_payments/balance/transactions.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::BalanceTransactionList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.transactions, next))
+            }
+        })
     }
 }
@@ -14,20 +14,20 @@ impl StoreProperties {
     /**
      * Retrieves a list of countries.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-10
      *
      * **Parameters:**
      *
      * * `since_id: &str` -- Restrict results to after the specified ID.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_countrie(
+    pub async fn get_countrie(
         &self,
         since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CountryList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -37,7 +37,11 @@ impl StoreProperties {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-01/countries.json?{}", query_),
+            &format!(
+                "/admin/api/{}/countries.json?{}",
+                self.client.api_version(),
+                query_
+            ),
             None,
         );
         self.client
@@ -50,21 +54,84 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries.json` endpoint.
+     *
+     * As opposed to `get_countries`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-10
+     */
+    pub async fn get_all_countries(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Country>> {
+        futures::TryStreamExt::try_collect(self.get_all_countries_stream(since_id, fields)).await
+    }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries.json` endpoint.
+     *
+     * As opposed to `get_all_countries`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-10
+     */
+    pub fn get_all_countries_stream(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Country>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/countries.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::CountryList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.countries, next))
+            }
+        })
+    }
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
 
     Creates a country.
     *
-    * This function performs a `POST` to the `/admin/api/2020-01/countries.json` endpoint.
+    * This function performs a `POST` to the `/admin/api/{api_version}/countries.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2020-01
+    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2020-10
     */
-    pub async fn deprecated_202001_create_countries(
+    pub async fn create_countries(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/countries.json", None);
+        body: &crate::types::CountryEnvelope,
+    ) -> ClientResult<crate::types::CountryEnvelope> {
+        let url = self.client.url(
+            &format!("/admin/api/{}/countries.json", self.client.api_version(),),
+            None,
+        );
         self.client
             .post(
                 &url,
@@ -78,14 +145,18 @@ impl StoreProperties {
     /**
      * Retrieves a count of countries.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2020-10
      */
-    pub async fn deprecated_202001_get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/countries/count.json", None);
+    pub async fn get_countries_count(&self) -> ClientResult<crate::types::Count> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/countries/count.json",
+                self.client.api_version(),
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -99,20 +170,20 @@ impl StoreProperties {
     /**
      * Retrieves a specific county.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries/{country_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-10
      *
      * **Parameters:**
      *
-     * * `country_id: &str` -- storefront_access_token_id.
+     * * `country_id: &str` -- country_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_countries_param_country(
+    pub async fn get_countries_param_country(
         &self,
         country_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CountryEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -120,7 +191,8 @@ impl StoreProperties {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/countries/{}/json?{}",
+                "/admin/api/{}/countries/{}.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(country_id),
                 query_
             ),
@@ -142,22 +214,23 @@ impl StoreProperties {
 
     Updates an existing country.
     *
-    * This function performs a `PUT` to the `/admin/api/2020-01/countries/{country_id}.json` endpoint.
+    * This function performs a `PUT` to the `/admin/api/{api_version}/countries/{country_id}.json` endpoint.
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2020-01
+    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2020-10
     *
     * **Parameters:**
     *
-    * * `country_id: &str` -- storefront_access_token_id.
+    * * `country_id: &str` -- country_id.
     */
-    pub async fn deprecated_202001_update_countries_param_country(
+    pub async fn update_countries_param_country(
         &self,
         country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::CountryEnvelope,
+    ) -> ClientResult<crate::types::CountryEnvelope> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/countries/{}/json",
+                "/admin/api/{}/countries/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(country_id),
             ),
             None,
@@ -175,21 +248,19 @@ impl StoreProperties {
     /**
      * Deletes a country.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/countries/{country_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/{api_version}/countries/{country_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `country_id: &str` -- storefront_access_token_id.
+     * * `country_id: &str` -- country_id.
      */
-    pub async fn deprecated_202001_delete_countries_param_country(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
+    pub async fn delete_countries_param_country(&self, country_id: &str) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/countries/{}/json",
+                "/admin/api/{}/countries/{}.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(country_id),
             ),
             None,
@@ -205,32 +276,15 @@ impl StoreProperties {
             .await
     }
     /**
-     * Retrieves a list of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-04
+     * Retrieves a list of currencies enabled on a shop.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/{api_version}/currencies.json` endpoint.
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-10
      */
-    pub async fn deprecated_202004_get_countrie(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub async fn get_currencie(&self) -> ClientResult<crate::types::CurrencyList> {
         let url = self.client.url(
-            &format!("/admin/api/2020-04/countries.json?{}", query_),
+            &format!("/admin/api/{}/currencies.json", self.client.api_version(),),
             None,
         );
         self.client
@@ -244,41 +298,59 @@ impl StoreProperties {
             .await
     }
     /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Creates a country.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/countries.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2020-04
-    */
-    pub async fn deprecated_202004_create_countries(
+     * Retrieves a list of currencies enabled on a shop.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/currencies.json` endpoint.
+     *
+     * As opposed to `get_currencies`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-10
+     */
+    pub async fn get_all_currencies(&self) -> ClientResult<Vec<crate::types::Currency>> {
+        futures::TryStreamExt::try_collect(self.get_all_currencies_stream()).await
+    }
+    /**
+     * Retrieves a list of currencies enabled on a shop.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/currencies.json` endpoint.
+     *
+     * As opposed to `get_all_currencies`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-10
+     */
+    pub fn get_all_currencies_stream(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/countries.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Currency>> + '_ {
+        let url = self.client.url(
+            &format!("/admin/api/{}/currencies.json", self.client.api_version(),),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::CurrencyList) =
+                    self.client
+                        .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                        .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.currencies, next))
+            }
+        })
     }
     /**
-     * Retrieves a count of countries.
+     * Retrieves a list of the shop's policies.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/policies.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-10
      */
-    pub async fn deprecated_202004_get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/countries/count.json", None);
+    pub async fn get_policie(&self) -> ClientResult<crate::types::PolicyList> {
+        let url = self.client.url(
+            &format!("/admin/api/{}/policies.json", self.client.api_version(),),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -290,30 +362,78 @@ impl StoreProperties {
             .await
     }
     /**
-     * Retrieves a specific county.
+     * Retrieves a list of the shop's policies.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/policies.json` endpoint.
+     *
+     * As opposed to `get_policies`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-10
+     */
+    pub async fn get_all_policies(&self) -> ClientResult<Vec<crate::types::Policy>> {
+        futures::TryStreamExt::try_collect(self.get_all_policies_stream()).await
+    }
+    /**
+     * Retrieves a list of the shop's policies.
+     *
+     * This function performs a `GET` to the `/admin/api/{api_version}/policies.json` endpoint.
+     *
+     * As opposed to `get_all_policies`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-10
+     */
+    pub fn get_all_policies_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Policy>> + '_ {
+        let url = self.client.url(
+            &format!("/admin/api/{}/policies.json", self.client.api_version(),),
+            None,
+        );
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (Option<crate::utils::NextLink>, crate::types::PolicyList) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.policies, next))
+            }
+        })
+    }
+    /**
+     * Retrieves a list of provinces.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries/{country_id}/provinces.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-10
      *
      * **Parameters:**
      *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `country_id: &str` -- country_id.
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
      */
-    pub async fn deprecated_202004_get_countries_param_country(
+    pub async fn get_countries_param_country_province(
         &self,
         country_id: &str,
+        since_id: &str,
         fields: &str,
     ) -> ClientResult<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/countries/{}/json?{}",
+                "/admin/api/{}/countries/{}/provinces.json?{}",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(country_id),
                 query_
             ),
@@ -330,65 +450,30 @@ impl StoreProperties {
             .await
     }
     /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-04/countries/{country_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_update_countries_param_country(
-        &self,
-        country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a country.
+     * Retrieves a count of provinces for a country.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/countries/{country_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries/{country_id}/provinces/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2020-10
      *
      * **Parameters:**
      *
-     * * `country_id: &str` -- storefront_access_token_id.
+     * * `country_id: &str` -- country_id.
      */
-    pub async fn deprecated_202004_delete_countries_param_country(
+    pub async fn get_countries_param_country_provinces_count(
         &self,
         country_id: &str,
     ) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/countries/{}/json",
+                "/admin/api/{}/countries/{}/provinces/count.json",
+                self.client.api_version(),
                 crate::progenitor_support::encode_path(country_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .get(
                 &url,
                 crate::Message {
                     body: None,
@@ -398,32 +483,37 @@ impl StoreProperties {
             .await
     }
     /**
-     * Retrieves a list of countries.
+     * Retrieves a single province for a country.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/countries/{country_id}/provinces/{province_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-10
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `country_id: &str` -- country_id.
+     * * `province_id: &str` -- province_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202007_get_countrie(
+    pub async fn get_countries_param_country_provinces_province(
         &self,
-        since_id: &str,
+        country_id: &str,
+        province_id: &str,
         fields: &str,
     ) -> ClientResult<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/countries.json?{}", query_),
+            &format!(
+                "/admin/api/{}/countries/{}/provinces/{}.json?{}",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
             None,
         );
         self.client
@@ -440,19 +530,34 @@ impl StoreProperties {
     * Caution
       As of version 2020-10, the tax field is deprecated.
 
-    Creates a country.
+    Updates an existing province for a country.
+    *
+    * This function performs a `PUT` to the `/admin/api/{api_version}/countries/{country_id}/provinces/{province_id}.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2020-10
     *
-    * This function performs a `POST` to the `/admin/api/2020-07/countries.json` endpoint.
+    * **Parameters:**
     *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2020-07
+    * * `country_id: &str` -- country_id.
+    * * `province_id: &str` -- province_id.
     */
-    pub async fn deprecated_202007_create_countries(
+    pub async fn update_countries_param_country_provinces_province(
         &self,
+        country_id: &str,
+        province_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/countries.json", None);
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/countries/{}/provinces/{}.json",
+                self.client.api_version(),
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+            ),
+            None,
+        );
         self.client
-            .post(
+            .put(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -462,16 +567,33 @@ impl StoreProperties {
             .await
     }
     /**
-     * Retrieves a count of countries.
+     * Get a list of all shipping zones.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shipping_zones.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-10
+     *
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2020-07
+     * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202007_get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/countries/count.json", None);
+    pub async fn get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::types::ShippingZoneList> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/{}/shipping_zones.json?{}",
+                self.client.api_version(),
+                query_
+            ),
+            None,
+        );
         self.client
             .get(
                 &url,
@@ -483,208 +605,33 @@ impl StoreProperties {
             .await
     }
     /**
-     * Retrieves a specific county.
+     * Get a list of all shipping zones.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shipping_zones.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-07
+     * As opposed to `get_shipping_zones`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-10
      */
-    pub async fn deprecated_202007_get_countries_param_country(
+    pub async fn get_all_shipping_zones(
         &self,
-        country_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-07/countries/{country_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_update_countries_param_country(
-        &self,
-        country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a country.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_countries_param_country(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_countrie(&self, since_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/countries.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Creates a country.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/countries.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2020-10
-    */
-    pub async fn create_countries(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/countries.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2020-10
-     */
-    pub async fn get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/countries/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::ShippingZone>> {
+        futures::TryStreamExt::try_collect(self.get_all_shipping_zones_stream(fields)).await
     }
     /**
-     * Retrieves a specific county.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}.json` endpoint.
+     * Get a list of all shipping zones.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-10
+     * This function performs a `GET` to the `/admin/api/{api_version}/shipping_zones.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_all_shipping_zones`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-10
      */
-    pub async fn get_countries_param_country(
+    pub fn get_all_shipping_zones_stream(
         &self,
-        country_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShippingZone>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -692,114 +639,52 @@ impl StoreProperties {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/countries/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
+                "/admin/api/{}/shipping_zones.json?{}",
+                self.client.api_version(),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::ShippingZoneList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
 
-    Updates an existing country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-10/countries/{country_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn update_countries_param_country(
-        &self,
-        country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a country.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_countries_param_country(&self, country_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.shipping_zones, next))
+            }
+        })
     }
     /**
-     * Retrieves a list of countries.
+     * Retrieves the shop's configuration.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/shop.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-10
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202101_get_countrie(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+    pub async fn get_shop(&self, fields: &str) -> ClientResult<crate::types::ShopEnvelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2021-01/countries.json?{}", query_),
+            &format!(
+                "/admin/api/{}/shop.json?{}",
+                self.client.api_version(),
+                query_
+            ),
             None,
         );
         self.client
@@ -812,1899 +697,4 @@ impl StoreProperties {
             )
             .await
     }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Creates a country.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/countries.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-2021-01
-    */
-    pub async fn deprecated_202101_create_countries(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/countries.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-2021-01
-     */
-    pub async fn deprecated_202101_get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/countries/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific county.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_countries_param_country(
-        &self,
-        country_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2021-01/countries/{country_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_update_countries_param_country(
-        &self,
-        country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a country.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_countries_param_country(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_countrie(
-        &self,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/countries.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Creates a country.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/countries.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#create-unstable
-    */
-    pub async fn deprecated_unstable_create_countries(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/countries.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of countries.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#count-unstable
-     */
-    pub async fn deprecated_unstable_get_countries_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/countries/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific county.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_countries_param_country(
-        &self,
-        country_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing country.
-    *
-    * This function performs a `PUT` to the `/admin/api/unstable/countries/{country_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#update-unstable
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_update_countries_param_country(
-        &self,
-        country_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a country.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/countries/{country_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_countries_param_country(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-01
-     */
-    pub async fn deprecated_202001_get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-04
-     */
-    pub async fn deprecated_202004_get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-07
-     */
-    pub async fn deprecated_202007_get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2020-10
-     */
-    pub async fn get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-2021-01
-     */
-    pub async fn deprecated_202101_get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of currencies enabled on a shop.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/currencies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/currency#index-unstable
-     */
-    pub async fn deprecated_unstable_get_currencie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/currencies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-01
-     */
-    pub async fn deprecated_202001_get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-04
-     */
-    pub async fn deprecated_202004_get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-07
-     */
-    pub async fn deprecated_202007_get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2020-10
-     */
-    pub async fn get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-2021-01
-     */
-    pub async fn deprecated_202101_get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of the shop's policies.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/policies.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/policy#index-unstable
-     */
-    pub async fn deprecated_unstable_get_policie(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/policies.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn deprecated_202001_get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn deprecated_202004_get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn deprecated_202007_get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn deprecated_202101_get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of provinces.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
-     */
-    pub async fn deprecated_unstable_get_countries_param_country_province(
-        &self,
-        country_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/provinces.json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of provinces for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_countries_param_country_provinces_count(
-        &self,
-        country_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/provinces/count.json",
-                crate::progenitor_support::encode_path(country_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single province for a country.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `country_id: &str` -- storefront_access_token_id.
-     * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/provinces/{}/json?{}",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Caution
-      As of version 2020-10, the tax field is deprecated.
-
-    Updates an existing province for a country.
-    *
-    * This function performs a `PUT` to the `/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#update-unstable
-    *
-    * **Parameters:**
-    *
-    * * `country_id: &str` -- storefront_access_token_id.
-    * * `province_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_update_countries_param_country_provinces_province(
-        &self,
-        country_id: &str,
-        province_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/countries/{}/provinces/{}/json",
-                crate::progenitor_support::encode_path(country_id),
-                crate::progenitor_support::encode_path(province_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a list of all shipping zones.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/shipping_zones.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/shipping_zones.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-01/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-04/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-07/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-10/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2021-01/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the shop's configuration.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/shop.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_shop(&self, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/unstable/shop.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
 }
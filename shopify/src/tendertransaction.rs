@@ -14,187 +14,7 @@ impl Tendertransaction {
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/tender_transactions.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
-     */
-    pub async fn deprecated_202001_get_tender_transaction(
-        &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
-        }
-        if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
-        }
-        if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/tender_transactions.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/tender_transactions.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
-     */
-    pub async fn deprecated_202004_get_tender_transaction(
-        &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
-        }
-        if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
-        }
-        if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/tender_transactions.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/tender_transactions.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
-     */
-    pub async fn deprecated_202007_get_tender_transaction(
-        &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
-        }
-        if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
-        }
-        if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/tender_transactions.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/tender_transactions.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/tender_transactions.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-10
      *
@@ -216,7 +36,7 @@ impl Tendertransaction {
         processed_at_max: &str,
         processed_at: &str,
         order: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::TenderTransactionList> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -238,7 +58,11 @@ impl Tendertransaction {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-10/tender_transactions.json?{}", query_),
+            &format!(
+                "/admin/api/{}/tender_transactions.json?{}",
+                self.client.api_version(),
+                query_
+            ),
             None,
         );
         self.client
@@ -254,93 +78,47 @@ impl Tendertransaction {
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/tender_transactions.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/tender_transactions.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2021-01
+     * As opposed to `get_tender_transactions`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-10
      */
-    pub async fn deprecated_202101_get_tender_transaction(
+    pub async fn get_all_tender_transactions(
         &self,
-        limit: &str,
         since_id: &str,
         processed_at_min: &str,
         processed_at_max: &str,
         processed_at: &str,
         order: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
-        }
-        if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
-        }
-        if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/tender_transactions.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<Vec<crate::types::TenderTransaction>> {
+        futures::TryStreamExt::try_collect(self.get_all_tender_transactions_stream(
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        ))
+        .await
     }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/tender_transactions.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/{api_version}/tender_transactions.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-unstable
+     * As opposed to `get_all_tender_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-10
      */
-    pub async fn deprecated_unstable_get_tender_transaction(
+    pub fn get_all_tender_transactions_stream(
         &self,
-        limit: &str,
         since_id: &str,
         processed_at_min: &str,
         processed_at_max: &str,
         processed_at: &str,
         order: &str,
-    ) -> ClientResult<()> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TenderTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
         if !order.is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
         }
@@ -358,17 +136,28 @@ impl Tendertransaction {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/unstable/tender_transactions.json?{}", query_),
+            &format!(
+                "/admin/api/{}/tender_transactions.json?{}",
+                self.client.api_version(),
+                query_
+            ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        crate::utils::paginate(crate::utils::shopify_first_page(&url), move |page_url| {
+            async move {
+                let (link, resp): (
+                    Option<crate::utils::NextLink>,
+                    crate::types::TenderTransactionList,
+                ) = self
+                    .client
+                    .request_with_links(http::Method::GET, &page_url, crate::Message::default())
+                    .await?;
+
+                // Follow the `page_info` cursor Shopify links to, if there is one.
+                let next = link.and_then(|l| crate::utils::shopify_next_page(&page_url, &l.0));
+
+                Ok((resp.tender_transactions, next))
+            }
+        })
     }
 }
@@ -0,0 +1,274 @@
+//! Paces requests to stay under Shopify's leaky bucket rate limit.
+//!
+//! Every store has a bucket of requests, 40 deep (400 on Shopify Plus), that
+//! drains in 20 seconds. Shopify tells us how full it is after each request in
+//! `X-Shopify-Shop-Api-Call-Limit`, e.g. `32/40`, and answers `429` with a
+//! `Retry-After` once it overflows.
+//! See <https://shopify.dev/api/usage/rate-limits>.
+//!
+//! `Throttle` waits out a `429` itself, so `RetryTransient` retries everything
+//! else that failed transiently, but not that.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::{RetryPolicy, Retryable};
+use retry_policies::RetryDecision;
+use task_local_extensions::Extensions;
+use tokio::time::Instant;
+
+/// The header Shopify reports the fill of the bucket in.
+pub const CALL_LIMIT_HEADER: &str = "X-Shopify-Shop-Api-Call-Limit";
+
+/// How many times a request is sent again after a `429`.
+const MAX_RETRIES: u32 = 3;
+
+/// The size of a bucket on a store that hasn't told us its size.
+const DEFAULT_CAPACITY: u32 = 40;
+
+/// How long it takes a full bucket to drain, whatever its size.
+const DRAIN_SECS: f64 = 20.0;
+
+/// Parse a `X-Shopify-Shop-Api-Call-Limit` value, e.g. `32/40`, into the
+/// requests in the bucket and its size.
+pub fn parse_call_limit(value: &str) -> Option<(u32, u32)> {
+    let (used, capacity) = value.split_once('/')?;
+    let used = used.trim().parse().ok()?;
+    let capacity = capacity.trim().parse().ok()?;
+    if capacity == 0 {
+        return None;
+    }
+
+    Some((used, capacity))
+}
+
+/// What we know about one store's bucket.
+#[derive(Debug, Clone)]
+struct Bucket {
+    /// The requests in the bucket at `at`, which is in the future if requests
+    /// are waiting on it.
+    used: f64,
+    at: Instant,
+    capacity: f64,
+    /// When the store said we could send requests again after a `429`.
+    retry_at: Option<Instant>,
+}
+
+impl Bucket {
+    fn new(now: Instant, capacity: u32) -> Self {
+        Bucket {
+            used: 0.0,
+            at: now,
+            capacity: capacity as f64,
+            retry_at: None,
+        }
+    }
+
+    fn leak_rate(&self) -> f64 {
+        self.capacity / DRAIN_SECS
+    }
+
+    /// The requests in the bucket at `now`.
+    fn level(&self, now: Instant) -> f64 {
+        let drained = if now >= self.at {
+            now.duration_since(self.at).as_secs_f64()
+        } else {
+            -self.at.duration_since(now).as_secs_f64()
+        };
+
+        (self.used - drained * self.leak_rate()).max(0.0)
+    }
+
+    /// Make room for a request, returning how long it has to wait for it.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let level = self.level(now);
+        let mut wait = ((level + 1.0 - self.capacity) / self.leak_rate()).max(0.0);
+        if let Some(retry_at) = self.retry_at {
+            wait = wait.max(retry_at.saturating_duration_since(now).as_secs_f64());
+        }
+
+        self.used = (level - wait * self.leak_rate()).max(0.0) + 1.0;
+        self.at = now + Duration::from_secs_f64(wait);
+        Duration::from_secs_f64(wait)
+    }
+
+    /// Catch up with what the store told us about its bucket.
+    fn observe(&mut self, now: Instant, used: u32, capacity: u32) {
+        let level = self.level(now);
+        self.capacity = capacity as f64;
+        // Keep counting requests we've let through that the store hasn't seen yet.
+        self.used = level.max(used as f64);
+        self.at = now;
+    }
+
+    /// How long it takes one request to leak out of the bucket.
+    fn drip(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.leak_rate())
+    }
+
+    fn overflowed(&mut self, now: Instant, retry_after: Duration) {
+        self.used = self.capacity;
+        self.at = now;
+        self.retry_at = Some(now + retry_after);
+    }
+}
+
+/// Middleware that holds requests back while a store's bucket is full, and
+/// waits out `Retry-After` when Shopify says it overflowed.
+///
+/// Buckets are kept per store, and shared by every clone of the
+/// `crate::Client` that made this middleware.
+#[derive(Debug, Clone, Default)]
+pub struct Throttle {
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl Throttle {
+    /// The store a request is for.
+    fn store(req: &Request) -> String {
+        let url = req.url();
+        format!(
+            "{}:{}",
+            url.host_str().unwrap_or_default(),
+            url.port_or_known_default().unwrap_or_default()
+        )
+    }
+
+    fn wait_for(&self, store: &str) -> Duration {
+        let mut buckets = self.buckets.lock().unwrap();
+        match buckets.get_mut(store) {
+            Some(bucket) => bucket.reserve(Instant::now()),
+            // We don't know how full the bucket is until the store tells us.
+            None => Duration::ZERO,
+        }
+    }
+
+    fn observe(&self, store: &str, resp: &Response) -> Option<Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if let Some((used, capacity)) = resp
+            .headers()
+            .get(CALL_LIMIT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_call_limit)
+        {
+            buckets
+                .entry(store.to_string())
+                .or_insert_with(|| Bucket::new(now, capacity))
+                .observe(now, used, capacity);
+        }
+
+        if resp.status() != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        let bucket = buckets
+            .entry(store.to_string())
+            .or_insert_with(|| Bucket::new(now, DEFAULT_CAPACITY));
+        // Without a `Retry-After`, there's room again once a request has leaked out.
+        let retry_after = resp
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|s| s.is_finite() && *s >= 0.0)
+            .map(Duration::from_secs_f64)
+            .unwrap_or_else(|| bucket.drip());
+        bucket.overflowed(now, retry_after);
+
+        Some(retry_after)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Throttle {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let store = Self::store(&req);
+        let mut attempt = 0;
+
+        loop {
+            let wait = self.wait_for(&store);
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+
+            // Keep a copy to send again, if the body lets us.
+            let retry = if attempt < MAX_RETRIES {
+                req.try_clone()
+            } else {
+                None
+            };
+
+            let resp = next.clone().run(req, extensions).await?;
+            let retry_after = self.observe(&store, &resp);
+
+            match (retry_after, retry) {
+                (Some(retry_after), Some(retry)) => {
+                    tokio::time::sleep(retry_after).await;
+                    attempt += 1;
+                    req = retry;
+                }
+                _ => return Ok(resp),
+            }
+        }
+    }
+}
+
+/// Middleware that retries requests that failed in a way that may not happen
+/// again, like `reqwest_retry::RetryTransientMiddleware`, except for a `429`,
+/// which `Throttle` has already waited out as long as Shopify asked.
+#[derive(Debug, Clone)]
+pub struct RetryTransient<T> {
+    retry_policy: T,
+}
+
+impl<T: RetryPolicy + Send + Sync + 'static> RetryTransient<T> {
+    pub fn new_with_policy(retry_policy: T) -> Self {
+        RetryTransient { retry_policy }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: RetryPolicy + Send + Sync + 'static> Middleware for RetryTransient<T> {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut past_retries = 0;
+
+        loop {
+            let retry = req.try_clone();
+            let result = next.clone().run(req, extensions).await;
+
+            let rate_limited =
+                matches!(&result, Ok(resp) if resp.status() == StatusCode::TOO_MANY_REQUESTS);
+            let transient = Retryable::from_reqwest_response(&result) == Some(Retryable::Transient);
+
+            match (retry, self.retry_policy.should_retry(past_retries)) {
+                (Some(retry), RetryDecision::Retry { execute_after })
+                    if transient && !rate_limited =>
+                {
+                    let wait = (execute_after - chrono::Utc::now())
+                        .to_std()
+                        .unwrap_or_default();
+                    tokio::time::sleep(wait).await;
+                    past_retries += 1;
+                    req = retry;
+                }
+                _ => return result,
+            }
+        }
+    }
+}
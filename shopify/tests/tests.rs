@@ -1 +1,400 @@
+use shopify::{Client, RootDefaultServer};
 
+fn acme() -> RootDefaultServer {
+    RootDefaultServer::new("acme")
+}
+
+#[test]
+fn test_server_fills_in_the_shop() {
+    assert_eq!(acme().default_url(), "https://acme.myshopify.com");
+}
+
+#[test]
+fn test_user_consent_url_uses_the_shop() {
+    let client = Client::new("client-id", "client-secret", "redirect-uri", "", "", acme());
+
+    let url = client.user_consent_url(&[]);
+
+    assert!(url.starts_with("https://acme.myshopify.com/admin/oauth/authorize?client_id=client-id"));
+}
+
+fn client(server: &wiremock::MockServer) -> Client {
+    let mut client = Client::new(
+        "client-id",
+        "client-secret",
+        "redirect-uri",
+        "token",
+        "",
+        acme(),
+    );
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_products_list_is_typed() {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/products.json"))
+        .and(query_param("vendor", "Burton"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "products": [{
+                "id": 632910392,
+                "title": "IPod Nano - 8GB",
+                "vendor": "Burton",
+                "created_at": "2021-07-01T14:14:08-04:00",
+                "variants": [{ "id": 808950810, "price": "199.00", "sku": "IPOD2008PINK" }],
+                "options": [{ "name": "Color", "values": ["Pink", "Red"] }],
+            }],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let products = client(&server)
+        .products()
+        .get(
+            "", "", "", "", "Burton", "", "", "", "", "", "", "", "", "", "", "", "", "",
+        )
+        .await
+        .unwrap()
+        .products;
+
+    assert_eq!(products.len(), 1);
+    assert_eq!(products[0].id, 632910392);
+    assert_eq!(products[0].variants[0].price, "199.00");
+    assert_eq!(products[0].options[0].values, vec!["Pink", "Red"]);
+    assert!(products[0].created_at.is_some());
+}
+
+#[tokio::test]
+async fn test_product_create_sends_the_envelope() {
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/admin/api/2021-07/products.json"))
+        .and(body_json(serde_json::json!({
+            "product": { "title": "Burton Custom Freestyle 151", "vendor": "Burton" },
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "product": { "id": 1071559748, "title": "Burton Custom Freestyle 151" },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let product: shopify::types::Product = serde_json::from_value(serde_json::json!({
+        "title": "Burton Custom Freestyle 151",
+        "vendor": "Burton",
+    }))
+    .unwrap();
+
+    let created = client(&server)
+        .products()
+        .create(&shopify::types::ProductEnvelope {
+            product: Some(product),
+        })
+        .await
+        .unwrap()
+        .product
+        .unwrap();
+
+    assert_eq!(created.id, 1071559748);
+}
+
+#[tokio::test]
+async fn test_shop_is_typed() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "shop": {
+                "id": 690933842,
+                "name": "Apple Computers",
+                "myshopify_domain": "apple.myshopify.com",
+                "enabled_presentment_currencies": ["USD"],
+                "latitude": 45.45,
+                "address2": null,
+            },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let shop = client(&server)
+        .store_properties()
+        .get_shop("")
+        .await
+        .unwrap()
+        .shop
+        .unwrap();
+
+    assert_eq!(shop.id, 690933842);
+    assert_eq!(shop.myshopify_domain, "apple.myshopify.com");
+    assert_eq!(shop.enabled_presentment_currencies, vec!["USD"]);
+}
+
+#[tokio::test]
+async fn test_requests_use_the_configured_api_version() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-10/orders/450789469.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "order": { "id": 450789469, "financial_status": "paid" },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = client(&server);
+    client.with_api_version("2021-10".parse().unwrap());
+    assert_eq!(client.api_version().as_str(), "2021-10");
+
+    let order = client
+        .orders()
+        .get_param("450789469", "")
+        .await
+        .unwrap()
+        .order
+        .unwrap();
+
+    assert_eq!(order.id, 450789469);
+    assert_eq!(order.financial_status, "paid");
+}
+
+#[test]
+fn test_api_version_defaults_to_the_generated_release() {
+    assert_eq!(shopify::ApiVersion::default().to_string(), "2021-07");
+    assert_eq!(shopify::ApiVersion::unstable().as_str(), "unstable");
+}
+
+#[tokio::test]
+async fn test_products_get_all_follows_page_info() {
+    use wiremock::{
+        matchers::{method, path, query_param, query_param_is_missing},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    // Follow-up pages only carry the cursor and the page size.
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/products.json"))
+        .and(query_param("page_info", "abc123"))
+        .and(query_param("limit", "250"))
+        .and(query_param_is_missing("vendor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "products": [{ "id": 3 }],
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/products.json"))
+        .and(query_param("vendor", "Burton"))
+        .and(query_param("limit", "250"))
+        .and(query_param_is_missing("page_info"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    "<https://acme.myshopify.com/admin/api/2021-07/products.json?limit=250&page_info=abc123>; rel=\"next\"",
+                )
+                .set_body_json(serde_json::json!({
+                    "products": [{ "id": 1 }, { "id": 2 }],
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let products = client(&server)
+        .products()
+        .get_all(
+            "", "", "", "Burton", "", "", "", "", "", "", "", "", "", "", "", "", "",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        products.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_throttle_waits_out_retry_after() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "0.5")
+                .insert_header("X-Shopify-Shop-Api-Call-Limit", "40/40"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Shopify-Shop-Api-Call-Limit", "1/40")
+                .set_body_json(serde_json::json!({ "shop": { "id": 690933842 } })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = std::time::Instant::now();
+    let shop = client(&server)
+        .store_properties()
+        .get_shop("")
+        .await
+        .unwrap()
+        .shop
+        .unwrap();
+
+    assert_eq!(shop.id, 690933842);
+    assert!(started.elapsed() >= std::time::Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn test_throttle_waits_for_the_bucket_without_retry_after() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(
+            ResponseTemplate::new(429).insert_header("X-Shopify-Shop-Api-Call-Limit", "40/40"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Shopify-Shop-Api-Call-Limit", "1/40")
+                .set_body_json(serde_json::json!({ "shop": { "id": 690933842 } })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // A bucket of 40 leaks a request every half second.
+    let started = std::time::Instant::now();
+    client(&server)
+        .store_properties()
+        .get_shop("")
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() >= std::time::Duration::from_millis(450));
+}
+
+#[tokio::test]
+async fn test_throttle_is_the_only_one_to_retry_a_429() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    // Sent once, then three more times by the throttle, and not again on top
+    // of that by the retries for transient failures.
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    assert!(client(&server)
+        .store_properties()
+        .get_shop("")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_throttle_paces_clones_of_a_client() {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    // A full bucket of 40 drains a request every half second.
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2021-07/shop.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-Shopify-Shop-Api-Call-Limit", "40/40")
+                .set_body_json(serde_json::json!({ "shop": { "id": 690933842 } })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let first = client(&server);
+    let second = first.clone();
+
+    first.store_properties().get_shop("").await.unwrap();
+
+    let started = std::time::Instant::now();
+    second.store_properties().get_shop("").await.unwrap();
+
+    assert!(started.elapsed() >= std::time::Duration::from_millis(450));
+}
+
+#[test]
+fn test_parse_call_limit() {
+    use shopify::throttle::parse_call_limit;
+
+    assert_eq!(parse_call_limit("32/40"), Some((32, 40)));
+    assert_eq!(parse_call_limit("1/400"), Some((1, 400)));
+    assert_eq!(parse_call_limit("40"), None);
+    assert_eq!(parse_call_limit("1/0"), None);
+}
@@ -22,13 +22,16 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn approve(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.apps.approve", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn restrict(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.apps.restrict", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
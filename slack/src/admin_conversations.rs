@@ -22,13 +22,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,7 +48,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn convert_private(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.convertToPrivate", None);
@@ -53,7 +59,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -70,13 +76,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::AdminConversationsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> ClientResult<crate::types::AdminConversationsCreateSchema> {
         let url = self.client.url("/admin.conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -93,13 +102,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -116,7 +128,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn disconnect_shared(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.disconnectShared", None);
@@ -124,7 +139,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -209,6 +224,69 @@ impl AdminConversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_teams`, this function returns all the pages of the request at once.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub async fn get_all_teams(&self, channel_id: &str, limit: i64) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.get_all_teams_stream(channel_id, limit)).await
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_all_teams`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub fn get_all_teams_stream(
+        &self,
+        channel_id: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin.conversations.getTeams?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::AdminConversationsGetTeamsSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.team_ids, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
      *
@@ -220,13 +298,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,13 +324,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -333,7 +417,10 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_conversation_prefs(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.setConversationPrefs", None);
@@ -341,7 +428,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -358,13 +445,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.setTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -381,13 +471,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,7 +18,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.addGroup", None);
@@ -26,7 +29,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -79,7 +82,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.removeGroup", None);
@@ -87,7 +93,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,13 +18,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -37,13 +40,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.addAlias", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -91,13 +97,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -110,13 +119,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.emoji.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -31,8 +31,8 @@ impl AdminInviteRequests {
             .post(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
             .await
@@ -57,8 +57,8 @@ impl AdminInviteRequests {
             .post(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
             .await
@@ -22,13 +22,16 @@ impl AdminTeams {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
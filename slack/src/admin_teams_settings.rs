@@ -49,7 +49,10 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
      */
-    pub async fn set_default_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDefaultChannels", None);
@@ -57,7 +60,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -74,7 +77,10 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_description(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDescription", None);
@@ -82,7 +88,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -99,7 +105,10 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_discoverability(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self
             .client
             .url("/admin.teams.settings.setDiscoverability", None);
@@ -107,7 +116,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -120,13 +129,16 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.settings.setIcon", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -143,13 +155,16 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.teams.settings.setName", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
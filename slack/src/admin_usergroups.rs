@@ -22,13 +22,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.addChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.addTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -115,13 +121,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn remove_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/admin.usergroups.removeChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -51,4 +51,64 @@ impl AppsPermissionsResources {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub async fn list_all(&self, limit: i64) -> ClientResult<Vec<crate::types::Resources>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(limit)).await
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Resources>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/apps.permissions.resources.list?{}", query_),
+            None,
+        );
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::AppsPermissionsResourcesListSuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.resources, next))
+            }
+        })
+    }
 }
@@ -22,13 +22,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn end(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.end", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -97,13 +103,16 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/calls.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
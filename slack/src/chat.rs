@@ -22,13 +22,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::ChatDeleteSuccessSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> ClientResult<crate::types::ChatDeleteSuccessSchema> {
         let url = self.client.url("/chat.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete_scheduled_message(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/chat.deleteScheduledMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -107,13 +113,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn me_message(&self) -> ClientResult<crate::types::ChatMeMessageSchema> {
+    pub async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> ClientResult<crate::types::ChatMeMessageSchema> {
         let url = self.client.url("/chat.meMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -132,13 +141,14 @@ impl Chat {
      */
     pub async fn post_ephemeral(
         &self,
+        body: &crate::types::ChatPostEphemeralRequest,
     ) -> ClientResult<crate::types::ChatPostEphemeralSuccessSchema> {
         let url = self.client.url("/chat.postEphemeral", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -155,13 +165,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_message(&self) -> ClientResult<crate::types::ChatPostMessageSuccessSchema> {
+    pub async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> ClientResult<crate::types::ChatPostMessageSuccessSchema> {
         let url = self.client.url("/chat.postMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -180,13 +193,14 @@ impl Chat {
      */
     pub async fn schedule_message(
         &self,
+        body: &crate::types::ChatScheduleMessageRequest,
     ) -> ClientResult<crate::types::ChatScheduleMessageSuccessSchema> {
         let url = self.client.url("/chat.scheduleMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -203,13 +217,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/chat.unfurl", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -226,13 +243,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::ChatUpdateSuccessSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> ClientResult<crate::types::ChatUpdateSuccessSchema> {
         let url = self.client.url("/chat.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
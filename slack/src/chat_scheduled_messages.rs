@@ -65,4 +65,82 @@ impl ChatScheduledMessages {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub async fn list_all(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+    ) -> ClientResult<Vec<crate::types::ScheduledMessages>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(channel, latest, oldest, limit))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ScheduledMessages>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/chat.scheduledMessages.list?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ChatScheduledMessagesListSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.scheduled_messages, next))
+            }
+        })
+    }
 }
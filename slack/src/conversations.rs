@@ -22,13 +22,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn close(&self) -> ClientResult<crate::types::ConversationsCloseSuccessSchema> {
+    pub async fn close(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsCloseSuccessSchema> {
         let url = self.client.url("/conversations.close", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +74,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -139,6 +148,91 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `history`, this function returns all the pages of the request at once.
+     *
+     * Fetches a conversation's history of messages and events.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub async fn get_all_history(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+    ) -> ClientResult<Vec<crate::types::ObjsMessage>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_history_stream(channel, latest, oldest, inclusive, limit),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `get_all_history`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Fetches a conversation's history of messages and events.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub fn get_all_history_stream(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ObjsMessage>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.history?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ConversationsHistorySuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.messages, next))
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/conversations.info` endpoint.
      *
@@ -197,13 +291,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -220,13 +317,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `channels:write`.
      */
-    pub async fn join(&self) -> ClientResult<crate::types::ConversationsJoinSuccessSchema> {
+    pub async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsJoinSuccessSchema> {
         let url = self.client.url("/conversations.join", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,13 +343,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.kick", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -266,13 +369,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn leave(&self) -> ClientResult<crate::types::ConversationsLeaveSuccessSchema> {
+    pub async fn leave(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsLeaveSuccessSchema> {
         let url = self.client.url("/conversations.leave", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -327,6 +433,80 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub async fn list_all(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+    ) -> ClientResult<Vec<Vec<crate::types::ObjsConversationAnyOf>>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(exclude_archived, types, limit))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<crate::types::ObjsConversationAnyOf>>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.list?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ConversationsListSuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.channels, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/conversations.mark` endpoint.
      *
@@ -338,13 +518,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.mark", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -394,6 +577,69 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `members`, this function returns all the pages of the request at once.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub async fn get_all_members(&self, channel: &str, limit: i64) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.get_all_members_stream(channel, limit)).await
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub fn get_all_members_stream(
+        &self,
+        channel: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.members?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ConversationsMembersSuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.members, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/conversations.open` endpoint.
      *
@@ -405,13 +651,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn open(&self) -> ClientResult<crate::types::ConversationsOpenSuccessSchema> {
+    pub async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> ClientResult<crate::types::ConversationsOpenSuccessSchema> {
         let url = self.client.url("/conversations.open", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -428,13 +677,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -504,6 +756,96 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `replies`, this function returns all the pages of the request at once.
+     *
+     * Retrieve a thread of messages posted to a conversation
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub async fn get_all_replies(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+    ) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_replies_stream(channel, ts, latest, oldest, inclusive, limit),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `get_all_replies`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieve a thread of messages posted to a conversation
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub fn get_all_replies_stream(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        if !ts.to_string().is_empty() {
+            query_args.push(("ts".to_string(), ts.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/conversations.replies?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ConversationsRepliesSuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.messages, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/conversations.setPurpose` endpoint.
      *
@@ -515,13 +857,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_purpose(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.setPurpose", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -538,13 +883,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_topic(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
         let url = self.client.url("/conversations.setTopic", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -561,13 +909,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -93,13 +93,16 @@ impl Dnd {
      *
      * FROM: <https://api.slack.com/methods/dnd.setSnooze>
      */
-    pub async fn set_snooze(&self) -> ClientResult<crate::types::DndSetSnoozeSchema> {
+    pub async fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> ClientResult<crate::types::DndSetSnoozeSchema> {
         let url = self.client.url("/dnd.setSnooze", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
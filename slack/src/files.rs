@@ -22,13 +22,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/files.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -86,6 +89,75 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `info`, this function returns all the pages of the request at once.
+     *
+     * Gets information about a file.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub async fn get_all_info(
+        &self,
+        file: &str,
+        count: &str,
+        limit: i64,
+    ) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.get_all_info_stream(file, count, limit)).await
+    }
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `get_all_info`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Gets information about a file.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub fn get_all_info_stream(
+        &self,
+        file: &str,
+        count: &str,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !file.is_empty() {
+            query_args.push(("file".to_string(), file.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files.info?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::FilesInfoSchema = serde_json::from_value(value)?;
+
+                Ok((resp.comments, next))
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/files.list` endpoint.
      *
@@ -167,13 +239,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn revoke_public_url(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.revokePublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -190,13 +265,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn shared_public_url(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.sharedPublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -209,13 +287,16 @@ impl Files {
      *
      * FROM: <https://api.slack.com/methods/files.upload>
      */
-    pub async fn upload(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
         let url = self.client.url("/files.upload", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,13 +18,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -130,13 +133,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -193,13 +199,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/files.remote.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
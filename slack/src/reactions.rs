@@ -22,13 +22,16 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/reactions.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -145,6 +148,82 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists reactions made by a user.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub async fn list_all(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        limit: i64,
+    ) -> ClientResult<Vec<String>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(user, full, count, limit)).await
+    }
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists reactions made by a user.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if full {
+            query_args.push(("full".to_string(), full.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/reactions.list?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::ReactionsListSchema = serde_json::from_value(value)?;
+
+                Ok((resp.items, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/reactions.remove` endpoint.
      *
@@ -156,13 +235,16 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/reactions.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
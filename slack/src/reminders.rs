@@ -22,13 +22,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::RemindersAddSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> ClientResult<crate::types::RemindersAddSchema> {
         let url = self.client.url("/reminders.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn complete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/reminders.complete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +74,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/reminders.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -3615,7 +3615,7 @@ pub struct ObjsUserProfileShort {
     pub team: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminInviteRequestsApproveRequest {
     #[serde(
         default,
@@ -3631,6 +3631,28 @@ pub struct AdminInviteRequestsApproveRequest {
     pub team_id: String,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminAppsApproveRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub app_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub request_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
 /// Schema for successful response from dnd.endDnd method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DndEndSchema {
@@ -3641,6 +3663,42 @@ pub struct DndEndSchema {
     pub ok: bool,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsDeleteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsCreateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_wide: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
 /// Schema for successful response of admin.conversations.create
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminConversationsCreateSchema {
@@ -3660,6 +3718,22 @@ pub struct AdminConversationsCreateSchema {
     pub ok: bool,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsDisconnectSharedRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub leaving_team_ids: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CanThread {
     #[serde(
@@ -3721,299 +3795,1162 @@ pub struct AdminConversationsGetTeamsSchema {
     pub team_ids: Vec<String>,
 }
 
-/// Schema for successful response of admin.conversations.search
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsSearchSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub channels: Vec<ObjsChannel>,
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsInviteRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_cursor: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Im {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub resources: Option<ObjsResources>,
+    pub channel_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub scopes: Vec<String>,
+    pub user_ids: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Team {
-    #[serde()]
-    pub resources: ObjsResources,
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsRenameRequest {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Info {
-    #[serde()]
-    pub app_home: Im,
-    #[serde()]
-    pub channel: Im,
-    #[serde()]
-    pub group: Im,
-    #[serde()]
-    pub im: Im,
-    #[serde()]
-    pub mpim: Im,
-    #[serde()]
-    pub team: Team,
-}
-
-/// Schema for successful response from apps.permissions.info method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppsPermissionsInfoSchema {
-    #[serde()]
-    pub info: Info,
+    pub channel_id: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Resources {
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsRestrictAccessAddGroupRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub channel_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub type_: String,
-}
-
-/// Schema for successful response apps.permissions.resources.list method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppsPermissionsResourcesListSuccessSchema {
+    pub group_id: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub team_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub resources: Vec<Resources>,
-    /**
-     * Schema for successful response apps.permissions.resources.list method
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response_metadata: Option<NewPagingStyle>,
+    pub token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Scopes {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub app_home: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub channel: Vec<String>,
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsRestrictAccessRemoveGroupRequest {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub group: Vec<String>,
+    pub channel_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub im: Vec<String>,
+    pub group_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub mpim: Vec<String>,
+    pub team_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team: Vec<String>,
+    pub token: String,
+}
+
+/// Schema for successful response of admin.conversations.search
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsSearchSchema {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub user: Vec<String>,
-}
-
-/// Schema for successful response api.permissions.scopes.list method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiPermissionsScopesListSuccessSchema {
+    pub channels: Vec<ObjsChannel>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-    #[serde()]
-    pub scopes: Scopes,
+    pub next_cursor: String,
 }
 
-/// Schema for successful response from auth.revoke method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthRevokeSchema {
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsSetConversationPrefsRequest {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub channel_id: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub revoked: bool,
+    pub prefs: String,
 }
 
-/// Schema for successful response auth.test method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthTestSuccessSchema {
-    /**
-     * Schema for successful response auth.test method
-     */
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsSetTeamsRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub bot_id: String,
-    /**
-     * Schema for successful response auth.test method
-     */
+    pub channel_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_channel: Option<bool>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_enterprise_install: bool,
+    pub target_team_ids: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminEmojiAddRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team: String,
+    pub name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team_id: String,
+    pub token: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminEmojiAddAliasRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub alias_for: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminEmojiRemoveRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminEmojiRenameRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub new_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsCreateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_discoverability: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_name: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetDefaultChannelsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetDescriptionRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetDiscoverabilityRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub discoverability: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetIconRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub image_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetNameRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsergroupsAddChannelsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsergroupsAddTeamsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_provision: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_ids: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsergroupsRemoveChannelsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersAssignRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_restricted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_ultra_restricted: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersInviteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub custom_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub guest_expiration_ts: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_restricted: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_ultra_restricted: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub real_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resend: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSetRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSessionInvalidateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub session_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSessionResetRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile_only: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web_only: Option<bool>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSetExpirationRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub expiration_ts: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Im {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Team {
+    #[serde()]
+    pub resources: ObjsResources,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Info {
+    #[serde()]
+    pub app_home: Im,
+    #[serde()]
+    pub channel: Im,
+    #[serde()]
+    pub group: Im,
+    #[serde()]
+    pub im: Im,
+    #[serde()]
+    pub mpim: Im,
+    #[serde()]
+    pub team: Team,
+}
+
+/// Schema for successful response from apps.permissions.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsInfoSchema {
+    #[serde()]
+    pub info: Info,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Resources {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+/// Schema for successful response apps.permissions.resources.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsResourcesListSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub resources: Vec<Resources>,
+    /**
+     * Schema for successful response apps.permissions.resources.list method
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<NewPagingStyle>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Scopes {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub app_home: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub group: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub im: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub mpim: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub user: Vec<String>,
+}
+
+/// Schema for successful response api.permissions.scopes.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiPermissionsScopesListSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde()]
+    pub scopes: Scopes,
+}
+
+/// Schema for successful response from auth.revoke method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthRevokeSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub revoked: bool,
+}
+
+/// Schema for successful response auth.test method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthTestSuccessSchema {
+    /**
+     * Schema for successful response auth.test method
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bot_id: String,
+    /**
+     * Schema for successful response auth.test method
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_enterprise_install: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Bot {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub app_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deleted: bool,
+    #[serde()]
+    pub icons: Icons,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+/// Schema for successful response from bots.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BotsInfoSchema {
+    #[serde()]
+    pub bot: Bot,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_by: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub date_start: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub desktop_app_join_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_display_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user: String,
+    pub external_unique_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user_id: String,
+    pub join_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsEndRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub duration: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsParticipantsAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub desktop_app_join_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub join_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatDeleteRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub ts: f64,
 }
 
+/// Schema for successful response of chat.delete method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Bot {
+pub struct ChatDeleteSuccessSchema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub app_id: String,
+    pub channel: String,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub deleted: bool,
-    #[serde()]
-    pub icons: Icons,
+    pub ok: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub ts: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatDeleteScheduledMessageRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub channel: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub updated: i64,
+    pub scheduled_message_id: String,
+}
+
+/// Schema for successful response chat.getPermalink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatGetPermalinkSuccessSchema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user_id: String,
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub permalink: String,
 }
 
-/// Schema for successful response from bots.info method
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatMeMessageRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+/// Schema for successful response from chat.meMessage method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BotsInfoSchema {
-    #[serde()]
-    pub bot: Bot,
+pub struct ChatMeMessageSchema {
+    /**
+     * Schema for successful response from chat.meMessage method
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    /**
+     * Schema for successful response from chat.meMessage method
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostEphemeralRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_emoji: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thread_ts: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// Schema for successful response from chat.postEphemeral method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostEphemeralSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message_ts: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostMessageRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub as_user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-}
-
-/// Schema for successful response of chat.delete method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatDeleteSuccessSchema {
+    pub blocks: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4022,59 +4959,68 @@ pub struct ChatDeleteSuccessSchema {
     pub channel: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub icon_emoji: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
-}
-
-/// Schema for successful response chat.getPermalink
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatGetPermalinkSuccessSchema {
+    pub icon_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
+    pub parse: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub text: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub permalink: String,
+    pub thread_ts: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
 }
 
-/// Schema for successful response from chat.meMessage method
+/// Schema for successful response of chat.postMessage method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatMeMessageSchema {
-    /**
-     * Schema for successful response from chat.meMessage method
-     */
+pub struct ChatPostMessageSuccessSchema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub channel: String,
+    #[serde()]
+    pub message: ObjsMessage,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from chat.meMessage method
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4083,44 +5029,60 @@ pub struct ChatMeMessageSchema {
     pub ts: String,
 }
 
-/// Schema for successful response from chat.postEphemeral method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatPostEphemeralSuccessSchema {
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatScheduleMessageRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub message_ts: String,
+    pub attachments: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-}
-
-/// Schema for successful response of chat.postMessage method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatPostMessageSuccessSchema {
+    pub blocks: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub channel: String,
-    #[serde()]
-    pub message: ObjsMessage,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub parse: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
+    pub post_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub thread_ts: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4248,6 +5210,94 @@ pub struct ChatScheduledMessagesListSchema {
     pub scheduled_messages: Vec<ScheduledMessages>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatUnfurlRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub unfurls: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_auth_message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_auth_required: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_auth_url: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub as_user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub link_names: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MessageObject {
     #[serde(
@@ -4296,6 +5346,16 @@ pub struct ChatUpdateSuccessSchema {
     pub ts: String,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsJoinRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+}
+
 /// Schema for successful response conversations.close method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsCloseSuccessSchema {
@@ -4322,6 +5382,18 @@ pub struct ConversationsCloseSuccessSchema {
     pub ok: bool,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsCreateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
 /// Schema for successful response conversations.info
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsInfoSuccessSchema {
@@ -4390,6 +5462,22 @@ pub struct ConversationsHistorySuccessSchema {
     pub pin_count: i64,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsInviteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ResponseMetadata {
     #[serde(
@@ -4430,6 +5518,22 @@ pub struct ConversationsJoinSuccessSchema {
     pub warning: String,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsKickRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
 /// Schema for successful response from conversations.leave method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsLeaveSuccessSchema {
@@ -4469,6 +5573,22 @@ pub struct ConversationsListSuccessSchema {
     pub response_metadata: Option<NewPagingStyle>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsMarkRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub ts: f64,
+}
+
 /// Schema for successful response conversations.members method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConversationsMembersSuccessSchema {
@@ -4487,6 +5607,24 @@ pub struct ConversationsMembersSuccessSchema {
     pub response_metadata: NewPagingStyle,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsOpenRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_im: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChannelData {
     #[serde(
@@ -4581,7 +5719,23 @@ pub struct ConversationsOpenSuccessSchema {
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub ok: bool,
+    pub ok: bool,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsRenameRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
 }
 
 /// Schema for successful response from conversations.replies method
@@ -4608,6 +5762,38 @@ pub struct ConversationsRepliesSuccessSchema {
     pub ok: bool,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsSetPurposeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub purpose: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsSetTopicRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub topic: String,
+}
+
 /// Schema for successful response from dnd.endSnooze method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DndEndSnoozeSchema {
@@ -4693,6 +5879,22 @@ pub struct DndInfoSchema {
     pub snooze_remaining: i64,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DndSetSnoozeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub num_minutes: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 /// Schema for successful response from dnd.setSnooze method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DndSetSnoozeSchema {
@@ -4720,6 +5922,32 @@ pub struct DndSetSnoozeSchema {
     pub snooze_remaining: i64,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesCommentsDeleteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesDeleteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+}
+
 /// Schema for successful response from files.info method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesInfoSchema {
@@ -4781,6 +6009,126 @@ pub struct FilesListSchema {
     pub paging: ObjsPaging,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesRemoteAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filetype: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub indexable_file_contents: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub preview_image: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesRemoteRemoveRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesRemoteUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filetype: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub indexable_file_contents: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub preview_image: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 /// Schema for successful response files.upload method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesUploadSchema {
@@ -4793,6 +6141,64 @@ pub struct FilesUploadSchema {
     pub ok: bool,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesUploadRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channels: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filename: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filetype: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub initial_comment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub thread_ts: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 /// Schema for successful response from migration.exchange method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MigrationExchangeSuccessSchema {
@@ -4829,6 +6235,22 @@ pub struct MigrationExchangeSuccessSchema {
     pub user_id_map: Option<Fields>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PinsAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub enum ItemsType {
     #[serde(rename = "file")]
@@ -4995,6 +6417,28 @@ pub struct PinsListResponseAnyOf {
     pub pins_list_response_data: PinsListResponseData,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
 /// Schema for successful response from reactions.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReactionsListSchema {
@@ -5025,6 +6469,62 @@ pub struct ReactionsListSchema {
     pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsRemoveRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file_comment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemindersAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub time: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
 /// Schema for successful response from reminders.add method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersAddSchema {
@@ -5037,6 +6537,16 @@ pub struct RemindersAddSchema {
     pub reminder: ObjsReminder,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemindersDeleteRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reminder: String,
+}
+
 /// Schema for successful response from reminders.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersListSchema {
@@ -5111,6 +6621,34 @@ pub struct RtmConnectSchema {
     pub url: String,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StarsAddRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file_comment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
 /// Schema for successful response from stars.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StarsListSchema {
@@ -5326,6 +6864,36 @@ pub struct TeamProfileGetSuccessSchema {
     pub profile: Profile,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsCreateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channels: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
 /// Schema for successful response from usergroups.create method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsCreateSchema {
@@ -5338,6 +6906,18 @@ pub struct UsergroupsCreateSchema {
     pub usergroup: ObjsSubteam,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsEnableRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+}
+
 /// Schema for successful response from usergroups.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsListSchema {
@@ -5354,6 +6934,42 @@ pub struct UsergroupsListSchema {
     pub usergroups: Vec<ObjsSubteam>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsUpdateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channels: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub handle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+}
+
 /// Schema for successful response from usergroups.users.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsUsersListSchema {
@@ -5370,6 +6986,24 @@ pub struct UsergroupsUsersListSchema {
     pub users: Vec<String>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsUsersUpdateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
 /// Schema for successful response from users.conversations method. Returned conversation objects do not include `num_members` or `is_member`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersConversationsSuccessSchema {
@@ -5391,6 +7025,16 @@ pub struct UsersConversationsSuccessSchema {
     pub response_metadata: Option<NewPagingStyle>,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersDeletePhotoRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 /// Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiMethodUsersGetPresence {
@@ -5748,6 +7392,34 @@ pub struct UsersProfileGetSchema {
     pub profile: ObjsUserProfile,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersProfileSetRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub profile: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
 /// Schema for successful response from users.profile.set method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersProfileSetSchema {
@@ -5775,6 +7447,40 @@ pub struct UsersProfileSetSchema {
     pub username: String,
 }
 
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSetPhotoRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_w: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_x: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_y: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub image: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersSetPhotoSchemaProfile {
     #[serde(
@@ -5844,3 +7550,674 @@ pub struct UsersSetPhotoSchema {
     #[serde()]
     pub profile: UsersSetPhotoSchemaProfile,
 }
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSetPresenceRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub presence: String,
+}
+
+/// The error code Slack answers with when `ok` is `false`.
+///
+/// Codes Slack documents are given their own variant, anything else is kept in `Other`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum SlackErrorCode {
+    AccountInactiv,
+    AccountInactive,
+    AlreadyArchived,
+    AlreadyInChannel,
+    AlreadyPinned,
+    AlreadyReacted,
+    AlreadyStarred,
+    AppMissingActionUrl,
+    BadClientSecret,
+    BadImage,
+    BadTimestamp,
+    BadToken,
+    BotNotFound,
+    CannotAddBot,
+    CannotAddOthers,
+    CannotAddOthersRecurring,
+    CannotAddSlackbot,
+    CannotCompleteOthers,
+    CannotCompleteRecurring,
+    CannotCreateDialog,
+    CannotFindService,
+    CannotKickHomeTeam,
+    CannotKickTeam,
+    CannotParse,
+    CannotPrompt,
+    CannotUnfurlUrl,
+    CannotUpdateAdminUser,
+    CantArchiveGeneral,
+    CantDelete,
+    CantDeleteFile,
+    CantDeleteMessage,
+    CantInvite,
+    CantInviteSelf,
+    CantKickFromGeneral,
+    CantKickSelf,
+    CantLeaveGeneral,
+    CantUpdateMessage,
+    ChannelNotArchived,
+    ChannelNotFound,
+    ChannelTypeNotSupported,
+    ClientTokenMismatch,
+    CommentNotFound,
+    ComplianceExportsPreventDeletion,
+    CouldNotArchiveChannel,
+    CouldNotConvertChannel,
+    CouldNotCreateChannel,
+    CouldNotDeleteChannel,
+    CouldNotGetConversationPrefs,
+    CouldNotGetTeams,
+    CouldNotRenameChannel,
+    CouldNotSetChannelPref,
+    CouldNotUnarchiveChannel,
+    DefaultOrgWideChannel,
+    EditWindowClosed,
+    EkmAccessDenied,
+    EnterpriseIsRestricted,
+    ExternalChannelMigrating,
+    FailedForSomeUsers,
+    FailedSendingDialog,
+    FatalError,
+    FeatureNotEnabled,
+    FetchMembersFailed,
+    FileCommentNotFound,
+    FileDeleted,
+    FileNotFound,
+    FileNotShared,
+    FileUploadsDisabled,
+    FileUploadsExceptImagesDisabled,
+    InternalError,
+    InvalidArgName,
+    InvalidArguments,
+    InvalidArrayArg,
+    InvalidAuth,
+    InvalidChannel,
+    InvalidCharset,
+    InvalidClientId,
+    InvalidCursor,
+    InvalidFormData,
+    InvalidJson,
+    InvalidLimit,
+    InvalidName,
+    InvalidNameMaxlength,
+    InvalidNamePunctuation,
+    InvalidNameRequired,
+    InvalidNameSpecials,
+    InvalidPostTyp,
+    InvalidPostType,
+    InvalidPresence,
+    InvalidProfile,
+    InvalidScheduledMessageId,
+    InvalidScope,
+    InvalidSearchChannelType,
+    InvalidSort,
+    InvalidSortDir,
+    InvalidTeam,
+    InvalidTime,
+    InvalidTimestamp,
+    InvalidTrigger,
+    InvalidTsLatest,
+    InvalidTsOldest,
+    InvalidTypes,
+    InvalidUser,
+    InvalidUserCombination,
+    IsArchived,
+    IsInactive,
+    JsonNotObject,
+    LastMember,
+    LeavingTeamNotInChannel,
+    LeavingTeamRequired,
+    LimitRequired,
+    MessageNotFound,
+    MethodNotSupportedForChannelType,
+    MissingCharset,
+    MissingDialog,
+    MissingDuration,
+    MissingPostTyp,
+    MissingPostType,
+    MissingScope,
+    MissingTrigger,
+    MissingUnfurls,
+    MsgTooLong,
+    NameTaken,
+    NoChannel,
+    NoItemSpecified,
+    NoPermission,
+    NoReaction,
+    NoTeamsToDisconnect,
+    NoText,
+    NoUser,
+    NotAdmin,
+    NotAllowed,
+    NotAllowedTokenType,
+    NotAnAdmin,
+    NotAnEnterprise,
+    NotAppAdmin,
+    NotArchived,
+    NotAuthed,
+    NotAuthorized,
+    NotEnoughUsers,
+    NotEnterpriseTeam,
+    NotFound,
+    NotInChannel,
+    NotPinnable,
+    NotPinned,
+    NotStarred,
+    NotSupported,
+    OrgLoginRequired,
+    OverPaginationLimit,
+    PaidOnly,
+    PermissionDenied,
+    PostingToGeneralChannelDenied,
+    ProfileSetFailed,
+    RateLimited,
+    RequestTimeou,
+    RequestTimeout,
+    ReservedName,
+    RestrictedAction,
+    RestrictedActionNonThreadableChannel,
+    RestrictedActionReadOnlyChannel,
+    RestrictedActionThreadOnlyChannel,
+    SnoozeEndFailed,
+    SnoozeFailed,
+    SnoozeNotActive,
+    StorageLimitReached,
+    SuperfluousCharset,
+    TeamAddedToOrg,
+    TeamOrOrgRequired,
+    TeamNotFound,
+    ThreadNotFound,
+    TimeInPast,
+    TimeTooFar,
+    TimezoneCountFailed,
+    TokenRevoked,
+    TooLarge,
+    TooLong,
+    TooManyAttachments,
+    TooManyEmoji,
+    TooManyFrames,
+    TooManyReactions,
+    TooManyUsers,
+    TriggerExchanged,
+    TriggerExpired,
+    UnknownError,
+    UnknownType,
+    UnsupportedTeamType,
+    UpgradeRequire,
+    UpgradeRequired,
+    UraMaxChannels,
+    UserDisabled,
+    UserDoesNotOwnChannel,
+    UserIsBot,
+    UserIsRestricted,
+    UserIsUltraRestricted,
+    UserMustBeAdmin,
+    UserNotFound,
+    UserNotInChannel,
+    UserNotVisible,
+    UsersListNotSupplied,
+    UsersNotFound,
+    ValidationErrors,
+    Other(String),
+}
+
+impl SlackErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SlackErrorCode::AccountInactiv => "account_inactiv",
+            SlackErrorCode::AccountInactive => "account_inactive",
+            SlackErrorCode::AlreadyArchived => "already_archived",
+            SlackErrorCode::AlreadyInChannel => "already_in_channel",
+            SlackErrorCode::AlreadyPinned => "already_pinned",
+            SlackErrorCode::AlreadyReacted => "already_reacted",
+            SlackErrorCode::AlreadyStarred => "already_starred",
+            SlackErrorCode::AppMissingActionUrl => "app_missing_action_url",
+            SlackErrorCode::BadClientSecret => "bad_client_secret",
+            SlackErrorCode::BadImage => "bad_image",
+            SlackErrorCode::BadTimestamp => "bad_timestamp",
+            SlackErrorCode::BadToken => "bad_token",
+            SlackErrorCode::BotNotFound => "bot_not_found",
+            SlackErrorCode::CannotAddBot => "cannot_add_bot",
+            SlackErrorCode::CannotAddOthers => "cannot_add_others",
+            SlackErrorCode::CannotAddOthersRecurring => "cannot_add_others_recurring",
+            SlackErrorCode::CannotAddSlackbot => "cannot_add_slackbot",
+            SlackErrorCode::CannotCompleteOthers => "cannot_complete_others",
+            SlackErrorCode::CannotCompleteRecurring => "cannot_complete_recurring",
+            SlackErrorCode::CannotCreateDialog => "cannot_create_dialog",
+            SlackErrorCode::CannotFindService => "cannot_find_service",
+            SlackErrorCode::CannotKickHomeTeam => "cannot_kick_home_team",
+            SlackErrorCode::CannotKickTeam => "cannot_kick_team",
+            SlackErrorCode::CannotParse => "cannot_parse",
+            SlackErrorCode::CannotPrompt => "cannot_prompt",
+            SlackErrorCode::CannotUnfurlUrl => "cannot_unfurl_url",
+            SlackErrorCode::CannotUpdateAdminUser => "cannot_update_admin_user",
+            SlackErrorCode::CantArchiveGeneral => "cant_archive_general",
+            SlackErrorCode::CantDelete => "cant_delete",
+            SlackErrorCode::CantDeleteFile => "cant_delete_file",
+            SlackErrorCode::CantDeleteMessage => "cant_delete_message",
+            SlackErrorCode::CantInvite => "cant_invite",
+            SlackErrorCode::CantInviteSelf => "cant_invite_self",
+            SlackErrorCode::CantKickFromGeneral => "cant_kick_from_general",
+            SlackErrorCode::CantKickSelf => "cant_kick_self",
+            SlackErrorCode::CantLeaveGeneral => "cant_leave_general",
+            SlackErrorCode::CantUpdateMessage => "cant_update_message",
+            SlackErrorCode::ChannelNotArchived => "channel_not_archived",
+            SlackErrorCode::ChannelNotFound => "channel_not_found",
+            SlackErrorCode::ChannelTypeNotSupported => "channel_type_not_supported",
+            SlackErrorCode::ClientTokenMismatch => "client_id_token_mismatch",
+            SlackErrorCode::CommentNotFound => "comment_not_found",
+            SlackErrorCode::ComplianceExportsPreventDeletion => {
+                "compliance_exports_prevent_deletion"
+            }
+            SlackErrorCode::CouldNotArchiveChannel => "could_not_archive_channel",
+            SlackErrorCode::CouldNotConvertChannel => "could_not_convert_channel",
+            SlackErrorCode::CouldNotCreateChannel => "could_not_create_channel",
+            SlackErrorCode::CouldNotDeleteChannel => "could_not_delete_channel",
+            SlackErrorCode::CouldNotGetConversationPrefs => "could_not_get_conversation_prefs",
+            SlackErrorCode::CouldNotGetTeams => "could_not_get_teams",
+            SlackErrorCode::CouldNotRenameChannel => "could_not_rename_channel",
+            SlackErrorCode::CouldNotSetChannelPref => "could_not_set_channel_pref",
+            SlackErrorCode::CouldNotUnarchiveChannel => "could_not_unarchive_channel",
+            SlackErrorCode::DefaultOrgWideChannel => "default_org_wide_channel",
+            SlackErrorCode::EditWindowClosed => "edit_window_closed",
+            SlackErrorCode::EkmAccessDenied => "ekm_access_denied",
+            SlackErrorCode::EnterpriseIsRestricted => "enterprise_is_restricted",
+            SlackErrorCode::ExternalChannelMigrating => "external_channel_migrating",
+            SlackErrorCode::FailedForSomeUsers => "failed_for_some_users",
+            SlackErrorCode::FailedSendingDialog => "failed_sending_dialog",
+            SlackErrorCode::FatalError => "fatal_error",
+            SlackErrorCode::FeatureNotEnabled => "feature_not_enabled",
+            SlackErrorCode::FetchMembersFailed => "fetch_members_failed",
+            SlackErrorCode::FileCommentNotFound => "file_comment_not_found",
+            SlackErrorCode::FileDeleted => "file_deleted",
+            SlackErrorCode::FileNotFound => "file_not_found",
+            SlackErrorCode::FileNotShared => "file_not_shared",
+            SlackErrorCode::FileUploadsDisabled => "file_uploads_disabled",
+            SlackErrorCode::FileUploadsExceptImagesDisabled => {
+                "file_uploads_except_images_disabled"
+            }
+            SlackErrorCode::InternalError => "internal_error",
+            SlackErrorCode::InvalidArgName => "invalid_arg_name",
+            SlackErrorCode::InvalidArguments => "invalid_arguments",
+            SlackErrorCode::InvalidArrayArg => "invalid_array_arg",
+            SlackErrorCode::InvalidAuth => "invalid_auth",
+            SlackErrorCode::InvalidChannel => "invalid_channel",
+            SlackErrorCode::InvalidCharset => "invalid_charset",
+            SlackErrorCode::InvalidClientId => "invalid_client_id",
+            SlackErrorCode::InvalidCursor => "invalid_cursor",
+            SlackErrorCode::InvalidFormData => "invalid_form_data",
+            SlackErrorCode::InvalidJson => "invalid_json",
+            SlackErrorCode::InvalidLimit => "invalid_limit",
+            SlackErrorCode::InvalidName => "invalid_name",
+            SlackErrorCode::InvalidNameMaxlength => "invalid_name_maxlength",
+            SlackErrorCode::InvalidNamePunctuation => "invalid_name_punctuation",
+            SlackErrorCode::InvalidNameRequired => "invalid_name_required",
+            SlackErrorCode::InvalidNameSpecials => "invalid_name_specials",
+            SlackErrorCode::InvalidPostTyp => "invalid_post_typ",
+            SlackErrorCode::InvalidPostType => "invalid_post_type",
+            SlackErrorCode::InvalidPresence => "invalid_presence",
+            SlackErrorCode::InvalidProfile => "invalid_profile",
+            SlackErrorCode::InvalidScheduledMessageId => "invalid_scheduled_message_id",
+            SlackErrorCode::InvalidScope => "invalid_scope",
+            SlackErrorCode::InvalidSearchChannelType => "invalid_search_channel_type",
+            SlackErrorCode::InvalidSort => "invalid_sort",
+            SlackErrorCode::InvalidSortDir => "invalid_sort_dir",
+            SlackErrorCode::InvalidTeam => "invalid_team",
+            SlackErrorCode::InvalidTime => "invalid_time",
+            SlackErrorCode::InvalidTimestamp => "invalid_timestamp",
+            SlackErrorCode::InvalidTrigger => "invalid_trigger",
+            SlackErrorCode::InvalidTsLatest => "invalid_ts_latest",
+            SlackErrorCode::InvalidTsOldest => "invalid_ts_oldest",
+            SlackErrorCode::InvalidTypes => "invalid_types",
+            SlackErrorCode::InvalidUser => "invalid_user",
+            SlackErrorCode::InvalidUserCombination => "invalid_user_combination",
+            SlackErrorCode::IsArchived => "is_archived",
+            SlackErrorCode::IsInactive => "is_inactive",
+            SlackErrorCode::JsonNotObject => "json_not_object",
+            SlackErrorCode::LastMember => "last_member",
+            SlackErrorCode::LeavingTeamNotInChannel => "leaving_team_not_in_channel",
+            SlackErrorCode::LeavingTeamRequired => "leaving_team_required",
+            SlackErrorCode::LimitRequired => "limit_required",
+            SlackErrorCode::MessageNotFound => "message_not_found",
+            SlackErrorCode::MethodNotSupportedForChannelType => {
+                "method_not_supported_for_channel_type"
+            }
+            SlackErrorCode::MissingCharset => "missing_charset",
+            SlackErrorCode::MissingDialog => "missing_dialog",
+            SlackErrorCode::MissingDuration => "missing_duration",
+            SlackErrorCode::MissingPostTyp => "missing_post_typ",
+            SlackErrorCode::MissingPostType => "missing_post_type",
+            SlackErrorCode::MissingScope => "missing_scope",
+            SlackErrorCode::MissingTrigger => "missing_trigger",
+            SlackErrorCode::MissingUnfurls => "missing_unfurls",
+            SlackErrorCode::MsgTooLong => "msg_too_long",
+            SlackErrorCode::NameTaken => "name_taken",
+            SlackErrorCode::NoChannel => "no_channel",
+            SlackErrorCode::NoItemSpecified => "no_item_specified",
+            SlackErrorCode::NoPermission => "no_permission",
+            SlackErrorCode::NoReaction => "no_reaction",
+            SlackErrorCode::NoTeamsToDisconnect => "no_teams_to_disconnect",
+            SlackErrorCode::NoText => "no_text",
+            SlackErrorCode::NoUser => "no_user",
+            SlackErrorCode::NotAdmin => "not_admin",
+            SlackErrorCode::NotAllowed => "not_allowed",
+            SlackErrorCode::NotAllowedTokenType => "not_allowed_token_type",
+            SlackErrorCode::NotAnAdmin => "not_an_admin",
+            SlackErrorCode::NotAnEnterprise => "not_an_enterprise",
+            SlackErrorCode::NotAppAdmin => "not_app_admin",
+            SlackErrorCode::NotArchived => "not_archived",
+            SlackErrorCode::NotAuthed => "not_authed",
+            SlackErrorCode::NotAuthorized => "not_authorized",
+            SlackErrorCode::NotEnoughUsers => "not_enough_users",
+            SlackErrorCode::NotEnterpriseTeam => "not_enterprise_team",
+            SlackErrorCode::NotFound => "not_found",
+            SlackErrorCode::NotInChannel => "not_in_channel",
+            SlackErrorCode::NotPinnable => "not_pinnable",
+            SlackErrorCode::NotPinned => "not_pinned",
+            SlackErrorCode::NotStarred => "not_starred",
+            SlackErrorCode::NotSupported => "not_supported",
+            SlackErrorCode::OrgLoginRequired => "org_login_required",
+            SlackErrorCode::OverPaginationLimit => "over_pagination_limit",
+            SlackErrorCode::PaidOnly => "paid_only",
+            SlackErrorCode::PermissionDenied => "permission_denied",
+            SlackErrorCode::PostingToGeneralChannelDenied => "posting_to_general_channel_denied",
+            SlackErrorCode::ProfileSetFailed => "profile_set_failed",
+            SlackErrorCode::RateLimited => "rate_limited",
+            SlackErrorCode::RequestTimeou => "request_timeou",
+            SlackErrorCode::RequestTimeout => "request_timeout",
+            SlackErrorCode::ReservedName => "reserved_name",
+            SlackErrorCode::RestrictedAction => "restricted_action",
+            SlackErrorCode::RestrictedActionNonThreadableChannel => {
+                "restricted_action_non_threadable_channel"
+            }
+            SlackErrorCode::RestrictedActionReadOnlyChannel => {
+                "restricted_action_read_only_channel"
+            }
+            SlackErrorCode::RestrictedActionThreadOnlyChannel => {
+                "restricted_action_thread_only_channel"
+            }
+            SlackErrorCode::SnoozeEndFailed => "snooze_end_failed",
+            SlackErrorCode::SnoozeFailed => "snooze_failed",
+            SlackErrorCode::SnoozeNotActive => "snooze_not_active",
+            SlackErrorCode::StorageLimitReached => "storage_limit_reached",
+            SlackErrorCode::SuperfluousCharset => "superfluous_charset",
+            SlackErrorCode::TeamAddedToOrg => "team_added_to_org",
+            SlackErrorCode::TeamOrOrgRequired => "team_id_or_org_required",
+            SlackErrorCode::TeamNotFound => "team_not_found",
+            SlackErrorCode::ThreadNotFound => "thread_not_found",
+            SlackErrorCode::TimeInPast => "time_in_past",
+            SlackErrorCode::TimeTooFar => "time_too_far",
+            SlackErrorCode::TimezoneCountFailed => "timezone_count_failed",
+            SlackErrorCode::TokenRevoked => "token_revoked",
+            SlackErrorCode::TooLarge => "too_large",
+            SlackErrorCode::TooLong => "too_long",
+            SlackErrorCode::TooManyAttachments => "too_many_attachments",
+            SlackErrorCode::TooManyEmoji => "too_many_emoji",
+            SlackErrorCode::TooManyFrames => "too_many_frames",
+            SlackErrorCode::TooManyReactions => "too_many_reactions",
+            SlackErrorCode::TooManyUsers => "too_many_users",
+            SlackErrorCode::TriggerExchanged => "trigger_exchanged",
+            SlackErrorCode::TriggerExpired => "trigger_expired",
+            SlackErrorCode::UnknownError => "unknown_error",
+            SlackErrorCode::UnknownType => "unknown_type",
+            SlackErrorCode::UnsupportedTeamType => "unsupported_team_type",
+            SlackErrorCode::UpgradeRequire => "upgrade_require",
+            SlackErrorCode::UpgradeRequired => "upgrade_required",
+            SlackErrorCode::UraMaxChannels => "ura_max_channels",
+            SlackErrorCode::UserDisabled => "user_disabled",
+            SlackErrorCode::UserDoesNotOwnChannel => "user_does_not_own_channel",
+            SlackErrorCode::UserIsBot => "user_is_bot",
+            SlackErrorCode::UserIsRestricted => "user_is_restricted",
+            SlackErrorCode::UserIsUltraRestricted => "user_is_ultra_restricted",
+            SlackErrorCode::UserMustBeAdmin => "user_must_be_admin",
+            SlackErrorCode::UserNotFound => "user_not_found",
+            SlackErrorCode::UserNotInChannel => "user_not_in_channel",
+            SlackErrorCode::UserNotVisible => "user_not_visible",
+            SlackErrorCode::UsersListNotSupplied => "users_list_not_supplied",
+            SlackErrorCode::UsersNotFound => "users_not_found",
+            SlackErrorCode::ValidationErrors => "validation_errors",
+            SlackErrorCode::Other(s) => s,
+        }
+    }
+}
+
+impl From<String> for SlackErrorCode {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "account_inactiv" => SlackErrorCode::AccountInactiv,
+            "account_inactive" => SlackErrorCode::AccountInactive,
+            "already_archived" => SlackErrorCode::AlreadyArchived,
+            "already_in_channel" => SlackErrorCode::AlreadyInChannel,
+            "already_pinned" => SlackErrorCode::AlreadyPinned,
+            "already_reacted" => SlackErrorCode::AlreadyReacted,
+            "already_starred" => SlackErrorCode::AlreadyStarred,
+            "app_missing_action_url" => SlackErrorCode::AppMissingActionUrl,
+            "bad_client_secret" => SlackErrorCode::BadClientSecret,
+            "bad_image" => SlackErrorCode::BadImage,
+            "bad_timestamp" => SlackErrorCode::BadTimestamp,
+            "bad_token" => SlackErrorCode::BadToken,
+            "bot_not_found" => SlackErrorCode::BotNotFound,
+            "cannot_add_bot" => SlackErrorCode::CannotAddBot,
+            "cannot_add_others" => SlackErrorCode::CannotAddOthers,
+            "cannot_add_others_recurring" => SlackErrorCode::CannotAddOthersRecurring,
+            "cannot_add_slackbot" => SlackErrorCode::CannotAddSlackbot,
+            "cannot_complete_others" => SlackErrorCode::CannotCompleteOthers,
+            "cannot_complete_recurring" => SlackErrorCode::CannotCompleteRecurring,
+            "cannot_create_dialog" => SlackErrorCode::CannotCreateDialog,
+            "cannot_find_service" => SlackErrorCode::CannotFindService,
+            "cannot_kick_home_team" => SlackErrorCode::CannotKickHomeTeam,
+            "cannot_kick_team" => SlackErrorCode::CannotKickTeam,
+            "cannot_parse" => SlackErrorCode::CannotParse,
+            "cannot_prompt" => SlackErrorCode::CannotPrompt,
+            "cannot_unfurl_url" => SlackErrorCode::CannotUnfurlUrl,
+            "cannot_update_admin_user" => SlackErrorCode::CannotUpdateAdminUser,
+            "cant_archive_general" => SlackErrorCode::CantArchiveGeneral,
+            "cant_delete" => SlackErrorCode::CantDelete,
+            "cant_delete_file" => SlackErrorCode::CantDeleteFile,
+            "cant_delete_message" => SlackErrorCode::CantDeleteMessage,
+            "cant_invite" => SlackErrorCode::CantInvite,
+            "cant_invite_self" => SlackErrorCode::CantInviteSelf,
+            "cant_kick_from_general" => SlackErrorCode::CantKickFromGeneral,
+            "cant_kick_self" => SlackErrorCode::CantKickSelf,
+            "cant_leave_general" => SlackErrorCode::CantLeaveGeneral,
+            "cant_update_message" => SlackErrorCode::CantUpdateMessage,
+            "channel_not_archived" => SlackErrorCode::ChannelNotArchived,
+            "channel_not_found" => SlackErrorCode::ChannelNotFound,
+            "channel_type_not_supported" => SlackErrorCode::ChannelTypeNotSupported,
+            "client_id_token_mismatch" => SlackErrorCode::ClientTokenMismatch,
+            "comment_not_found" => SlackErrorCode::CommentNotFound,
+            "compliance_exports_prevent_deletion" => {
+                SlackErrorCode::ComplianceExportsPreventDeletion
+            }
+            "could_not_archive_channel" => SlackErrorCode::CouldNotArchiveChannel,
+            "could_not_convert_channel" => SlackErrorCode::CouldNotConvertChannel,
+            "could_not_create_channel" => SlackErrorCode::CouldNotCreateChannel,
+            "could_not_delete_channel" => SlackErrorCode::CouldNotDeleteChannel,
+            "could_not_get_conversation_prefs" => SlackErrorCode::CouldNotGetConversationPrefs,
+            "could_not_get_teams" => SlackErrorCode::CouldNotGetTeams,
+            "could_not_rename_channel" => SlackErrorCode::CouldNotRenameChannel,
+            "could_not_set_channel_pref" => SlackErrorCode::CouldNotSetChannelPref,
+            "could_not_unarchive_channel" => SlackErrorCode::CouldNotUnarchiveChannel,
+            "default_org_wide_channel" => SlackErrorCode::DefaultOrgWideChannel,
+            "edit_window_closed" => SlackErrorCode::EditWindowClosed,
+            "ekm_access_denied" => SlackErrorCode::EkmAccessDenied,
+            "enterprise_is_restricted" => SlackErrorCode::EnterpriseIsRestricted,
+            "external_channel_migrating" => SlackErrorCode::ExternalChannelMigrating,
+            "failed_for_some_users" => SlackErrorCode::FailedForSomeUsers,
+            "failed_sending_dialog" => SlackErrorCode::FailedSendingDialog,
+            "fatal_error" => SlackErrorCode::FatalError,
+            "feature_not_enabled" => SlackErrorCode::FeatureNotEnabled,
+            "fetch_members_failed" => SlackErrorCode::FetchMembersFailed,
+            "file_comment_not_found" => SlackErrorCode::FileCommentNotFound,
+            "file_deleted" => SlackErrorCode::FileDeleted,
+            "file_not_found" => SlackErrorCode::FileNotFound,
+            "file_not_shared" => SlackErrorCode::FileNotShared,
+            "file_uploads_disabled" => SlackErrorCode::FileUploadsDisabled,
+            "file_uploads_except_images_disabled" => {
+                SlackErrorCode::FileUploadsExceptImagesDisabled
+            }
+            "internal_error" => SlackErrorCode::InternalError,
+            "invalid_arg_name" => SlackErrorCode::InvalidArgName,
+            "invalid_arguments" => SlackErrorCode::InvalidArguments,
+            "invalid_array_arg" => SlackErrorCode::InvalidArrayArg,
+            "invalid_auth" => SlackErrorCode::InvalidAuth,
+            "invalid_channel" => SlackErrorCode::InvalidChannel,
+            "invalid_charset" => SlackErrorCode::InvalidCharset,
+            "invalid_client_id" => SlackErrorCode::InvalidClientId,
+            "invalid_cursor" => SlackErrorCode::InvalidCursor,
+            "invalid_form_data" => SlackErrorCode::InvalidFormData,
+            "invalid_json" => SlackErrorCode::InvalidJson,
+            "invalid_limit" => SlackErrorCode::InvalidLimit,
+            "invalid_name" => SlackErrorCode::InvalidName,
+            "invalid_name_maxlength" => SlackErrorCode::InvalidNameMaxlength,
+            "invalid_name_punctuation" => SlackErrorCode::InvalidNamePunctuation,
+            "invalid_name_required" => SlackErrorCode::InvalidNameRequired,
+            "invalid_name_specials" => SlackErrorCode::InvalidNameSpecials,
+            "invalid_post_typ" => SlackErrorCode::InvalidPostTyp,
+            "invalid_post_type" => SlackErrorCode::InvalidPostType,
+            "invalid_presence" => SlackErrorCode::InvalidPresence,
+            "invalid_profile" => SlackErrorCode::InvalidProfile,
+            "invalid_scheduled_message_id" => SlackErrorCode::InvalidScheduledMessageId,
+            "invalid_scope" => SlackErrorCode::InvalidScope,
+            "invalid_search_channel_type" => SlackErrorCode::InvalidSearchChannelType,
+            "invalid_sort" => SlackErrorCode::InvalidSort,
+            "invalid_sort_dir" => SlackErrorCode::InvalidSortDir,
+            "invalid_team" => SlackErrorCode::InvalidTeam,
+            "invalid_time" => SlackErrorCode::InvalidTime,
+            "invalid_timestamp" => SlackErrorCode::InvalidTimestamp,
+            "invalid_trigger" => SlackErrorCode::InvalidTrigger,
+            "invalid_ts_latest" => SlackErrorCode::InvalidTsLatest,
+            "invalid_ts_oldest" => SlackErrorCode::InvalidTsOldest,
+            "invalid_types" => SlackErrorCode::InvalidTypes,
+            "invalid_user" => SlackErrorCode::InvalidUser,
+            "invalid_user_combination" => SlackErrorCode::InvalidUserCombination,
+            "is_archived" => SlackErrorCode::IsArchived,
+            "is_inactive" => SlackErrorCode::IsInactive,
+            "json_not_object" => SlackErrorCode::JsonNotObject,
+            "last_member" => SlackErrorCode::LastMember,
+            "leaving_team_not_in_channel" => SlackErrorCode::LeavingTeamNotInChannel,
+            "leaving_team_required" => SlackErrorCode::LeavingTeamRequired,
+            "limit_required" => SlackErrorCode::LimitRequired,
+            "message_not_found" => SlackErrorCode::MessageNotFound,
+            "method_not_supported_for_channel_type" => {
+                SlackErrorCode::MethodNotSupportedForChannelType
+            }
+            "missing_charset" => SlackErrorCode::MissingCharset,
+            "missing_dialog" => SlackErrorCode::MissingDialog,
+            "missing_duration" => SlackErrorCode::MissingDuration,
+            "missing_post_typ" => SlackErrorCode::MissingPostTyp,
+            "missing_post_type" => SlackErrorCode::MissingPostType,
+            "missing_scope" => SlackErrorCode::MissingScope,
+            "missing_trigger" => SlackErrorCode::MissingTrigger,
+            "missing_unfurls" => SlackErrorCode::MissingUnfurls,
+            "msg_too_long" => SlackErrorCode::MsgTooLong,
+            "name_taken" => SlackErrorCode::NameTaken,
+            "no_channel" => SlackErrorCode::NoChannel,
+            "no_item_specified" => SlackErrorCode::NoItemSpecified,
+            "no_permission" => SlackErrorCode::NoPermission,
+            "no_reaction" => SlackErrorCode::NoReaction,
+            "no_teams_to_disconnect" => SlackErrorCode::NoTeamsToDisconnect,
+            "no_text" => SlackErrorCode::NoText,
+            "no_user" => SlackErrorCode::NoUser,
+            "not_admin" => SlackErrorCode::NotAdmin,
+            "not_allowed" => SlackErrorCode::NotAllowed,
+            "not_allowed_token_type" => SlackErrorCode::NotAllowedTokenType,
+            "not_an_admin" => SlackErrorCode::NotAnAdmin,
+            "not_an_enterprise" => SlackErrorCode::NotAnEnterprise,
+            "not_app_admin" => SlackErrorCode::NotAppAdmin,
+            "not_archived" => SlackErrorCode::NotArchived,
+            "not_authed" => SlackErrorCode::NotAuthed,
+            "not_authorized" => SlackErrorCode::NotAuthorized,
+            "not_enough_users" => SlackErrorCode::NotEnoughUsers,
+            "not_enterprise_team" => SlackErrorCode::NotEnterpriseTeam,
+            "not_found" => SlackErrorCode::NotFound,
+            "not_in_channel" => SlackErrorCode::NotInChannel,
+            "not_pinnable" => SlackErrorCode::NotPinnable,
+            "not_pinned" => SlackErrorCode::NotPinned,
+            "not_starred" => SlackErrorCode::NotStarred,
+            "not_supported" => SlackErrorCode::NotSupported,
+            "org_login_required" => SlackErrorCode::OrgLoginRequired,
+            "over_pagination_limit" => SlackErrorCode::OverPaginationLimit,
+            "paid_only" => SlackErrorCode::PaidOnly,
+            "permission_denied" => SlackErrorCode::PermissionDenied,
+            "posting_to_general_channel_denied" => SlackErrorCode::PostingToGeneralChannelDenied,
+            "profile_set_failed" => SlackErrorCode::ProfileSetFailed,
+            "rate_limited" => SlackErrorCode::RateLimited,
+            "request_timeou" => SlackErrorCode::RequestTimeou,
+            "request_timeout" => SlackErrorCode::RequestTimeout,
+            "reserved_name" => SlackErrorCode::ReservedName,
+            "restricted_action" => SlackErrorCode::RestrictedAction,
+            "restricted_action_non_threadable_channel" => {
+                SlackErrorCode::RestrictedActionNonThreadableChannel
+            }
+            "restricted_action_read_only_channel" => {
+                SlackErrorCode::RestrictedActionReadOnlyChannel
+            }
+            "restricted_action_thread_only_channel" => {
+                SlackErrorCode::RestrictedActionThreadOnlyChannel
+            }
+            "snooze_end_failed" => SlackErrorCode::SnoozeEndFailed,
+            "snooze_failed" => SlackErrorCode::SnoozeFailed,
+            "snooze_not_active" => SlackErrorCode::SnoozeNotActive,
+            "storage_limit_reached" => SlackErrorCode::StorageLimitReached,
+            "superfluous_charset" => SlackErrorCode::SuperfluousCharset,
+            "team_added_to_org" => SlackErrorCode::TeamAddedToOrg,
+            "team_id_or_org_required" => SlackErrorCode::TeamOrOrgRequired,
+            "team_not_found" => SlackErrorCode::TeamNotFound,
+            "thread_not_found" => SlackErrorCode::ThreadNotFound,
+            "time_in_past" => SlackErrorCode::TimeInPast,
+            "time_too_far" => SlackErrorCode::TimeTooFar,
+            "timezone_count_failed" => SlackErrorCode::TimezoneCountFailed,
+            "token_revoked" => SlackErrorCode::TokenRevoked,
+            "too_large" => SlackErrorCode::TooLarge,
+            "too_long" => SlackErrorCode::TooLong,
+            "too_many_attachments" => SlackErrorCode::TooManyAttachments,
+            "too_many_emoji" => SlackErrorCode::TooManyEmoji,
+            "too_many_frames" => SlackErrorCode::TooManyFrames,
+            "too_many_reactions" => SlackErrorCode::TooManyReactions,
+            "too_many_users" => SlackErrorCode::TooManyUsers,
+            "trigger_exchanged" => SlackErrorCode::TriggerExchanged,
+            "trigger_expired" => SlackErrorCode::TriggerExpired,
+            "unknown_error" => SlackErrorCode::UnknownError,
+            "unknown_type" => SlackErrorCode::UnknownType,
+            "unsupported_team_type" => SlackErrorCode::UnsupportedTeamType,
+            "upgrade_require" => SlackErrorCode::UpgradeRequire,
+            "upgrade_required" => SlackErrorCode::UpgradeRequired,
+            "ura_max_channels" => SlackErrorCode::UraMaxChannels,
+            "user_disabled" => SlackErrorCode::UserDisabled,
+            "user_does_not_own_channel" => SlackErrorCode::UserDoesNotOwnChannel,
+            "user_is_bot" => SlackErrorCode::UserIsBot,
+            "user_is_restricted" => SlackErrorCode::UserIsRestricted,
+            "user_is_ultra_restricted" => SlackErrorCode::UserIsUltraRestricted,
+            "user_must_be_admin" => SlackErrorCode::UserMustBeAdmin,
+            "user_not_found" => SlackErrorCode::UserNotFound,
+            "user_not_in_channel" => SlackErrorCode::UserNotInChannel,
+            "user_not_visible" => SlackErrorCode::UserNotVisible,
+            "users_list_not_supplied" => SlackErrorCode::UsersListNotSupplied,
+            "users_not_found" => SlackErrorCode::UsersNotFound,
+            "validation_errors" => SlackErrorCode::ValidationErrors,
+            _ => SlackErrorCode::Other(s),
+        }
+    }
+}
+
+impl From<SlackErrorCode> for String {
+    fn from(code: SlackErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for SlackErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -22,13 +22,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +48,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn disable(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn disable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.disable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +74,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn enable(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.enable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -135,13 +144,16 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -61,13 +61,16 @@ impl UsergroupsUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
         let url = self.client.url("/usergroups.users.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -65,6 +65,90 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversations`, this function returns all the pages of the request at once.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub async fn get_all_conversations(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+    ) -> ClientResult<Vec<Vec<crate::types::ObjsConversationAnyOf>>> {
+        futures::TryStreamExt::try_collect(self.get_all_conversations_stream(
+            user,
+            types,
+            exclude_archived,
+            limit,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `get_all_conversations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub fn get_all_conversations_stream(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<crate::types::ObjsConversationAnyOf>>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users.conversations?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::UsersConversationsSuccessSchema =
+                    serde_json::from_value(value)?;
+
+                Ok((resp.channels, next))
+            }
+        })
+    }
     /**
      * This function performs a `POST` to the `/users.deletePhoto` endpoint.
      *
@@ -72,13 +156,16 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/users.deletePhoto", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -153,16 +240,23 @@ impl Users {
     pub async fn get_all_identity(
         &self,
     ) -> ClientResult<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_identity_stream()).await
+    }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get a user's identity.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn get_all_identity_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersIdentityResponseAnyOf>> + '_
+    {
         let url = self.client.url("/users.identity", None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(&url)
     }
     /**
      * This function performs a `GET` to the `/users.info` endpoint.
@@ -243,6 +337,70 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists all users in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub async fn list_all(
+        &self,
+        limit: i64,
+        include_locale: bool,
+    ) -> ClientResult<Vec<Vec<crate::types::ObjsUserAnyOf>>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(limit, include_locale)).await
+    }
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Lists all users in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        limit: i64,
+        include_locale: bool,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<crate::types::ObjsUserAnyOf>>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_locale {
+            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users.list?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                // Read the page loosely first, the cursor isn't always where the spec says.
+                let value: serde_json::Value = self
+                    .client
+                    .get(
+                        &page_url,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+                let next = crate::utils::slack_next_cursor(&value)
+                    .map(|cursor| crate::utils::add_query_param(&url, "cursor", &cursor))
+                    .filter(|next_url| *next_url != page_url);
+
+                let resp: crate::types::UsersListSchema = serde_json::from_value(value)?;
+
+                Ok((resp.members, next))
+            }
+        })
+    }
     /**
      * This function performs a `GET` to the `/users.lookupByEmail` endpoint.
      *
@@ -307,13 +465,16 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.setPhoto>
      */
-    pub async fn set_photo(&self) -> ClientResult<crate::types::UsersSetPhotoSchema> {
+    pub async fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> ClientResult<crate::types::UsersSetPhotoSchema> {
         let url = self.client.url("/users.setPhoto", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -330,13 +491,16 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
         let url = self.client.url("/users.setPresence", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
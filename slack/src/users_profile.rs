@@ -61,13 +61,16 @@ impl UsersProfile {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users.profile:write`.
      */
-    pub async fn set(&self) -> ClientResult<crate::types::UsersProfileSetSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> ClientResult<crate::types::UsersProfileSetSchema> {
         let url = self.client.url("/users.profile.set", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
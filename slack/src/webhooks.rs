@@ -0,0 +1,565 @@
+//! Receive requests from Slack: check Slack sent them and parse them.
+//!
+//! Slack signs the Events API, slash commands and interactivity requests alike
+//! with the app's signing secret: `X-Slack-Signature` is an HMAC-SHA256 of
+//! `v0:{timestamp}:{body}`, with the timestamp in `X-Slack-Request-Timestamp`.
+//! See <https://api.slack.com/authentication/verifying-requests-from-slack>.
+//!
+//! ```
+//! # fn run(headers: &http::HeaderMap, body: &[u8]) -> Result<(), slack_chat_api::ClientError> {
+//! use slack_chat_api::webhooks::{Event, InteractionPayload, WebhookHandler, WebhookRequest};
+//!
+//! let handler = WebhookHandler::new("my-signing-secret");
+//! match handler.handle(headers, body)? {
+//!     // Answer with the challenge to have Slack enable the request URL.
+//!     WebhookRequest::UrlVerification { challenge } => println!("{}", challenge),
+//!     WebhookRequest::Event(callback) => match callback.event {
+//!         Event::AppMention(mention) => println!("{} said {}", mention.user, mention.text),
+//!         _ => {}
+//!     },
+//!     WebhookRequest::SlashCommand(command) => println!("{} {}", command.command, command.text),
+//!     WebhookRequest::Interaction(interaction) => {
+//!         if let InteractionPayload::BlockActions(actions) = *interaction {
+//!             println!("{} clicked {}", actions.user.id, actions.actions[0].action_id);
+//!         }
+//!     }
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    utils::{decode_hex, encode_hex, hmac_sha256, verify_hmac_sha256},
+    ClientError, ClientResult,
+};
+
+/// The header Slack signs requests in, as `v0=<hex digest>`.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// The header with the Unix time Slack signed a request at.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// How many seconds from now a request's timestamp may be, before we take it
+/// for a replay.
+pub const REPLAY_WINDOW_SECS: i64 = 60 * 5;
+
+/// Check a `X-Slack-Signature` value against the timestamp and body of the
+/// request, in constant time.
+///
+/// This doesn't look at how old the timestamp is, see `verify_timestamp`.
+pub fn verify_signature(
+    secret: &[u8],
+    timestamp: &str,
+    signature: &str,
+    body: &[u8],
+) -> ClientResult<()> {
+    let tag = signature
+        .trim()
+        .strip_prefix("v0=")
+        .and_then(decode_hex)
+        .ok_or(ClientError::InvalidWebhookSignature)?;
+
+    if !verify_hmac_sha256(&[secret], &[tag], &base_string(timestamp, body)) {
+        return Err(ClientError::InvalidWebhookSignature);
+    }
+
+    Ok(())
+}
+
+/// Check a `X-Slack-Request-Timestamp` value is within `REPLAY_WINDOW_SECS`
+/// of `now`, in seconds since the epoch.
+pub fn verify_timestamp(timestamp: &str, now: i64) -> ClientResult<i64> {
+    let at: i64 = timestamp
+        .trim()
+        .parse()
+        .map_err(|_| ClientError::InvalidWebhookSignature)?;
+
+    // Far-off timestamps would overflow a plain subtraction.
+    if now.abs_diff(at) > REPLAY_WINDOW_SECS as u64 {
+        return Err(ClientError::StaleWebhook { timestamp: at });
+    }
+
+    Ok(at)
+}
+
+/// Sign a request the way Slack does, for `X-Slack-Signature`.
+pub fn sign(secret: &[u8], timestamp: &str, body: &[u8]) -> String {
+    let tag = hmac_sha256(secret, &base_string(timestamp, body));
+    format!("v0={}", encode_hex(&tag))
+}
+
+fn base_string(timestamp: &str, body: &[u8]) -> Vec<u8> {
+    let mut base = format!("v0:{}:", timestamp).into_bytes();
+    base.extend_from_slice(body);
+    base
+}
+
+/// The `type` of a JSON object, and the object.
+fn tagged(payload: serde_json::Value) -> (String, serde_json::Value) {
+    let type_ = payload
+        .get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+        .to_string();
+    (type_, payload)
+}
+
+fn parse<T: DeserializeOwned>(payload: serde_json::Value) -> ClientResult<Box<T>> {
+    Ok(Box::new(serde_json::from_value(payload)?))
+}
+
+/// `message`: a message was posted, edited or deleted in a conversation the
+/// app is in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MessageEvent {
+    /// Set for anything but a plain message, e.g. `message_changed`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub subtype: String,
+    #[serde(default)]
+    pub channel: String,
+    /// `channel`, `group`, `im` or `mpim`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_type: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub bot_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub thread_ts: String,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// `app_mention`: a message mentioned the app.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppMentionEvent {
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub thread_ts: String,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// What a reaction was added to or removed from.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionItem {
+    /// `message`, `file` or `file_comment`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ts: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub file: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub file_comment: String,
+}
+
+/// `reaction_added` and `reaction_removed`: someone reacted to an item, or
+/// took their reaction back.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionEvent {
+    #[serde(default)]
+    pub user: String,
+    /// The name of the emoji, without colons.
+    #[serde(default)]
+    pub reaction: String,
+    /// Who posted the item.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub item_user: String,
+    pub item: ReactionItem,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// `member_joined_channel`: someone joined a conversation the app is in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MemberJoinedChannelEvent {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_type: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub inviter: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub event_ts: String,
+}
+
+/// `app_home_opened`: someone opened the app's home.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppHomeOpenedEvent {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub channel: String,
+    /// `home` or `messages`.
+    #[serde(default)]
+    pub tab: String,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// An event from the Events API.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Event {
+    Message(Box<MessageEvent>),
+    AppMention(Box<AppMentionEvent>),
+    ReactionAdded(Box<ReactionEvent>),
+    ReactionRemoved(Box<ReactionEvent>),
+    MemberJoinedChannel(Box<MemberJoinedChannelEvent>),
+    AppHomeOpened(Box<AppHomeOpenedEvent>),
+    /// An event we don't have a type for.
+    Other {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl Event {
+    /// Parse an event by its `type`.
+    pub fn parse(payload: serde_json::Value) -> ClientResult<Self> {
+        let (type_, payload) = tagged(payload);
+
+        Ok(match type_.as_str() {
+            "message" => Event::Message(parse(payload)?),
+            "app_mention" => Event::AppMention(parse(payload)?),
+            "reaction_added" => Event::ReactionAdded(parse(payload)?),
+            "reaction_removed" => Event::ReactionRemoved(parse(payload)?),
+            "member_joined_channel" => Event::MemberJoinedChannel(parse(payload)?),
+            "app_home_opened" => Event::AppHomeOpened(parse(payload)?),
+            _ => Event::Other { type_, payload },
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let payload = serde_json::Value::deserialize(deserializer)?;
+        Event::parse(payload).map_err(serde::de::Error::custom)
+    }
+}
+
+/// An `event_callback`: the envelope the Events API sends each event in.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct EventCallback {
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub api_app_id: String,
+    pub event: Event,
+    /// Unique to the event, which Slack sends again if we don't answer in time.
+    #[serde(default)]
+    pub event_id: String,
+    #[serde(default)]
+    pub event_time: i64,
+}
+
+/// A slash command someone ran.
+/// See <https://api.slack.com/interactivity/slash-commands>.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SlashCommand {
+    /// The command, e.g. `/weather`.
+    #[serde(default)]
+    pub command: String,
+    /// What followed the command.
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_domain: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub enterprise_id: String,
+    #[serde(default)]
+    pub channel_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_name: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub api_app_id: String,
+    /// Where to send more messages in answer, for the next 30 minutes.
+    #[serde(default)]
+    pub response_url: String,
+    /// Lets the app open a modal, for the next 3 seconds.
+    #[serde(default)]
+    pub trigger_id: String,
+}
+
+/// The workspace an interaction happened in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InteractionTeam {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub domain: String,
+}
+
+/// Who interacted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InteractionUser {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_id: String,
+}
+
+/// The conversation an interaction happened in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InteractionChannel {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+}
+
+/// The modal or home tab an interaction happened in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct InteractionView {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, rename = "type", skip_serializing_if = "String::is_empty")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub callback_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub private_metadata: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub hash: String,
+    /// The values of the view's inputs, by block then action ID.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub state: serde_json::Value,
+}
+
+/// One action in a `block_actions` payload.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BlockAction {
+    #[serde(default)]
+    pub action_id: String,
+    #[serde(default)]
+    pub block_id: String,
+    /// The type of the element, e.g. `button`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<serde_json::Value>,
+    #[serde(default)]
+    pub action_ts: String,
+}
+
+/// `block_actions`: someone used an interactive element in a message, modal or
+/// home tab.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BlockActionsPayload {
+    #[serde(default)]
+    pub team: InteractionTeam,
+    #[serde(default)]
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub response_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionChannel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<InteractionView>,
+    #[serde(default)]
+    pub actions: Vec<BlockAction>,
+}
+
+/// `view_submission`: someone submitted a modal.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ViewSubmissionPayload {
+    #[serde(default)]
+    pub team: InteractionTeam,
+    #[serde(default)]
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    pub view: InteractionView,
+}
+
+/// `shortcut` and `message_action`: someone ran a global shortcut, or a
+/// message shortcut from a message's menu.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ShortcutPayload {
+    #[serde(default)]
+    pub team: InteractionTeam,
+    #[serde(default)]
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    #[serde(default)]
+    pub action_ts: String,
+    /// Only for message shortcuts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionChannel>,
+    /// Only for message shortcuts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<serde_json::Value>,
+    /// Only for message shortcuts.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub response_url: String,
+}
+
+/// The `payload` of an interactivity request.
+/// See <https://api.slack.com/reference/interaction-payloads>.
+#[derive(PartialEq, Debug, Clone)]
+pub enum InteractionPayload {
+    BlockActions(Box<BlockActionsPayload>),
+    ViewSubmission(Box<ViewSubmissionPayload>),
+    Shortcut(Box<ShortcutPayload>),
+    MessageAction(Box<ShortcutPayload>),
+    /// A payload we don't have a type for.
+    Other {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl InteractionPayload {
+    /// Parse a payload by its `type`.
+    pub fn parse(payload: serde_json::Value) -> ClientResult<Self> {
+        let (type_, payload) = tagged(payload);
+
+        Ok(match type_.as_str() {
+            "block_actions" => InteractionPayload::BlockActions(parse(payload)?),
+            "view_submission" => InteractionPayload::ViewSubmission(parse(payload)?),
+            "shortcut" => InteractionPayload::Shortcut(parse(payload)?),
+            "message_action" => InteractionPayload::MessageAction(parse(payload)?),
+            _ => InteractionPayload::Other { type_, payload },
+        })
+    }
+}
+
+/// A verified request from Slack.
+#[derive(PartialEq, Debug, Clone)]
+pub enum WebhookRequest {
+    /// Slack checking the request URL, which wants the challenge back.
+    UrlVerification {
+        challenge: String,
+    },
+    Event(Box<EventCallback>),
+    SlashCommand(Box<SlashCommand>),
+    Interaction(Box<InteractionPayload>),
+    /// An Events API request we don't have a type for, e.g.
+    /// `app_rate_limited`.
+    Other {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WebhookRequest {
+    /// Parse the body of a request, JSON from the Events API or a form from
+    /// slash commands and interactivity.
+    pub fn parse(body: &[u8]) -> ClientResult<Self> {
+        if body.trim_ascii_start().starts_with(b"{") {
+            let (type_, payload) = tagged(serde_json::from_slice(body)?);
+            return Ok(match type_.as_str() {
+                "url_verification" => WebhookRequest::UrlVerification {
+                    challenge: payload
+                        .get("challenge")
+                        .and_then(|c| c.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+                "event_callback" => WebhookRequest::Event(parse(payload)?),
+                _ => WebhookRequest::Other { type_, payload },
+            });
+        }
+
+        let form: serde_json::Map<String, serde_json::Value> = url::form_urlencoded::parse(body)
+            .map(|(k, v)| (k.into_owned(), v.into_owned().into()))
+            .collect();
+
+        // Interactivity sends its JSON in a `payload` field.
+        if let Some(payload) = form.get("payload").and_then(|p| p.as_str()) {
+            let payload = InteractionPayload::parse(serde_json::from_str(payload)?)?;
+            return Ok(WebhookRequest::Interaction(Box::new(payload)));
+        }
+
+        Ok(WebhookRequest::SlashCommand(parse(form.into())?))
+    }
+}
+
+/// Holds the app's signing secret, and sorts signed requests into URL
+/// verifications, events, slash commands and interactions.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    secret: Vec<u8>,
+}
+
+impl WebhookHandler {
+    /// Create a handler for requests signed with the app's signing secret.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: AsRef<[u8]>,
+    {
+        WebhookHandler {
+            secret: secret.as_ref().to_vec(),
+        }
+    }
+
+    /// Verify a request from its headers and raw body, then parse it.
+    pub fn handle(&self, headers: &http::HeaderMap, body: &[u8]) -> ClientResult<WebhookRequest> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .ok_or(ClientError::MissingWebhookHeader { header: name })
+        };
+
+        let timestamp = header(TIMESTAMP_HEADER)?;
+        verify_timestamp(timestamp, chrono::Utc::now().timestamp())?;
+        verify_signature(&self.secret, timestamp, header(SIGNATURE_HEADER)?, body)?;
+
+        WebhookRequest::parse(body)
+    }
+}
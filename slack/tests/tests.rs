@@ -1 +1,460 @@
+use slack_chat_api::{types::SlackErrorCode, Client, ClientError};
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
 
+fn client(server: &MockServer) -> Client {
+    let mut client = Client::new("client-id", "client-secret", "", "test-token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_follows_next_cursor() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/conversations.members"))
+        .and(query_param("limit", "2"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "members": ["U1", "U2"],
+            "response_metadata": {"next_cursor": "dXNlcjpVMg=="},
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/conversations.members"))
+        .and(query_param("limit", "2"))
+        .and(query_param("cursor", "dXNlcjpVMg=="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "members": ["U3"],
+            "response_metadata": {"next_cursor": ""},
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let members = client(&server)
+        .conversations()
+        .get_all_members("C1", 2)
+        .await
+        .unwrap();
+
+    assert_eq!(members, vec!["U1", "U2", "U3"]);
+}
+
+#[tokio::test]
+async fn test_not_ok_mid_stream_is_an_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/conversations.members"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "members": ["U1"],
+            "response_metadata": {"next_cursor": "next"},
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/conversations.members"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": false,
+            "error": "invalid_cursor",
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .conversations()
+        .get_all_members("C1", 0)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ClientError::SlackApi {
+            error: SlackErrorCode::InvalidCursor,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_not_ok_is_a_slack_api_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/conversations.info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": false,
+            "error": "channel_not_found",
+            "warning": "missing_charset",
+            "response_metadata": {"warnings": ["missing_charset"]},
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .conversations()
+        .info("C1", false, false)
+        .await
+        .unwrap_err();
+
+    match err {
+        ClientError::SlackApi {
+            error,
+            warning,
+            response_metadata,
+        } => {
+            assert_eq!(error, SlackErrorCode::ChannelNotFound);
+            assert_eq!(warning, "missing_charset");
+            assert!(response_metadata.is_some());
+        }
+        e => panic!("expected a Slack API error, got {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_post_message_sends_a_form() {
+    use wiremock::matchers::{body_string, header};
+
+    let server = MockServer::start().await;
+
+    // Only the fields that are set, with the empty strings and `None`s left out.
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .and(header("content-type", "application/x-www-form-urlencoded"))
+        .and(body_string("channel=C1234567890&text=Hello+world"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "channel": "C1234567890",
+            "ts": "1503435956.000247",
+            "message": {
+                "type": "message",
+                "text": "Hello world",
+                "ts": "1503435956.000247",
+            },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sent = client(&server)
+        .chat()
+        .post_message(&slack_chat_api::types::ChatPostMessageRequest {
+            channel: "C1234567890".to_string(),
+            text: "Hello world".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(sent.ts, "1503435956.000247");
+}
+
+#[test]
+fn test_unknown_error_code_is_kept() {
+    let code: SlackErrorCode = "some_new_error".to_string().into();
+
+    assert_eq!(code, SlackErrorCode::Other("some_new_error".to_string()));
+    assert_eq!(code.to_string(), "some_new_error");
+}
+
+#[cfg(feature = "webhooks")]
+mod webhooks {
+    use slack_chat_api::{
+        webhooks::{
+            sign, verify_signature, verify_timestamp, Event, InteractionPayload, WebhookHandler,
+            WebhookRequest,
+        },
+        ClientError,
+    };
+
+    const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+    fn headers(body: &[u8]) -> http::HeaderMap {
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let mut headers = http::HeaderMap::new();
+        headers.insert("X-Slack-Request-Timestamp", timestamp.parse().unwrap());
+        headers.insert(
+            "X-Slack-Signature",
+            sign(SIGNING_SECRET.as_bytes(), &timestamp, body)
+                .parse()
+                .unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        // The example from Slack's docs.
+        let body = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+        let signature = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+        verify_signature(
+            SIGNING_SECRET.as_bytes(),
+            "1531420618",
+            signature,
+            body.as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            sign(SIGNING_SECRET.as_bytes(), "1531420618", body.as_bytes()),
+            signature
+        );
+
+        // Signed for another time.
+        assert!(matches!(
+            verify_signature(
+                SIGNING_SECRET.as_bytes(),
+                "1531420619",
+                signature,
+                body.as_bytes()
+            ),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+        let forgeries = [
+            (
+                "a digest that's one digit off",
+                "v0=b2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            ),
+            ("a truncated digest", "v0=a2114d57"),
+            ("a digest that isn't hex", "v0=not hex"),
+            (
+                "a digest with a sign in place of a zero",
+                "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b5+3",
+            ),
+            (
+                "a version Slack doesn't sign with",
+                "v1=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            ),
+        ];
+        for (forgery, signature) in forgeries {
+            let result = verify_signature(
+                SIGNING_SECRET.as_bytes(),
+                "1531420618",
+                signature,
+                body.as_bytes(),
+            );
+            assert!(
+                matches!(result, Err(ClientError::InvalidWebhookSignature)),
+                "accepted {}",
+                forgery
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_timestamp() {
+        let now = 1531420618;
+
+        assert_eq!(verify_timestamp("1531420618", now).unwrap(), now);
+        assert_eq!(verify_timestamp("1531420318", now).unwrap(), now - 300);
+        assert!(matches!(
+            verify_timestamp("1531420317", now),
+            Err(ClientError::StaleWebhook {
+                timestamp: 1531420317
+            })
+        ));
+        assert!(matches!(
+            verify_timestamp("1531420919", now),
+            Err(ClientError::StaleWebhook { .. })
+        ));
+
+        // As far from now as an i64 goes, either way.
+        assert!(matches!(
+            verify_timestamp(&i64::MIN.to_string(), now),
+            Err(ClientError::StaleWebhook {
+                timestamp: i64::MIN
+            })
+        ));
+        assert!(matches!(
+            verify_timestamp(&i64::MAX.to_string(), -now),
+            Err(ClientError::StaleWebhook {
+                timestamp: i64::MAX
+            })
+        ));
+    }
+
+    #[test]
+    fn test_handle_url_verification() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+            "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+            "type": "url_verification",
+        }))
+        .unwrap();
+
+        let request = WebhookHandler::new(SIGNING_SECRET)
+            .handle(&headers(&body), &body)
+            .unwrap();
+
+        assert_eq!(
+            request,
+            WebhookRequest::UrlVerification {
+                challenge: "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_handle_event_callback() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "token": "XXYYZZ",
+            "team_id": "T123ABC456",
+            "api_app_id": "A123ABC456",
+            "event": {
+                "type": "reaction_added",
+                "user": "U123ABC456",
+                "reaction": "thumbsup",
+                "item_user": "U222222222",
+                "item": {
+                    "type": "message",
+                    "channel": "C123ABC456",
+                    "ts": "1360782400.498405",
+                },
+                "event_ts": "1360782804.083113",
+            },
+            "type": "event_callback",
+            "event_id": "Ev123ABC456",
+            "event_time": 1360782804,
+        }))
+        .unwrap();
+
+        let request = WebhookHandler::new(SIGNING_SECRET)
+            .handle(&headers(&body), &body)
+            .unwrap();
+
+        let callback = match request {
+            WebhookRequest::Event(callback) => callback,
+            r => panic!("expected an event callback, got {:?}", r),
+        };
+        assert_eq!(callback.team_id, "T123ABC456");
+        assert_eq!(callback.event_id, "Ev123ABC456");
+        assert_eq!(callback.event_time, 1360782804);
+        match callback.event {
+            Event::ReactionAdded(reaction) => {
+                assert_eq!(reaction.reaction, "thumbsup");
+                assert_eq!(reaction.item.channel, "C123ABC456");
+            }
+            e => panic!("expected reaction_added, got {:?}", e),
+        }
+
+        // Events we don't have a type for are kept as they came.
+        let event = Event::parse(serde_json::json!({
+            "type": "tokens_revoked",
+            "tokens": { "bot": ["U123"] },
+        }))
+        .unwrap();
+        assert!(matches!(event, Event::Other { type_, .. } if type_ == "tokens_revoked"));
+    }
+
+    #[test]
+    fn test_handle_slash_command_and_interaction() {
+        let handler = WebhookHandler::new(SIGNING_SECRET);
+
+        let body = b"command=%2Fweather&text=94070&team_id=T123&channel_id=C123&user_id=U123&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1234%2F5678&trigger_id=13345224609.738474920.8088930838d88f008e0";
+        match handler.handle(&headers(body), body).unwrap() {
+            WebhookRequest::SlashCommand(command) => {
+                assert_eq!(command.command, "/weather");
+                assert_eq!(command.text, "94070");
+                assert_eq!(
+                    command.response_url,
+                    "https://hooks.slack.com/commands/1234/5678"
+                );
+            }
+            r => panic!("expected a slash command, got {:?}", r),
+        }
+
+        let payload = serde_json::json!({
+            "type": "block_actions",
+            "team": { "id": "T123", "domain": "example" },
+            "user": { "id": "U123", "username": "ann", "team_id": "T123" },
+            "api_app_id": "A123",
+            "trigger_id": "12466734323.1395872398",
+            "response_url": "https://hooks.slack.com/actions/1234/5678",
+            "channel": { "id": "C123", "name": "general" },
+            "actions": [{
+                "type": "button",
+                "action_id": "approve",
+                "block_id": "request",
+                "value": "42",
+                "action_ts": "1548426417.840180",
+            }],
+        });
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("payload", &payload.to_string())
+            .finish();
+        let request = handler
+            .handle(&headers(body.as_bytes()), body.as_bytes())
+            .unwrap();
+        match request {
+            WebhookRequest::Interaction(interaction) => match *interaction {
+                InteractionPayload::BlockActions(actions) => {
+                    assert_eq!(actions.user.id, "U123");
+                    assert_eq!(actions.actions[0].action_id, "approve");
+                    assert_eq!(actions.actions[0].value, "42");
+                }
+                i => panic!("expected block_actions, got {:?}", i),
+            },
+            r => panic!("expected an interaction, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_handle_rejects_bad_requests() {
+        let handler = WebhookHandler::new(SIGNING_SECRET);
+        let body = br#"{"type":"url_verification","challenge":"abc"}"#;
+
+        // Signed with another secret.
+        let mut headers = headers(body);
+        let timestamp = headers["X-Slack-Request-Timestamp"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        headers.insert(
+            "X-Slack-Signature",
+            sign(b"another secret", &timestamp, body).parse().unwrap(),
+        );
+        assert!(matches!(
+            handler.handle(&headers, body),
+            Err(ClientError::InvalidWebhookSignature)
+        ));
+
+        // Signed too long ago.
+        let stale = (chrono::Utc::now().timestamp() - 600).to_string();
+        headers.insert("X-Slack-Request-Timestamp", stale.parse().unwrap());
+        headers.insert(
+            "X-Slack-Signature",
+            sign(SIGNING_SECRET.as_bytes(), &stale, body)
+                .parse()
+                .unwrap(),
+        );
+        assert!(matches!(
+            handler.handle(&headers, body),
+            Err(ClientError::StaleWebhook { .. })
+        ));
+
+        headers.remove("X-Slack-Signature");
+        headers.insert(
+            "X-Slack-Request-Timestamp",
+            chrono::Utc::now().timestamp().to_string().parse().unwrap(),
+        );
+        assert!(matches!(
+            handler.handle(&headers, body),
+            Err(ClientError::MissingWebhookHeader {
+                header: "X-Slack-Signature"
+            })
+        ));
+    }
+}
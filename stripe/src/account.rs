@@ -20,8 +20,13 @@ impl Account {
      *
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get(&self) -> ClientResult<crate::types::Account> {
-        let url = self.client.url("/v1/account", None);
+    pub async fn get(&self, expand: &[String]) -> ClientResult<crate::types::Account> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/account?{}", query_), None);
         self.client
             .get(
                 &url,
@@ -99,11 +104,21 @@ impl Account {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_bank(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_bank(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/bank_accounts/{}",
+                "/v1/account/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -185,8 +200,18 @@ impl Account {
      *
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get_capabilities(&self) -> ClientResult<Vec<crate::types::Capability>> {
-        let url = self.client.url("/v1/account/capabilities", None);
+    pub async fn get_capabilities(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/capabilities?{}", query_), None);
         let resp: crate::types::ListAccountCapability = self
             .client
             .get(
@@ -208,66 +233,60 @@ impl Account {
      *
      * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
      */
-    pub async fn get_all_capabilities(&self) -> ClientResult<Vec<crate::types::Capability>> {
-        let url = self.client.url("/v1/account/capabilities", None);
-        let mut resp: crate::types::ListAccountCapability = self
+    pub async fn get_all_capabilities(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Capability>> {
+        futures::TryStreamExt::try_collect(self.get_all_capabilities_stream(expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+            .url(&format!("/v1/account/capabilities?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListAccountCapability = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/account/capabilities/{capability}` endpoint.
@@ -282,11 +301,18 @@ impl Account {
     pub async fn get_capabilities_capability(
         &self,
         capability: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::Capability> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/capabilities/{}",
+                "/v1/account/capabilities/{}?{}",
                 crate::progenitor_support::encode_path(capability),
+                query_
             ),
             None,
         );
@@ -345,6 +371,7 @@ impl Account {
     pub async fn get_external(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
@@ -352,6 +379,9 @@ impl Account {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -383,66 +413,60 @@ impl Account {
      *
      * <p>List external accounts for an account.</p>
      */
-    pub async fn get_all_external(&self) -> ClientResult<Vec<crate::types::DataAnyOf>> {
-        let url = self.client.url("/v1/account/external_accounts", None);
-        let mut resp: crate::types::ExternalAccounts = self
+    pub async fn get_all_external(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_external_stream(expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+            .url(&format!("/v1/account/external_accounts?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ExternalAccounts = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((*resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
@@ -471,11 +495,21 @@ impl Account {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_external_account(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_external_account(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/external_accounts/{}",
+                "/v1/account/external_accounts/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -583,6 +617,7 @@ impl Account {
     pub async fn get_people(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
@@ -591,6 +626,9 @@ impl Account {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -624,67 +662,60 @@ impl Account {
      */
     pub async fn get_all_people(
         &self,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
-        let url = self.client.url("/v1/account/people", None);
-        let mut resp: crate::types::GetAccountPeopleResponse = self
+        futures::TryStreamExt::try_collect(self.get_all_people_stream(expand, _relationship)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+            .url(&format!("/v1/account/people?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountPeopleResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/account/people` endpoint.
@@ -713,11 +744,21 @@ impl Account {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `person: &str` -- The account's country.
      */
-    pub async fn get_people_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn get_people_person(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/people/{}",
+                "/v1/account/people/{}?{}",
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
@@ -804,6 +845,7 @@ impl Account {
     pub async fn get_persons(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
@@ -812,6 +854,9 @@ impl Account {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -845,67 +890,60 @@ impl Account {
      */
     pub async fn get_all_persons(
         &self,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
-        let url = self.client.url("/v1/account/persons", None);
-        let mut resp: crate::types::GetAccountPeopleResponse = self
+        futures::TryStreamExt::try_collect(self.get_all_persons_stream(expand, _relationship)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+            .url(&format!("/v1/account/persons?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountPeopleResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/account/persons` endpoint.
@@ -934,11 +972,21 @@ impl Account {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `person: &str` -- The account's country.
      */
-    pub async fn get_persons_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn get_persons_person(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/persons/{}",
+                "/v1/account/persons/{}?{}",
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
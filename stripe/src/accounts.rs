@@ -18,7 +18,7 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -26,15 +26,22 @@ impl Accounts {
      */
     pub async fn get_page(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Account>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -64,66 +71,70 @@ impl Accounts {
      *
      * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
      */
-    pub async fn get_all(&self, _created: &str) -> ClientResult<Vec<crate::types::Account>> {
-        let url = self.client.url("/v1/accounts", None);
-        let mut resp: crate::types::GetAccountsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
+    pub async fn get_all(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Account>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(created, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Account>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
                 }
             }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/accounts?{}", query_), None);
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/accounts` endpoint.
@@ -153,11 +164,21 @@ impl Accounts {
      * * `account: &str` -- The account's country.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get(&self, account: &str) -> ClientResult<crate::types::Account> {
+    pub async fn get(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::Account> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}",
+                "/v1/accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -269,12 +290,23 @@ impl Accounts {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_bank(&self, account: &str, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_bank(
+        &self,
+        account: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/bank_accounts/{}",
+                "/v1/accounts/{}/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -369,11 +401,18 @@ impl Accounts {
     pub async fn get_capabilities(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities",
+                "/v1/accounts/{}/capabilities?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -401,72 +440,64 @@ impl Accounts {
     pub async fn get_all_capabilities(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Capability>> {
+        futures::TryStreamExt::try_collect(self.get_all_capabilities_stream(account, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities",
+                "/v1/accounts/{}/capabilities?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::ListAccountCapability = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListAccountCapability = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
@@ -483,12 +514,19 @@ impl Accounts {
         &self,
         account: &str,
         capability: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::Capability> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities/{}",
+                "/v1/accounts/{}/capabilities/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(capability),
+                query_
             ),
             None,
         );
@@ -552,6 +590,7 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
@@ -559,6 +598,9 @@ impl Accounts {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -598,72 +640,64 @@ impl Accounts {
     pub async fn get_all_external(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_external_stream(account, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/external_accounts",
+                "/v1/accounts/{}/external_accounts?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::ExternalAccounts = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ExternalAccounts = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((*resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts` endpoint.
@@ -706,13 +740,20 @@ impl Accounts {
     pub async fn get_external_accounts(
         &self,
         account: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/external_accounts/{}",
+                "/v1/accounts/{}/external_accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -841,6 +882,7 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
@@ -849,6 +891,9 @@ impl Accounts {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -888,73 +933,71 @@ impl Accounts {
     pub async fn get_all_people(
         &self,
         account: &str,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        futures::TryStreamExt::try_collect(self.get_all_people_stream(
+            account,
+            expand,
+            _relationship,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/people",
+                "/v1/accounts/{}/people?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::GetAccountPeopleResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountPeopleResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/people` endpoint.
@@ -997,13 +1040,20 @@ impl Accounts {
     pub async fn get_people_person(
         &self,
         account: &str,
+        expand: &[String],
         person: &str,
     ) -> ClientResult<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/people/{}",
+                "/v1/accounts/{}/people/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
@@ -1101,6 +1151,7 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
@@ -1109,6 +1160,9 @@ impl Accounts {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1148,73 +1202,71 @@ impl Accounts {
     pub async fn get_all_persons(
         &self,
         account: &str,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        futures::TryStreamExt::try_collect(self.get_all_persons_stream(
+            account,
+            expand,
+            _relationship,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/persons",
+                "/v1/accounts/{}/persons?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::GetAccountPeopleResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountPeopleResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/persons` endpoint.
@@ -1257,13 +1309,20 @@ impl Accounts {
     pub async fn get_persons_person(
         &self,
         account: &str,
+        expand: &[String],
         person: &str,
     ) -> ClientResult<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/persons/{}",
+                "/v1/accounts/{}/persons/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
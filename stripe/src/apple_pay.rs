@@ -28,6 +28,7 @@ impl ApplePay {
         &self,
         domain_name: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ApplePayDomain>> {
@@ -38,6 +39,9 @@ impl ApplePay {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -72,73 +76,62 @@ impl ApplePay {
     pub async fn get_all_domains(
         &self,
         domain_name: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ApplePayDomain>> {
+        futures::TryStreamExt::try_collect(self.get_all_domains_stream(domain_name, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_all_domains`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List apple pay domains.</p>
+     */
+    pub fn get_all_domains_stream(
+        &self,
+        domain_name: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplePayDomain>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain_name.is_empty() {
             query_args.push(("domain_name".to_string(), domain_name.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/v1/apple_pay/domains?{}", query_), None);
-        let mut resp: crate::types::ApplePayDomainList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ApplePayDomainList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
@@ -170,11 +163,18 @@ impl ApplePay {
     pub async fn get_domains_domain(
         &self,
         domain: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::ApplePayDomain> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/apple_pay/domains/{}",
+                "/v1/apple_pay/domains/{}?{}",
                 crate::progenitor_support::encode_path(domain),
+                query_
             ),
             None,
         );
@@ -19,7 +19,7 @@ impl ApplicationFees {
      * **Parameters:**
      *
      * * `charge: &str` -- Only return application fees for the charge specified by this charge ID.
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -28,8 +28,9 @@ impl ApplicationFees {
     pub async fn get_page(
         &self,
         charge: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PlatformFee>> {
@@ -37,9 +38,15 @@ impl ApplicationFees {
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -74,74 +81,74 @@ impl ApplicationFees {
     pub async fn get_all(
         &self,
         charge: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::PlatformFee>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(charge, created, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PlatformFee>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/v1/application_fees?{}", query_), None);
-        let mut resp: crate::types::GetApplicationFeesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetApplicationFeesResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
@@ -156,14 +163,21 @@ impl ApplicationFees {
      */
     pub async fn get_fee_refund(
         &self,
+        expand: &[String],
         fee: &str,
         id: &str,
     ) -> ClientResult<crate::types::FeeRefund> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}/refunds/{}",
+                "/v1/application_fees/{}/refunds/{}?{}",
                 crate::progenitor_support::encode_path(fee),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -222,11 +236,21 @@ impl ApplicationFees {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::PlatformFee> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::PlatformFee> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}",
+                "/v1/application_fees/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -283,6 +307,7 @@ impl ApplicationFees {
     pub async fn get_refunds(
         &self,
         ending_before: &str,
+        expand: &[String],
         id: &str,
         limit: i64,
         starting_after: &str,
@@ -291,6 +316,9 @@ impl ApplicationFees {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -327,72 +355,67 @@ impl ApplicationFees {
      *
      * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
      */
-    pub async fn get_all_refunds(&self, id: &str) -> ClientResult<Vec<crate::types::FeeRefund>> {
+    pub async fn get_all_refunds(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<Vec<crate::types::FeeRefund>> {
+        futures::TryStreamExt::try_collect(self.get_all_refunds_stream(expand, id)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FeeRefund>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}/refunds",
+                "/v1/application_fees/{}/refunds?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::Refunds = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Refunds = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/application_fees/{id}/refunds` endpoint.
@@ -20,7 +20,7 @@ impl BalanceTransactions {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `currency: &str` -- Only return transactions in a certain currency. Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase. Must be a [supported currency](https://stripe.com/docs/currencies).
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -32,9 +32,10 @@ impl BalanceTransactions {
      */
     pub async fn get_page(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payout: &str,
         source: &str,
@@ -42,12 +43,18 @@ impl BalanceTransactions {
         type_: &str,
     ) -> ClientResult<Vec<crate::types::BalanceTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -92,16 +99,53 @@ impl BalanceTransactions {
      */
     pub async fn get_all(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
+        expand: &[String],
         payout: &str,
         source: &str,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::BalanceTransaction>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_stream(created, currency, expand, payout, source, type_),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
+     *
+     * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        currency: &str,
+        expand: &[String],
+        payout: &str,
+        source: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BalanceTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !payout.is_empty() {
             query_args.push(("payout".to_string(), payout.to_string()));
         }
@@ -115,64 +159,35 @@ impl BalanceTransactions {
         let url = self
             .client
             .url(&format!("/v1/balance_transactions?{}", query_), None);
-        let mut resp: crate::types::BalanceTransactionsList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::BalanceTransactionsList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/balance_transactions/{id}` endpoint.
@@ -186,11 +201,21 @@ impl BalanceTransactions {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::BalanceTransaction> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::BalanceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/balance_transactions/{}",
+                "/v1/balance_transactions/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
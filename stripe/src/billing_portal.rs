@@ -29,6 +29,7 @@ impl BillingPortal {
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         is_default: bool,
         limit: i64,
         starting_after: &str,
@@ -40,6 +41,9 @@ impl BillingPortal {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
@@ -78,12 +82,34 @@ impl BillingPortal {
     pub async fn get_all_configurations(
         &self,
         active: bool,
+        expand: &[String],
         is_default: bool,
     ) -> ClientResult<Vec<crate::types::PortalConfiguration>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_configurations_stream(active, expand, is_default),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_all_configurations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     */
+    pub fn get_all_configurations_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        is_default: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PortalConfiguration>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
@@ -92,64 +118,35 @@ impl BillingPortal {
             &format!("/v1/billing_portal/configurations?{}", query_),
             None,
         );
-        let mut resp: crate::types::GetBillingPortalConfigurationsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetBillingPortalConfigurationsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
@@ -181,11 +178,18 @@ impl BillingPortal {
     pub async fn get_configurations_configuration(
         &self,
         configuration: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::PortalConfiguration> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/billing_portal/configurations/{}",
+                "/v1/billing_portal/configurations/{}?{}",
                 crate::progenitor_support::encode_path(configuration),
+                query_
             ),
             None,
         );
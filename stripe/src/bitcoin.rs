@@ -30,6 +30,7 @@ impl Bitcoin {
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         filled: bool,
         limit: i64,
         starting_after: &str,
@@ -42,6 +43,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
@@ -82,13 +86,39 @@ impl Bitcoin {
     pub async fn get_all_receivers(
         &self,
         active: bool,
+        expand: &[String],
         filled: bool,
         uncaptured_funds: bool,
     ) -> ClientResult<Vec<crate::types::BitcoinReceiver>> {
+        futures::TryStreamExt::try_collect(self.get_all_receivers_stream(
+            active,
+            expand,
+            filled,
+            uncaptured_funds,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_all_receivers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your receivers. Receivers are returned sorted by creation date, with the most recently created receivers appearing first.</p>
+     */
+    pub fn get_all_receivers_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        filled: bool,
+        uncaptured_funds: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinReceiver>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
@@ -99,64 +129,35 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
-        let mut resp: crate::types::GetBitcoinReceiversResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetBitcoinReceiversResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{id}` endpoint.
@@ -168,11 +169,21 @@ impl Bitcoin {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_receiver(&self, id: &str) -> ClientResult<crate::types::BitcoinReceiver> {
+    pub async fn get_receiver(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::BitcoinReceiver> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/bitcoin/receivers/{}",
+                "/v1/bitcoin/receivers/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -204,6 +215,7 @@ impl Bitcoin {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         receiver: &str,
         starting_after: &str,
@@ -215,6 +227,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -254,12 +269,34 @@ impl Bitcoin {
     pub async fn get_all_receivers_receiver_transactions(
         &self,
         customer: &str,
+        expand: &[String],
         receiver: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_receivers_receiver_transactions_stream(customer, expand, receiver),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_all_receivers_receiver_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_all_receivers_receiver_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -269,64 +306,35 @@ impl Bitcoin {
             ),
             None,
         );
-        let mut resp: crate::types::Transactions = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Transactions = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
@@ -346,6 +354,7 @@ impl Bitcoin {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         receiver: &str,
         starting_after: &str,
@@ -357,6 +366,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -394,12 +406,34 @@ impl Bitcoin {
     pub async fn get_all_transactions(
         &self,
         customer: &str,
+        expand: &[String],
         receiver: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_transactions_stream(customer, expand, receiver),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !receiver.is_empty() {
             query_args.push(("receiver".to_string(), receiver.to_string()));
         }
@@ -407,63 +441,34 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
-        let mut resp: crate::types::Transactions = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Transactions = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
 }
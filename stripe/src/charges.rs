@@ -18,7 +18,7 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `customer: &str` -- Only return charges for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -29,21 +29,28 @@ impl Charges {
      */
     pub async fn get_page(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -81,15 +88,53 @@ impl Charges {
      */
     pub async fn get_all(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
+        expand: &[String],
         payment_intent: &str,
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            created,
+            customer,
+            expand,
+            payment_intent,
+            transfer_group,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of charges you’ve previously created. The charges are returned in sorted order, with the most recent charges appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        customer: &str,
+        expand: &[String],
+        payment_intent: &str,
+        transfer_group: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -98,77 +143,51 @@ impl Charges {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/charges?{}", query_), None);
-        let mut resp: crate::types::Charges = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Charges = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/charges` endpoint.
      *
      * <p>To charge a credit card or other payment source, you create a <code>Charge</code> object. If your API key is in test mode, the supplied payment source (e.g., card) won’t actually be charged, although everything else will occur as if in live mode. (Stripe assumes that the charge would have completed successfully).</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Charge> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostChargesRequest,
+    ) -> ClientResult<crate::types::Charge> {
         let url = self.client.url("/v1/charges", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -191,11 +210,15 @@ impl Charges {
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -209,7 +232,7 @@ impl Charges {
         let url = self
             .client
             .url(&format!("/v1/charges/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::types::ChargeSearchResult = self
             .client
             .get(
                 &url,
@@ -233,8 +256,32 @@ impl Charges {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
+        futures::TryStreamExt::try_collect(self.get_all_search_stream(expand, query)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Search for charges you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -242,64 +289,30 @@ impl Charges {
         let url = self
             .client
             .url(&format!("/v1/charges/search?{}", query_), None);
-        let mut resp: crate::types::SearchResult = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ChargeSearchResult = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Search results hand us the cursor for the next page.
+                let next = if resp.has_more && !resp.next_page.is_empty() {
+                    Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                } else {
+                    None
+                };
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/charges/{charge}` endpoint.
@@ -311,11 +324,17 @@ impl Charges {
      * * `charge: &str` -- The account's country.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn get(&self, charge: &str, expand: &[String]) -> ClientResult<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}",
+                "/v1/charges/{}?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
@@ -338,7 +357,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_charges(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_charges(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRequest,
+    ) -> ClientResult<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}",
@@ -350,7 +373,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -367,7 +390,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_capture(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_capture(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeCaptureRequest,
+    ) -> ClientResult<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/capture",
@@ -379,7 +406,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -395,11 +422,21 @@ impl Charges {
      * * `charge: &str` -- The account's country.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get_dispute(&self, charge: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn get_dispute(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::Dispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/dispute",
+                "/v1/charges/{}/dispute?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
@@ -486,7 +523,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_refund(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_refund(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRefundRequest,
+    ) -> ClientResult<crate::types::Charge> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refund",
@@ -498,7 +539,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -521,6 +562,7 @@ impl Charges {
         &self,
         charge: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Refund>> {
@@ -528,6 +570,9 @@ impl Charges {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -564,72 +609,67 @@ impl Charges {
      *
      * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
      */
-    pub async fn get_all_refunds(&self, charge: &str) -> ClientResult<Vec<crate::types::Refund>> {
+    pub async fn get_all_refunds(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Refund>> {
+        futures::TryStreamExt::try_collect(self.get_all_refunds_stream(charge, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Refund>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/refunds",
+                "/v1/charges/{}/refunds?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::RefundList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::RefundList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/charges/{charge}/refunds` endpoint.
@@ -640,7 +680,11 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      */
-    pub async fn post_refund_charges(&self, charge: &str) -> ClientResult<crate::types::Refund> {
+    pub async fn post_refund_charges(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRefundsRequest,
+    ) -> ClientResult<crate::types::Refund> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refunds",
@@ -652,7 +696,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -672,13 +716,20 @@ impl Charges {
     pub async fn get_refunds_refund(
         &self,
         charge: &str,
+        expand: &[String],
         refund: &str,
     ) -> ClientResult<crate::types::Refund> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/refunds/{}",
+                "/v1/charges/{}/refunds/{}?{}",
                 crate::progenitor_support::encode_path(charge),
                 crate::progenitor_support::encode_path(refund),
+                query_
             ),
             None,
         );
@@ -706,6 +757,7 @@ impl Charges {
         &self,
         charge: &str,
         refund: &str,
+        body: &crate::types::PostChargesChargeRefundsRefundRequest,
     ) -> ClientResult<crate::types::Refund> {
         let url = self.client.url(
             &format!(
@@ -719,7 +771,7 @@ impl Charges {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -28,6 +28,7 @@ impl Checkout {
     pub async fn get_sessions(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
@@ -37,6 +38,9 @@ impl Checkout {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -76,10 +80,34 @@ impl Checkout {
      */
     pub async fn get_all_sessions(
         &self,
+        expand: &[String],
         payment_intent: &str,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Session>> {
+        futures::TryStreamExt::try_collect(self.get_all_sessions_stream(
+            expand,
+            payment_intent,
+            subscription,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `get_all_sessions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Checkout Sessions.</p>
+     */
+    pub fn get_all_sessions_stream(
+        &self,
+        expand: &[String],
+        payment_intent: &str,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Session>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -90,64 +118,35 @@ impl Checkout {
         let url = self
             .client
             .url(&format!("/v1/checkout/sessions?{}", query_), None);
-        let mut resp: crate::types::PaymentPagesCheckoutSessionList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PaymentPagesCheckoutSessionList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/checkout/sessions` endpoint.
@@ -176,11 +175,21 @@ impl Checkout {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `session: &str` -- The account's country.
      */
-    pub async fn get_sessions_session(&self, session: &str) -> ClientResult<crate::types::Session> {
+    pub async fn get_sessions_session(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> ClientResult<crate::types::Session> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/checkout/sessions/{}",
+                "/v1/checkout/sessions/{}?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
@@ -242,6 +251,7 @@ impl Checkout {
     pub async fn get_sessions_session_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         session: &str,
         starting_after: &str,
@@ -250,6 +260,9 @@ impl Checkout {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -288,72 +301,67 @@ impl Checkout {
      */
     pub async fn get_all_sessions_session_line_items(
         &self,
+        expand: &[String],
         session: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_sessions_session_line_items_stream(expand, session),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
+     *
+     * As opposed to `get_all_sessions_session_line_items`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a Checkout Session, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_sessions_session_line_items_stream(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/checkout/sessions/{}/line_items",
+                "/v1/checkout/sessions/{}/line_items?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::LineItems = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::LineItems = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
 }
@@ -29,6 +29,7 @@ impl CreditNotes {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         starting_after: &str,
@@ -40,6 +41,9 @@ impl CreditNotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -77,12 +81,31 @@ impl CreditNotes {
     pub async fn get_all(
         &self,
         customer: &str,
+        expand: &[String],
         invoice: &str,
     ) -> ClientResult<Vec<crate::types::CreditNote>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(customer, expand, invoice)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of credit notes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNote>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -90,64 +113,35 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes?{}", query_), None);
-        let mut resp: crate::types::CreditNotesList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CreditNotesList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/credit_notes` endpoint.
@@ -202,6 +196,7 @@ impl CreditNotes {
         &self,
         amount: i64,
         credit_amount: i64,
+        expand: &[String],
         invoice: &str,
         _lines: &[String],
         memo: &str,
@@ -218,6 +213,9 @@ impl CreditNotes {
         if credit_amount > 0 {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -280,6 +278,7 @@ impl CreditNotes {
         amount: i64,
         credit_amount: i64,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         _lines: &[String],
@@ -301,6 +300,9 @@ impl CreditNotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -357,6 +359,7 @@ impl CreditNotes {
         &self,
         amount: i64,
         credit_amount: i64,
+        expand: &[String],
         invoice: &str,
         _lines: &[String],
         memo: &str,
@@ -366,6 +369,42 @@ impl CreditNotes {
         refund: &str,
         refund_amount: i64,
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
+        futures::TryStreamExt::try_collect(self.get_all_preview_lines_stream(
+            amount,
+            credit_amount,
+            expand,
+            invoice,
+            _lines,
+            memo,
+            _metadata,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_all_preview_lines`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_preview_lines_stream(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        expand: &[String],
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if amount > 0 {
             query_args.push(("amount".to_string(), amount.to_string()));
@@ -373,6 +412,9 @@ impl CreditNotes {
         if credit_amount > 0 {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -398,64 +440,35 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
-        let mut resp: crate::types::Lines = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Lines = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
@@ -474,6 +487,7 @@ impl CreditNotes {
         &self,
         credit_note: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
@@ -481,6 +495,9 @@ impl CreditNotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -520,72 +537,65 @@ impl CreditNotes {
     pub async fn get_all_note_lines(
         &self,
         credit_note: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
+        futures::TryStreamExt::try_collect(self.get_all_note_lines_stream(credit_note, expand))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_all_note_lines`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_note_lines_stream(
+        &self,
+        credit_note: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/credit_notes/{}/lines",
+                "/v1/credit_notes/{}/lines?{}",
                 crate::progenitor_support::encode_path(credit_note),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::Lines = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Lines = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{id}` endpoint.
@@ -597,11 +607,17 @@ impl CreditNotes {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::CreditNote> {
+    pub async fn get(&self, expand: &[String], id: &str) -> ClientResult<crate::types::CreditNote> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/credit_notes/{}",
+                "/v1/credit_notes/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
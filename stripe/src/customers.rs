@@ -18,7 +18,7 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `email: &str` -- A case-sensitive filter on the list based on the customer's `email` field. The value must be a string.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -28,20 +28,27 @@ impl Customers {
      */
     pub async fn get_page(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         email: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -76,90 +83,95 @@ impl Customers {
      */
     pub async fn get_all(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         email: &str,
+        expand: &[String],
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Customer>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(created, email, expand, test_clock))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your customers. The customers are returned sorted by creation date, with the most recent customers appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        email: &str,
+        expand: &[String],
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !test_clock.is_empty() {
             query_args.push(("test_clock".to_string(), test_clock.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/customers?{}", query_), None);
-        let mut resp: crate::types::GetCustomersResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetCustomersResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/customers` endpoint.
      *
      * <p>Creates a new customer object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Customer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCustomersRequest,
+    ) -> ClientResult<crate::types::Customer> {
         let url = self.client.url("/v1/customers", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -182,11 +194,15 @@ impl Customers {
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
-    ) -> ClientResult<Vec<crate::types::Charge>> {
+    ) -> ClientResult<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -200,7 +216,7 @@ impl Customers {
         let url = self
             .client
             .url(&format!("/v1/customers/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::types::CustomerSearchResult = self
             .client
             .get(
                 &url,
@@ -224,8 +240,32 @@ impl Customers {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        futures::TryStreamExt::try_collect(self.get_all_search_stream(expand, query)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Search for customers you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -233,64 +273,30 @@ impl Customers {
         let url = self
             .client
             .url(&format!("/v1/customers/search?{}", query_), None);
-        let mut resp: crate::types::SearchResult = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CustomerSearchResult = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Search results hand us the cursor for the next page.
+                let next = if resp.has_more && !resp.next_page.is_empty() {
+                    Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                } else {
+                    None
+                };
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}` endpoint.
@@ -305,11 +311,18 @@ impl Customers {
     pub async fn get(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::GetCustomersCustomerResponseAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}",
+                "/v1/customers/{}?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -334,7 +347,11 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      */
-    pub async fn post_customers(&self, customer: &str) -> ClientResult<crate::types::Customer> {
+    pub async fn post_customers(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerRequest,
+    ) -> ClientResult<crate::types::Customer> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}",
@@ -346,7 +363,7 @@ impl Customers {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -396,6 +413,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CustomerBalanceTransaction>> {
@@ -403,6 +421,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -442,72 +463,68 @@ impl Customers {
     pub async fn get_all_balance_transactions(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::CustomerBalanceTransaction>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_balance_transactions_stream(customer, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all_balance_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of transactions that updated the customer’s <a href="/docs/billing/customer/balance">balances</a>.</p>
+     */
+    pub fn get_all_balance_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CustomerBalanceTransaction>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/balance_transactions",
+                "/v1/customers/{}/balance_transactions?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::CustomerBalanceTransactionList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::CustomerBalanceTransactionList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/balance_transactions` endpoint.
@@ -553,13 +570,20 @@ impl Customers {
     pub async fn get_balance_transactions_transaction(
         &self,
         customer: &str,
+        expand: &[String],
         transaction: &str,
     ) -> ClientResult<crate::types::CustomerBalanceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/balance_transactions/{}",
+                "/v1/customers/{}/balance_transactions/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(transaction),
+                query_
             ),
             None,
         );
@@ -623,6 +647,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::BankAccount>> {
@@ -630,6 +655,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -669,72 +697,65 @@ impl Customers {
     pub async fn get_all_bank_accounts(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::BankAccount>> {
+        futures::TryStreamExt::try_collect(self.get_all_bank_accounts_stream(customer, expand))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_bank_accounts`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can see a list of the bank accounts belonging to a Customer. Note that the 10 most recent sources are always available by default on the Customer. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional bank accounts.</p>
+     */
+    pub fn get_all_bank_accounts_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BankAccount>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/bank_accounts",
+                "/v1/customers/{}/bank_accounts?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::BankAccountList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::BankAccountList = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts` endpoint.
@@ -784,13 +805,20 @@ impl Customers {
     pub async fn get_bank_account(
         &self,
         customer: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::BankAccount> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/bank_accounts/{}",
+                "/v1/customers/{}/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -922,6 +950,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Card>> {
@@ -929,6 +958,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -967,72 +999,69 @@ impl Customers {
      * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
      * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
      */
-    pub async fn get_all_cards(&self, customer: &str) -> ClientResult<Vec<crate::types::Card>> {
+    pub async fn get_all_cards(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Card>> {
+        futures::TryStreamExt::try_collect(self.get_all_cards_stream(customer, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can see a list of the cards belonging to a customer.
+     * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
+     * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Card>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/cards",
+                "/v1/customers/{}/cards?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::Cards = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Cards = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/cards` endpoint.
@@ -1079,12 +1108,23 @@ impl Customers {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_card(&self, customer: &str, id: &str) -> ClientResult<crate::types::Card> {
+    pub async fn get_card(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::Card> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/cards/{}",
+                "/v1/customers/{}/cards/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -1174,11 +1214,21 @@ impl Customers {
      * * `customer: &str` -- The account's country.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get_discount(&self, customer: &str) -> ClientResult<crate::types::DiscountData> {
+    pub async fn get_discount(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::DiscountData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/discount",
+                "/v1/customers/{}/discount?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -1240,6 +1290,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
@@ -1248,6 +1299,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1290,9 +1344,31 @@ impl Customers {
     pub async fn get_all_payment_methods(
         &self,
         customer: &str,
+        expand: &[String],
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_payment_methods_stream(customer, expand, type_),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_all_payment_methods`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of PaymentMethods for a given Customer</p>
+     */
+    pub fn get_all_payment_methods_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentMethod>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -1305,64 +1381,35 @@ impl Customers {
             ),
             None,
         );
-        let mut resp: crate::types::PaymentFlowsMethodList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PaymentFlowsMethodList = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
@@ -1382,6 +1429,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         object: &str,
         starting_after: &str,
@@ -1390,6 +1438,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1432,9 +1483,30 @@ impl Customers {
     pub async fn get_all_sources(
         &self,
         customer: &str,
+        expand: &[String],
         object: &str,
     ) -> ClientResult<Vec<crate::types::CustomerSourcesDataAnyOf>> {
+        futures::TryStreamExt::try_collect(self.get_all_sources_stream(customer, expand, object))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_all_sources`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List sources for a specified customer.</p>
+     */
+    pub fn get_all_sources_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        object: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CustomerSourcesDataAnyOf>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !object.is_empty() {
             query_args.push(("object".to_string(), object.to_string()));
         }
@@ -1447,64 +1519,35 @@ impl Customers {
             ),
             None,
         );
-        let mut resp: crate::types::Sources = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Sources = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((*resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/sources` endpoint.
@@ -1554,13 +1597,20 @@ impl Customers {
     pub async fn get_source(
         &self,
         customer: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/sources/{}",
+                "/v1/customers/{}/sources/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -1690,6 +1740,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Subscription>> {
@@ -1697,6 +1748,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1736,72 +1790,65 @@ impl Customers {
     pub async fn get_all_subscriptions(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Subscription>> {
+        futures::TryStreamExt::try_collect(self.get_all_subscriptions_stream(customer, expand))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_all_subscriptions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can see a list of the customer’s active subscriptions. Note that the 10 most recent active subscriptions are always available by default on the customer object. If you need more than those 10, you can use the limit and starting_after parameters to page through additional subscriptions.</p>
+     */
+    pub fn get_all_subscriptions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subscription>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions",
+                "/v1/customers/{}/subscriptions?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::Subscriptions = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Subscriptions = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/subscriptions` endpoint.
@@ -1847,13 +1894,20 @@ impl Customers {
     pub async fn get_subscriptions_subscription_exposed(
         &self,
         customer: &str,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::Subscription> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions/{}",
+                "/v1/customers/{}/subscriptions/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
@@ -1951,13 +2005,20 @@ impl Customers {
     pub async fn get_subscriptions_subscription_exposed_discount(
         &self,
         customer: &str,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::DiscountData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions/{}/discount",
+                "/v1/customers/{}/subscriptions/{}/discount?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
@@ -2021,6 +2082,7 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TaxId>> {
@@ -2028,6 +2090,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -2064,72 +2129,67 @@ impl Customers {
      *
      * <p>Returns a list of tax IDs for a customer.</p>
      */
-    pub async fn get_all_tax_ids(&self, customer: &str) -> ClientResult<Vec<crate::types::TaxId>> {
+    pub async fn get_all_tax_ids(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::TaxId>> {
+        futures::TryStreamExt::try_collect(self.get_all_tax_ids_stream(customer, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_all_tax_ids`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of tax IDs for a customer.</p>
+     */
+    pub fn get_all_tax_ids_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TaxId>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/tax_ids",
+                "/v1/customers/{}/tax_ids?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::TaxIds = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::TaxIds = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/tax_ids` endpoint.
@@ -2169,12 +2229,23 @@ impl Customers {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get_tax_id(&self, customer: &str, id: &str) -> ClientResult<crate::types::TaxId> {
+    pub async fn get_tax_id(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::TaxId> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/tax_ids/{}",
+                "/v1/customers/{}/tax_ids/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
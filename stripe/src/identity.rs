@@ -18,7 +18,7 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -28,17 +28,24 @@ impl Identity {
      */
     pub async fn get_verification_reports(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -82,11 +89,48 @@ impl Identity {
      */
     pub async fn get_all_verification_reports(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationReport>> {
+        futures::TryStreamExt::try_collect(self.get_all_verification_reports_stream(
+            created,
+            expand,
+            type_,
+            verification_session,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_all_verification_reports`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List all verification reports.</p>
+     */
+    pub fn get_all_verification_reports_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GelatoVerificationReport>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -101,64 +145,35 @@ impl Identity {
             &format!("/v1/identity/verification_reports?{}", query_),
             None,
         );
-        let mut resp: crate::types::GetIdentityVerificationReportsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIdentityVerificationReportsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/identity/verification_reports/{report}` endpoint.
@@ -172,12 +187,19 @@ impl Identity {
      */
     pub async fn get_verification_reports_report(
         &self,
+        expand: &[String],
         report: &str,
     ) -> ClientResult<crate::types::GelatoVerificationReport> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/identity/verification_reports/{}",
+                "/v1/identity/verification_reports/{}?{}",
                 crate::progenitor_support::encode_path(report),
+                query_
             ),
             None,
         );
@@ -198,7 +220,7 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -207,16 +229,23 @@ impl Identity {
      */
     pub async fn get_verification_sessions(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationSession>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -254,10 +283,43 @@ impl Identity {
      */
     pub async fn get_all_verification_sessions(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationSession>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_verification_sessions_stream(created, expand, status),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_all_verification_sessions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of VerificationSessions</p>
+     */
+    pub fn get_all_verification_sessions_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GelatoVerificationSession>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -266,64 +328,35 @@ impl Identity {
             &format!("/v1/identity/verification_sessions?{}", query_),
             None,
         );
-        let mut resp: crate::types::GetIdentityVerificationSessionsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIdentityVerificationSessionsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/identity/verification_sessions` endpoint.
@@ -365,12 +398,19 @@ impl Identity {
      */
     pub async fn get_verification_sessions_session(
         &self,
+        expand: &[String],
         session: &str,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/identity/verification_sessions/{}",
+                "/v1/identity/verification_sessions/{}?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
@@ -19,9 +19,9 @@ impl Invoices {
      * **Parameters:**
      *
      * * `collection_method: crate::types::CollectionMethod` -- Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `customer: &str` -- Only return invoices for the customer specified by this customer ID.
-     * * `due_date: &str`
+     * * `due_date: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -32,10 +32,11 @@ impl Invoices {
     pub async fn get_page(
         &self,
         collection_method: crate::types::CollectionMethod,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
-        _due_date: &str,
+        due_date: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GetInvoicesStatus,
@@ -48,12 +49,21 @@ impl Invoices {
                 collection_method.to_string(),
             ));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(v) = &due_date {
+            query_args.extend(crate::utils::deep_object_query_args("due_date", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -92,12 +102,41 @@ impl Invoices {
     pub async fn get_all(
         &self,
         collection_method: crate::types::CollectionMethod,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
-        _due_date: &str,
+        due_date: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Invoice>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            collection_method,
+            created,
+            customer,
+            due_date,
+            expand,
+            status,
+            subscription,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        customer: &str,
+        due_date: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Invoice>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
             query_args.push((
@@ -105,9 +144,32 @@ impl Invoices {
                 collection_method.to_string(),
             ));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(v) = &due_date {
+            match crate::utils::deep_object_query_args("due_date", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -116,64 +178,35 @@ impl Invoices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
-        let mut resp: crate::types::InvoicesList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::InvoicesList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/invoices` endpoint.
@@ -209,11 +242,15 @@ impl Invoices {
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
-    ) -> ClientResult<Vec<crate::types::Charge>> {
+    ) -> ClientResult<Vec<crate::types::Invoice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -227,7 +264,7 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::types::InvoiceSearchResult = self
             .client
             .get(
                 &url,
@@ -251,8 +288,32 @@ impl Invoices {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Invoice>> {
+        futures::TryStreamExt::try_collect(self.get_all_search_stream(expand, query)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Search for invoices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Invoice>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -260,64 +321,30 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/search?{}", query_), None);
-        let mut resp: crate::types::SearchResult = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::InvoiceSearchResult = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
+                // Search results hand us the cursor for the next page.
+                let next = if resp.has_more && !resp.next_page.is_empty() {
+                    Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                } else {
+                    None
+                };
 
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
@@ -362,6 +389,7 @@ impl Invoices {
         customer: &str,
         _customer_details: &str,
         _discounts: &str,
+        expand: &[String],
         _invoice_items: &[String],
         schedule: &str,
         subscription: &str,
@@ -384,6 +412,9 @@ impl Invoices {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !schedule.is_empty() {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
@@ -483,6 +514,7 @@ impl Invoices {
         _customer_details: &str,
         _discounts: &str,
         ending_before: &str,
+        expand: &[String],
         _invoice_items: &[String],
         limit: i64,
         schedule: &str,
@@ -510,6 +542,9 @@ impl Invoices {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -590,6 +625,7 @@ impl Invoices {
         customer: &str,
         _customer_details: &str,
         _discounts: &str,
+        expand: &[String],
         _invoice_items: &[String],
         schedule: &str,
         subscription: &str,
@@ -605,6 +641,60 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> ClientResult<Vec<crate::types::LineItem>> {
+        futures::TryStreamExt::try_collect(self.get_all_upcoming_lines_stream(
+            _automatic_tax,
+            coupon,
+            customer,
+            _customer_details,
+            _discounts,
+            expand,
+            _invoice_items,
+            schedule,
+            subscription,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            _subscription_trial_end,
+            subscription_trial_from_plan,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_all_upcoming_lines`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_upcoming_lines_stream(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        expand: &[String],
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coupon.is_empty() {
             query_args.push(("coupon".to_string(), coupon.to_string()));
@@ -612,6 +702,9 @@ impl Invoices {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !schedule.is_empty() {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
@@ -658,64 +751,35 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
-        let mut resp: crate::types::InvoiceLinesList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::InvoiceLinesList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}` endpoint.
@@ -727,11 +791,21 @@ impl Invoices {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `invoice: &str` -- The account's country.
      */
-    pub async fn get(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> ClientResult<crate::types::Invoice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/invoices/{}",
+                "/v1/invoices/{}?{}",
                 crate::progenitor_support::encode_path(invoice),
+                query_
             ),
             None,
         );
@@ -847,6 +921,7 @@ impl Invoices {
     pub async fn get_lines(
         &self,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         starting_after: &str,
@@ -855,6 +930,9 @@ impl Invoices {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -891,72 +969,67 @@ impl Invoices {
      *
      * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
      */
-    pub async fn get_all_lines(&self, invoice: &str) -> ClientResult<Vec<crate::types::LineItem>> {
+    pub async fn get_all_lines(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> ClientResult<Vec<crate::types::LineItem>> {
+        futures::TryStreamExt::try_collect(self.get_all_lines_stream(expand, invoice)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_all_lines`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_lines_stream(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/invoices/{}/lines",
+                "/v1/invoices/{}/lines?{}",
                 crate::progenitor_support::encode_path(invoice),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::InvoiceLinesList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::InvoiceLinesList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/invoices/{invoice}/mark_uncollectible` endpoint.
@@ -28,6 +28,7 @@ impl IssuerFraudRecords {
         &self,
         charge: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::IssuerFraudRecord>> {
@@ -38,6 +39,9 @@ impl IssuerFraudRecords {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -73,6 +77,19 @@ impl IssuerFraudRecords {
         &self,
         charge: &str,
     ) -> ClientResult<Vec<crate::types::IssuerFraudRecord>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(charge)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuer_fraud_records` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of issuer fraud records.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuerFraudRecord>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
@@ -81,64 +98,35 @@ impl IssuerFraudRecords {
         let url = self
             .client
             .url(&format!("/v1/issuer_fraud_records?{}", query_), None);
-        let mut resp: crate::types::RadarIssuerFraudRecordList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::RadarIssuerFraudRecordList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/issuer_fraud_records/{issuer_fraud_record}` endpoint.
@@ -154,12 +142,19 @@ impl IssuerFraudRecords {
      */
     pub async fn get_record(
         &self,
+        expand: &[String],
         issuer_fraud_record: &str,
     ) -> ClientResult<crate::types::IssuerFraudRecord> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuer_fraud_records/{}",
+                "/v1/issuer_fraud_records/{}?{}",
                 crate::progenitor_support::encode_path(issuer_fraud_record),
+                query_
             ),
             None,
         );
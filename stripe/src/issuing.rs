@@ -20,7 +20,7 @@ impl Issuing {
      *
      * * `card: &str` -- Only return authorizations that belong to the given card.
      * * `cardholder: &str` -- Only return authorizations that belong to the given cardholder.
-     * * `created: &str` -- Only return authorizations that were created during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return authorizations that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -31,8 +31,9 @@ impl Issuing {
         &self,
         card: &str,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::IssuingAuthorizationStatus,
@@ -44,9 +45,15 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -85,9 +92,30 @@ impl Issuing {
         &self,
         card: &str,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         status: crate::types::IssuingAuthorizationStatus,
     ) -> ClientResult<Vec<crate::types::IssuingAuthorization>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_authorizations_stream(card, cardholder, created, expand, status),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_all_authorizations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Authorization</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_authorizations_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingAuthorization>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card.is_empty() {
             query_args.push(("card".to_string(), card.to_string()));
@@ -95,6 +123,19 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -102,64 +143,35 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/authorizations?{}", query_), None);
-        let mut resp: crate::types::GetIssuingAuthorizationsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIssuingAuthorizationsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/authorizations/{authorization}` endpoint.
@@ -174,11 +186,18 @@ impl Issuing {
     pub async fn get_authorizations_authorization(
         &self,
         authorization: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingAuthorization> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/authorizations/{}",
+                "/v1/issuing/authorizations/{}?{}",
                 crate::progenitor_support::encode_path(authorization),
+                query_
             ),
             None,
         );
@@ -289,7 +308,7 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Only return cardholders that were created during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return cardholders that were created during the given date interval.
      * * `email: &str` -- Only return cardholders that have the given email address.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -301,9 +320,10 @@ impl Issuing {
      */
     pub async fn get_cardholders(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         email: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         phone_number: &str,
         starting_after: &str,
@@ -311,12 +331,18 @@ impl Issuing {
         type_: crate::types::AccountHolderType,
     ) -> ClientResult<Vec<crate::types::IssuingCardholder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -359,16 +385,56 @@ impl Issuing {
      */
     pub async fn get_all_cardholders(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         email: &str,
+        expand: &[String],
         phone_number: &str,
         status: crate::types::IssuingCardholderStatus,
         type_: crate::types::AccountHolderType,
     ) -> ClientResult<Vec<crate::types::IssuingCardholder>> {
+        futures::TryStreamExt::try_collect(self.get_all_cardholders_stream(
+            created,
+            email,
+            expand,
+            phone_number,
+            status,
+            type_,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_all_cardholders`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Cardholder</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_cardholders_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        email: &str,
+        expand: &[String],
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingCardholder>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !phone_number.is_empty() {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
@@ -382,64 +448,35 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/cardholders?{}", query_), None);
-        let mut resp: crate::types::GetIssuingCardholdersResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIssuingCardholdersResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/issuing/cardholders` endpoint.
@@ -471,11 +508,18 @@ impl Issuing {
     pub async fn get_cardholders_cardholder(
         &self,
         cardholder: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingCardholder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/cardholders/{}",
+                "/v1/issuing/cardholders/{}?{}",
                 crate::progenitor_support::encode_path(cardholder),
+                query_
             ),
             None,
         );
@@ -527,7 +571,7 @@ impl Issuing {
      * **Parameters:**
      *
      * * `cardholder: &str` -- Only return cards belonging to the Cardholder with the provided ID.
-     * * `created: &str` -- Only return cards that were issued during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return cards that were issued during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `exp_month: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
      * * `exp_year: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
@@ -541,10 +585,11 @@ impl Issuing {
     pub async fn get_cards(
         &self,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
         exp_month: i64,
         exp_year: i64,
+        expand: &[String],
         last_4: &str,
         limit: i64,
         starting_after: &str,
@@ -555,6 +600,9 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
@@ -564,6 +612,9 @@ impl Issuing {
         if exp_year > 0 {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !last_4.is_empty() {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
@@ -607,23 +658,60 @@ impl Issuing {
     pub async fn get_all_cards(
         &self,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         exp_month: i64,
         exp_year: i64,
+        expand: &[String],
         last_4: &str,
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
     ) -> ClientResult<Vec<crate::types::IssuingCard>> {
+        futures::TryStreamExt::try_collect(self.get_all_cards_stream(
+            cardholder, created, exp_month, exp_year, expand, last_4, status, type_,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Card</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        cardholder: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        exp_month: i64,
+        exp_year: i64,
+        expand: &[String],
+        last_4: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingCard>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if exp_month > 0 {
             query_args.push(("exp_month".to_string(), exp_month.to_string()));
         }
         if exp_year > 0 {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !last_4.is_empty() {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
@@ -637,64 +725,35 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/cards?{}", query_), None);
-        let mut resp: crate::types::GetIssuingCardsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIssuingCardsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/issuing/cards` endpoint.
@@ -723,11 +782,21 @@ impl Issuing {
      * * `card: &str` -- The account's country.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      */
-    pub async fn get_cards_card(&self, card: &str) -> ClientResult<crate::types::IssuingCard> {
+    pub async fn get_cards_card(
+        &self,
+        card: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::IssuingCard> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/cards/{}",
+                "/v1/issuing/cards/{}?{}",
                 crate::progenitor_support::encode_path(card),
+                query_
             ),
             None,
         );
@@ -775,7 +844,7 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Select Issuing disputes that were created during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Select Issuing disputes that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -785,17 +854,24 @@ impl Issuing {
      */
     pub async fn get_disputes(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> ClientResult<Vec<crate::types::IssuingDispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -835,11 +911,47 @@ impl Issuing {
      */
     pub async fn get_all_disputes(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> ClientResult<Vec<crate::types::IssuingDispute>> {
+        futures::TryStreamExt::try_collect(self.get_all_disputes_stream(
+            created,
+            expand,
+            status,
+            transaction,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_all_disputes`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Dispute</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_disputes_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingDispute>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -850,64 +962,35 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/disputes?{}", query_), None);
-        let mut resp: crate::types::IssuingDisputeList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::IssuingDisputeList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/issuing/disputes` endpoint.
@@ -939,11 +1022,18 @@ impl Issuing {
     pub async fn get_disputes_dispute(
         &self,
         dispute: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingDispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/disputes/{}",
+                "/v1/issuing/disputes/{}?{}",
                 crate::progenitor_support::encode_path(dispute),
+                query_
             ),
             None,
         );
@@ -1024,7 +1114,7 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Only return issuing settlements that were created during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return issuing settlements that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -1032,15 +1122,22 @@ impl Issuing {
      */
     pub async fn get_settlements(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::IssuingSettlement>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1074,67 +1171,70 @@ impl Issuing {
      */
     pub async fn get_all_settlements(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::IssuingSettlement>> {
-        let url = self.client.url("/v1/issuing/settlements", None);
-        let mut resp: crate::types::GetIssuingSettlementsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
+        futures::TryStreamExt::try_collect(self.get_all_settlements_stream(created, expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_all_settlements`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Settlement</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_settlements_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingSettlement>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
                 }
             }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/settlements?{}", query_), None);
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIssuingSettlementsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/settlements/{settlement}` endpoint.
@@ -1148,12 +1248,19 @@ impl Issuing {
      */
     pub async fn get_settlements_settlement(
         &self,
+        expand: &[String],
         settlement: &str,
     ) -> ClientResult<crate::types::IssuingSettlement> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/settlements/{}",
+                "/v1/issuing/settlements/{}?{}",
                 crate::progenitor_support::encode_path(settlement),
+                query_
             ),
             None,
         );
@@ -1206,7 +1313,7 @@ impl Issuing {
      *
      * * `card: &str` -- Only return transactions that belong to the given card.
      * * `cardholder: &str` -- Only return transactions that belong to the given cardholder.
-     * * `created: &str` -- Only return transactions that were created during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return transactions that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -1217,8 +1324,9 @@ impl Issuing {
         &self,
         card: &str,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::IssuingTransactionType,
@@ -1230,9 +1338,15 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1271,9 +1385,30 @@ impl Issuing {
         &self,
         card: &str,
         cardholder: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         type_: crate::types::IssuingTransactionType,
     ) -> ClientResult<Vec<crate::types::IssuingTransaction>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_transactions_stream(card, cardholder, created, expand, type_),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of Issuing <code>Transaction</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        type_: crate::types::IssuingTransactionType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingTransaction>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card.is_empty() {
             query_args.push(("card".to_string(), card.to_string()));
@@ -1281,6 +1416,19 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -1288,64 +1436,35 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/transactions?{}", query_), None);
-        let mut resp: crate::types::GetIssuingTransactionsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetIssuingTransactionsResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/transactions/{transaction}` endpoint.
@@ -1359,12 +1478,19 @@ impl Issuing {
      */
     pub async fn get_transactions_transaction(
         &self,
+        expand: &[String],
         transaction: &str,
     ) -> ClientResult<crate::types::IssuingTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/transactions/{}",
+                "/v1/issuing/transactions/{}?{}",
                 crate::progenitor_support::encode_path(transaction),
+                query_
             ),
             None,
         );
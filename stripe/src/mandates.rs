@@ -21,11 +21,21 @@ impl Mandates {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `mandate: &str` -- The account's country.
      */
-    pub async fn get(&self, mandate: &str) -> ClientResult<crate::types::Mandate> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        mandate: &str,
+    ) -> ClientResult<crate::types::Mandate> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/mandates/{}",
+                "/v1/mandates/{}?{}",
                 crate::progenitor_support::encode_path(mandate),
+                query_
             ),
             None,
         );
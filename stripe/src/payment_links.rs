@@ -28,6 +28,7 @@ impl PaymentLinks {
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PaymentLink>> {
@@ -38,6 +39,9 @@ impl PaymentLinks {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -70,6 +74,19 @@ impl PaymentLinks {
      * <p>Returns a list of your payment links.</p>
      */
     pub async fn get_all(&self, active: bool) -> ClientResult<Vec<crate::types::PaymentLink>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(active)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your payment links.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentLink>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -78,64 +95,35 @@ impl PaymentLinks {
         let url = self
             .client
             .url(&format!("/v1/payment_links?{}", query_), None);
-        let mut resp: crate::types::GetPaymentLinksResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetPaymentLinksResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/payment_links` endpoint.
@@ -164,11 +152,21 @@ impl PaymentLinks {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `payment_link: &str` -- The account's country.
      */
-    pub async fn get_link(&self, payment_link: &str) -> ClientResult<crate::types::PaymentLink> {
+    pub async fn get_link(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+    ) -> ClientResult<crate::types::PaymentLink> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_links/{}",
+                "/v1/payment_links/{}?{}",
                 crate::progenitor_support::encode_path(payment_link),
+                query_
             ),
             None,
         );
@@ -225,6 +223,7 @@ impl PaymentLinks {
     pub async fn get_link_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_link: &str,
         starting_after: &str,
@@ -233,6 +232,9 @@ impl PaymentLinks {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -271,72 +273,67 @@ impl PaymentLinks {
      */
     pub async fn get_all_link_line_items(
         &self,
+        expand: &[String],
         payment_link: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_link_line_items_stream(expand, payment_link),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links/{payment_link}/line_items` endpoint.
+     *
+     * As opposed to `get_all_link_line_items`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a payment link, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_link_line_items_stream(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_links/{}/line_items",
+                "/v1/payment_links/{}/line_items?{}",
                 crate::progenitor_support::encode_path(payment_link),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::LineItems = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::LineItems = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
 }
@@ -29,6 +29,7 @@ impl PaymentMethods {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
@@ -40,6 +41,9 @@ impl PaymentMethods {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -79,6 +83,20 @@ impl PaymentMethods {
         customer: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(customer, type_)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of PaymentMethods. For listing a customer’s payment methods, you should use <a href="/docs/api/payment_methods/customer_list">List a Customer’s PaymentMethods</a></p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentMethod>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -90,64 +108,35 @@ impl PaymentMethods {
         let url = self
             .client
             .url(&format!("/v1/payment_methods?{}", query_), None);
-        let mut resp: crate::types::PaymentFlowsMethodList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PaymentFlowsMethodList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/payment_methods` endpoint.
@@ -180,12 +169,19 @@ impl PaymentMethods {
      */
     pub async fn get_method(
         &self,
+        expand: &[String],
         payment_method: &str,
     ) -> ClientResult<crate::types::PaymentMethod> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_methods/{}",
+                "/v1/payment_methods/{}?{}",
                 crate::progenitor_support::encode_path(payment_method),
+                query_
             ),
             None,
         );
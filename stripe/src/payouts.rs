@@ -18,8 +18,8 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `arrival_date: &str`
-     * * `created: &str`
+     * * `arrival_date: Option<crate::types::RangeQueryAnyOf>`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `destination: &str` -- The ID of an external account - only return payouts sent to this external account.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -29,21 +29,31 @@ impl Payouts {
      */
     pub async fn get_page(
         &self,
-        _arrival_date: &str,
-        _created: &str,
+        arrival_date: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
         destination: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: &str,
     ) -> ClientResult<Vec<crate::types::Payout>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &arrival_date {
+            query_args.extend(crate::utils::deep_object_query_args("arrival_date", v)?);
+        }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -78,78 +88,97 @@ impl Payouts {
      */
     pub async fn get_all(
         &self,
-        _arrival_date: &str,
-        _created: &str,
+        arrival_date: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
         destination: &str,
+        expand: &[String],
         status: &str,
     ) -> ClientResult<Vec<crate::types::Payout>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            arrival_date,
+            created,
+            destination,
+            expand,
+            status,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of existing payouts sent to third-party bank accounts or that Stripe has sent you. The payouts are returned in sorted order, with the most recently created payouts appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        arrival_date: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        destination: &str,
+        expand: &[String],
+        status: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Payout>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &arrival_date {
+            match crate::utils::deep_object_query_args("arrival_date", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/payouts?{}", query_), None);
-        let mut resp: crate::types::PayoutList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PayoutList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/payouts` endpoint.
@@ -182,11 +211,17 @@ impl Payouts {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `payout: &str` -- The account's country.
      */
-    pub async fn get(&self, payout: &str) -> ClientResult<crate::types::Payout> {
+    pub async fn get(&self, expand: &[String], payout: &str) -> ClientResult<crate::types::Payout> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payouts/{}",
+                "/v1/payouts/{}?{}",
                 crate::progenitor_support::encode_path(payout),
+                query_
             ),
             None,
         );
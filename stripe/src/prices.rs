@@ -19,7 +19,7 @@ impl Prices {
      * **Parameters:**
      *
      * * `active: bool` -- Only return prices that are active or inactive (e.g., pass `false` to list all inactive prices).
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `currency: &str` -- Only return prices for the given currency.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -33,9 +33,10 @@ impl Prices {
     pub async fn get_page(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _lookup_keys: &[String],
         product: &str,
@@ -47,12 +48,18 @@ impl Prices {
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -91,20 +98,64 @@ impl Prices {
     pub async fn get_all(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
+        expand: &[String],
         _lookup_keys: &[String],
         product: &str,
         _recurring: &str,
         type_: crate::types::PriceType,
     ) -> ClientResult<Vec<crate::types::PriceData>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            active,
+            created,
+            currency,
+            expand,
+            _lookup_keys,
+            product,
+            _recurring,
+            type_,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your prices.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        currency: &str,
+        expand: &[String],
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        type_: crate::types::PriceType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PriceData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !product.is_empty() {
             query_args.push(("product".to_string(), product.to_string()));
         }
@@ -113,64 +164,35 @@ impl Prices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/prices?{}", query_), None);
-        let mut resp: crate::types::PriceList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PriceList = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/prices` endpoint.
@@ -206,11 +228,15 @@ impl Prices {
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
-    ) -> ClientResult<Vec<crate::types::Charge>> {
+    ) -> ClientResult<Vec<crate::types::PriceData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -224,7 +250,7 @@ impl Prices {
         let url = self
             .client
             .url(&format!("/v1/prices/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::types::PriceSearchResult = self
             .client
             .get(
                 &url,
@@ -248,8 +274,32 @@ impl Prices {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::PriceData>> {
+        futures::TryStreamExt::try_collect(self.get_all_search_stream(expand, query)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Search for prices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PriceData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -257,64 +307,30 @@ impl Prices {
         let url = self
             .client
             .url(&format!("/v1/prices/search?{}", query_), None);
-        let mut resp: crate::types::SearchResult = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PriceSearchResult = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
+                // Search results hand us the cursor for the next page.
+                let next = if resp.has_more && !resp.next_page.is_empty() {
+                    Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                } else {
+                    None
+                };
 
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/prices/{price}` endpoint.
@@ -326,11 +342,21 @@ impl Prices {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `price: &str` -- The account's country.
      */
-    pub async fn get(&self, price: &str) -> ClientResult<crate::types::PriceData> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        price: &str,
+    ) -> ClientResult<crate::types::PriceData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/prices/{}",
+                "/v1/prices/{}?{}",
                 crate::progenitor_support::encode_path(price),
+                query_
             ),
             None,
         );
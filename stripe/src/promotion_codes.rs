@@ -21,7 +21,7 @@ impl PromotionCodes {
      * * `active: bool` -- Filter promotion codes by whether they are active.
      * * `code: &str` -- Only return promotion codes that have this case-insensitive code.
      * * `coupon: &str` -- Only return promotion codes for this coupon.
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `customer: &str` -- Only return promotion codes that are restricted to this customer.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -33,9 +33,10 @@ impl PromotionCodes {
         active: bool,
         code: &str,
         coupon: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PromotionCode>> {
@@ -49,12 +50,18 @@ impl PromotionCodes {
         if !coupon.is_empty() {
             query_args.push(("coupon".to_string(), coupon.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -91,9 +98,29 @@ impl PromotionCodes {
         active: bool,
         code: &str,
         coupon: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
     ) -> ClientResult<Vec<crate::types::PromotionCode>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_stream(active, code, coupon, created, customer),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your promotion codes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PromotionCode>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -104,6 +131,16 @@ impl PromotionCodes {
         if !coupon.is_empty() {
             query_args.push(("coupon".to_string(), coupon.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
@@ -111,64 +148,35 @@ impl PromotionCodes {
         let url = self
             .client
             .url(&format!("/v1/promotion_codes?{}", query_), None);
-        let mut resp: crate::types::GetPromotionCodesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetPromotionCodesResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/promotion_codes` endpoint.
@@ -199,12 +207,19 @@ impl PromotionCodes {
      */
     pub async fn get_code(
         &self,
+        expand: &[String],
         promotion_code: &str,
     ) -> ClientResult<crate::types::PromotionCode> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/promotion_codes/{}",
+                "/v1/promotion_codes/{}?{}",
                 crate::progenitor_support::encode_path(promotion_code),
+                query_
             ),
             None,
         );
@@ -30,6 +30,7 @@ impl Quotes {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::QuoteStatus,
@@ -42,6 +43,9 @@ impl Quotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -80,13 +84,36 @@ impl Quotes {
     pub async fn get_all(
         &self,
         customer: &str,
+        expand: &[String],
         status: crate::types::QuoteStatus,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Quote>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_stream(customer, expand, status, test_clock),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your quotes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Quote>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -95,64 +122,35 @@ impl Quotes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/quotes?{}", query_), None);
-        let mut resp: crate::types::GetQuotesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetQuotesResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/quotes` endpoint.
@@ -181,11 +179,17 @@ impl Quotes {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `quote: &str` -- The account's country.
      */
-    pub async fn get(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn get(&self, expand: &[String], quote: &str) -> ClientResult<crate::types::Quote> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}",
+                "/v1/quotes/{}?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
@@ -296,6 +300,7 @@ impl Quotes {
     pub async fn get_computed_upfront_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         quote: &str,
         starting_after: &str,
@@ -304,6 +309,9 @@ impl Quotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -342,73 +350,68 @@ impl Quotes {
      */
     pub async fn get_all_computed_upfront_line_items(
         &self,
+        expand: &[String],
         quote: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_computed_upfront_line_items_stream(expand, quote),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
+     *
+     * As opposed to `get_all_computed_upfront_line_items`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a quote, there is an includable <a href="https://stripe.com/docs/api/quotes/object#quote_object-computed-upfront-line_items"><strong>computed.upfront.line_items</strong></a> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of upfront line items.</p>
+     */
+    pub fn get_all_computed_upfront_line_items_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/computed_upfront_line_items",
+                "/v1/quotes/{}/computed_upfront_line_items?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::LineItems = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::LineItems = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/quotes/{quote}/finalize` endpoint.
@@ -453,6 +456,7 @@ impl Quotes {
     pub async fn get_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         quote: &str,
         starting_after: &str,
@@ -461,6 +465,9 @@ impl Quotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -497,72 +504,67 @@ impl Quotes {
      *
      * <p>When retrieving a quote, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
      */
-    pub async fn get_all_line_items(&self, quote: &str) -> ClientResult<Vec<crate::types::Item>> {
+    pub async fn get_all_line_items(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> ClientResult<Vec<crate::types::Item>> {
+        futures::TryStreamExt::try_collect(self.get_all_line_items_stream(expand, quote)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
+     *
+     * As opposed to `get_all_line_items`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>When retrieving a quote, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_line_items_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/line_items",
+                "/v1/quotes/{}/line_items?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::LineItems = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::LineItems = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/quotes/{quote}/pdf` endpoint.
@@ -574,11 +576,17 @@ impl Quotes {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `quote: &str` -- The account's country.
      */
-    pub async fn get_pdf(&self, quote: &str) -> ClientResult<()> {
+    pub async fn get_pdf(&self, expand: &[String], quote: &str) -> ClientResult<()> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/pdf",
+                "/v1/quotes/{}/pdf?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
@@ -29,6 +29,7 @@ impl Radar {
         &self,
         charge: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
@@ -40,6 +41,9 @@ impl Radar {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -77,12 +81,36 @@ impl Radar {
     pub async fn get_all_early_fraud_warnings(
         &self,
         charge: &str,
+        expand: &[String],
         payment_intent: &str,
     ) -> ClientResult<Vec<crate::types::RadarEarlyFraudWarning>> {
+        futures::TryStreamExt::try_collect(self.get_all_early_fraud_warnings_stream(
+            charge,
+            expand,
+            payment_intent,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
+     *
+     * As opposed to `get_all_early_fraud_warnings`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of early fraud warnings.</p>
+     */
+    pub fn get_all_early_fraud_warnings_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+        payment_intent: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarEarlyFraudWarning>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -90,64 +118,35 @@ impl Radar {
         let url = self
             .client
             .url(&format!("/v1/radar/early_fraud_warnings?{}", query_), None);
-        let mut resp: crate::types::RadarEarlyFraudWarningList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::RadarEarlyFraudWarningList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/radar/early_fraud_warnings/{early_fraud_warning}` endpoint.
@@ -164,11 +163,18 @@ impl Radar {
     pub async fn get_early_fraud_warnings_warning(
         &self,
         early_fraud_warning: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::RadarEarlyFraudWarning> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/early_fraud_warnings/{}",
+                "/v1/radar/early_fraud_warnings/{}?{}",
                 crate::progenitor_support::encode_path(early_fraud_warning),
+                query_
             ),
             None,
         );
@@ -189,7 +195,7 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -199,17 +205,24 @@ impl Radar {
      */
     pub async fn get_value_list_items(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         value: &str,
         value_list: &str,
     ) -> ClientResult<Vec<crate::types::RadarListItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -249,11 +262,44 @@ impl Radar {
      */
     pub async fn get_all_value_list_all_items(
         &self,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         value: &str,
         value_list: &str,
     ) -> ClientResult<Vec<crate::types::RadarListItem>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_value_list_all_items_stream(created, expand, value, value_list),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `get_all_value_list_all_items`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of <code>ValueListItem</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_value_list_all_items_stream(
+        &self,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        value: &str,
+        value_list: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarListItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !value.is_empty() {
             query_args.push(("value".to_string(), value.to_string()));
         }
@@ -264,64 +310,35 @@ impl Radar {
         let url = self
             .client
             .url(&format!("/v1/radar/value_list_items?{}", query_), None);
-        let mut resp: crate::types::ListItems = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListItems = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/radar/value_list_items` endpoint.
@@ -352,12 +369,19 @@ impl Radar {
      */
     pub async fn get_value_list_items_item(
         &self,
+        expand: &[String],
         item: &str,
     ) -> ClientResult<crate::types::RadarListItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/value_list_items/{}",
+                "/v1/radar/value_list_items/{}?{}",
                 crate::progenitor_support::encode_path(item),
+                query_
             ),
             None,
         );
@@ -410,7 +434,7 @@ impl Radar {
      *
      * * `alias: &str` -- The alias used to reference the value list when writing rules.
      * * `contains: &str` -- A value contained within a value list - returns all value lists containing this value.
-     * * `created: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -420,8 +444,9 @@ impl Radar {
         &self,
         alias: &str,
         contains: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::RadarList>> {
@@ -432,9 +457,15 @@ impl Radar {
         if !contains.is_empty() {
             query_args.push(("contains".to_string(), contains.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -470,8 +501,28 @@ impl Radar {
         &self,
         alias: &str,
         contains: &str,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::RadarList>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_value_lists_stream(alias, contains, created, expand),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `get_all_value_lists`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of <code>ValueList</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_all_value_lists_stream(
+        &self,
+        alias: &str,
+        contains: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarList>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alias.is_empty() {
             query_args.push(("alias".to_string(), alias.to_string()));
@@ -479,68 +530,52 @@ impl Radar {
         if !contains.is_empty() {
             query_args.push(("contains".to_string(), contains.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/v1/radar/value_lists?{}", query_), None);
-        let mut resp: crate::types::GetRadarValueListsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetRadarValueListsResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/radar/value_lists` endpoint.
@@ -571,12 +606,19 @@ impl Radar {
      */
     pub async fn get_value_lists_list(
         &self,
+        expand: &[String],
         value_list: &str,
     ) -> ClientResult<crate::types::RadarList> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/value_lists/{}",
+                "/v1/radar/value_lists/{}?{}",
                 crate::progenitor_support::encode_path(value_list),
+                query_
             ),
             None,
         );
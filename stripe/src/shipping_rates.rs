@@ -19,7 +19,7 @@ impl ShippingRates {
      * **Parameters:**
      *
      * * `active: bool` -- Only return shipping rates that are active or inactive.
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `currency: &str` -- Only return shipping rates for the given currency.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -29,9 +29,10 @@ impl ShippingRates {
     pub async fn get_page(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ShippingRate>> {
@@ -39,12 +40,18 @@ impl ShippingRates {
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -79,13 +86,38 @@ impl ShippingRates {
     pub async fn get_all(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         currency: &str,
     ) -> ClientResult<Vec<crate::types::ShippingRate>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(active, created, currency)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/shipping_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your shipping rates.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        currency: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShippingRate>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
@@ -93,64 +125,35 @@ impl ShippingRates {
         let url = self
             .client
             .url(&format!("/v1/shipping_rates?{}", query_), None);
-        let mut resp: crate::types::ShippingResourcesRateList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ShippingResourcesRateList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/shipping_rates` endpoint.
@@ -181,12 +184,19 @@ impl ShippingRates {
      */
     pub async fn get_rate_token(
         &self,
+        expand: &[String],
         shipping_rate_token: &str,
     ) -> ClientResult<crate::types::ShippingRate> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/shipping_rates/{}",
+                "/v1/shipping_rates/{}?{}",
                 crate::progenitor_support::encode_path(shipping_rate_token),
+                query_
             ),
             None,
         );
@@ -26,6 +26,7 @@ impl Sigma {
     pub async fn get_scheduled_query_runs(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ScheduledQueryRun>> {
@@ -33,6 +34,9 @@ impl Sigma {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -66,66 +70,58 @@ impl Sigma {
      */
     pub async fn get_all_scheduled_query_runs(
         &self,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ScheduledQueryRun>> {
-        let url = self.client.url("/v1/sigma/scheduled_query_runs", None);
-        let mut resp: crate::types::GetSigmaScheduledQueryRunsResponse = self
+        futures::TryStreamExt::try_collect(self.get_all_scheduled_query_runs_stream(expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
+     *
+     * As opposed to `get_all_scheduled_query_runs`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of scheduled query runs.</p>
+     */
+    pub fn get_all_scheduled_query_runs_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ScheduledQueryRun>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+            .url(&format!("/v1/sigma/scheduled_query_runs?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetSigmaScheduledQueryRunsResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs/{scheduled_query_run}` endpoint.
@@ -139,12 +135,19 @@ impl Sigma {
      */
     pub async fn get_scheduled_query_runs_run(
         &self,
+        expand: &[String],
         scheduled_query_run: &str,
     ) -> ClientResult<crate::types::ScheduledQueryRun> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sigma/scheduled_query_runs/{}",
+                "/v1/sigma/scheduled_query_runs/{}?{}",
                 crate::progenitor_support::encode_path(scheduled_query_run),
+                query_
             ),
             None,
         );
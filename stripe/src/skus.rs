@@ -33,6 +33,7 @@ impl Skus {
         active: bool,
         _attributes: &str,
         ending_before: &str,
+        expand: &[String],
         _ids: &[String],
         in_stock: bool,
         limit: i64,
@@ -46,6 +47,9 @@ impl Skus {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
@@ -85,14 +89,44 @@ impl Skus {
         &self,
         active: bool,
         _attributes: &str,
+        expand: &[String],
         _ids: &[String],
         in_stock: bool,
         product: &str,
     ) -> ClientResult<Vec<crate::types::Sku>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            active,
+            _attributes,
+            expand,
+            _ids,
+            in_stock,
+            product,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your SKUs. The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _attributes: &str,
+        expand: &[String],
+        _ids: &[String],
+        in_stock: bool,
+        product: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Sku>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
@@ -101,64 +135,35 @@ impl Skus {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/skus?{}", query_), None);
-        let mut resp: crate::types::GetSkusResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetSkusResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/skus` endpoint.
@@ -187,9 +192,22 @@ impl Skus {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::GetSkusResponseAnyOf> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::GetSkusResponseAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),),
+            &format!(
+                "/v1/skus/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
             None,
         );
         self.client
@@ -42,12 +42,16 @@ impl Sources {
     pub async fn get(
         &self,
         client_secret: &str,
+        expand: &[String],
         source: &str,
     ) -> ClientResult<crate::types::SourceData> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_secret.is_empty() {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -109,14 +113,21 @@ impl Sources {
      */
     pub async fn get_mandate_notifications_notification(
         &self,
+        expand: &[String],
         mandate_notification: &str,
         source: &str,
     ) -> ClientResult<crate::types::SourceMandateNotification> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/mandate_notifications/{}",
+                "/v1/sources/{}/mandate_notifications/{}?{}",
                 crate::progenitor_support::encode_path(source),
                 crate::progenitor_support::encode_path(mandate_notification),
+                query_
             ),
             None,
         );
@@ -146,6 +157,7 @@ impl Sources {
     pub async fn get_transactions(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         source: &str,
         starting_after: &str,
@@ -154,6 +166,9 @@ impl Sources {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -192,73 +207,65 @@ impl Sources {
      */
     pub async fn get_all_transactions(
         &self,
+        expand: &[String],
         source: &str,
     ) -> ClientResult<Vec<crate::types::SourceTransaction>> {
+        futures::TryStreamExt::try_collect(self.get_all_transactions_stream(expand, source)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>List source transactions for a given source.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        expand: &[String],
+        source: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SourceTransaction>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/source_transactions",
+                "/v1/sources/{}/source_transactions?{}",
                 crate::progenitor_support::encode_path(source),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::ApmsSourcesSourceTransactionList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ApmsSourcesSourceTransactionList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions/{source_transaction}` endpoint.
@@ -273,14 +280,21 @@ impl Sources {
      */
     pub async fn get_transactions_transaction(
         &self,
+        expand: &[String],
         source: &str,
         source_transaction: &str,
     ) -> ClientResult<crate::types::SourceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/source_transactions/{}",
+                "/v1/sources/{}/source_transactions/{}?{}",
                 crate::progenitor_support::encode_path(source),
                 crate::progenitor_support::encode_path(source_transaction),
+                query_
             ),
             None,
         );
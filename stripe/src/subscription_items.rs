@@ -27,6 +27,7 @@ impl SubscriptionItems {
     pub async fn get_page(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         subscription: &str,
@@ -35,6 +36,9 @@ impl SubscriptionItems {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -73,6 +77,19 @@ impl SubscriptionItems {
         &self,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::SubscriptionItem>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(subscription)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your subscription items for a given subscription.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SubscriptionItem>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !subscription.is_empty() {
             query_args.push(("subscription".to_string(), subscription.to_string()));
@@ -81,64 +98,35 @@ impl SubscriptionItems {
         let url = self
             .client
             .url(&format!("/v1/subscription_items?{}", query_), None);
-        let mut resp: crate::types::Items = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Items = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/subscription_items` endpoint.
@@ -167,11 +155,21 @@ impl SubscriptionItems {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `item: &str` -- The account's country.
      */
-    pub async fn get_item(&self, item: &str) -> ClientResult<crate::types::SubscriptionItem> {
+    pub async fn get_item(
+        &self,
+        expand: &[String],
+        item: &str,
+    ) -> ClientResult<crate::types::SubscriptionItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_items/{}",
+                "/v1/subscription_items/{}?{}",
                 crate::progenitor_support::encode_path(item),
+                query_
             ),
             None,
         );
@@ -260,6 +258,7 @@ impl SubscriptionItems {
     pub async fn get_item_usage_record_summaries(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         subscription_item: &str,
@@ -268,6 +267,9 @@ impl SubscriptionItems {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -308,73 +310,70 @@ impl SubscriptionItems {
      */
     pub async fn get_all_item_usage_record_summaries(
         &self,
+        expand: &[String],
         subscription_item: &str,
     ) -> ClientResult<Vec<crate::types::UsageRecordSummary>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_item_usage_record_summaries_stream(expand, subscription_item),
+        )
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_all_item_usage_record_summaries`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>For the specified subscription item, returns a list of summary objects. Each object in the list provides usage information that’s been summarized from multiple usage records and over a subscription billing period (e.g., 15 usage records in the month of September).</p>
+     *
+     * <p>The list is sorted in reverse-chronological order (newest first). The first list item represents the most current usage period that hasn’t ended yet. Since new usage records can still be added, the returned summary information for the subscription item’s ID should be seen as unstable until the subscription billing period ends.</p>
+     */
+    pub fn get_all_item_usage_record_summaries_stream(
+        &self,
+        expand: &[String],
+        subscription_item: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsageRecordSummary>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_items/{}/usage_record_summaries",
+                "/v1/subscription_items/{}/usage_record_summaries?{}",
                 crate::progenitor_support::encode_path(subscription_item),
+                query_
             ),
             None,
         );
-        let mut resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/subscription_items/{subscription_item}/usage_records` endpoint.
@@ -18,39 +18,55 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `canceled_at: &str` -- Only return subscription schedules that were created canceled the given date interval.
-     * * `completed_at: &str` -- Only return subscription schedules that completed during the given date interval.
-     * * `created: &str` -- Only return subscription schedules that were created during the given date interval.
+     * * `canceled_at: Option<crate::types::RangeQueryAnyOf>` -- Only return subscription schedules that were created canceled the given date interval.
+     * * `completed_at: Option<crate::types::RangeQueryAnyOf>` -- Only return subscription schedules that completed during the given date interval.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Only return subscription schedules that were created during the given date interval.
      * * `customer: &str` -- Only return subscription schedules for the given customer.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `released_at: &str` -- Only return subscription schedules that were released during the given date interval.
+     * * `released_at: Option<crate::types::RangeQueryAnyOf>` -- Only return subscription schedules that were released during the given date interval.
      * * `scheduled: bool` -- Only return subscription schedules that have not started yet.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_page(
         &self,
-        _canceled_at: &str,
-        _completed_at: &str,
-        _created: &str,
+        canceled_at: Option<crate::types::RangeQueryAnyOf>,
+        completed_at: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
-        _released_at: &str,
+        released_at: Option<crate::types::RangeQueryAnyOf>,
         scheduled: bool,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::SubscriptionSchedule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &canceled_at {
+            query_args.extend(crate::utils::deep_object_query_args("canceled_at", v)?);
+        }
+        if let Some(v) = &completed_at {
+            query_args.extend(crate::utils::deep_object_query_args("completed_at", v)?);
+        }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if let Some(v) = &released_at {
+            query_args.extend(crate::utils::deep_object_query_args("released_at", v)?);
+        }
         if scheduled {
             query_args.push(("scheduled".to_string(), scheduled.to_string()));
         }
@@ -84,17 +100,83 @@ impl SubscriptionSchedules {
      */
     pub async fn get_all(
         &self,
-        _canceled_at: &str,
-        _completed_at: &str,
-        _created: &str,
+        canceled_at: Option<crate::types::RangeQueryAnyOf>,
+        completed_at: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
-        _released_at: &str,
+        released_at: Option<crate::types::RangeQueryAnyOf>,
         scheduled: bool,
     ) -> ClientResult<Vec<crate::types::SubscriptionSchedule>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            canceled_at,
+            completed_at,
+            created,
+            customer,
+            released_at,
+            scheduled,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Retrieves the list of your subscription schedules.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        canceled_at: Option<crate::types::RangeQueryAnyOf>,
+        completed_at: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        customer: &str,
+        released_at: Option<crate::types::RangeQueryAnyOf>,
+        scheduled: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SubscriptionSchedule>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &canceled_at {
+            match crate::utils::deep_object_query_args("canceled_at", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &completed_at {
+            match crate::utils::deep_object_query_args("completed_at", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(v) = &released_at {
+            match crate::utils::deep_object_query_args("released_at", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if scheduled {
             query_args.push(("scheduled".to_string(), scheduled.to_string()));
         }
@@ -102,64 +184,35 @@ impl SubscriptionSchedules {
         let url = self
             .client
             .url(&format!("/v1/subscription_schedules?{}", query_), None);
-        let mut resp: crate::types::GetSubscriptionSchedulesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetSubscriptionSchedulesResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/subscription_schedules` endpoint.
@@ -190,12 +243,19 @@ impl SubscriptionSchedules {
      */
     pub async fn get_schedule(
         &self,
+        expand: &[String],
         schedule: &str,
     ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_schedules/{}",
+                "/v1/subscription_schedules/{}?{}",
                 crate::progenitor_support::encode_path(schedule),
+                query_
             ),
             None,
         );
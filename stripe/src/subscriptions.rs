@@ -19,9 +19,9 @@ impl Subscriptions {
      * **Parameters:**
      *
      * * `collection_method: crate::types::CollectionMethod` -- Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
-     * * `created: &str`
-     * * `current_period_end: &str`
-     * * `current_period_start: &str`
+     * * `created: Option<crate::types::RangeQueryAnyOf>`
+     * * `current_period_end: Option<crate::types::RangeQueryAnyOf>`
+     * * `current_period_start: Option<crate::types::RangeQueryAnyOf>`
      * * `customer: &str` -- The ID of the customer whose subscriptions will be retrieved.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
@@ -34,11 +34,12 @@ impl Subscriptions {
     pub async fn get_page(
         &self,
         collection_method: crate::types::CollectionMethod,
-        _created: &str,
-        _current_period_end: &str,
-        _current_period_start: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        current_period_end: Option<crate::types::RangeQueryAnyOf>,
+        current_period_start: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         price: &str,
         starting_after: &str,
@@ -52,12 +53,30 @@ impl Subscriptions {
                 collection_method.to_string(),
             ));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
+        if let Some(v) = &current_period_end {
+            query_args.extend(crate::utils::deep_object_query_args(
+                "current_period_end",
+                v,
+            )?);
+        }
+        if let Some(v) = &current_period_start {
+            query_args.extend(crate::utils::deep_object_query_args(
+                "current_period_start",
+                v,
+            )?);
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -101,14 +120,44 @@ impl Subscriptions {
     pub async fn get_all(
         &self,
         collection_method: crate::types::CollectionMethod,
-        _created: &str,
-        _current_period_end: &str,
-        _current_period_start: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        current_period_end: Option<crate::types::RangeQueryAnyOf>,
+        current_period_start: Option<crate::types::RangeQueryAnyOf>,
         customer: &str,
         price: &str,
         status: crate::types::GetSubscriptionsStatus,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Subscription>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            collection_method,
+            created,
+            current_period_end,
+            current_period_start,
+            customer,
+            price,
+            status,
+            test_clock,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>By default, returns a list of subscriptions that have not been canceled. In order to list canceled subscriptions, specify <code>status=canceled</code>.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        current_period_end: Option<crate::types::RangeQueryAnyOf>,
+        current_period_start: Option<crate::types::RangeQueryAnyOf>,
+        customer: &str,
+        price: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subscription>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
             query_args.push((
@@ -116,6 +165,36 @@ impl Subscriptions {
                 collection_method.to_string(),
             ));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &current_period_end {
+            match crate::utils::deep_object_query_args("current_period_end", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &current_period_start {
+            match crate::utils::deep_object_query_args("current_period_start", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
@@ -132,64 +211,35 @@ impl Subscriptions {
         let url = self
             .client
             .url(&format!("/v1/subscriptions?{}", query_), None);
-        let mut resp: crate::types::Subscriptions = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::Subscriptions = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/subscriptions` endpoint.
@@ -202,13 +252,16 @@ impl Subscriptions {
      * <p>To start subscriptions where the first invoice always begins in a <code>draft</code> status, use <a href="/docs/billing/subscriptions/subscription-schedules#managing">subscription schedules</a> instead.
      * Schedules provide the flexibility to model more complex billing configurations that change over time.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Subscription> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionsRequest,
+    ) -> ClientResult<crate::types::Subscription> {
         let url = self.client.url("/v1/subscriptions", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -231,11 +284,15 @@ impl Subscriptions {
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
-    ) -> ClientResult<Vec<crate::types::Charge>> {
+    ) -> ClientResult<Vec<crate::types::Subscription>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -249,7 +306,7 @@ impl Subscriptions {
         let url = self
             .client
             .url(&format!("/v1/subscriptions/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::types::SubscriptionSearchResult = self
             .client
             .get(
                 &url,
@@ -273,8 +330,32 @@ impl Subscriptions {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Subscription>> {
+        futures::TryStreamExt::try_collect(self.get_all_search_stream(expand, query)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Search for subscriptions you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subscription>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -282,64 +363,30 @@ impl Subscriptions {
         let url = self
             .client
             .url(&format!("/v1/subscriptions/search?{}", query_), None);
-        let mut resp: crate::types::SearchResult = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::SubscriptionSearchResult = self
                     .client
                     .get(
-                        &format!("{}?startng_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            data.append(&mut resp.data);
+                // Search results hand us the cursor for the next page.
+                let next = if resp.has_more && !resp.next_page.is_empty() {
+                    Some(crate::utils::add_query_param(&url, "page", &resp.next_page))
+                } else {
+                    None
+                };
 
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `GET` to the `/v1/subscriptions/{subscription_exposed_id}` endpoint.
@@ -353,12 +400,19 @@ impl Subscriptions {
      */
     pub async fn get_exposed(
         &self,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::Subscription> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscriptions/{}",
+                "/v1/subscriptions/{}?{}",
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
@@ -384,6 +438,7 @@ impl Subscriptions {
     pub async fn post_exposed(
         &self,
         subscription_exposed_id: &str,
+        body: &crate::types::PostSubscriptionsSubscriptionExposedIdRequest,
     ) -> ClientResult<crate::types::Subscription> {
         let url = self.client.url(
             &format!(
@@ -396,7 +451,7 @@ impl Subscriptions {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -19,7 +19,7 @@ impl TaxRates {
      * **Parameters:**
      *
      * * `active: bool` -- Optional flag to filter by tax rates that are either active or inactive (archived).
-     * * `created: &str` -- Optional range for filtering created date.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- Optional range for filtering created date.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `inclusive: bool` -- Optional flag to filter by tax rates that are inclusive (or those that are not inclusive).
@@ -29,8 +29,9 @@ impl TaxRates {
     pub async fn get_page(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         inclusive: bool,
         limit: i64,
         starting_after: &str,
@@ -39,9 +40,15 @@ impl TaxRates {
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
@@ -77,76 +84,72 @@ impl TaxRates {
     pub async fn get_all(
         &self,
         active: bool,
-        _created: &str,
+        created: Option<crate::types::RangeQueryAnyOf>,
         inclusive: bool,
     ) -> ClientResult<Vec<crate::types::TaxRate>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(active, created, inclusive)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of your tax rates. Tax rates are returned sorted by creation date, with the most recently created tax rates appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        inclusive: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TaxRate>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
         if inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/tax_rates?{}", query_), None);
-        let mut resp: crate::types::GetTaxRatesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetTaxRatesResponse = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/tax_rates` endpoint.
@@ -175,11 +178,21 @@ impl TaxRates {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `tax_rate: &str` -- The account's country.
      */
-    pub async fn get_rate(&self, tax_rate: &str) -> ClientResult<crate::types::TaxRate> {
+    pub async fn get_rate(
+        &self,
+        expand: &[String],
+        tax_rate: &str,
+    ) -> ClientResult<crate::types::TaxRate> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/tax_rates/{}",
+                "/v1/tax_rates/{}?{}",
                 crate::progenitor_support::encode_path(tax_rate),
+                query_
             ),
             None,
         );
@@ -45,6 +45,7 @@ impl Terminal {
     pub async fn get_locations(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TerminalLocation>> {
@@ -52,6 +53,9 @@ impl Terminal {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -83,66 +87,60 @@ impl Terminal {
      *
      * <p>Returns a list of <code>Location</code> objects.</p>
      */
-    pub async fn get_all_locations(&self) -> ClientResult<Vec<crate::types::TerminalLocation>> {
-        let url = self.client.url("/v1/terminal/locations", None);
-        let mut resp: crate::types::TerminalLocationList = self
+    pub async fn get_all_locations(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::TerminalLocation>> {
+        futures::TryStreamExt::try_collect(self.get_all_locations_stream(expand)).await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_all_locations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of <code>Location</code> objects.</p>
+     */
+    pub fn get_all_locations_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TerminalLocation>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+            .url(&format!("/v1/terminal/locations?{}", query_), None);
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::TerminalLocationList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/terminal/locations` endpoint.
@@ -174,12 +172,19 @@ impl Terminal {
      */
     pub async fn get_locations_location(
         &self,
+        expand: &[String],
         location: &str,
     ) -> ClientResult<crate::types::GetTerminalLocationResponseAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/terminal/locations/{}",
+                "/v1/terminal/locations/{}?{}",
                 crate::progenitor_support::encode_path(location),
+                query_
             ),
             None,
         );
@@ -272,6 +277,7 @@ impl Terminal {
         &self,
         device_type: crate::types::DeviceType,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         location: &str,
         starting_after: &str,
@@ -284,6 +290,9 @@ impl Terminal {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -324,13 +333,39 @@ impl Terminal {
     pub async fn get_all_readers(
         &self,
         device_type: crate::types::DeviceType,
+        expand: &[String],
         location: &str,
         status: crate::types::CustomerAcceptanceType,
     ) -> ClientResult<Vec<crate::types::TerminalReader>> {
+        futures::TryStreamExt::try_collect(self.get_all_readers_stream(
+            device_type,
+            expand,
+            location,
+            status,
+        ))
+        .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_all_readers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of <code>Reader</code> objects.</p>
+     */
+    pub fn get_all_readers_stream(
+        &self,
+        device_type: crate::types::DeviceType,
+        expand: &[String],
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TerminalReader>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !device_type.to_string().is_empty() {
             query_args.push(("device_type".to_string(), device_type.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !location.is_empty() {
             query_args.push(("location".to_string(), location.to_string()));
         }
@@ -341,64 +376,35 @@ impl Terminal {
         let url = self
             .client
             .url(&format!("/v1/terminal/readers?{}", query_), None);
-        let mut resp: crate::types::TerminalReaderRetrieve = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::TerminalReaderRetrieve = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        })
     }
     /**
      * This function performs a `POST` to the `/v1/terminal/readers` endpoint.
@@ -429,12 +435,19 @@ impl Terminal {
      */
     pub async fn get_readers_reader(
         &self,
+        expand: &[String],
         reader: &str,
     ) -> ClientResult<crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/terminal/readers/{}",
+                "/v1/terminal/readers/{}?{}",
                 crate::progenitor_support::encode_path(reader),
+                query_
             ),
             None,
         );
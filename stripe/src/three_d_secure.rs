@@ -40,12 +40,19 @@ impl ThreeDSecure {
      */
     pub async fn get_3d_secure_three_d(
         &self,
+        expand: &[String],
         three_d_secure: &str,
     ) -> ClientResult<crate::types::ThreeDSecure> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/3d_secure/{}",
+                "/v1/3d_secure/{}?{}",
                 crate::progenitor_support::encode_path(three_d_secure),
+                query_
             ),
             None,
         );
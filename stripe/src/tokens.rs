@@ -39,11 +39,17 @@ impl Tokens {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `token: &str` -- The account's country.
      */
-    pub async fn get(&self, token: &str) -> ClientResult<crate::types::Token> {
+    pub async fn get(&self, expand: &[String], token: &str) -> ClientResult<crate::types::Token> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/tokens/{}",
+                "/v1/tokens/{}?{}",
                 crate::progenitor_support::encode_path(token),
+                query_
             ),
             None,
         );
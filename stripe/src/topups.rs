@@ -18,8 +18,8 @@ impl Topups {
      *
      * **Parameters:**
      *
-     * * `amount: &str` -- A positive integer representing how much to transfer.
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+     * * `amount: Option<crate::types::RangeQueryAnyOf>` -- A positive integer representing how much to transfer.
+     * * `created: Option<crate::types::RangeQueryAnyOf>` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -28,17 +28,27 @@ impl Topups {
      */
     pub async fn get_page(
         &self,
-        _amount: &str,
-        _created: &str,
+        amount: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GetTopupsStatus,
     ) -> ClientResult<Vec<crate::types::Topup>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &amount {
+            query_args.extend(crate::utils::deep_object_query_args("amount", v)?);
+        }
+        if let Some(v) = &created {
+            query_args.extend(crate::utils::deep_object_query_args("created", v)?);
+        }
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -73,74 +83,86 @@ impl Topups {
      */
     pub async fn get_all(
         &self,
-        _amount: &str,
-        _created: &str,
+        amount: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
         status: crate::types::GetTopupsStatus,
     ) -> ClientResult<Vec<crate::types::Topup>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(amount, created, expand, status))
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * <p>Returns a list of top-ups.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        amount: Option<crate::types::RangeQueryAnyOf>,
+        created: Option<crate::types::RangeQueryAnyOf>,
+        expand: &[String],
+        status: crate::types::GetTopupsStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Topup>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = &amount {
+            match crate::utils::deep_object_query_args("amount", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        if let Some(v) = &created {
+            match crate::utils::deep_object_query_args("created", v) {
+                Ok(args) => query_args.extend(args),
+                Err(e) => {
+                    return futures::future::Either::Left(futures::stream::once(
+                        futures::future::ready(Err(e)),
+                    ))
+                }
+            }
+        }
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/topups?{}", query_), None);
-        let mut resp: crate::types::TopupList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        futures::future::Either::Right(crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::TopupList = self
                     .client
                     .get(
-                        &format!("{}&starting_after={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            data.append(&mut resp.data);
 
-            has_more = resp.has_more;
-        }
+                // Stripe pages by the id of the last item we were given.
+                let mut next = None;
+                if resp.has_more {
+                    if let Some(serde_json::Value::Object(o)) =
+                        resp.data.last().map(|last| serde_json::json!(last))
+                    {
+                        if let Some(serde_json::Value::String(s)) = o.get("id") {
+                            next = Some(crate::utils::add_query_param(&url, "starting_after", s));
+                        }
+                    }
+                }
 
-        // Return our response data.
-        Ok(data.to_vec())
+                Ok((resp.data, next))
+            }
+        }))
     }
     /**
      * This function performs a `POST` to the `/v1/topups` endpoint.
@@ -169,11 +191,17 @@ impl Topups {
      * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `topup: &str` -- The account's country.
      */
-    pub async fn get(&self, topup: &str) -> ClientResult<crate::types::Topup> {
+    pub async fn get(&self, expand: &[String], topup: &str) -> ClientResult<crate::types::Topup> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for v in expand {
+            query_args.push(("expand[]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/topups/{}",
+                "/v1/topups/{}?{}",
                 crate::progenitor_support::encode_path(topup),
+                query_
             ),
             None,
         );
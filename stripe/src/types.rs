@@ -414,10 +414,10 @@ pub struct Account {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -547,13 +547,13 @@ pub struct AccountBrandingSettings {
     /**
      *
      */
-    #[serde()]
-    pub icon: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub logo: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -1913,8 +1913,8 @@ pub struct AlipayAccount {
     /**
      *
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * The account's country.
      */
@@ -1946,10 +1946,10 @@ pub struct AlipayAccount {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -2167,8 +2167,8 @@ pub struct ApiErrors {
     /**
      *
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntent>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntent>>,
     /**
      *
      */
@@ -2191,8 +2191,8 @@ pub struct ApiErrors {
     /**
      *
      */
-    #[serde()]
-    pub source: Box<Option<SourceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Box<SourceAnyOf>>,
     /**
      * The type of error returned. One of `api_error`, `card_error`, `idempotency_error`, or `invalid_request_error`
      */
@@ -2650,8 +2650,8 @@ pub struct PlatformFee {
     /**
      *
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     #[serde()]
     pub charge: Box<ChargeAnyOf>,
     /**
@@ -2697,8 +2697,8 @@ pub struct PlatformFee {
     /**
      *
      */
-    #[serde()]
-    pub originating_transaction: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub originating_transaction: Option<Box<ChargeAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -3551,8 +3551,8 @@ pub struct BalanceTransaction {
      *  
      *  Related guide: [Balance Transaction Types](https://stripe.com/docs/reports/balance-transaction-types).
      */
-    #[serde()]
-    pub source: Box<Option<BalanceTransactionSourceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Box<BalanceTransactionSourceAnyOf>>,
     /**
      * The account's country.
      */
@@ -3661,8 +3661,8 @@ pub struct BankAccount {
      *  
      *  Related guide: [Bank Debits and Transfers](https://stripe.com/docs/payments/bank-debits-transfers).
      */
-    #[serde()]
-    pub account: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<Box<AccountAnyOf>>,
     /**
      * These bank accounts are payment methods on `Customer` objects.
      *  
@@ -3759,8 +3759,8 @@ pub struct BankAccount {
      *  
      *  Related guide: [Bank Debits and Transfers](https://stripe.com/docs/payments/bank-debits-transfers).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * These bank accounts are payment methods on `Customer` objects.
      *  
@@ -3820,10 +3820,10 @@ pub struct BankAccount {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -4005,10 +4005,10 @@ pub struct PortalConfiguration {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -4597,10 +4597,10 @@ pub struct BitcoinReceiver {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -4995,8 +4995,8 @@ pub struct Card {
      *  
      *  Related guide: [Card Payments with Sources](https://stripe.com/docs/sources/cards).
      */
-    #[serde()]
-    pub account: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<Box<AccountAnyOf>>,
     /**
      * You can store multiple cards on a customer in order to charge the customer
      *  later. You can also store multiple debit cards on a recipient in order to
@@ -5155,8 +5155,8 @@ pub struct Card {
      *  
      *  Related guide: [Card Payments with Sources](https://stripe.com/docs/sources/cards).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * You can store multiple cards on a customer in order to charge the customer
      *  later. You can also store multiple debit cards on a recipient in order to
@@ -5264,10 +5264,10 @@ pub struct Card {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can store multiple cards on a customer in order to charge the customer
      *  later. You can also store multiple debit cards on a recipient in order to
@@ -5293,8 +5293,8 @@ pub struct Card {
      *  
      *  Related guide: [Card Payments with Sources](https://stripe.com/docs/sources/cards).
      */
-    #[serde()]
-    pub recipient: Box<Option<RecipientAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<Box<RecipientAnyOf>>,
     /**
      * You can store multiple cards on a customer in order to charge the customer
      *  later. You can also store multiple debit cards on a recipient in order to
@@ -5877,8 +5877,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub application: Box<Option<ApplicationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<Box<ApplicationAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -5886,8 +5886,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub application_fee: Box<Option<FeeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_fee: Option<Box<FeeAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -5908,8 +5908,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      *
      */
@@ -5961,8 +5961,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -5991,8 +5991,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub failure_balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -6040,8 +6040,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub invoice: Box<Option<InvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<InvoiceAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -6055,10 +6055,10 @@ pub struct Charge {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -6071,8 +6071,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -6080,8 +6080,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub order: Box<Option<OrderAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<Box<OrderAnyOf>>,
     /**
      * Details about whether the payment was accepted, and why. See [understanding declines](https://stripe.com/docs/declines) for details.
      */
@@ -6102,8 +6102,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -6181,8 +6181,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub review: Box<Option<ReviewAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<Box<ReviewAnyOf>>,
     /**
      * Shipping information for the charge.
      */
@@ -6195,8 +6195,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub source_transfer: Box<Option<TransferAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_transfer: Option<Box<TransferAnyOf>>,
     /**
      * To charge a credit or a debit card, you create a `Charge` object. You can
      *  retrieve and refund individual charges as well as list all charges. Charges
@@ -6235,8 +6235,8 @@ pub struct Charge {
      *  
      *  Related guide: [Accept a payment with the Charges API](https://stripe.com/docs/payments/accept-a-payment-charges).
      */
-    #[serde()]
-    pub transfer: Box<Option<TransferAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<Box<TransferAnyOf>>,
     /**
      * An optional dictionary including the account to automatically transfer to as part of a destination charge. [See the Connect documentation](https://stripe.com/docs/connect/destination-charges) for details.
      */
@@ -6370,8 +6370,8 @@ pub struct ChargeOutcome {
     /**
      *
      */
-    #[serde()]
-    pub rule: Box<Option<RuleAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<Box<RuleAnyOf>>,
     /**
      *
      */
@@ -7277,8 +7277,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * Configure whether a Checkout Session creates a Customer when the Checkout Session completes.
      */
@@ -7378,10 +7378,10 @@ pub struct Session {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The mode of the Checkout Session.
      */
@@ -7408,8 +7408,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      * A Checkout Session represents your customer's session as they pay for
      *  one-time purchases or subscriptions through [Checkout](https://stripe.com/docs/payments/checkout)
@@ -7426,8 +7426,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub payment_link: Box<Option<PaymentLinkAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_link: Option<Box<PaymentLinkAnyOf>>,
     /**
      * Payment-method-specific configuration for the PaymentIntent or SetupIntent of this CheckoutSession.
      */
@@ -7504,8 +7504,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub setup_intent: Box<Option<SetupIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_intent: Option<Box<SetupIntentAnyOf>>,
     /**
      * Shipping information for the charge.
      */
@@ -7541,8 +7541,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub shipping_rate: Box<Option<ShippingRateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<Box<ShippingRateAnyOf>>,
     /**
      * The status of the Checkout Session, one of `open`, `complete`, or `expired`.
      */
@@ -7572,8 +7572,8 @@ pub struct Session {
      *  
      *  Related guide: [Checkout Server Quickstart](https://stripe.com/docs/payments/checkout/api).
      */
-    #[serde()]
-    pub subscription: Box<Option<SubscriptionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<SubscriptionAnyOf>>,
     /**
      * The account's country.
      */
@@ -8398,10 +8398,10 @@ pub struct Coupon {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * A coupon contains information about a percent-off or amount-off discount you
      *  might want to apply to a customer. Coupons may be applied to [invoices](https://stripe.com/docs/api#invoices) or
@@ -8806,8 +8806,8 @@ pub struct CreditNote {
      *  
      *  Related guide: [Credit Notes](https://stripe.com/docs/billing/invoices/credit-notes).
      */
-    #[serde()]
-    pub customer_balance_transaction: Box<Option<CustomerBalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_balance_transaction: Option<Box<CustomerBalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -8868,10 +8868,10 @@ pub struct CreditNote {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -8916,8 +8916,8 @@ pub struct CreditNote {
      *  
      *  Related guide: [Credit Notes](https://stripe.com/docs/billing/invoices/credit-notes).
      */
-    #[serde()]
-    pub refund: Box<Option<RefundAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund: Option<Box<RefundAnyOf>>,
     /**
      * Status of this credit note, one of `issued` or `void`. Learn more about [voiding credit notes](https://stripe.com/docs/billing/invoices/credit-notes#voiding).
      */
@@ -9719,8 +9719,8 @@ pub struct Customer {
      *  
      *  Related guide: [Save a card during payment](https://stripe.com/docs/payments/save-during-payment).
      */
-    #[serde()]
-    pub default_source: Box<Option<DefaultSourceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<Box<DefaultSourceAnyOf>>,
     /**
      * This object represents a customer of your business. It lets you create recurring charges and track payments that belong to the same customer.
      *  
@@ -9800,10 +9800,10 @@ pub struct Customer {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * This object represents a customer of your business. It lets you create recurring charges and track payments that belong to the same customer.
      *  
@@ -9896,8 +9896,8 @@ pub struct Customer {
      *  
      *  Related guide: [Save a card during payment](https://stripe.com/docs/payments/save-during-payment).
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
 }
 
 /**
@@ -10201,8 +10201,8 @@ pub struct CustomerBalanceTransaction {
      *  
      *  Related guide: [Customer Balance](https://stripe.com/docs/billing/customer/balance) to learn more.
      */
-    #[serde()]
-    pub credit_note: Box<Option<CreditNoteAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credit_note: Option<Box<CreditNoteAnyOf>>,
     /**
      * The account's country.
      */
@@ -10254,8 +10254,8 @@ pub struct CustomerBalanceTransaction {
      *  
      *  Related guide: [Customer Balance](https://stripe.com/docs/billing/customer/balance) to learn more.
      */
-    #[serde()]
-    pub invoice: Box<Option<InvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<InvoiceAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -10274,10 +10274,10 @@ pub struct CustomerBalanceTransaction {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -10777,8 +10777,8 @@ pub struct DeletedDiscount {
     /**
      *
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -10822,8 +10822,8 @@ pub struct DeletedDiscount {
     /**
      *
      */
-    #[serde()]
-    pub promotion_code: Box<Option<PromotionCodeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<Box<PromotionCodeAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -12014,8 +12014,8 @@ pub struct DiscountData {
      *  
      *  Related guide: [Applying Discounts to Subscriptions](https://stripe.com/docs/billing/subscriptions/discounts).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A discount represents the actual application of a coupon to a particular
      *  customer. It contains information about when the discount began and when it
@@ -12076,8 +12076,8 @@ pub struct DiscountData {
      *  
      *  Related guide: [Applying Discounts to Subscriptions](https://stripe.com/docs/billing/subscriptions/discounts).
      */
-    #[serde()]
-    pub promotion_code: Box<Option<PromotionCodeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<Box<PromotionCodeAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -12360,10 +12360,10 @@ pub struct Dispute {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -12378,8 +12378,8 @@ pub struct Dispute {
      *  
      *  Related guide: [Disputes and Fraud](https://stripe.com/docs/disputes).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      * The account's country.
      */
@@ -12420,8 +12420,8 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub cancellation_policy: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancellation_policy: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12443,8 +12443,8 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub customer_communication: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_communication: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12475,13 +12475,13 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub customer_signature: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_signature: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub duplicate_charge_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_charge_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12512,13 +12512,13 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub receipt: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub refund_policy: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_policy: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12549,8 +12549,8 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub service_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12581,8 +12581,8 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub shipping_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -12595,8 +12595,8 @@ pub struct DisputeEvidence {
     /**
      *
      */
-    #[serde()]
-    pub uncategorized_file: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uncategorized_file: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -13153,8 +13153,8 @@ pub struct FeeRefund {
      *  
      *  Related guide: [Refunding Application Fees](https://stripe.com/docs/connect/destination-charges#refunding-app-fee).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -13193,10 +13193,10 @@ pub struct FeeRefund {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -13570,10 +13570,10 @@ pub struct FileLink {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -14967,8 +14967,8 @@ pub struct GelatoVerificationSession {
      *  
      *  Related guide: [The Verification Sessions API](https://stripe.com/docs/identity/verification-sessions)
      */
-    #[serde()]
-    pub last_verification_report: Box<Option<LastVerificationReportAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_verification_report: Option<Box<LastVerificationReportAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -14982,10 +14982,10 @@ pub struct GelatoVerificationSession {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -15680,8 +15680,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub charge: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge: Option<Box<ChargeAnyOf>>,
     /**
      * Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
      */
@@ -15748,8 +15748,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A publicly available mailing address for sending support issues to.
      */
@@ -15928,8 +15928,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub default_payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -15964,8 +15964,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub default_source: Box<Option<DefaultSourceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<Box<DefaultSourceAnyOf>>,
     /**
      * The tax rates which apply to the line item.
      */
@@ -16268,8 +16268,8 @@ pub struct Invoice {
     /**
      * The error encountered during the previous attempt to finalize the invoice. This field is cleared when the invoice is successfully finalized.
      */
-    #[serde()]
-    pub last_finalization_error: Box<Option<ApiErrors>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_finalization_error: Option<Box<ApiErrors>>,
     /**
      * The individual line items that make up the invoice. `lines` is sorted as follows: invoice items in reverse chronological order, followed by the subscription, if any.
      */
@@ -16319,10 +16319,10 @@ pub struct Invoice {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -16442,8 +16442,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -16494,8 +16494,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      *
      */
@@ -16571,8 +16571,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub quote: Box<Option<QuoteAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quote: Option<Box<QuoteAnyOf>>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -16706,8 +16706,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub subscription: Box<Option<SubscriptionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<SubscriptionAnyOf>>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -16831,8 +16831,8 @@ pub struct Invoice {
      *  
      *  Related guide: [Send Invoices to Customers](https://stripe.com/docs/billing/invoices/sending).
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
     /**
      * Invoices are statements of amounts owed by a customer, and are either
      *  generated one-off, or generated periodically from a subscription.
@@ -17326,8 +17326,8 @@ pub struct InvoiceSettingCustomer {
     /**
      *
      */
-    #[serde()]
-    pub default_payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
@@ -17542,8 +17542,8 @@ pub struct InvoiceItem {
      *  
      *  Related guide: [Subscription Invoices](https://stripe.com/docs/billing/invoices/subscription#adding-upcoming-invoice-items).
      */
-    #[serde()]
-    pub invoice: Box<Option<InvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<InvoiceAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -17563,10 +17563,10 @@ pub struct InvoiceItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -17608,8 +17608,8 @@ pub struct InvoiceItem {
      *  
      *  Related guide: [Subscription Invoices](https://stripe.com/docs/billing/invoices/subscription#adding-upcoming-invoice-items).
      */
-    #[serde()]
-    pub subscription: Box<Option<SubscriptionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<SubscriptionAnyOf>>,
     /**
      * Sometimes you want to add a charge or credit to a customer, but actually
      *  charge or credit the customer's card only at the end of a regular billing
@@ -17649,8 +17649,8 @@ pub struct InvoiceItem {
      *  
      *  Related guide: [Subscription Invoices](https://stripe.com/docs/billing/invoices/subscription#adding-upcoming-invoice-items).
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
     /**
      * Sometimes you want to add a charge or credit to a customer, but actually
      *  charge or credit the customer's card only at the end of a regular billing
@@ -18421,8 +18421,8 @@ pub struct IssuingAuthorization {
      *  
      *  Related guide: [Issued Card Authorizations](https://stripe.com/docs/issuing/purchases/authorizations).
      */
-    #[serde()]
-    pub cardholder: Box<Option<CardholderAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<Box<CardholderAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -18486,10 +18486,10 @@ pub struct IssuingAuthorization {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -18888,10 +18888,10 @@ pub struct IssuingCard {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can [create physical or virtual cards](https://stripe.com/docs/issuing/cards) that are issued to cardholders.
      */
@@ -18909,13 +18909,13 @@ pub struct IssuingCard {
     /**
      * You can [create physical or virtual cards](https://stripe.com/docs/issuing/cards) that are issued to cardholders.
      */
-    #[serde()]
-    pub replaced_by: Box<Option<CardAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<Box<CardAnyOf>>,
     /**
      * You can [create physical or virtual cards](https://stripe.com/docs/issuing/cards) that are issued to cardholders.
      */
-    #[serde()]
-    pub replacement_for: Box<Option<CardAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<Box<CardAnyOf>>,
     /**
      * The reason why the previous card needed to be replaced.
      */
@@ -19128,10 +19128,10 @@ pub struct IssuingCardholder {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -19387,10 +19387,10 @@ pub struct IssuingDispute {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -19545,10 +19545,10 @@ pub struct IssuingSettlement {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -19859,8 +19859,8 @@ pub struct IssuingTransaction {
      *  
      *  Related guide: [Issued Card Transactions](https://stripe.com/docs/issuing/purchases/transactions).
      */
-    #[serde()]
-    pub authorization: Box<Option<AuthorizationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<Box<AuthorizationAnyOf>>,
     /**
      * Any use of an [issued card](https://stripe.com/docs/issuing) that results in funds entering or leaving
      *  your Stripe account, such as a completed purchase or refund, is represented by an Issuing
@@ -19868,8 +19868,8 @@ pub struct IssuingTransaction {
      *  
      *  Related guide: [Issued Card Transactions](https://stripe.com/docs/issuing/purchases/transactions).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     #[serde()]
     pub card: Box<CardAnyOf>,
     /**
@@ -19879,8 +19879,8 @@ pub struct IssuingTransaction {
      *  
      *  Related guide: [Issued Card Transactions](https://stripe.com/docs/issuing/purchases/transactions).
      */
-    #[serde()]
-    pub cardholder: Box<Option<CardholderAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<Box<CardholderAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -19906,8 +19906,8 @@ pub struct IssuingTransaction {
      *  
      *  Related guide: [Issued Card Transactions](https://stripe.com/docs/issuing/purchases/transactions).
      */
-    #[serde()]
-    pub dispute: Box<Option<DisputeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dispute: Option<Box<DisputeAnyOf>>,
     /**
      * The account's country.
      */
@@ -19953,10 +19953,10 @@ pub struct IssuingTransaction {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -21812,13 +21812,13 @@ pub struct IssuingCardholderDocument {
     /**
      *
      */
-    #[serde()]
-    pub back: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub front: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front: Option<Box<IconAnyOf>>,
 }
 
 ///
@@ -22088,8 +22088,8 @@ pub struct IssuingDisputeCanceledEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22170,23 +22170,23 @@ pub struct IssuingDisputeDuplicateEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub card_statement: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_statement: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub cash_receipt: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_receipt: Option<Box<IconAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub check_image: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_image: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22313,8 +22313,8 @@ pub struct IssuingDisputeFraudulentEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22332,8 +22332,8 @@ pub struct IssuingDisputeMerchandiseNotAsDescribedEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22383,8 +22383,8 @@ pub struct IssuingDisputeNotReceivedEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22425,8 +22425,8 @@ pub struct IssuingDisputeOtherEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -22458,8 +22458,8 @@ pub struct IssuingDisputeServiceNotAsDescribedEvidence {
     /**
      *
      */
-    #[serde()]
-    pub additional_documentation: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub additional_documentation: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -23115,8 +23115,8 @@ pub struct LegalEntityCompanyVerificationDocument {
     /**
      *
      */
-    #[serde()]
-    pub back: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -23138,8 +23138,8 @@ pub struct LegalEntityCompanyVerificationDocument {
     /**
      *
      */
-    #[serde()]
-    pub front: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front: Option<Box<IconAnyOf>>,
 }
 
 ///
@@ -23292,8 +23292,8 @@ pub struct LegalEntityPersonVerificationDocument {
     /**
      *
      */
-    #[serde()]
-    pub back: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub back: Option<Box<IconAnyOf>>,
     /**
      *
      */
@@ -23315,8 +23315,8 @@ pub struct LegalEntityPersonVerificationDocument {
     /**
      *
      */
-    #[serde()]
-    pub front: Box<Option<IconAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front: Option<Box<IconAnyOf>>,
 }
 
 ///
@@ -23507,10 +23507,10 @@ pub struct LineItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -24261,8 +24261,8 @@ pub struct Order {
      *  
      *  Related guide: [Tax, Shipping, and Inventory](https://stripe.com/docs/orders-legacy).
      */
-    #[serde()]
-    pub charge: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge: Option<Box<ChargeAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -24288,8 +24288,8 @@ pub struct Order {
      *  
      *  Related guide: [Tax, Shipping, and Inventory](https://stripe.com/docs/orders-legacy).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * Order objects are created to handle end customers' purchases of previously
      *  defined [products](https://stripe.com/docs/api#products). You can create, retrieve, and pay individual orders, as well
@@ -24351,10 +24351,10 @@ pub struct Order {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -24566,8 +24566,8 @@ pub struct OrderItem {
      *  
      *  Related guide: [Orders](https://stripe.com/docs/orders/guide).
      */
-    #[serde()]
-    pub parent: Box<Option<ParentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<ParentAnyOf>>,
     /**
      * A representation of the constituent items of any given order. Can be used to
      *  represent [SKUs](https://stripe.com/docs/api#skus), shipping costs, or taxes owed on the order.
@@ -24697,16 +24697,16 @@ pub struct OrderReturn {
      *  
      *  Related guide: [Handling Returns](https://stripe.com/docs/orders/guide#handling-returns).
      */
-    #[serde()]
-    pub order: Box<Option<OrderAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<Box<OrderAnyOf>>,
     /**
      * A return represents the full or partial return of a number of [order items](https://stripe.com/docs/api#order_items).
      *  Returns always belong to an order, and may optionally contain a refund.
      *  
      *  Related guide: [Handling Returns](https://stripe.com/docs/orders/guide#handling-returns).
      */
-    #[serde()]
-    pub refund: Box<Option<RefundAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund: Option<Box<RefundAnyOf>>,
 }
 
 ///
@@ -25157,8 +25157,8 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub application: Box<Option<ApplicationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<Box<ApplicationAnyOf>>,
     /**
      * A PaymentIntent guides you through the process of collecting a payment from your customer.
      *  We recommend that you create exactly one PaymentIntent for each order or
@@ -25282,8 +25282,8 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A PaymentIntent guides you through the process of collecting a payment from your customer.
      *  We recommend that you create exactly one PaymentIntent for each order or
@@ -25325,13 +25325,13 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub invoice: Box<Option<InvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<InvoiceAnyOf>>,
     /**
      * The error encountered during the previous attempt to finalize the invoice. This field is cleared when the invoice is successfully finalized.
      */
-    #[serde()]
-    pub last_payment_error: Box<Option<ApiErrors>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_payment_error: Option<Box<ApiErrors>>,
     /**
      * Whether the account can create live charges.
      */
@@ -25355,10 +25355,10 @@ pub struct PaymentIntent {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * If present, this property tells you what actions you need to take in order for your customer to fulfill a payment using the provided source.
      */
@@ -25382,8 +25382,8 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * A PaymentIntent guides you through the process of collecting a payment from your customer.
      *  We recommend that you create exactly one PaymentIntent for each order or
@@ -25397,8 +25397,8 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * Payment-method-specific configuration for this PaymentIntent.
      */
@@ -25450,8 +25450,8 @@ pub struct PaymentIntent {
      *  
      *  Related guide: [Payment Intents API](https://stripe.com/docs/payments/payment-intents).
      */
-    #[serde()]
-    pub review: Box<Option<ReviewAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<Box<ReviewAnyOf>>,
     /**
      * Indicates that you intend to make future payments with this PaymentIntent's payment method.
      *  
@@ -27077,123 +27077,123 @@ pub struct PaymentIntentMethodOptionsData {
     /**
      *
      */
-    #[serde()]
-    pub acss_debit: Box<Option<AcssDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<Box<AcssDebitAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub afterpay_clearpay: Box<Option<AfterpayClearpayAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub afterpay_clearpay: Option<Box<AfterpayClearpayAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub alipay: Box<Option<AlipayAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alipay: Option<Box<AlipayAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub au_becs_debit: Box<Option<AuBecsDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub au_becs_debit: Option<Box<AuBecsDebitAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub bacs_debit: Box<Option<BacsDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bacs_debit: Option<Box<BacsDebitAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub bancontact: Box<Option<BancontactAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<Box<BancontactAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub boleto: Box<Option<BoletoAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boleto: Option<Box<BoletoAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub card: Box<Option<PaymentIntentMethodOptionsCardAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card: Option<Box<PaymentIntentMethodOptionsCardAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub card_present: Box<Option<CardPresentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_present: Option<Box<CardPresentAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub eps: Box<Option<EpsAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eps: Option<Box<EpsAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub fpx: Box<Option<FpxAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fpx: Option<Box<FpxAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub giropay: Box<Option<GiropayAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub giropay: Option<Box<GiropayAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub grabpay: Box<Option<GrabpayAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grabpay: Option<Box<GrabpayAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub ideal: Box<Option<IdealAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<Box<IdealAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub interac_present: Box<Option<InteracPresentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interac_present: Option<Box<InteracPresentAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub klarna: Box<Option<KlarnaAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub klarna: Option<Box<KlarnaAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub konbini: Box<Option<KonbiniAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub konbini: Option<Box<KonbiniAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub oxxo: Box<Option<OxxoAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oxxo: Option<Box<OxxoAnyOf>>,
     /**
      *
      */
-    #[serde(rename = "p24")]
-    pub p_24: Box<Option<P24AnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "p24")]
+    pub p_24: Option<Box<P24AnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub paynow: Box<Option<PaynowAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paynow: Option<Box<PaynowAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub sepa_debit: Box<Option<SepaDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<Box<SepaDebitAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub sofort: Box<Option<SofortAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<Box<SofortAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub us_bank_account: Box<Option<UsBankAccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<Box<UsBankAccountAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub wechat_pay: Box<Option<WechatPayAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wechat_pay: Option<Box<WechatPayAnyOf>>,
 }
 
 /**
@@ -27747,10 +27747,10 @@ pub struct PaymentLink {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -27763,8 +27763,8 @@ pub struct PaymentLink {
      *  
      *  Related guide: [Payment Links API](https://stripe.com/docs/payments/payment-links/api)
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * The list of payment method types that customers can use. When `null`, Stripe will dynamically show relevant payment methods you've enabled in your [payment method settings](https://dashboard.stripe.com/settings/payment_methods).
      */
@@ -29013,10 +29013,10 @@ pub struct PaymentMethod {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -29425,8 +29425,8 @@ pub struct PaymentMethodCardGenerated {
     /**
      *
      */
-    #[serde()]
-    pub setup_attempt: Box<Option<SetupAttemptAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_attempt: Option<Box<SetupAttemptAnyOf>>,
 }
 
 /**
@@ -30095,13 +30095,13 @@ pub struct PaymentMethodDetailsBancontact {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -31273,13 +31273,13 @@ pub struct PaymentMethodDetailsIdeal {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -31980,13 +31980,13 @@ pub struct PaymentMethodDetailsSofort {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -33527,8 +33527,8 @@ pub struct Payout {
      *  
      *  Related guide: [Receiving Payouts](https://stripe.com/docs/payouts).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -33573,8 +33573,8 @@ pub struct Payout {
      *  
      *  Related guide: [Receiving Payouts](https://stripe.com/docs/payouts).
      */
-    #[serde()]
-    pub destination: Box<Option<DestinationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination: Option<Box<DestinationAnyOf>>,
     /**
      * A `Payout` object is created when you receive funds from Stripe, or when you
      *  initiate a payout to either a bank account or debit card of a [connected
@@ -33585,8 +33585,8 @@ pub struct Payout {
      *  
      *  Related guide: [Receiving Payouts](https://stripe.com/docs/payouts).
      */
-    #[serde()]
-    pub failure_balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * A `Payout` object is created when you receive funds from Stripe, or when you
      *  initiate a payout to either a bank account or debit card of a [connected
@@ -33648,10 +33648,10 @@ pub struct Payout {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -33676,8 +33676,8 @@ pub struct Payout {
      *  
      *  Related guide: [Receiving Payouts](https://stripe.com/docs/payouts).
      */
-    #[serde()]
-    pub original_payout: Box<Option<ReversedByAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_payout: Option<Box<ReversedByAnyOf>>,
     /**
      * A `Payout` object is created when you receive funds from Stripe, or when you
      *  initiate a payout to either a bank account or debit card of a [connected
@@ -33688,8 +33688,8 @@ pub struct Payout {
      *  
      *  Related guide: [Receiving Payouts](https://stripe.com/docs/payouts).
      */
-    #[serde()]
-    pub reversed_by: Box<Option<ReversedByAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reversed_by: Option<Box<ReversedByAnyOf>>,
     /**
      * The account's country.
      */
@@ -34022,10 +34022,10 @@ pub struct Person {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * This is an object representing a person associated with a Stripe account.
      *  
@@ -34603,10 +34603,10 @@ pub struct PlanData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * You can now model subscriptions more flexibly using the [Prices API](https://stripe.com/docs/api#prices). It replaces the Plans API and is backwards compatible to simplify your migration.
      *  
@@ -34638,8 +34638,8 @@ pub struct PlanData {
      *  
      *  Related guides: [Set up a subscription](https://stripe.com/docs/billing/subscriptions/set-up-subscription) and more about [products and prices](https://stripe.com/docs/products-prices/overview).
      */
-    #[serde()]
-    pub product: Box<Option<ProductAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub product: Option<Box<ProductAnyOf>>,
     /**
      * You can now model subscriptions more flexibly using the [Prices API](https://stripe.com/docs/api#prices). It replaces the Plans API and is backwards compatible to simplify your migration.
      *  
@@ -35387,10 +35387,10 @@ pub struct PriceData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Prices define the unit cost, currency, and (optional) billing cycle for both recurring and one-time purchases of products.
      *  [Products](https://stripe.com/docs/api#products) help you track inventory or provisioning, and prices help you track payment terms. Different physical goods or levels of service should be represented by products, and pricing options should be represented by prices. This approach lets you change prices without having to change your provisioning scheme.
@@ -35648,10 +35648,10 @@ pub struct Product {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -35712,8 +35712,8 @@ pub struct Product {
      *  [accept payments with Checkout](https://stripe.com/docs/payments/accept-a-payment#create-product-prices-upfront),
      *  and more about [Products and Prices](https://stripe.com/docs/products-prices/overview)
      */
-    #[serde()]
-    pub tax_code: Box<Option<TaxCodeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<Box<TaxCodeAnyOf>>,
     /**
      * Products describe the specific goods or services you offer to your customers.
      *  For example, you might offer a Standard and Premium version of your goods or service; each version would be a separate Product.
@@ -35833,8 +35833,8 @@ pub struct PromotionCode {
      * A Promotion Code represents a customer-redeemable code for a coupon. It can be used to
      *  create multiple codes for a single coupon.
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A Promotion Code represents a customer-redeemable code for a coupon. It can be used to
      *  create multiple codes for a single coupon.
@@ -35878,10 +35878,10 @@ pub struct PromotionCode {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -36233,8 +36233,8 @@ pub struct Quote {
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
@@ -36303,8 +36303,8 @@ pub struct Quote {
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub invoice: Box<Option<QuoteInvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<Box<QuoteInvoiceAnyOf>>,
     /**
      * All invoices will be billed using the specified settings.
      */
@@ -36329,10 +36329,10 @@ pub struct Quote {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
@@ -36352,8 +36352,8 @@ pub struct Quote {
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * The status of the quote.
      */
@@ -36368,8 +36368,8 @@ pub struct Quote {
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub subscription: Box<Option<SubscriptionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<SubscriptionAnyOf>>,
     /**
      *
      */
@@ -36379,14 +36379,14 @@ pub struct Quote {
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub subscription_schedule: Box<Option<ScheduleAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_schedule: Option<Box<ScheduleAnyOf>>,
     /**
      * A Quote is a way to model prices that you'd like to provide to a customer.
      *  Once accepted, it will automatically create an invoice, subscription or subscription schedule.
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
     /**
      *
      */
@@ -36718,8 +36718,8 @@ pub struct RadarEarlyFraudWarning {
      *  
      *  Related guide: [Early Fraud Warnings](https://stripe.com/docs/disputes/measuring#early-fraud-warnings).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
 }
 
 /**
@@ -36878,10 +36878,10 @@ pub struct RadarList {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * The account's country.
      */
@@ -37182,8 +37182,8 @@ pub struct Recipient {
      *  recipients can no longer begin doing so. Please use `Account` objects
      *  instead.\*\*
      */
-    #[serde()]
-    pub default_card: Box<Option<DefaultCardAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_card: Option<Box<DefaultCardAnyOf>>,
     /**
      * With `Recipient` objects, you can transfer money from your Stripe account to a
      *  third-party bank account or debit card. The API allows you to create, delete,
@@ -37242,10 +37242,10 @@ pub struct Recipient {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * With `Recipient` objects, you can transfer money from your Stripe account to a
      *  third-party bank account or debit card. The API allows you to create, delete,
@@ -37258,8 +37258,8 @@ pub struct Recipient {
      *  recipients can no longer begin doing so. Please use `Account` objects
      *  instead.\*\*
      */
-    #[serde()]
-    pub migrated_to: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migrated_to: Option<Box<AccountAnyOf>>,
     /**
      * With `Recipient` objects, you can transfer money from your Stripe account to a
      *  third-party bank account or debit card. The API allows you to create, delete,
@@ -37295,8 +37295,8 @@ pub struct Recipient {
      *  recipients can no longer begin doing so. Please use `Account` objects
      *  instead.\*\*
      */
-    #[serde()]
-    pub rolled_back_from: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rolled_back_from: Option<Box<AccountAnyOf>>,
     /**
      * The account's country.
      */
@@ -37500,8 +37500,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * `Refund` objects allow you to refund a charge that has previously been created
      *  but not yet refunded. Funds will be refunded to the credit or debit card that
@@ -37509,8 +37509,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub charge: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge: Option<Box<ChargeAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -37549,8 +37549,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub failure_balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * `Refund` objects allow you to refund a charge that has previously been created
      *  but not yet refunded. Funds will be refunded to the credit or debit card that
@@ -37582,10 +37582,10 @@ pub struct Refund {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * `Refund` objects allow you to refund a charge that has previously been created
      *  but not yet refunded. Funds will be refunded to the credit or debit card that
@@ -37607,8 +37607,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      * Reason for the refund, either user-provided (`duplicate`, `fraudulent`, or `requested_by_customer`) or generated by Stripe internally (`expired_uncaptured_charge`).
      */
@@ -37634,8 +37634,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub source_transfer_reversal: Box<Option<TransferReversalAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_transfer_reversal: Option<Box<TransferReversalAnyOf>>,
     /**
      * `Refund` objects allow you to refund a charge that has previously been created
      *  but not yet refunded. Funds will be refunded to the credit or debit card that
@@ -37656,8 +37656,8 @@ pub struct Refund {
      *  
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
-    #[serde()]
-    pub transfer_reversal: Box<Option<TransferReversalAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_reversal: Option<Box<TransferReversalAnyOf>>,
 }
 
 ///
@@ -38193,8 +38193,8 @@ pub struct Review {
      *  Learn more about [Radar](/radar) and reviewing payments
      *  [here](https://stripe.com/docs/radar/reviews).
      */
-    #[serde()]
-    pub charge: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge: Option<Box<ChargeAnyOf>>,
     /**
      * The reason the review was closed, or null if it has not yet been closed. One of `approved`, `refunded`, `refunded_as_fraud`, `disputed`, or `redacted`.
      */
@@ -38267,8 +38267,8 @@ pub struct Review {
      *  Learn more about [Radar](/radar) and reviewing payments
      *  [here](https://stripe.com/docs/radar/reviews).
      */
-    #[serde()]
-    pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent: Option<Box<PaymentIntentAnyOf>>,
     /**
      * The account's country.
      */
@@ -38456,13 +38456,13 @@ pub struct SepaDebitGeneratedFrom {
     /**
      *
      */
-    #[serde()]
-    pub charge: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charge: Option<Box<ChargeAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub setup_attempt: Box<Option<SetupAttemptAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_attempt: Option<Box<SetupAttemptAnyOf>>,
 }
 
 /**
@@ -38512,8 +38512,8 @@ pub struct SetupAttempt {
      *  SetupAttempts to inspect details of a specific attempt at setting up a
      *  payment method using a SetupIntent.
      */
-    #[serde()]
-    pub application: Box<Option<ApplicationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<Box<ApplicationAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -38529,8 +38529,8 @@ pub struct SetupAttempt {
      *  SetupAttempts to inspect details of a specific attempt at setting up a
      *  payment method using a SetupIntent.
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * The account's country.
      */
@@ -38559,8 +38559,8 @@ pub struct SetupAttempt {
      *  SetupAttempts to inspect details of a specific attempt at setting up a
      *  payment method using a SetupIntent.
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     #[serde()]
     pub payment_method: Box<PaymentMethodAnyOf>,
     /**
@@ -38571,8 +38571,8 @@ pub struct SetupAttempt {
     /**
      * The error encountered during the previous attempt to finalize the invoice. This field is cleared when the invoice is successfully finalized.
      */
-    #[serde()]
-    pub setup_error: Box<Option<ApiErrors>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub setup_error: Option<Box<ApiErrors>>,
     #[serde()]
     pub setup_intent: Box<SetupIntentAnyOf>,
     /**
@@ -38698,13 +38698,13 @@ pub struct SetupAttemptPaymentMethodDetailsBancontact {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -38747,8 +38747,8 @@ pub struct SetupAttemptPaymentMethodDetailsCardPresent {
     /**
      *
      */
-    #[serde()]
-    pub generated_card: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_card: Option<Box<PaymentMethodAnyOf>>,
 }
 
 ///
@@ -38767,13 +38767,13 @@ pub struct SetupAttemptPaymentMethodDetailsIdeal {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -38828,13 +38828,13 @@ pub struct SetupAttemptPaymentMethodDetailsSofort {
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit: Option<Box<PaymentMethodAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub generated_sepa_debit_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_sepa_debit_mandate: Option<Box<MandateAnyOf>>,
     /**
      *
      */
@@ -39035,8 +39035,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub application: Box<Option<ApplicationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<Box<ApplicationAnyOf>>,
     /**
      * Reason for cancellation of this SetupIntent, one of `abandoned`, `requested_by_customer`, or `duplicate`.
      */
@@ -39105,8 +39105,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub customer: Box<Option<CustomerAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Box<CustomerAnyOf>>,
     /**
      * A SetupIntent guides you through the process of setting up and saving a customer's payment credentials for future payments.
      *  For example, you could use a SetupIntent to set up and save your customer's card without immediately collecting a payment.
@@ -39149,8 +39149,8 @@ pub struct SetupIntent {
     /**
      * The error encountered during the previous attempt to finalize the invoice. This field is cleared when the invoice is successfully finalized.
      */
-    #[serde()]
-    pub last_setup_error: Box<Option<ApiErrors>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_setup_error: Option<Box<ApiErrors>>,
     /**
      * A SetupIntent guides you through the process of setting up and saving a customer's payment credentials for future payments.
      *  For example, you could use a SetupIntent to set up and save your customer's card without immediately collecting a payment.
@@ -39175,8 +39175,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub latest_attempt: Box<Option<SetupAttemptAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_attempt: Option<Box<SetupAttemptAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -39209,8 +39209,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<Box<MandateAnyOf>>,
     /**
      * A SetupIntent guides you through the process of setting up and saving a customer's payment credentials for future payments.
      *  For example, you could use a SetupIntent to set up and save your customer's card without immediately collecting a payment.
@@ -39237,10 +39237,10 @@ pub struct SetupIntent {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * If present, this property tells you what actions you need to take in order for your customer to continue payment setup.
      */
@@ -39275,8 +39275,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub on_behalf_of: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Box<AccountAnyOf>>,
     /**
      * A SetupIntent guides you through the process of setting up and saving a customer's payment credentials for future payments.
      *  For example, you could use a SetupIntent to set up and save your customer's card without immediately collecting a payment.
@@ -39301,8 +39301,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * Payment-method-specific configuration for this SetupIntent.
      */
@@ -39341,8 +39341,8 @@ pub struct SetupIntent {
      *  
      *  Related guide: [Setup Intents API](https://stripe.com/docs/payments/setup-intents).
      */
-    #[serde()]
-    pub single_use_mandate: Box<Option<MandateAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub single_use_mandate: Option<Box<MandateAnyOf>>,
     /**
      * [Status](https://stripe.com/docs/payments/intents#intent-statuses) of this SetupIntent, one of `requires_payment_method`, `requires_confirmation`, `requires_action`, `processing`, `canceled`, or `succeeded`.
      */
@@ -39544,8 +39544,8 @@ pub struct SetupIntentPaymentMethodOptionsData {
     /**
      *
      */
-    #[serde()]
-    pub acss_debit: Box<Option<SetupIntentPaymentMethodOptionsAcssDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acss_debit: Option<Box<SetupIntentPaymentMethodOptionsAcssDebitAnyOf>>,
     /**
      *
      */
@@ -39554,13 +39554,13 @@ pub struct SetupIntentPaymentMethodOptionsData {
     /**
      *
      */
-    #[serde()]
-    pub sepa_debit: Box<Option<SetupIntentPaymentMethodOptionsSepaDebitAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<Box<SetupIntentPaymentMethodOptionsSepaDebitAnyOf>>,
     /**
      *
      */
-    #[serde()]
-    pub us_bank_account: Box<Option<SetupIntentPaymentMethodOptionsUsBankAccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub us_bank_account: Option<Box<SetupIntentPaymentMethodOptionsUsBankAccountAnyOf>>,
 }
 
 ///
@@ -39986,10 +39986,10 @@ pub struct ShippingRate {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -40004,8 +40004,8 @@ pub struct ShippingRate {
      * Shipping rates describe the price of shipping presented to your customers and can be
      *  applied to [Checkout Sessions](https://stripe.com/docs/payments/checkout/shipping) to collect shipping costs.
      */
-    #[serde()]
-    pub tax_code: Box<Option<TaxCodeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<Box<TaxCodeAnyOf>>,
     /**
      * The type of calculation to use on the shipping rate. Can only be `fixed_amount` for now.
      */
@@ -40251,10 +40251,10 @@ pub struct Sku {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -40675,10 +40675,10 @@ pub struct SourceData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * `Source` objects allow you to accept a variety of payment methods. They
      *  represent a customer's payment instrument, and can be used with the Stripe API
@@ -43547,15 +43547,15 @@ pub struct Subscription {
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub default_payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * Subscriptions allow you to charge a customer on a recurring basis.
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub default_source: Box<Option<DefaultSourceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<Box<DefaultSourceAnyOf>>,
     /**
      * Subscriptions allow you to charge a customer on a recurring basis.
      *  
@@ -43602,8 +43602,8 @@ pub struct Subscription {
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub latest_invoice: Box<Option<InvoiceAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_invoice: Option<Box<InvoiceAnyOf>>,
     /**
      * Whether the account can create live charges.
      */
@@ -43617,10 +43617,10 @@ pub struct Subscription {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * Subscriptions allow you to charge a customer on a recurring basis.
      *  
@@ -43657,8 +43657,8 @@ pub struct Subscription {
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub pending_setup_intent: Box<Option<SetupIntentAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_setup_intent: Option<Box<SetupIntentAnyOf>>,
     /**
      * If specified, [pending updates](https://stripe.com/docs/billing/subscriptions/pending-updates) that will be applied to the subscription once the `latest_invoice` has been paid.
      */
@@ -43669,8 +43669,8 @@ pub struct Subscription {
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub schedule: Box<Option<ScheduleAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Box<ScheduleAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -43698,8 +43698,8 @@ pub struct Subscription {
      *  
      *  Related guide: [Creating Subscriptions](https://stripe.com/docs/billing/subscriptions/creating).
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
     /**
      * The account (if any) the subscription's payments will be attributed to for tax reporting, and where funds from each payment will be transferred to for each of the subscription's invoices.
      */
@@ -43783,10 +43783,10 @@ pub struct SubscriptionItem {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -44087,10 +44087,10 @@ pub struct SubscriptionSchedule {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -44137,15 +44137,15 @@ pub struct SubscriptionSchedule {
      *  
      *  Related guide: [Subscription Schedules](https://stripe.com/docs/billing/subscriptions/subscription-schedules).
      */
-    #[serde()]
-    pub subscription: Box<Option<SubscriptionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<Box<SubscriptionAnyOf>>,
     /**
      * A subscription schedule allows you to create and manage the lifecycle of a subscription by predefining expected changes.
      *  
      *  Related guide: [Subscription Schedules](https://stripe.com/docs/billing/subscriptions/subscription-schedules).
      */
-    #[serde()]
-    pub test_clock: Box<Option<TestClockAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<Box<TestClockAnyOf>>,
 }
 
 /// All of the following types:
@@ -44435,13 +44435,13 @@ pub struct SubscriptionSchedulePhaseConfiguration {
     /**
      * A phase describes the plans, coupon, and trialing status of a subscription for a predefined time period.
      */
-    #[serde()]
-    pub coupon: Box<Option<CouponAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<Box<CouponAnyOf>>,
     /**
      * A phase describes the plans, coupon, and trialing status of a subscription for a predefined time period.
      */
-    #[serde()]
-    pub default_payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * A phase describes the plans, coupon, and trialing status of a subscription for a predefined time period.
      */
@@ -44539,8 +44539,8 @@ pub struct SubscriptionSchedulesResourceDefaultSettings {
     /**
      *
      */
-    #[serde()]
-    pub default_payment_method: Box<Option<PaymentMethodAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<Box<PaymentMethodAnyOf>>,
     /**
      * The invoice settings applicable during this phase.
      */
@@ -45233,10 +45233,10 @@ pub struct TaxRate {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -45380,10 +45380,10 @@ pub struct TerminalLocation {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -45559,17 +45559,17 @@ pub struct TerminalReader {
      *  
      *  Related guide: [Connecting to a Reader](https://stripe.com/docs/terminal/payments/connect-reader).
      */
-    #[serde()]
-    pub location: Box<Option<LocationAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Box<LocationAnyOf>>,
     /**
      * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -46643,8 +46643,8 @@ pub struct Topup {
      *  
      *  Related guide: [Topping Up your Platform Account](https://stripe.com/docs/connect/top-ups).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -46737,10 +46737,10 @@ pub struct Topup {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -46902,8 +46902,8 @@ pub struct Transfer {
      *  
      *  Related guide: [Creating Separate Charges and Transfers](https://stripe.com/docs/connect/charges-transfers).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -46952,8 +46952,8 @@ pub struct Transfer {
      *  
      *  Related guide: [Creating Separate Charges and Transfers](https://stripe.com/docs/connect/charges-transfers).
      */
-    #[serde()]
-    pub destination: Box<Option<AccountAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination: Option<Box<AccountAnyOf>>,
     /**
      * A `Transfer` object is created when you move funds between Stripe accounts as
      *  part of Connect.
@@ -46966,8 +46966,8 @@ pub struct Transfer {
      *  
      *  Related guide: [Creating Separate Charges and Transfers](https://stripe.com/docs/connect/charges-transfers).
      */
-    #[serde()]
-    pub destination_payment: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_payment: Option<Box<ChargeAnyOf>>,
     /**
      * The account's country.
      */
@@ -46990,10 +46990,10 @@ pub struct Transfer {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -47024,8 +47024,8 @@ pub struct Transfer {
      *  
      *  Related guide: [Creating Separate Charges and Transfers](https://stripe.com/docs/connect/charges-transfers).
      */
-    #[serde()]
-    pub source_transaction: Box<Option<ChargeAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<Box<ChargeAnyOf>>,
     /**
      * A `Transfer` object is created when you move funds between Stripe accounts as
      *  part of Connect.
@@ -47154,8 +47154,8 @@ pub struct TransferReversal {
      *  
      *  Related guide: [Reversing Transfers](https://stripe.com/docs/connect/charges-transfers#reversing-transfers).
      */
-    #[serde()]
-    pub balance_transaction: Box<Option<BalanceTransactionAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Box<BalanceTransactionAnyOf>>,
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
@@ -47189,8 +47189,8 @@ pub struct TransferReversal {
      *  
      *  Related guide: [Reversing Transfers](https://stripe.com/docs/connect/charges-transfers#reversing-transfers).
      */
-    #[serde()]
-    pub destination_payment_refund: Box<Option<RefundAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination_payment_refund: Option<Box<RefundAnyOf>>,
     /**
      * The account's country.
      */
@@ -47217,10 +47217,10 @@ pub struct TransferReversal {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -47241,8 +47241,8 @@ pub struct TransferReversal {
      *  
      *  Related guide: [Reversing Transfers](https://stripe.com/docs/connect/charges-transfers#reversing-transfers).
      */
-    #[serde()]
-    pub source_refund: Box<Option<RefundAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_refund: Option<Box<RefundAnyOf>>,
     #[serde()]
     pub transfer: Box<TransferAnyOf>,
 }
@@ -47676,10 +47676,10 @@ pub struct WebhookEndpoint {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub metadata: String,
+    pub metadata: std::collections::HashMap<String, String>,
     /**
      * String representing the object's type. Objects of the same type share the same value.
      */
@@ -47823,75 +47823,59 @@ pub struct GetAccountPeopleResponse {
     pub url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Paid {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub gt: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub gte: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub lt: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub lte: i64,
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RangeQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<i64>,
 }
 
 /// All of the following types:
 ///
 /// - `i64`
-/// - `Paid`
+/// - `RangeQuery`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(untagged)]
-pub enum PaidAnyOf {
+pub enum RangeQueryAnyOf {
     /**
      * Time at which the account was connected. Measured in seconds since the Unix epoch.
      */
     I64(i64),
-    Paid(Paid),
+    RangeQuery(RangeQuery),
 }
 
-impl PaidAnyOf {
+impl RangeQueryAnyOf {
     pub fn i64(&self) -> Option<&i64> {
-        if let PaidAnyOf::I64(ref_) = self {
+        if let RangeQueryAnyOf::I64(ref_) = self {
             return Some(ref_);
         }
         None
     }
 
-    pub fn paid(&self) -> Option<&Paid> {
-        if let PaidAnyOf::Paid(ref_) = self {
+    pub fn range_query(&self) -> Option<&RangeQuery> {
+        if let RangeQueryAnyOf::RangeQuery(ref_) = self {
             return Some(ref_);
         }
         None
     }
 }
 
-impl std::convert::From<i64> for PaidAnyOf {
+impl std::convert::From<i64> for RangeQueryAnyOf {
     fn from(f: i64) -> Self {
-        PaidAnyOf::I64(f)
+        RangeQueryAnyOf::I64(f)
     }
 }
 
-impl std::convert::From<PaidAnyOf> for i64 {
-    fn from(f: PaidAnyOf) -> Self {
+impl std::convert::From<RangeQueryAnyOf> for i64 {
+    fn from(f: RangeQueryAnyOf) -> Self {
         *f.i64().unwrap()
     }
 }
@@ -48134,16 +48118,64 @@ impl SearchResultObject {
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SearchResult {
+pub struct ChargeSearchResult {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Charge>,
     /**
-     * This list only contains the latest charge, even if there were previously multiple unsuccessful charges. To view all previous charges for a PaymentIntent, you can filter the charges list using the `payment_intent` [parameter](https://stripe.com/docs/api/charges/list#list_charges-payment_intent).
+     * Whether the account can create live charges.
      */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomerSearchResult {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub data: Vec<Charge>,
+    pub data: Vec<Customer>,
     /**
      * Whether the account can create live charges.
      */
@@ -48188,13 +48220,13 @@ pub struct SearchResult {
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PaymentPagesCheckoutSessionList {
+pub struct InvoiceSearchResult {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub data: Vec<Session>,
+    pub data: Vec<Invoice>,
     /**
      * Whether the account can create live charges.
      */
@@ -48204,10 +48236,28 @@ pub struct PaymentPagesCheckoutSessionList {
     )]
     pub has_more: bool,
     /**
-     * String representing the object's type. Objects of the same type share the same value. Always has the value `list`.
+     *
      */
-    #[serde(default, skip_serializing_if = "Object::is_noop")]
-    pub object: Object,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
     /**
      * The account's country.
      */
@@ -48221,13 +48271,13 @@ pub struct PaymentPagesCheckoutSessionList {
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCountrySpecsResponse {
+pub struct PaymentIntentSearchResult {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub data: Vec<CountrySpec>,
+    pub data: Vec<PaymentIntent>,
     /**
      * Whether the account can create live charges.
      */
@@ -48237,10 +48287,28 @@ pub struct GetCountrySpecsResponse {
     )]
     pub has_more: bool,
     /**
-     * String representing the object's type. Objects of the same type share the same value. Always has the value `list`.
+     *
      */
-    #[serde(default, skip_serializing_if = "Object::is_noop")]
-    pub object: Object,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
     /**
      * The account's country.
      */
@@ -48254,13 +48322,166 @@ pub struct GetCountrySpecsResponse {
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCouponsResponse {
+pub struct PriceSearchResult {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub data: Vec<Coupon>,
+    pub data: Vec<PriceData>,
+    /**
+     * Whether the account can create live charges.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductSearchResult {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Product>,
+    /**
+     * Whether the account can create live charges.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SubscriptionSearchResult {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Subscription>,
+    /**
+     * Whether the account can create live charges.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_page: String,
+    /**
+     * String representing the object's type. Objects of the same type share the same value.
+     */
+    #[serde(default, skip_serializing_if = "SearchResultObject::is_noop")]
+    pub object: SearchResultObject,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PaymentPagesCheckoutSessionList {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Session>,
     /**
      * Whether the account can create live charges.
      */
@@ -48287,13 +48508,79 @@ pub struct GetCouponsResponse {
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreditNotesList {
+pub struct GetCountrySpecsResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub data: Vec<CreditNote>,
+    pub data: Vec<CountrySpec>,
+    /**
+     * Whether the account can create live charges.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     * String representing the object's type. Objects of the same type share the same value. Always has the value `list`.
+     */
+    #[serde(default, skip_serializing_if = "Object::is_noop")]
+    pub object: Object,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCouponsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Coupon>,
+    /**
+     * Whether the account can create live charges.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    /**
+     * String representing the object's type. Objects of the same type share the same value. Always has the value `list`.
+     */
+    #[serde(default, skip_serializing_if = "Object::is_noop")]
+    pub object: Object,
+    /**
+     * The account's country.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreditNotesList {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<CreditNote>,
     /**
      * Whether the account can create live charges.
      */
@@ -48418,8 +48705,8 @@ pub struct CreditNoteLineItemParams {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
-    #[serde()]
-    pub tax_rates: Box<Option<TaxRatesAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Box<TaxRatesAnyOf>>,
     /**
      * The type of the credit note line item, one of `invoice_line_item` or `custom_line_item`. When the type is `invoice_line_item` there is an additional `invoice_line_item` property on the resource the value of which is the id of the credited line item on the invoice.
      */
@@ -49231,8 +49518,8 @@ impl std::convert::From<IpAddressAnyOf> for String {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Tax {
-    #[serde()]
-    pub ip_address: Box<Option<IpAddressAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<Box<IpAddressAnyOf>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -49462,13 +49749,13 @@ pub struct CustomerDetails {
     /**
      * Details about the customer you want to invoice or overrides for an existing customer.
      */
-    #[serde()]
-    pub address: Box<Option<AddressAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Box<AddressAnyOf>>,
     /**
      * Details about the customer you want to invoice or overrides for an existing customer.
      */
-    #[serde()]
-    pub shipping: Box<Option<ShippingAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Box<ShippingAnyOf>>,
     /**
      * Details about the customer you want to invoice or overrides for an existing customer.
      */
@@ -49663,16 +49950,20 @@ pub struct InvoiceItems {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub discountable: bool,
-    #[serde()]
-    pub discounts: Box<Option<GetInvoicesUpcomingDiscountsAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Box<GetInvoicesUpcomingDiscountsAnyOf>>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub invoiceitem: String,
-    #[serde()]
-    pub metadata: Box<Option<MetadataAnyOf>>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub period: Option<InvoiceLineItemPeriod>,
     #[serde(
@@ -49689,8 +49980,8 @@ pub struct InvoiceItems {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
-    #[serde()]
-    pub tax_rates: Box<Option<TaxRatesAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Box<TaxRatesAnyOf>>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -49943,8 +50234,8 @@ pub struct RecurringPriceData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubscriptionItems {
-    #[serde()]
-    pub billing_thresholds: Box<Option<BillingThresholdsAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_thresholds: Option<Box<BillingThresholdsAnyOf>>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -49961,8 +50252,12 @@ pub struct SubscriptionItems {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    #[serde()]
-    pub metadata: Box<Option<MetadataAnyOf>>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -49977,8 +50272,8 @@ pub struct SubscriptionItems {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
-    #[serde()]
-    pub tax_rates: Box<Option<TaxRatesAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Box<TaxRatesAnyOf>>,
 }
 
 /**
@@ -50304,23 +50599,23 @@ pub struct OrderTimestampSpecs {
     /**
      * Filter orders based on when they were paid, fulfilled, canceled, or returned.
      */
-    #[serde()]
-    pub canceled: Box<Option<PaidAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canceled: Option<Box<RangeQueryAnyOf>>,
     /**
      * Filter orders based on when they were paid, fulfilled, canceled, or returned.
      */
-    #[serde()]
-    pub fulfilled: Box<Option<PaidAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfilled: Option<Box<RangeQueryAnyOf>>,
     /**
      * Filter orders based on when they were paid, fulfilled, canceled, or returned.
      */
-    #[serde()]
-    pub paid: Box<Option<PaidAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paid: Option<Box<RangeQueryAnyOf>>,
     /**
      * Filter orders based on when they were paid, fulfilled, canceled, or returned.
      */
-    #[serde()]
-    pub returned: Box<Option<PaidAnyOf>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub returned: Option<Box<RangeQueryAnyOf>>,
 }
 
 ///
@@ -51678,3 +51973,1023 @@ pub struct GetWebhookEndpointsResponse {
     )]
     pub url: String,
 }
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCustomersRequest {
+    /**
+     * The customer's address.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /**
+     * An integer amount in cents that represents the customer's current balance, which affect the customer's future invoices. A negative amount represents a credit that decreases the amount due on an invoice; a positive amount increases the amount due on an invoice.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub balance: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub coupon: String,
+    /**
+     * An arbitrary string that you can attach to a customer object. It is displayed alongside the customer in the dashboard.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Customer's email address. It's displayed alongside the customer in your dashboard and can be useful for searching and tracking. This may be up to *512 characters*.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * The prefix for the customer used to generate unique invoice numbers. Must be 3–12 uppercase letters or numbers.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub invoice_prefix: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * The customer's full name or business name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The sequence to be used on the customer's next invoice. Defaults to 1.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub next_invoice_sequence: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payment_method: String,
+    /**
+     * The customer's phone number.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone: String,
+    /**
+     * Customer's preferred languages, ordered by preference.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub preferred_locales: Vec<String>,
+    /**
+     * The API ID of a promotion code to apply to the customer. The customer will have a discount applied on all recurring payments. Charges you create through the API will not have the discount.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub promotion_code: String,
+    /**
+     * The customer's shipping information. Appears on invoices emailed to this customer.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShipping>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    /**
+     * The customer's tax exemption. One of `none`, `exempt`, or `reverse`.
+     */
+    #[serde(default, skip_serializing_if = "TaxExempt::is_noop")]
+    pub tax_exempt: TaxExempt,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCustomersCustomerRequest {
+    /**
+     * The customer's address.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /**
+     * An integer amount in cents that represents the customer's current balance, which affect the customer's future invoices. A negative amount represents a credit that decreases the amount due on an invoice; a positive amount increases the amount due on an invoice.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub balance: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub coupon: String,
+    /**
+     * If you are using payment methods created via the PaymentMethods API, see the [invoice_settings.default_payment_method](https://stripe.com/docs/api/customers/update#update_customer-invoice_settings-default_payment_method) parameter.
+     *  
+     *  Provide the ID of a payment source already attached to this customer to make it this customer's default payment source.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_source: String,
+    /**
+     * An arbitrary string that you can attach to a customer object. It is displayed alongside the customer in the dashboard.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Customer's email address. It's displayed alongside the customer in your dashboard and can be useful for searching and tracking. This may be up to *512 characters*.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * The prefix for the customer used to generate unique invoice numbers. Must be 3–12 uppercase letters or numbers.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub invoice_prefix: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * The customer's full name or business name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The sequence to be used on the customer's next invoice. Defaults to 1.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub next_invoice_sequence: i64,
+    /**
+     * The customer's phone number.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone: String,
+    /**
+     * Customer's preferred languages, ordered by preference.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub preferred_locales: Vec<String>,
+    /**
+     * The API ID of a promotion code to apply to the customer. The customer will have a discount applied on all recurring payments. Charges you create through the API will not have the discount.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub promotion_code: String,
+    /**
+     * The customer's shipping information. Appears on invoices emailed to this customer.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShipping>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    /**
+     * The customer's tax exemption. One of `none`, `exempt`, or `reverse`.
+     */
+    #[serde(default, skip_serializing_if = "TaxExempt::is_noop")]
+    pub tax_exempt: TaxExempt,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesRequest {
+    /**
+     * Amount intended to be collected by this payment. A positive integer representing how much to charge in the [smallest currency unit](https://stripe.com/docs/currencies#zero-decimal) (e.g., 100 cents to charge $1.00 or 100 to charge ¥100, a zero-decimal currency).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub amount: i64,
+    /**
+     * A fee in cents that will be applied to the charge and transferred to the application owner's Stripe account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub application_fee_amount: i64,
+    /**
+     * Whether to immediately capture the charge. Defaults to `true`. When `false`, the charge issues an authorization (or pre-authorization), and will need to be [captured](https://stripe.com/docs/api#capture_charge) later.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture: Option<bool>,
+    /**
+     * Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase. Must be a [supported currency](https://stripe.com/docs/currencies).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub currency: String,
+    /**
+     * The ID of an existing customer that will be charged in this request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub customer: String,
+    /**
+     * An arbitrary string which you can attach to a `Charge` object. It is displayed when in the web interface alongside the charge.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * The Stripe account ID for which these funds are intended.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub on_behalf_of: String,
+    /**
+     * The email address to which this charge's [receipt](https://stripe.com/docs/dashboard/receipts) will be sent.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub receipt_email: String,
+    /**
+     * Shipping information for the charge. Helps prevent fraud on charges for physical goods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShipping>,
+    /**
+     * A payment source to be charged. This can be the ID of a [card](https://stripe.com/docs/api#cards) (i.e., credit or debit card), a [bank account](https://stripe.com/docs/api#bank_accounts), a [source](https://stripe.com/docs/api#sources), a [token](https://stripe.com/docs/api#tokens), or a [connected account](https://stripe.com/docs/connect/account-debits#charging-a-connected-account).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    /**
+     * For card charges, use `statement_descriptor_suffix` instead. Otherwise, you can use this value as the complete description of a charge on your customers’ statements. Must contain at least one letter, maximum 22 characters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statement_descriptor: String,
+    /**
+     * Provides information about the charge that customers see on their statements. Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor. Maximum 22 characters for the concatenated descriptor.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statement_descriptor_suffix: String,
+    /**
+     * A string that identifies this transaction as part of a group. For details, see [Grouping transactions](https://stripe.com/docs/connect/charges-transfers#transfer-options).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub transfer_group: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesChargeRequest {
+    /**
+     * The ID of an existing customer that will be associated with this request. This field may only be updated if there is no existing associated customer with this charge.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub customer: String,
+    /**
+     * An arbitrary string which you can attach to a charge object. It is displayed when in the web interface alongside the charge.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * This is the email address that the receipt for this charge will be sent to. If this field is updated, then a new email receipt will be sent to the updated address.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub receipt_email: String,
+    /**
+     * Shipping information for the charge. Helps prevent fraud on charges for physical goods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CustomerShipping>,
+    /**
+     * A string that identifies this transaction as part of a group. `transfer_group` may only be provided if it has not been set.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub transfer_group: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesChargeCaptureRequest {
+    /**
+     * The amount to capture, which must be less than or equal to the original amount. Any additional amount will be automatically refunded.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub amount: i64,
+    /**
+     * An application fee amount to add on to this charge, which must be less than or equal to the original amount.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub application_fee_amount: i64,
+    /**
+     * The email address to send this charge's receipt to. This will override the previously-specified email address for this charge, if one was set. Receipts will not be sent in test mode.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub receipt_email: String,
+    /**
+     * For card charges, use `statement_descriptor_suffix` instead. Otherwise, you can use this value as the complete description of a charge on your customers’ statements. Must contain at least one letter, maximum 22 characters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statement_descriptor: String,
+    /**
+     * Provides information about the charge that customers see on their statements. Concatenated with the prefix (shortened descriptor) or statement descriptor that’s set on the account to form the complete statement descriptor. Maximum 22 characters for the concatenated descriptor.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statement_descriptor_suffix: String,
+    /**
+     * A string that identifies this transaction as part of a group. `transfer_group` may only be provided if it has not been set.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub transfer_group: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesChargeRefundRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub amount: i64,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payment_intent: String,
+    #[serde(default, skip_serializing_if = "RefundReason::is_noop")]
+    pub reason: RefundReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesChargeRefundsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub amount: i64,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payment_intent: String,
+    #[serde(default, skip_serializing_if = "RefundReason::is_noop")]
+    pub reason: RefundReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostChargesChargeRefundsRefundRequest {
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostRefundsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub amount: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub charge: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payment_intent: String,
+    #[serde(default, skip_serializing_if = "RefundReason::is_noop")]
+    pub reason: RefundReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse_transfer: Option<bool>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostRefundsRefundRequest {
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+}
+
+/**
+* Use `allow_incomplete` to create subscriptions with `status=incomplete` if the first invoice cannot be paid. Use `default_incomplete` to create subscriptions with `status=incomplete` when the first invoice requires payment. Use `error_if_incomplete` to fail the request if the first invoice cannot be paid. Use `pending_if_incomplete` to update the subscription using pending updates.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub enum PaymentBehavior {
+    #[serde(rename = "allow_incomplete")]
+    AllowIncomplete,
+    #[serde(rename = "default_incomplete")]
+    DefaultIncomplete,
+    #[serde(rename = "error_if_incomplete")]
+    ErrorIfIncomplete,
+    #[serde(rename = "pending_if_incomplete")]
+    PendingIfIncomplete,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(other)]
+    FallthroughString,
+}
+
+impl std::fmt::Display for PaymentBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentBehavior::AllowIncomplete => "allow_incomplete",
+            PaymentBehavior::DefaultIncomplete => "default_incomplete",
+            PaymentBehavior::ErrorIfIncomplete => "error_if_incomplete",
+            PaymentBehavior::PendingIfIncomplete => "pending_if_incomplete",
+            PaymentBehavior::Noop => "",
+            PaymentBehavior::FallthroughString => "*",
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for PaymentBehavior {
+    fn default() -> PaymentBehavior {
+        PaymentBehavior::Noop
+    }
+}
+impl PaymentBehavior {
+    pub fn is_noop(&self) -> bool {
+        matches!(self, PaymentBehavior::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SubscriptionItemCreateParams {
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * The ID of the price object.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub price: String,
+    /**
+     * Quantity for this item.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    /**
+     * A list of [Tax Rate](https://stripe.com/docs/api/tax_rates) ids. These Tax Rates will override the [`default_tax_rates`](https://stripe.com/docs/api/subscriptions/create#create_subscription-default_tax_rates) on the Subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tax_rates: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SubscriptionItemUpdateParams {
+    /**
+     * A flag that, if set to `true`, will delete the specified item.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    /**
+     * Subscription item to update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * The ID of the price object. When changing a subscription item's price, `quantity` is set to 1 unless a `quantity` parameter is provided.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub price: String,
+    /**
+     * Quantity for this item.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    /**
+     * A list of [Tax Rate](https://stripe.com/docs/api/tax_rates) ids. These Tax Rates will override the [`default_tax_rates`](https://stripe.com/docs/api/subscriptions/create#create_subscription-default_tax_rates) on the Subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tax_rates: Vec<String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostSubscriptionsRequest {
+    /**
+     * Boolean indicating whether this subscription should cancel at the end of the current period.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_at_period_end: Option<bool>,
+    /**
+     * Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer. When sending an invoice, Stripe will email your customer an invoice with payment instructions. Defaults to `charge_automatically`.
+     */
+    #[serde(default, skip_serializing_if = "CollectionMethod::is_noop")]
+    pub collection_method: CollectionMethod,
+    /**
+     * The ID of the coupon to apply to this subscription. A coupon applied to a subscription will only affect invoices created for that particular subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub coupon: String,
+    /**
+     * The identifier of the customer to subscribe.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub customer: String,
+    /**
+     * Number of days a customer has to pay invoices generated by this subscription. Valid only for subscriptions where `collection_method` is set to `send_invoice`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub days_until_due: i64,
+    /**
+     * ID of the default payment method for the subscription. It must belong to the customer associated with the subscription. This takes precedence over `default_source`. If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://stripe.com/docs/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://stripe.com/docs/api/customers/object#customer_object-default_source).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_payment_method: String,
+    /**
+     * ID of the default payment source for the subscription. It must belong to the customer associated with the subscription and be in a chargeable state. If `default_payment_method` is also set, `default_payment_method` will take precedence. If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://stripe.com/docs/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://stripe.com/docs/api/customers/object#customer_object-default_source).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_source: String,
+    /**
+     * The tax rates that will apply to any subscription item that does not have `tax_rates` set. Invoices created will have their `default_tax_rates` populated from the subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub default_tax_rates: Vec<String>,
+    /**
+     * The subscription's description, meant to be displayable to the customer. Use this field to optionally store an explanation of the subscription for rendering in Stripe surfaces.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * A list of up to 20 subscription items, each with an attached price.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<SubscriptionItemCreateParams>,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * Indicates if a customer is on or off-session while an invoice payment is attempted.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(default, skip_serializing_if = "PaymentBehavior::is_noop")]
+    pub payment_behavior: PaymentBehavior,
+    /**
+     * The API ID of a promotion code to apply to this subscription. A promotion code applied to a subscription will only affect invoices created for that particular subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub promotion_code: String,
+    /**
+     * Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) resulting from the `billing_cycle_anchor`. Valid values are `create_prorations` or `none`.
+     */
+    #[serde(default, skip_serializing_if = "ProrationBehavior::is_noop")]
+    pub proration_behavior: ProrationBehavior,
+    /**
+     * Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `trial_end` is not allowed.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trial_from_plan: Option<bool>,
+    /**
+     * Integer representing the number of trial period days before the customer is charged for the first time. This will always overwrite any trials that might apply via a subscribed plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub trial_period_days: i64,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostSubscriptionsSubscriptionExposedIdRequest {
+    /**
+     * Boolean indicating whether this subscription should cancel at the end of the current period.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_at_period_end: Option<bool>,
+    /**
+     * Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this subscription at the end of the cycle using the default source attached to the customer. When sending an invoice, Stripe will email your customer an invoice with payment instructions. Defaults to `charge_automatically`.
+     */
+    #[serde(default, skip_serializing_if = "CollectionMethod::is_noop")]
+    pub collection_method: CollectionMethod,
+    /**
+     * The ID of the coupon to apply to this subscription. A coupon applied to a subscription will only affect invoices created for that particular subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub coupon: String,
+    /**
+     * Number of days a customer has to pay invoices generated by this subscription. Valid only for subscriptions where `collection_method` is set to `send_invoice`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub days_until_due: i64,
+    /**
+     * ID of the default payment method for the subscription. It must belong to the customer associated with the subscription. This takes precedence over `default_source`. If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://stripe.com/docs/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://stripe.com/docs/api/customers/object#customer_object-default_source).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_payment_method: String,
+    /**
+     * ID of the default payment source for the subscription. It must belong to the customer associated with the subscription and be in a chargeable state. If `default_payment_method` is also set, `default_payment_method` will take precedence. If neither are set, invoices will use the customer's [invoice_settings.default_payment_method](https://stripe.com/docs/api/customers/object#customer_object-invoice_settings-default_payment_method) or [default_source](https://stripe.com/docs/api/customers/object#customer_object-default_source).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_source: String,
+    /**
+     * The tax rates that will apply to any subscription item that does not have `tax_rates` set. Invoices created will have their `default_tax_rates` populated from the subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub default_tax_rates: Vec<String>,
+    /**
+     * The subscription's description, meant to be displayable to the customer. Use this field to optionally store an explanation of the subscription for rendering in Stripe surfaces.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * A list of up to 20 subscription items, each with an attached price.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<SubscriptionItemUpdateParams>,
+    /**
+     * Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::HashMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::HashMap<String, String>,
+    /**
+     * Indicates if a customer is on or off-session while an invoice payment is attempted.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+    #[serde(default, skip_serializing_if = "PaymentBehavior::is_noop")]
+    pub payment_behavior: PaymentBehavior,
+    /**
+     * The API ID of a promotion code to apply to this subscription. A promotion code applied to a subscription will only affect invoices created for that particular subscription.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub promotion_code: String,
+    /**
+     * Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) resulting from the `billing_cycle_anchor`. Valid values are `create_prorations` or `none`.
+     */
+    #[serde(default, skip_serializing_if = "ProrationBehavior::is_noop")]
+    pub proration_behavior: ProrationBehavior,
+    /**
+     * Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `trial_end` is not allowed.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trial_from_plan: Option<bool>,
+}
+
+/// The `multipart/form-data` body of a request.
+#[derive(Debug, Clone)]
+pub struct PostFilesForm {
+    /// A file to upload. The file should follow the specifications of RFC 2388 (which defines file transfers for the `multipart/form-data` protocol).
+    pub file: crate::utils::FilePart,
+    /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+    pub purpose: crate::types::Purpose,
+}
+
+impl PostFilesForm {
+    /// Turn this into a form to send.
+    pub fn into_form(self) -> crate::ClientResult<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        let file = self.file;
+        form = form.part("file", file.into_part()?);
+        let purpose = self.purpose;
+        form = form.text("purpose", crate::utils::form_text(&purpose)?);
+
+        Ok(form)
+    }
+}
@@ -0,0 +1,267 @@
+//! Receive webhook events: check they came from Stripe and parse them.
+//!
+//! Stripe signs each event with the endpoint's secret, in `Stripe-Signature`,
+//! as `t=<timestamp>,v1=<signature>`: an HMAC-SHA256 of `{timestamp}.{body}`.
+//! While a secret is being rolled, the header has a `v1` for each secret.
+//! See <https://stripe.com/docs/webhooks/signatures>.
+//!
+//! ```
+//! # fn run(headers: &http::HeaderMap, body: &[u8]) -> Result<(), dolladollabills::ClientError> {
+//! use dolladollabills::webhooks::{WebhookHandler, WebhookObject};
+//!
+//! let handler = WebhookHandler::new("whsec_...");
+//! let event = handler.handle(headers, body)?;
+//! match event.object {
+//!     WebhookObject::Invoice(invoice) if event.event.type_ == "invoice.paid" => {
+//!         println!("invoice {} was paid", invoice.id)
+//!     }
+//!     WebhookObject::Subscription(subscription) => println!("{} changed", subscription.id),
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    types,
+    utils::{decode_hex, encode_hex, hmac_sha256, verify_hmac_sha256},
+    ClientError, ClientResult,
+};
+
+/// The header Stripe signs events in.
+pub const SIGNATURE_HEADER: &str = "Stripe-Signature";
+
+/// How many seconds old an event's timestamp may be before we take it for a
+/// replay, the same as Stripe's own libraries.
+pub const DEFAULT_TOLERANCE_SECS: i64 = 60 * 5;
+
+/// The timestamp and `v1` signatures of a `Stripe-Signature` value.
+fn parse_signature_header(header: &str) -> Option<(i64, Vec<Vec<u8>>)> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for (key, value) in header
+        .split(',')
+        .filter_map(|pair| pair.trim().split_once('='))
+    {
+        match key {
+            "t" => timestamp = value.parse().ok(),
+            // Skip anything that isn't hex, rather than fail the other signatures.
+            "v1" => signatures.extend(decode_hex(value)),
+            _ => {}
+        }
+    }
+
+    Some((timestamp?, signatures))
+}
+
+/// Check a `Stripe-Signature` value against the body of the event, in
+/// constant time, with any of the endpoint's secrets. Returns the event's
+/// timestamp, once it's no more than `tolerance` seconds before `now`.
+pub fn verify_signature<S>(
+    secrets: &[S],
+    header: &str,
+    body: &[u8],
+    tolerance: i64,
+    now: i64,
+) -> ClientResult<i64>
+where
+    S: AsRef<[u8]>,
+{
+    let (timestamp, signatures) =
+        parse_signature_header(header).ok_or(ClientError::InvalidWebhookSignature)?;
+
+    if !verify_hmac_sha256(secrets, &signatures, &signed_payload(timestamp, body)) {
+        return Err(ClientError::InvalidWebhookSignature);
+    }
+
+    if now.saturating_sub(timestamp) > tolerance {
+        return Err(ClientError::StaleWebhook { timestamp });
+    }
+
+    Ok(timestamp)
+}
+
+/// Sign a body the way Stripe does, for `Stripe-Signature`.
+pub fn sign(secret: &[u8], timestamp: i64, body: &[u8]) -> String {
+    let tag = hmac_sha256(secret, &signed_payload(timestamp, body));
+    format!("t={},v1={}", timestamp, encode_hex(&tag))
+}
+
+fn signed_payload(timestamp: i64, body: &[u8]) -> Vec<u8> {
+    let mut payload = format!("{}.", timestamp).into_bytes();
+    payload.extend_from_slice(body);
+    payload
+}
+
+/// The resource an event is about, from its `data.object`.
+#[derive(PartialEq, Debug, Clone)]
+pub enum WebhookObject {
+    Account(Box<types::Account>),
+    Charge(Box<types::Charge>),
+    CheckoutSession(Box<types::Session>),
+    Coupon(Box<types::Coupon>),
+    CreditNote(Box<types::CreditNote>),
+    Customer(Box<types::Customer>),
+    Dispute(Box<types::Dispute>),
+    Invoice(Box<types::Invoice>),
+    InvoiceItem(Box<types::InvoiceItem>),
+    PaymentIntent(Box<types::PaymentIntent>),
+    PaymentMethod(Box<types::PaymentMethod>),
+    Payout(Box<types::Payout>),
+    Plan(Box<types::PlanData>),
+    Price(Box<types::PriceData>),
+    Product(Box<types::Product>),
+    PromotionCode(Box<types::PromotionCode>),
+    Quote(Box<types::Quote>),
+    Refund(Box<types::Refund>),
+    SetupIntent(Box<types::SetupIntent>),
+    Subscription(Box<types::Subscription>),
+    SubscriptionSchedule(Box<types::SubscriptionSchedule>),
+    TaxRate(Box<types::TaxRate>),
+    Topup(Box<types::Topup>),
+    Transfer(Box<types::Transfer>),
+    /// An object of an event type we don't have a type for, or that doesn't
+    /// fit the type we have.
+    Other(serde_json::Value),
+}
+
+impl WebhookObject {
+    /// Parse the `data.object` of an event by the event's `type`, e.g. the
+    /// `Subscription` of `customer.subscription.updated`.
+    ///
+    /// An object that doesn't fit its type, e.g. one from a newer API version,
+    /// is kept as it came, as `Other`, rather than losing the event.
+    pub fn parse(event_type: &str, object: serde_json::Value) -> Self {
+        fn parse<T: serde::de::DeserializeOwned>(object: &serde_json::Value) -> Option<Box<T>> {
+            T::deserialize(object).ok().map(Box::new)
+        }
+
+        // Event types are the resource, then what happened to it.
+        let resource = event_type
+            .rsplit_once('.')
+            .map(|(resource, _)| resource)
+            .unwrap_or_default();
+
+        let typed = match resource {
+            "account" => parse(&object).map(WebhookObject::Account),
+            "charge" => parse(&object).map(WebhookObject::Charge),
+            "charge.dispute" => parse(&object).map(WebhookObject::Dispute),
+            "charge.refund" => parse(&object).map(WebhookObject::Refund),
+            "checkout.session" => parse(&object).map(WebhookObject::CheckoutSession),
+            "coupon" => parse(&object).map(WebhookObject::Coupon),
+            "credit_note" => parse(&object).map(WebhookObject::CreditNote),
+            "customer" => parse(&object).map(WebhookObject::Customer),
+            "customer.subscription" => parse(&object).map(WebhookObject::Subscription),
+            "invoice" => parse(&object).map(WebhookObject::Invoice),
+            "invoiceitem" => parse(&object).map(WebhookObject::InvoiceItem),
+            "payment_intent" => parse(&object).map(WebhookObject::PaymentIntent),
+            "payment_method" => parse(&object).map(WebhookObject::PaymentMethod),
+            "payout" => parse(&object).map(WebhookObject::Payout),
+            "plan" => parse(&object).map(WebhookObject::Plan),
+            "price" => parse(&object).map(WebhookObject::Price),
+            "product" => parse(&object).map(WebhookObject::Product),
+            "promotion_code" => parse(&object).map(WebhookObject::PromotionCode),
+            "quote" => parse(&object).map(WebhookObject::Quote),
+            "setup_intent" => parse(&object).map(WebhookObject::SetupIntent),
+            "subscription_schedule" => parse(&object).map(WebhookObject::SubscriptionSchedule),
+            "tax_rate" => parse(&object).map(WebhookObject::TaxRate),
+            "topup" => parse(&object).map(WebhookObject::Topup),
+            "transfer" => parse(&object).map(WebhookObject::Transfer),
+            _ => None,
+        };
+
+        typed.unwrap_or(WebhookObject::Other(object))
+    }
+}
+
+/// A verified webhook event.
+#[derive(PartialEq, Debug, Clone)]
+pub struct WebhookEvent {
+    /// The event, with its `type` and `id`.
+    pub event: types::Event,
+    pub object: WebhookObject,
+    /// What changed in the object, for `*.updated` events.
+    pub previous_attributes: Option<serde_json::Value>,
+}
+
+impl WebhookEvent {
+    /// Parse the body of an event.
+    pub fn parse(body: &[u8]) -> ClientResult<Self> {
+        let mut payload: serde_json::Value = serde_json::from_slice(body)?;
+
+        // `types::Event` doesn't know what's in its `data`, so take it out to
+        // parse for ourselves.
+        let mut take = |field: &str| {
+            payload
+                .get_mut("data")
+                .and_then(|data| data.get_mut(field))
+                .map(|value| std::mem::replace(value, serde_json::json!({})))
+        };
+        let object = take("object").unwrap_or_default();
+        let previous_attributes = take("previous_attributes");
+        let event: types::Event = serde_json::from_value(payload)?;
+
+        Ok(WebhookEvent {
+            object: WebhookObject::parse(&event.type_, object),
+            event,
+            previous_attributes,
+        })
+    }
+}
+
+/// Holds the endpoint's signing secrets and how old an event may be, and
+/// parses the events that pass, with the object they're about.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    secrets: Vec<Vec<u8>>,
+    tolerance: i64,
+}
+
+impl WebhookHandler {
+    /// Create a handler for events signed with the endpoint's secret.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: AsRef<[u8]>,
+    {
+        WebhookHandler {
+            secrets: vec![secret.as_ref().to_vec()],
+            tolerance: DEFAULT_TOLERANCE_SECS,
+        }
+    }
+
+    /// Also accept events signed with another secret, e.g. the one being
+    /// rolled out.
+    pub fn with_secret<S>(&mut self, secret: S) -> &mut Self
+    where
+        S: AsRef<[u8]>,
+    {
+        self.secrets.push(secret.as_ref().to_vec());
+        self
+    }
+
+    /// Accept events up to `tolerance` seconds old, rather than
+    /// `DEFAULT_TOLERANCE_SECS`.
+    pub fn with_tolerance(&mut self, tolerance: i64) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Verify an event from its headers and raw body, then parse it.
+    pub fn handle(&self, headers: &http::HeaderMap, body: &[u8]) -> ClientResult<WebhookEvent> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(ClientError::MissingWebhookHeader {
+                header: SIGNATURE_HEADER,
+            })?;
+
+        verify_signature(
+            &self.secrets,
+            signature,
+            body,
+            self.tolerance,
+            chrono::Utc::now().timestamp(),
+        )?;
+
+        WebhookEvent::parse(body)
+    }
+}
@@ -1 +1,504 @@
+#[test]
+fn test_encode_form() {
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("order_id".to_string(), "6735".to_string());
 
+    let body = dolladollabills::types::PostSubscriptionsRequest {
+        customer: "cus_4QFOF3xrvBT2nU".to_string(),
+        items: vec![dolladollabills::types::SubscriptionItemCreateParams {
+            metadata: Default::default(),
+            price: "price_1KrQvZ".to_string(),
+            quantity: 2,
+            tax_rates: vec!["txr_1".to_string(), "txr_2".to_string()],
+        }],
+        metadata,
+        cancel_at_period_end: Some(false),
+        ..Default::default()
+    };
+
+    let encoded = dolladollabills::utils::encode_form(&body).unwrap();
+    assert_eq!(
+        encoded,
+        "cancel_at_period_end=false&customer=cus_4QFOF3xrvBT2nU&items%5B0%5D%5Bprice%5D=price_1KrQvZ&items%5B0%5D%5Bquantity%5D=2&items%5B0%5D%5Btax_rates%5D%5B0%5D=txr_1&items%5B0%5D%5Btax_rates%5D%5B1%5D=txr_2&metadata%5Border_id%5D=6735"
+    );
+}
+
+const CHARGE: &str = r#"{
+  "id": "ch_3MmlLrLkdIwHu7ix0snN0B15",
+  "object": "charge",
+  "amount": 1099,
+  "amount_captured": 1099,
+  "amount_refunded": 0,
+  "application": null,
+  "application_fee": null,
+  "application_fee_amount": null,
+  "balance_transaction": "txn_3MmlLrLkdIwHu7ix0uke3Ezy",
+  "billing_details": {
+    "address": {
+      "city": null,
+      "country": null,
+      "line1": null,
+      "line2": null,
+      "postal_code": null,
+      "state": null
+    },
+    "email": null,
+    "name": null,
+    "phone": null
+  },
+  "calculated_statement_descriptor": "Stripe",
+  "captured": true,
+  "created": 1679090539,
+  "currency": "usd",
+  "customer": CUSTOMER,
+  "description": null,
+  "disputed": false,
+  "failure_balance_transaction": null,
+  "failure_code": null,
+  "failure_message": null,
+  "fraud_details": {},
+  "invoice": null,
+  "livemode": false,
+  "metadata": {},
+  "on_behalf_of": null,
+  "outcome": {
+    "network_status": "approved_by_network",
+    "reason": null,
+    "risk_level": "normal",
+    "risk_score": 32,
+    "seller_message": "Payment complete.",
+    "type": "authorized"
+  },
+  "paid": true,
+  "payment_intent": null,
+  "payment_method": "card_1MmlLrLkdIwHu7ixIJwEWSNR",
+  "receipt_email": null,
+  "receipt_number": null,
+  "receipt_url": "https://pay.stripe.com/receipts/payment/CAcaFwoVYWNjdF8xTTJKVGtMa2RJd0h1N2l4KOvG06AGMgZfBXyr1aw6LBa9vaaSRWU96d8qBwz9z2J_CObiV_H2-e8RezSK_sw0KISesp4czsOUlVKY",
+  "refunded": false,
+  "refunds": {
+    "object": "list",
+    "data": [],
+    "has_more": false,
+    "total_count": 0,
+    "url": "/v1/charges/ch_3MmlLrLkdIwHu7ix0snN0B15/refunds"
+  },
+  "review": null,
+  "shipping": null,
+  "source_transfer": null,
+  "statement_descriptor": null,
+  "statement_descriptor_suffix": null,
+  "status": "succeeded",
+  "transfer_data": null,
+  "transfer_group": null
+}"#;
+
+const CUSTOMER: &str = r#"{
+  "id": "cus_NffrFeUfNV2Hib",
+  "object": "customer",
+  "address": null,
+  "balance": 0,
+  "created": 1680893993,
+  "currency": null,
+  "default_source": null,
+  "delinquent": false,
+  "description": null,
+  "discount": null,
+  "email": "jennyrosen@example.com",
+  "invoice_prefix": "0759376C",
+  "invoice_settings": {
+    "custom_fields": null,
+    "default_payment_method": null,
+    "footer": null,
+    "rendering_options": null
+  },
+  "livemode": false,
+  "metadata": {
+    "order_id": "6735"
+  },
+  "name": "Jenny Rosen",
+  "next_invoice_sequence": 1,
+  "phone": null,
+  "preferred_locales": [],
+  "shipping": null,
+  "tax_exempt": "none",
+  "test_clock": null
+}"#;
+
+#[test]
+fn test_deserialize_expandable() {
+    let charge: dolladollabills::types::Charge =
+        serde_json::from_str(&CHARGE.replace("CUSTOMER", r#""cus_NffrFeUfNV2Hib""#)).unwrap();
+    assert_eq!(
+        charge.customer.unwrap().string().unwrap(),
+        "cus_NffrFeUfNV2Hib"
+    );
+
+    let charge: dolladollabills::types::Charge =
+        serde_json::from_str(&CHARGE.replace("CUSTOMER", CUSTOMER)).unwrap();
+    let customer = charge.customer.unwrap().customer().cloned().unwrap();
+    assert_eq!(customer.id, "cus_NffrFeUfNV2Hib");
+    assert_eq!(customer.metadata.get("order_id").unwrap(), "6735");
+}
+
+#[test]
+fn test_range_query_args() {
+    use dolladollabills::types::{RangeQuery, RangeQueryAnyOf};
+
+    let query = |created: RangeQueryAnyOf| {
+        let args = dolladollabills::utils::deep_object_query_args("created", &created).unwrap();
+        serde_urlencoded::to_string(&args).unwrap()
+    };
+
+    assert_eq!(
+        query(RangeQueryAnyOf::RangeQuery(RangeQuery {
+            gte: Some(1680000000),
+            lt: Some(1680086400),
+            ..Default::default()
+        })),
+        "created%5Bgte%5D=1680000000&created%5Blt%5D=1680086400"
+    );
+    // Zero is a bound, not a missing one.
+    assert_eq!(
+        query(RangeQueryAnyOf::RangeQuery(RangeQuery {
+            gte: Some(0),
+            ..Default::default()
+        })),
+        "created%5Bgte%5D=0"
+    );
+    assert_eq!(query(1680000000.into()), "created=1680000000");
+}
+
+#[test]
+fn test_deserialize_search_result() {
+    let page: dolladollabills::types::CustomerSearchResult = serde_json::from_str(&format!(
+        r#"{{
+  "object": "search_result",
+  "url": "/v1/customers/search",
+  "has_more": true,
+  "data": [{}],
+  "next_page": "WzE2ODA4OTM5OTMsImN1c19OZmZyRmVVZk5WMkhpYiJd"
+}}"#,
+        CUSTOMER
+    ))
+    .unwrap();
+    assert!(page.has_more);
+    assert_eq!(
+        page.next_page,
+        "WzE2ODA4OTM5OTMsImN1c19OZmZyRmVVZk5WMkhpYiJd"
+    );
+    assert_eq!(page.data[0].email, "jennyrosen@example.com");
+}
+
+#[tokio::test]
+async fn test_get_all_search_follows_next_page() {
+    use wiremock::{
+        matchers::{method, path, query_param, query_param_is_missing},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+    let customer = |id: &str| {
+        let mut customer: serde_json::Value = serde_json::from_str(CUSTOMER).unwrap();
+        customer["id"] = id.into();
+        customer
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/search"))
+        .and(query_param("query", "email:'jennyrosen@example.com'"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "search_result",
+            "url": "/v1/customers/search",
+            "has_more": true,
+            "data": [customer("cus_1"), customer("cus_2")],
+            "next_page": "WzE2ODA4OTM5OTNd",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The last page still names a next page, but says there isn't one.
+    Mock::given(method("GET"))
+        .and(path("/v1/customers/search"))
+        .and(query_param("query", "email:'jennyrosen@example.com'"))
+        .and(query_param("page", "WzE2ODA4OTM5OTNd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "search_result",
+            "url": "/v1/customers/search",
+            "has_more": false,
+            "data": [customer("cus_3")],
+            "next_page": "WzE2ODA4OTM5OTRd",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stripe = dolladollabills::Client::new("sk_test_4eC39HqLyjWDarjtT1zdp7dc");
+    stripe.with_host_override(server.uri());
+
+    let customers = stripe
+        .customers()
+        .get_all_search(&[], "email:'jennyrosen@example.com'")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        customers.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(),
+        vec!["cus_1", "cus_2", "cus_3"]
+    );
+}
+
+#[tokio::test]
+async fn test_api_key_is_sent_with_basic_auth() {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+
+    // The key is the username, and there's no password.
+    Mock::given(method("GET"))
+        .and(path("/v1/balance"))
+        .and(header(
+            "authorization",
+            format!(
+                "Basic {}",
+                base64::encode("sk_test_4eC39HqLyjWDarjtT1zdp7dc:")
+            )
+            .as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "balance",
+            "livemode": false,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stripe = dolladollabills::Client::new("sk_test_4eC39HqLyjWDarjtT1zdp7dc");
+    stripe.with_host_override(server.uri());
+
+    let balance = stripe.balance().get(&[]).await.unwrap();
+    assert!(!balance.livemode);
+}
+
+#[cfg(feature = "webhooks")]
+mod webhooks {
+    use dolladollabills::{
+        webhooks::{sign, verify_signature, WebhookHandler, WebhookObject},
+        ClientError,
+    };
+
+    const ENDPOINT_SECRET: &str = "whsec_test_secret";
+
+    fn event(type_: &str, object: serde_json::Value) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "id": "evt_1MqqbKLt4dXK03v5qaIbiNCC",
+            "object": "event",
+            "api_version": "2020-08-27",
+            "created": 1680064028,
+            "data": {
+                "object": object,
+                "previous_attributes": { "status": "trialing" },
+            },
+            "livemode": false,
+            "pending_webhooks": 1,
+            "request": { "id": null, "idempotency_key": null },
+            "type": type_,
+        }))
+        .unwrap()
+    }
+
+    /// Headers for `body`, signed at `timestamp` with the endpoint's secret.
+    fn signed_at(timestamp: i64, body: &[u8]) -> http::HeaderMap {
+        let signature = sign(ENDPOINT_SECRET.as_bytes(), timestamp, body);
+        let mut headers = http::HeaderMap::new();
+        headers.insert("Stripe-Signature", signature.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let body = br#"{"id":"evt_1"}"#;
+        let now = 1680064028;
+        let signature = sign(ENDPOINT_SECRET.as_bytes(), now, body);
+
+        assert_eq!(
+            verify_signature(&[ENDPOINT_SECRET], &signature, body, 300, now).unwrap(),
+            now
+        );
+        assert_eq!(
+            verify_signature(&[ENDPOINT_SECRET], &signature, body, 300, now + 300).unwrap(),
+            now
+        );
+        assert!(matches!(
+            verify_signature(&[ENDPOINT_SECRET], &signature, body, 300, now + 301),
+            Err(ClientError::StaleWebhook { timestamp }) if timestamp == now
+        ));
+        // Too old to even subtract from now.
+        let ancient = sign(ENDPOINT_SECRET.as_bytes(), i64::MIN, body);
+        assert!(matches!(
+            verify_signature(&[ENDPOINT_SECRET], &ancient, body, 300, now),
+            Err(ClientError::StaleWebhook { timestamp }) if timestamp == i64::MIN
+        ));
+
+        let digest = &signature[signature.find("v1=").unwrap() + 3..];
+        let forged = [
+            sign(b"whsec_another", now, body),
+            // Signed a second later, then backdated.
+            sign(ENDPOINT_SECRET.as_bytes(), now + 1, body)
+                .replace(&format!("t={}", now + 1), &format!("t={}", now)),
+            format!("v1={}", digest),
+            format!("t={},v0={}", now, digest),
+            format!("t={},v1=not hex", now),
+        ];
+        assert!(forged.iter().all(|bad| matches!(
+            verify_signature(&[ENDPOINT_SECRET], bad, body, 300, now),
+            Err(ClientError::InvalidWebhookSignature)
+        )));
+    }
+
+    #[test]
+    fn test_sign_matches_a_known_signature() {
+        // From `openssl dgst -sha256 -hmac whsec_test_secret` of `{timestamp}.{body}`.
+        let body = br#"{"id":"evt_test_webhook","object":"event"}"#;
+        let signature =
+            "t=1680064028,v1=99f50a54ff573b172dc5659721a53dba7b72840cc6e8ce796504c6caf071d8bb";
+
+        assert_eq!(
+            sign(ENDPOINT_SECRET.as_bytes(), 1680064028, body),
+            signature
+        );
+        assert_eq!(
+            verify_signature(&[ENDPOINT_SECRET], signature, body, 300, 1680064028).unwrap(),
+            1680064028
+        );
+    }
+
+    #[test]
+    fn test_verify_signature_while_rolling_secrets() {
+        let body = br#"{"id":"evt_1"}"#;
+        let now = 1680064028;
+
+        // Stripe signs with both secrets while the old one is still live.
+        let old = sign(b"whsec_old", now, body);
+        let new = sign(b"whsec_new", now, body);
+        let both = format!("{},{}", old, &new[new.find(",").unwrap() + 1..]);
+
+        verify_signature(&[b"whsec_new"], &both, body, 300, now).unwrap();
+        verify_signature(&[b"whsec_old"], &both, body, 300, now).unwrap();
+        // And we may have either secret configured.
+        verify_signature(&["whsec_other", "whsec_old"], &old, body, 300, now).unwrap();
+    }
+
+    #[test]
+    fn test_handle_subscription_updated() {
+        let body = event(
+            "customer.subscription.updated",
+            serde_json::json!({
+                "id": "sub_1MowQVLkdIwHu7ixeRlqHVzs",
+                "object": "subscription",
+                "automatic_tax": { "enabled": false },
+                "billing_cycle_anchor": 1679609767,
+                "cancel_at_period_end": false,
+                "collection_method": "charge_automatically",
+                "created": 1679609767,
+                "currency": "usd",
+                "current_period_end": 1682288167,
+                "current_period_start": 1679609767,
+                "customer": "cus_Na6dX7aXxi11N4",
+                "items": {
+                    "object": "list",
+                    "data": [],
+                    "has_more": false,
+                    "url": "/v1/subscription_items?subscription=sub_1MowQVLkdIwHu7ixeRlqHVzs",
+                },
+                "livemode": false,
+                "metadata": {},
+                "start_date": 1679609767,
+                "status": "active",
+            }),
+        );
+        let mut handler = WebhookHandler::new("whsec_old");
+        handler.with_secret(ENDPOINT_SECRET);
+
+        let event = handler
+            .handle(&signed_at(chrono::Utc::now().timestamp(), &body), &body)
+            .unwrap();
+
+        assert_eq!(event.event.id, "evt_1MqqbKLt4dXK03v5qaIbiNCC");
+        assert_eq!(event.event.type_, "customer.subscription.updated");
+        assert_eq!(
+            event.previous_attributes,
+            Some(serde_json::json!({ "status": "trialing" }))
+        );
+        match event.object {
+            WebhookObject::Subscription(subscription) => {
+                assert_eq!(subscription.id, "sub_1MowQVLkdIwHu7ixeRlqHVzs");
+                assert_eq!(subscription.current_period_end, 1682288167);
+            }
+            o => panic!("expected a subscription, got {:?}", o),
+        }
+    }
+
+    #[test]
+    fn test_handle_customer_and_unknown_events() {
+        let handler = WebhookHandler::new(ENDPOINT_SECRET);
+        let now = chrono::Utc::now().timestamp();
+
+        let body = event(
+            "customer.created",
+            serde_json::json!({
+                "id": "cus_NffrFeUfNV2Hib",
+                "object": "customer",
+                "created": 1680893993,
+                "email": "jennyrosen@example.com",
+                "livemode": false,
+                "metadata": {},
+                "name": "Jenny Rosen",
+            }),
+        );
+        let received = handler.handle(&signed_at(now, &body), &body).unwrap();
+        match received.object {
+            WebhookObject::Customer(customer) => {
+                assert_eq!(customer.email, "jennyrosen@example.com")
+            }
+            o => panic!("expected a customer, got {:?}", o),
+        }
+
+        // Events we don't have a type for keep their object as it came.
+        let object = serde_json::json!({ "id": "cs_1", "object": "climate.order" });
+        let body = event("climate.order.created", object.clone());
+        let received = handler.handle(&signed_at(now, &body), &body).unwrap();
+        assert_eq!(received.object, WebhookObject::Other(object));
+
+        // Nor do we lose an event whose object doesn't fit its type.
+        let object = serde_json::json!({ "id": "cus_1", "object": "customer", "address": 7 });
+        let body = event("customer.updated", object.clone());
+        let received = handler.handle(&signed_at(now, &body), &body).unwrap();
+        assert_eq!(received.event.type_, "customer.updated");
+        assert_eq!(received.object, WebhookObject::Other(object));
+    }
+
+    #[test]
+    fn test_handle_rejects_bad_events() {
+        let handler = WebhookHandler::new(ENDPOINT_SECRET);
+        let body = event("customer.deleted", serde_json::json!({ "id": "cus_1" }));
+
+        assert!(matches!(
+            handler.handle(&http::HeaderMap::new(), &body),
+            Err(ClientError::MissingWebhookHeader {
+                header: "Stripe-Signature"
+            })
+        ));
+
+        let stale = signed_at(chrono::Utc::now().timestamp() - 600, &body);
+        assert!(matches!(
+            handler.handle(&stale, &body),
+            Err(ClientError::StaleWebhook { .. })
+        ));
+    }
+}
@@ -99,6 +99,34 @@ impl BookingData {
         booking_status: crate::types::BookingStatus,
         booking_type: crate::types::BookingType,
     ) -> ClientResult<Vec<crate::types::BookingReport>> {
+        futures::TryStreamExt::try_collect(self.get_all_booking_report_stream(
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            booking_type,
+        ))
+        .await
+    }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Return booking rows filtered by the parameters you select.
+     */
+    pub fn get_all_booking_report_stream(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BookingReport>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
             query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
@@ -120,64 +148,40 @@ impl BookingData {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
+        crate::utils::paginate(
+            crate::utils::add_query_param(
+                &crate::utils::add_query_param(&url, "page", "0"),
+                "size",
+                "100",
+            ),
+            move |page_url| {
+                let url = url.to_string();
+                async move {
+                    let resp: crate::types::BookingReportResponse = self
+                        .client
+                        .get(
+                            &page_url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
 
-        let mut resp: crate::types::BookingReportResponse = if !url.contains('?') {
-            self.client
-                .get(
-                    &format!("{}?page=0&size=100", url),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await?
-        } else {
-            self.client
-                .get(
-                    &format!("{}&page=0&size=100", url),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await?
-        };
-
-        let mut data = resp.data;
-        let mut page = resp.page.current_page + 1;
+                    let page = resp.page.current_page + 1;
+                    let next = if page < resp.page.total_pages {
+                        Some(crate::utils::add_query_param(
+                            &crate::utils::add_query_param(&url, "page", &page.to_string()),
+                            "size",
+                            "100",
+                        ))
+                    } else {
+                        None
+                    };
 
-        // Paginate if we should.
-        while page <= (resp.page.total_pages - 1) {
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?page={}&size=100", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&page={}&size=100", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            page = resp.page.current_page + 1;
-        }
-
-        // Return our response data.
-        Ok(data)
+                    Ok((resp.data, next))
+                }
+            },
+        )
     }
 }
@@ -95,6 +95,36 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> ClientResult<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        futures::TryStreamExt::try_collect(self.list_all_archived_files_stream(
+            from,
+            to,
+            query_date_type,
+        ))
+        .await
+    }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_all_archived_files`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Zoom’s [archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) allows account administrators to set up an automated mechanism to record, collect and archive meeting data to a 3rd party platform of their choice and hence, satisfy FINRA and/ or other compliance requirements.<br><br>
+     * Use this API to retrieve archived meeting or webinar files of an account.
+     *
+     * **Scope:** `recording:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br><br>
+     * **Prerequisites:** <br>
+     * * Enable cloud recording.
+     * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     */
+    pub fn list_all_archived_files_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListArchivedFilesResponseMeetings>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -107,58 +137,36 @@ impl Archiving {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/archive_files?{}", query_), None);
-        let mut resp: crate::types::ListArchivedFilesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut meetings = resp.meetings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListArchivedFilesResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            meetings.append(&mut resp.meetings);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.meetings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(meetings)
+        })
     }
     /**
      * Get meeting archived files.
@@ -85,6 +85,27 @@ impl ChatChannels {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::Channels>> {
+        futures::TryStreamExt::try_collect(self.get_all_channels_stream(user_id)).await
+    }
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_all_channels`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list a user's chat channels. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * Zoom chat [channels](https://support.zoom.us/hc/en-us/articles/200912909-Getting-Started-With-Channels-Group-Messaging-) allow users to communicate via chat in private or public groups.
+     *
+     * **Scopes:** `chat_channel:read` or `chat_channel:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> This API supports both user-managed apps and account-level apps. However, in an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, to list channels of another user in the same Zoom account, the user calling this API must have a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for the <b>Chat channels</b> feature.</p>
+     */
+    pub fn get_all_channels_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Channels>> + '_ {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels",
@@ -92,58 +113,36 @@ impl ChatChannels {
             ),
             None,
         );
-        let mut resp: crate::types::GetChannelsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut channels = resp.channels;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetChannelsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            channels.append(&mut resp.channels);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.channels, next))
             }
-        }
-
-        // Return our response data.
-        Ok(channels)
+        })
     }
     /**
      * Create a channel.
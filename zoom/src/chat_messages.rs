@@ -122,6 +122,41 @@ impl ChatMessages {
         date: chrono::NaiveDate,
         include_deleted_and_edited_message: &str,
     ) -> ClientResult<Vec<crate::types::Messages>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            include_deleted_and_edited_message,
+        ))
+        .await
+    }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list the current user's chat messages between the user and an individual contact or a chat channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide one of the following:
+     *
+     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
+     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Messages>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.to_string().is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -147,58 +182,36 @@ impl ChatMessages {
             ),
             None,
         );
-        let mut resp: crate::types::GetChatMessagesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut messages = resp.messages;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetChatMessagesResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            messages.append(&mut resp.messages);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.messages, next))
             }
-        }
-
-        // Return our response data.
-        Ok(messages)
+        })
     }
     /**
      * Send a chat message.
@@ -740,6 +740,36 @@ impl CloudRecording {
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        futures::TryStreamExt::try_collect(self.get_all_account_stream(account_id, from, to)).await
+    }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_account`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List [Cloud Recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) available on an Account.
+     *
+     * > To access a password protected cloud recording, add an "access_token" parameter to the download URL and provide [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the value of the "access_token".
+     * <br>
+     * **Prerequisites**:<br>
+     * * A Pro or a higher paid plan with Cloud Recording option enabled.<br>
+     * **Scopes**: `recording:read:admin` or `account:read:admin`
+     *
+     * If the scope `recording:read:admin` is used, the Account ID of the Account must be provided in the `accountId` path parameter to list recordings that belong to the Account. This scope only works for sub accounts.
+     *
+     * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn get_all_account_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::GetAccountCloudRecordingResponseMeetings>,
+    > + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = from {
             query_args.push(("from".to_string(), date.to_rfc3339()));
@@ -756,57 +786,35 @@ impl CloudRecording {
             ),
             None,
         );
-        let mut resp: crate::types::GetAccountCloudRecordingResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut meetings = resp.meetings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetAccountCloudRecordingResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            meetings.append(&mut resp.meetings);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.meetings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(meetings)
+        })
     }
 }
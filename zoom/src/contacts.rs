@@ -86,6 +86,29 @@ impl Contacts {
         search_key: &str,
         query_presence_status: &str,
     ) -> ClientResult<Vec<crate::types::Contacts>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_search_company_stream(search_key, query_presence_status),
+        )
+        .await
+    }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `get_all_search_company`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * A user under an organization's Zoom account has internal users listed under Company Contacts in the Zoom Client. Use this API to search users that are in the company contacts of a Zoom account. Using the `search_key` query parameter, provide either first name, last name or the email address of the user that you would like to search for. Optionally, set `query_presence_status` to `true` in order to include the presence status of a contact. <br><br>
+     *
+     * **Scopes:** `contact:read:admin`, `contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_search_company_stream(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contacts>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !query_presence_status.is_empty() {
             query_args.push((
@@ -98,58 +121,36 @@ impl Contacts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/contacts?{}", query_), None);
-        let mut resp: crate::types::SearchCompanyContactsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut contacts = resp.contacts;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::SearchCompanyContactsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            contacts.append(&mut resp.contacts);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.contacts, next))
             }
-        }
-
-        // Return our response data.
-        Ok(contacts)
+        })
     }
     /**
      * List user's contacts.
@@ -225,6 +226,27 @@ impl Contacts {
         &self,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::GetUserContactsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_stream(type_)).await
+    }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_all_user`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * A user under an organization’s Zoom account has internal users listed under Company Contacts in the Zoom Client. A Zoom user can also add another Zoom user as a [contact](https://support.zoom.us/hc/en-us/articles/115004055706-Managing-Contacts). Call this API to list all the contacts of a Zoom user. Zoom contacts are categorized into "company contacts" and "external contacts". You must specify the contact type in the `type` query parameter. If you do not specify, by default, the type will be set as company contact.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note: </b> This API only supports <b>user-managed</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>.</p><br>
+     *
+     * **Scope**: `chat_contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_user_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserContactsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -233,58 +255,36 @@ impl Contacts {
         let url = self
             .client
             .url(&format!("/chat/users/me/contacts?{}", query_), None);
-        let mut resp: crate::types::GetUserContactsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut contacts = resp.contacts;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetUserContactsResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            contacts.append(&mut resp.contacts);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.contacts, next))
             }
-        }
-
-        // Return our response data.
-        Ok(contacts)
+        })
     }
     /**
      * Get user's contact details.
@@ -1362,6 +1362,40 @@ impl Dashboards {
         site_id: &str,
         quality_type: &str,
     ) -> ClientResult<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_call_logs_metrics_stream(
+            from,
+            to,
+            site_id,
+            quality_type,
+        ))
+        .await
+    }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_all_call_logs_metrics`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Call logs provide a record of all incoming and outgoing calls over Zoom Phone in an account.
+     *
+     * Use this API to list monthly call logs metrics. You can use query parameters to filter the response by date, site and MOS(Mean Opinion Score) of the call.
+     *
+     * **Prerequisites:**
+     * * Business, or Education account
+     * * Zoom Phone license <br><br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
+     * **Rate Limit Label:** `Heavy`
+     */
+    pub fn list_all_call_logs_metrics_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListCallLogsMetricsResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1379,58 +1413,36 @@ impl Dashboards {
         let url = self
             .client
             .url(&format!("/phone/metrics/call_logs?{}", query_), None);
-        let mut resp: crate::types::ListCallLogsMetricsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_logs = resp.call_logs;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListCallLogsMetricsResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            call_logs.append(&mut resp.call_logs);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.call_logs, next))
             }
-        }
-
-        // Return our response data.
-        Ok(call_logs)
+        })
     }
     /**
      * Get call details from call log.
@@ -1600,6 +1612,35 @@ impl Dashboards {
         meeting_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> ClientResult<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_participant_feedback_stream(meeting_id, type_),
+        )
+        .await
+    }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_feedback`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * When a meeting ends, each attendee will be prompted to share their meeting experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific meeting. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     * <br> **Scope:** `dashboard_meetings:read:admiin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -1613,58 +1654,36 @@ impl Dashboards {
             ),
             None,
         );
-        let mut resp: crate::types::ParticipantFeedbackResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut participants = resp.participants;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ParticipantFeedbackResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            participants.append(&mut resp.participants);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.participants, next))
             }
-        }
-
-        // Return our response data.
-        Ok(participants)
+        })
     }
     /**
      * Get post webinar feedback.
@@ -1756,6 +1775,36 @@ impl Dashboards {
         type_: crate::types::DashboardMeetingsType,
         webinar_id: &str,
     ) -> ClientResult<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_participant_webinar_feedback_stream(type_, webinar_id),
+        )
+        .await
+    }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_webinar_feedback`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     *
+     * <br> **Scope:** `dashboard_webinars:read:admin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_webinar_feedback_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -1769,57 +1818,35 @@ impl Dashboards {
             ),
             None,
         );
-        let mut resp: crate::types::ParticipantFeedbackResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut participants = resp.participants;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ParticipantFeedbackResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            participants.append(&mut resp.participants);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.participants, next))
             }
-        }
-
-        // Return our response data.
-        Ok(participants)
+        })
     }
 }
@@ -251,6 +251,26 @@ impl Groups {
         &self,
         group_id: &str,
     ) -> ClientResult<Vec<crate::types::UserCreateResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_members_stream(group_id)).await
+    }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List the members of a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) under your account.
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_members_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserCreateResponse>> + '_ {
         let url = self.client.url(
             &format!(
                 "/groups/{}/members",
@@ -258,58 +278,36 @@ impl Groups {
             ),
             None,
         );
-        let mut resp: crate::types::GroupMembersResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut members = resp.members;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GroupMembersResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            members.append(&mut resp.members);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.members, next))
             }
-        }
-
-        // Return our response data.
-        Ok(members)
+        })
     }
     /**
      * Add group members.
@@ -222,6 +222,33 @@ impl ImChat {
         channel: &str,
         date: &str,
     ) -> ClientResult<Vec<crate::types::ListimmessagesResponseMessages>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_im_messages_stream(user_id, chat_user, channel, date),
+        )
+        .await
+    }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_all_im_messages`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Get IM Chat messages for a specified period of time. This API only supports Oauth2.<br>
+     * **Scopes:** `imchat:read`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     */
+    pub fn list_all_im_messages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListimmessagesResponseMessages>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -241,58 +268,36 @@ impl ImChat {
             ),
             None,
         );
-        let mut resp: crate::types::ListimmessagesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut messages = resp.messages;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListimmessagesResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            messages.append(&mut resp.messages);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.messages, next))
             }
-        }
-
-        // Return our response data.
-        Ok(messages)
+        })
     }
     /**
      * Send IM messages.
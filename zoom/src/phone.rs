@@ -151,6 +151,39 @@ impl Phone {
         pending_numbers: bool,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_account_numbers_stream(
+            type_,
+            extension_type,
+            number_type,
+            pending_numbers,
+            site_id,
+        ))
+        .await
+    }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_all_account_numbers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list all Zoom Phone numbers in a Zoom account.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_all_account_numbers_stream(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListAccountPhoneNumbersResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !extension_type.to_string().is_empty() {
             query_args.push(("extension_type".to_string(), extension_type.to_string()));
@@ -169,58 +202,36 @@ impl Phone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
-        let mut resp: crate::types::ListAccountPhoneNumbersResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut phone_numbers = resp.phone_numbers;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListAccountPhoneNumbersResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            phone_numbers.append(&mut resp.phone_numbers);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.phone_numbers, next))
             }
-        }
-
-        // Return our response data.
-        Ok(phone_numbers)
+        })
     }
     /**
      * Get user's profile.
@@ -462,6 +473,27 @@ impl Phone {
         &self,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::Templates>> {
+        futures::TryStreamExt::try_collect(self.list_all_setting_templates_stream(site_id)).await
+    }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_all_setting_templates`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to get a list of all the created phone template settings.
+     *
+     * **Scopes:** `phone:read:admin` or `phone:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn list_all_setting_templates_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Templates>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !site_id.is_empty() {
             query_args.push(("site_id".to_string(), site_id.to_string()));
@@ -470,58 +502,36 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/setting_templates?{}", query_), None);
-        let mut resp: crate::types::ListSettingTemplatesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut templates = resp.templates;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListSettingTemplatesResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            templates.append(&mut resp.templates);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.templates, next))
             }
-        }
-
-        // Return our response data.
-        Ok(templates)
+        })
     }
     /**
      * Add a setting template.
@@ -639,59 +649,57 @@ impl Phone {
     pub async fn list_all_locations(
         &self,
     ) -> ClientResult<Vec<crate::types::ListLocationsResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_locations_stream()).await
+    }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_all_locations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list emergency service locations.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_locations_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListLocationsResponse>> + '_ {
         let url = self.client.url("/phone/locations", None);
-        let mut resp: crate::types::ListLocationsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut locations = resp.locations;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListLocationsResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            locations.append(&mut resp.locations);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.locations, next))
             }
-        }
-
-        // Return our response data.
-        Ok(locations)
+        })
     }
     /**
      * Add emergency service location.
@@ -893,59 +901,57 @@ impl Phone {
      * * Account owner or admin permissions
      */
     pub async fn list_all_sip_groups(&self) -> ClientResult<Vec<crate::types::SipGroups>> {
+        futures::TryStreamExt::try_collect(self.list_all_sip_groups_stream()).await
+    }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_all_sip_groups`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_sip_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SipGroups>> + '_ {
         let url = self.client.url("/phone/sip_groups", None);
-        let mut resp: crate::types::ListSipGroupsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut sip_groups = resp.sip_groups;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListSipGroupsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            sip_groups.append(&mut resp.sip_groups);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.sip_groups, next))
             }
-        }
-
-        // Return our response data.
-        Ok(sip_groups)
+        })
     }
     /**
      * Get setting template details.
@@ -1139,6 +1145,40 @@ impl Phone {
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> ClientResult<Vec<crate::types::CallLogs>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_call_logs_stream(
+            user_id,
+            from,
+            to,
+            type_,
+            phone_number,
+            time_type,
+        ))
+        .await
+    }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `get_all_user_call_logs`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_call_logs_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallLogs>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1164,58 +1204,36 @@ impl Phone {
             ),
             None,
         );
-        let mut resp: crate::types::PhoneUserCallLogsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_logs = resp.call_logs;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PhoneUserCallLogsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            call_logs.append(&mut resp.call_logs);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.call_logs, next))
             }
-        }
-
-        // Return our response data.
-        Ok(call_logs)
+        })
     }
     /**
      * Get user's recordings.
@@ -1303,6 +1321,30 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::Recordings>> {
+        futures::TryStreamExt::try_collect(self.get_all_user_recordings_stream(user_id, from, to))
+            .await
+    }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_user_recordings`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_recordings_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Recordings>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1319,58 +1361,36 @@ impl Phone {
             ),
             None,
         );
-        let mut resp: crate::types::PhoneUserRecordingsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut recordings = resp.recordings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PhoneUserRecordingsResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            recordings.append(&mut resp.recordings);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.recordings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(recordings)
+        })
     }
     /**
      * Get user's voicemails.
@@ -1447,7 +1467,34 @@ impl Phone {
      *
      * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
      *
-     * As opposed to `user_voice_mails`, this function returns all the pages of the request at once.
+     * As opposed to `user_voice_mails`, this function returns all the pages of the request at once.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub async fn get_all_user_voice_mails(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<Vec<crate::types::VoiceMails>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_user_voice_mails_stream(user_id, status, from, to),
+        )
+        .await
+    }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `get_all_user_voice_mails`, this function returns a stream of the items, fetching each page only as the stream is polled.
      *
      * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
@@ -1457,13 +1504,13 @@ impl Phone {
      * * A Business or Enterprise account
      * * A Zoom Phone license
      */
-    pub async fn get_all_user_voice_mails(
+    pub fn get_all_user_voice_mails_stream(
         &self,
         user_id: &str,
         status: crate::types::PhoneUserVoiceMailsStatus,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
-    ) -> ClientResult<Vec<crate::types::VoiceMails>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::VoiceMails>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1483,58 +1530,36 @@ impl Phone {
             ),
             None,
         );
-        let mut resp: crate::types::PhoneUserVoiceMailsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut voice_mails = resp.voice_mails;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::PhoneUserVoiceMailsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            voice_mails.append(&mut resp.voice_mails);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.voice_mails, next))
             }
-        }
-
-        // Return our response data.
-        Ok(voice_mails)
+        })
     }
     /**
      * Set up shared access.
@@ -1784,6 +1809,36 @@ impl Phone {
         time_type: crate::types::TimeType,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::AccountCallLogsResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.get_all_account_call_logs_stream(from, to, type_, path, time_type, site_id),
+        )
+        .await
+    }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `get_all_account_call_logs`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
+     *
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     */
+    pub fn get_all_account_call_logs_stream(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AccountCallLogsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1807,58 +1862,36 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/call_logs?{}", query_), None);
-        let mut resp: crate::types::AccountCallLogsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_logs = resp.call_logs;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::AccountCallLogsResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            call_logs.append(&mut resp.call_logs);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.call_logs, next))
             }
-        }
-
-        // Return our response data.
-        Ok(call_logs)
+        })
     }
     /**
      * Assign phone number to user.
@@ -2116,6 +2149,42 @@ impl Phone {
         site_id: &str,
         query_date_type: crate::types::QueryDateType,
     ) -> ClientResult<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_recordings_stream(
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        ))
+        .await
+    }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_all_recordings`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     *
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
+     */
+    pub fn get_all_recordings_stream(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetPhoneRecordingsResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -2139,58 +2208,36 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/recordings?{}", query_), None);
-        let mut resp: crate::types::GetPhoneRecordingsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut recordings = resp.recordings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetPhoneRecordingsResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            recordings.append(&mut resp.recordings);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.recordings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(recordings)
+        })
     }
     /**
      * List BYOC SIP trunks.
@@ -2254,59 +2301,56 @@ impl Phone {
      * * A Business or Enterprise account
      */
     pub async fn list_all_byocsip_trunk(&self) -> ClientResult<Vec<crate::types::ByocSipTrunk>> {
+        futures::TryStreamExt::try_collect(self.list_all_byocsip_trunk_stream()).await
+    }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_all_byocsip_trunk`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn list_all_byocsip_trunk_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ByocSipTrunk>> + '_ {
         let url = self.client.url("/phone/sip_trunk/trunks", None);
-        let mut resp: crate::types::ListByocsipTrunkResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut byoc_sip_trunk = resp.byoc_sip_trunk;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListByocsipTrunkResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            byoc_sip_trunk.append(&mut resp.byoc_sip_trunk);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.byoc_sip_trunk, next))
             }
-        }
-
-        // Return our response data.
-        Ok(byoc_sip_trunk)
+        })
     }
     /**
      * Assign SIP trunks.
@@ -2453,59 +2497,57 @@ impl Phone {
     pub async fn list_all_external_contacts(
         &self,
     ) -> ClientResult<Vec<crate::types::ExternalContacts>> {
+        futures::TryStreamExt::try_collect(self.list_all_external_contacts_stream()).await
+    }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_all_external_contacts`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_external_contacts_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ExternalContacts>> + '_ {
         let url = self.client.url("/phone/external_contacts", None);
-        let mut resp: crate::types::ListExternalContactsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut external_contacts = resp.external_contacts;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListExternalContactsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
-
-            external_contacts.append(&mut resp.external_contacts);
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.external_contacts, next))
             }
-        }
-
-        // Return our response data.
-        Ok(external_contacts)
+        })
     }
     /**
      * Add an external contact.
@@ -2850,64 +2892,63 @@ impl Phone {
         &self,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::ListPhoneUsersResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_users_stream(site_id)).await
+    }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_all_users_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListPhoneUsersResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !site_id.is_empty() {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/users?{}", query_), None);
-        let mut resp: crate::types::ListPhoneUsersResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut users = resp.users;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListPhoneUsersResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            users.append(&mut resp.users);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
+
+                Ok((resp.users, next))
             }
-        }
-
-        // Return our response data.
-        Ok(users)
+        })
     }
     /**
      * Get call log details.
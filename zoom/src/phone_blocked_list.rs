@@ -75,59 +75,57 @@ impl PhoneBlockedList {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
      */
     pub async fn list_all_blocked(&self) -> ClientResult<Vec<crate::types::BlockedList>> {
+        futures::TryStreamExt::try_collect(self.list_all_blocked_stream()).await
+    }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_all_blocked`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * A Zoom account owner or a user with admin privilege can block phone numbers for phone users in an account. Blocked numbers can be inbound (numbers will be blocked from calling in) and outbound (phone users in your account won't be able to dial those numbers). Blocked callers will hear a generic message stating that the person they are calling is not available.<br>Use this API to list all the blocked lists in an acccount.<br>
+     * **Prerequisites:**
+     * * Pro or higher account plan with Zoom phone license<br>
+     * **Scope:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_blocked_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlockedList>> + '_ {
         let url = self.client.url("/phone/blocked_list", None);
-        let mut resp: crate::types::ListBlockedResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut blocked_list = resp.blocked_list;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListBlockedResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            blocked_list.append(&mut resp.blocked_list);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.blocked_list, next))
             }
-        }
-
-        // Return our response data.
-        Ok(blocked_list)
+        })
     }
     /**
      * Create a blocked list.
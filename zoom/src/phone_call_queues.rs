@@ -81,59 +81,60 @@ impl PhoneCallQueues {
      *
      */
     pub async fn list_all_call_queues(&self) -> ClientResult<Vec<crate::types::CallQueues>> {
+        futures::TryStreamExt::try_collect(self.list_all_call_queues_stream()).await
+    }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_all_call_queues`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Call queues allow you to route incoming calls to a group of users. For instance, you can use call queues to route calls to various departments in your organization such as sales, engineering, billing, customer service etc.<br> Use this API to list Call queues.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro, Business, or Education account
+     * * Account owner or admin permissions
+     * * Zoom Phone license<br>
+     * **Scopes:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn list_all_call_queues_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallQueues>> + '_ {
         let url = self.client.url("/phone/call_queues", None);
-        let mut resp: crate::types::ListCallQueuesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_queues = resp.call_queues;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListCallQueuesResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            call_queues.append(&mut resp.call_queues);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.call_queues, next))
             }
-        }
-
-        // Return our response data.
-        Ok(call_queues)
+        })
     }
     /**
      * Create a call queue.
@@ -656,6 +657,36 @@ impl PhoneCallQueues {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_call_queue_recordings_stream(
+            call_queue_id,
+            from,
+            to,
+        ))
+        .await
+    }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_call_queue_recordings`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to view [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091#h_cbc9f2a3-e06c-4daa-83d4-ddbceef9c77b) from the call queue.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or higher account with Zoom Phone license.
+     * * [Automatic call recordings](https://support.zoom.us/hc/en-us/articles/360033511872#h_fcb297bb-14e8-4094-91ca-dc61e1a18734) must be enabled in the Policy Settings for call queues. <br> **Scope:** `phone:read:admin`<br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     *
+     *
+     *
+     */
+    pub fn get_all_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCallQueueRecordingsResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -672,57 +703,35 @@ impl PhoneCallQueues {
             ),
             None,
         );
-        let mut resp: crate::types::GetCallQueueRecordingsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut recordings = resp.recordings;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::GetCallQueueRecordingsResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            recordings.append(&mut resp.recordings);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.recordings, next))
             }
-        }
-
-        // Return our response data.
-        Ok(recordings)
+        })
     }
 }
@@ -81,64 +81,64 @@ impl PhoneDevices {
         &self,
         type_: crate::types::ListPhoneDevicesType,
     ) -> ClientResult<Vec<crate::types::ListPhoneDevicesResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_stream(type_)).await
+    }
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List all the [desk phone devices](https://support.zoom.us/hc/en-us/articles/360021119092) that are configured with Zoom Phone on an account.
+     *
+     * **Scopes:** `phone:read:admin`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**<br>
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListPhoneDevicesResponse>> + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/devices?{}", query_), None);
-        let mut resp: crate::types::ListPhoneDevicesResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut devices = resp.devices;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListPhoneDevicesResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            devices.append(&mut resp.devices);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.devices, next))
             }
-        }
-
-        // Return our response data.
-        Ok(devices)
+        })
     }
     /**
      * Add a device.
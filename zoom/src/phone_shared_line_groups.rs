@@ -83,59 +83,60 @@ impl PhoneSharedLineGroups {
     pub async fn list_all_shared_line_groups(
         &self,
     ) -> ClientResult<Vec<crate::types::SharedLineGroups>> {
+        futures::TryStreamExt::try_collect(self.list_all_shared_line_groups_stream()).await
+    }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_all_shared_line_groups`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * A [shared line group](https://support.zoom.us/hc/en-us/articles/360038850792) allows Zoom Phone admins to share a phone number and extension with a group of phone users or common area phones. This gives members of the shared line group access to the group's direct phone number and voicemail. Use this API to list all the Shared Line Groups.
+     *
+     * **Prerequisties:** <br>
+     * * Pro or higher account with Zoom Phone license.
+     * * Account owner or admin privileges  <br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`
+     *
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_shared_line_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SharedLineGroups>> + '_ {
         let url = self.client.url("/phone/shared_line_groups", None);
-        let mut resp: crate::types::ListSharedLineGroupsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut shared_line_groups = resp.shared_line_groups;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListSharedLineGroupsResponse = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            shared_line_groups.append(&mut resp.shared_line_groups);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.shared_line_groups, next))
             }
-        }
-
-        // Return our response data.
-        Ok(shared_line_groups)
+        })
     }
     /**
      * Create a shared line group.
@@ -748,6 +748,27 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::ActivityLogs>> {
+        futures::TryStreamExt::try_collect(self.get_all_sign_out_activities_stream(from, to)).await
+    }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `get_all_sign_out_activities`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Retrieve a list of sign in / sign out activity logs [report](https://support.zoom.us/hc/en-us/articles/201363213-Getting-Started-with-Reports) of users under a Zoom account.<br>
+     * **Prerequisites**<br>
+     * * Pro or higher plan.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn get_all_sign_out_activities_stream(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ActivityLogs>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -759,58 +780,36 @@ impl Reports {
         let url = self
             .client
             .url(&format!("/report/activities?{}", query_), None);
-        let mut resp: crate::types::ReportSignInOutActivitiesResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut activity_logs = resp.activity_logs;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ReportSignInOutActivitiesResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            activity_logs.append(&mut resp.activity_logs);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.activity_logs, next))
             }
-        }
-
-        // Return our response data.
-        Ok(activity_logs)
+        })
     }
     /**
      * Get billing reports.
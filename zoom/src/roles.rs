@@ -151,6 +151,27 @@ impl Roles {
         role_id: &str,
         page_count: &str,
     ) -> ClientResult<Vec<crate::types::Domains>> {
+        futures::TryStreamExt::try_collect(self.get_all_members_stream(role_id, page_count)).await
+    }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * User [roles](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) can have a set of permissions that allows access only to the pages a user needs to view or edit. Use this API to list all the members that are assigned a specific role.
+     *
+     * **Scope:** `role:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
+     * * A Pro or a higher plan.
+     */
+    pub fn get_all_members_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Domains>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_count.is_empty() {
             query_args.push(("page_count".to_string(), page_count.to_string()));
@@ -164,58 +185,36 @@ impl Roles {
             ),
             None,
         );
-        let mut resp: crate::types::RoleMembersList = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut members = resp.members;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::RoleMembersList = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            members.append(&mut resp.members);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.members, next))
             }
-        }
-
-        // Return our response data.
-        Ok(members)
+        })
     }
     /**
      * Assign a role.
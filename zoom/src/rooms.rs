@@ -95,6 +95,34 @@ impl Rooms {
         unassigned_rooms: bool,
         location_id: &str,
     ) -> ClientResult<Vec<crate::types::ListZoomRoomsResponse>> {
+        futures::TryStreamExt::try_collect(self.list_all_zoom_stream(
+            status,
+            type_,
+            unassigned_rooms,
+            location_id,
+        ))
+        .await
+    }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_all_zoom`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Zoom Rooms is a software-based room system that provides an integrated experience for audio conferencing, wireless screen sharing and video conferencing. Use this API to list all the existing [Zoom Rooms](https://support.zoom.us/hc/en-us/articles/207483343-Getting-Started-with-Zoom-Rooms) in a Zoom account.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
+     * **Scopes**: `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zoom_stream(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListZoomRoomsResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !location_id.is_empty() {
             query_args.push(("location_id".to_string(), location_id.to_string()));
@@ -110,58 +138,36 @@ impl Rooms {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/rooms?{}", query_), None);
-        let mut resp: crate::types::ListZoomRoomsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut rooms = resp.rooms;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListZoomRoomsResponseData = self
                     .client
                     .get(
-                        &format!("{}?next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
 
-            rooms.append(&mut resp.rooms);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.rooms, next))
             }
-        }
-
-        // Return our response data.
-        Ok(rooms)
+        })
     }
     /**
      * Add a Zoom Room.
@@ -576,6 +582,32 @@ impl Rooms {
         type_: &str,
         folder_id: &str,
     ) -> ClientResult<Vec<crate::types::Site>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_digital_signage_content_stream(type_, folder_id),
+        )
+        .await
+    }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_all_digital_signage_content`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * List information about existing [Zoom Rooms digital signage](https://support.zoom.us/hc/en-us/articles/360000030683-Zoom-Rooms-digital-signage) content in a Zoom account.<br> You can also access this information by logging into your Zoom account in the Zoom web portal and visiting the [Digital Signage Content](https://zoom.us/digitalsignage#/) page listed under **Room Management**.
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Rooms.
+     * * Existing content files or folder in [Digital Signage Content](https://zoom.us/digitalsignage#/) page.
+     *
+     *
+     *
+     */
+    pub fn list_all_digital_signage_content_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Site>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !folder_id.is_empty() {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
@@ -587,58 +619,36 @@ impl Rooms {
         let url = self
             .client
             .url(&format!("/rooms/digital_signage?{}", query_), None);
-        let mut resp: crate::types::ListDigitalSignageContentResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut contents = resp.contents;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListDigitalSignageContentResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            contents.append(&mut resp.contents);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.contents, next))
             }
-        }
-
-        // Return our response data.
-        Ok(contents)
+        })
     }
     /**
      * Update E911 digital signage.
@@ -93,6 +93,31 @@ impl RoomsLocation {
         parent_location_id: &str,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::AddAzrLocationResponse>> {
+        futures::TryStreamExt::try_collect(
+            self.list_all_zr_locations_stream(parent_location_id, type_),
+        )
+        .await
+    }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_all_zr_locations`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * A Zoom account owner or a Zoom Room administrator can establish a [location hierarchy](https://support.zoom.us/hc/en-us/articles/115000342983-Zoom-Rooms-Location-Hierarchy) to help manage Zoom Rooms that are spread among a variety of locations. Use this API to list the different location types used for Zoom Rooms in an account.<br><br>
+     * **Prerequisites:**
+     * * Account owner or admin permissions.
+     * * Zoom Rooms Version 4.0 or higher<br><br>
+     * **Scopes:** `room:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zr_locations_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AddAzrLocationResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !parent_location_id.is_empty() {
             query_args.push((
@@ -107,58 +132,36 @@ impl RoomsLocation {
         let url = self
             .client
             .url(&format!("/rooms/locations?{}", query_), None);
-        let mut resp: crate::types::ListZrLocationsResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut locations = resp.locations;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListZrLocationsResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            locations.append(&mut resp.locations);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.locations, next))
             }
-        }
-
-        // Return our response data.
-        Ok(locations)
+        })
     }
     /**
      * Add a location.
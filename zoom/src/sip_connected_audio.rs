@@ -443,6 +443,27 @@ impl SipConnectedAudio {
         &self,
         account_id: &str,
     ) -> ClientResult<Vec<crate::types::InternalNumbers>> {
+        futures::TryStreamExt::try_collect(self.list_all_internal_numbers_stream(account_id)).await
+    }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_all_internal_numbers`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * This API allows a master account with SIP Connected Audio plan to list internal phone numbers (i.e., numbers that are not provided by Zoom but are owned by the organization consuming the API) assigned to a master account or a sub account.<br><br>To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
+     * <br><b>Prerequisites:</b><br>
+     * * The account making this API request must be a [master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) with SIP Connected Audio Plan.<br><br>
+     * **Scope:** `sip_trunk:master`
+     *
+     *
+     *
+     */
+    pub fn list_all_internal_numbers_stream(
+        &self,
+        account_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InternalNumbers>> + '_ {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/internal_numbers",
@@ -450,58 +471,36 @@ impl SipConnectedAudio {
             ),
             None,
         );
-        let mut resp: crate::types::ListInternalNumbersResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut internal_numbers = resp.internal_numbers;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListInternalNumbersResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            internal_numbers.append(&mut resp.internal_numbers);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.internal_numbers, next))
             }
-        }
-
-        // Return our response data.
-        Ok(internal_numbers)
+        })
     }
     /**
      * Add internal numbers.
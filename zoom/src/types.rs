@@ -35424,3 +35424,21 @@ pub struct ListArchivedFilesResponse {
     )]
     pub to: String,
 }
+
+/// The `multipart/form-data` body of a request.
+#[derive(Debug, Clone, Default)]
+pub struct UserPictureForm {
+    /// The file's path.
+    pub pic_file: crate::utils::FilePart,
+}
+
+impl UserPictureForm {
+    /// Turn this into a form to send.
+    pub fn into_form(self) -> crate::ClientResult<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new();
+        let pic_file = self.pic_file;
+        form = form.part("pic_file", pic_file.into_part()?);
+
+        Ok(form)
+    }
+}
@@ -97,6 +97,26 @@ impl Users {
         role_id: &str,
         include_fields: crate::types::UsersIncludeFields,
     ) -> ClientResult<Vec<crate::types::UsersResponse>> {
+        futures::TryStreamExt::try_collect(self.get_all_stream(status, role_id, include_fields))
+            .await
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list your account's users.
+     *
+     * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersResponse>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -109,58 +129,36 @@ impl Users {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
-        let mut resp: crate::types::UsersResponseData = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut users = resp.users;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::UsersResponseData = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            users.append(&mut resp.users);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.users, next))
             }
-        }
-
-        // Return our response data.
-        Ok(users)
+        })
     }
     /**
      * Create users.
@@ -665,7 +663,11 @@ impl Users {
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
-    pub async fn picture(&self, user_id: &str) -> ClientResult<()> {
+    pub async fn picture(
+        &self,
+        user_id: &str,
+        body: crate::types::UserPictureForm,
+    ) -> ClientResult<()> {
         let url = self.client.url(
             &format!(
                 "/users/{}/picture",
@@ -674,13 +676,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
+            .request_form(reqwest::Method::POST, &url, body.into_form()?)
             .await
     }
     /**
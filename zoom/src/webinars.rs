@@ -337,6 +337,31 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> ClientResult<Vec<crate::types::Participants>> {
+        futures::TryStreamExt::try_collect(self.list_all_participants_stream(webinar_id)).await
+    }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_all_participants`, this function returns a stream of the items, fetching each page only as the stream is polled.
+     *
+     * Use this API to list all the participants who attended a webinar hosted in the past. <br>
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     */
+    pub fn list_all_participants_stream(
+        &self,
+        webinar_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Participants>> + '_ {
         let url = self.client.url(
             &format!(
                 "/past_webinars/{}/participants",
@@ -344,58 +369,36 @@ impl Webinars {
             ),
             None,
         );
-        let mut resp: crate::types::ListWebinarParticipantsResponse = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut participants = resp.participants;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                resp = self
+        crate::utils::paginate(url.to_string(), move |page_url| {
+            let url = url.to_string();
+            async move {
+                let resp: crate::types::ListWebinarParticipantsResponse = self
                     .client
                     .get(
-                        &format!("{}&next_page_token={}", url, page),
+                        &page_url,
                         crate::Message {
                             body: None,
                             content_type: None,
                         },
                     )
                     .await?;
-            }
 
-            participants.append(&mut resp.participants);
+                // Keep going for as long as we are handed a new page token.
+                let mut next = None;
+                if !resp.next_page_token.is_empty() {
+                    let next_url = crate::utils::add_query_param(
+                        &url,
+                        "next_page_token",
+                        &resp.next_page_token,
+                    );
+                    if next_url != page_url {
+                        next = Some(next_url);
+                    }
+                }
 
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
+                Ok((resp.participants, next))
             }
-        }
-
-        // Return our response data.
-        Ok(participants)
+        })
     }
     /**
      * Update webinar status.